@@ -33,7 +33,7 @@ macro_rules! bench_euler {
 }
 
 fn bench_euler_3d(c: &mut Criterion) {
-    let mut group = c.benchmark_group("euler 3d");
+    let mut group = benchmark_group!(c, "euler 3d");
     for size in [10000].iter() {
         group.throughput(Throughput::Elements(*size as u64));
         bench_glam!(group, size, |b, size| {
@@ -83,12 +83,16 @@ fn bench_euler_3d(c: &mut Criterion) {
             use pathfinder_geometry::vector::Vector4F;
             bench_euler!(b, size, ty => Vector4F, zero => Vector4F::splat(0.0), dt => Vector4F::splat(UPDATE_RATE))
         });
+        bench_reference!(group, size, |b, size| {
+            use mathbench::reference::Vec3;
+            bench_euler!(b, size, ty => Vec3, zero => Vec3([0.0; 3]), dt => UPDATE_RATE)
+        });
     }
     group.finish();
 }
 
 fn bench_euler_2d(c: &mut Criterion) {
-    let mut group = c.benchmark_group("euler 2d");
+    let mut group = benchmark_group!(c, "euler 2d");
     for size in [10000].iter() {
         group.throughput(Throughput::Elements(*size as u64));
         bench_glam!(group, size, |b, size| {
@@ -138,6 +142,10 @@ fn bench_euler_2d(c: &mut Criterion) {
             use pathfinder_geometry::vector::Vector2F;
             bench_euler!(b, size, ty => Vector2F, zero => Vector2F::splat(0.0), dt => Vector2F::splat(UPDATE_RATE))
         });
+        bench_reference!(group, size, |b, size| {
+            use mathbench::reference::Vec2;
+            bench_euler!(b, size, ty => Vec2, zero => Vec2([0.0; 2]), dt => UPDATE_RATE)
+        });
     }
     group.finish();
 }
@@ -4,9 +4,18 @@ mod macros;
 use criterion::{criterion_group, criterion_main, Criterion};
 
 fn bench_transform_point3(c: &mut Criterion) {
-    let mut group = c.benchmark_group("isometry transform point3");
+    let mut group = benchmark_group!(c, "isometry transform point3");
     for size in [1, 100].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench_glam!(group, size, |b, size| {
+            use glam::{Quat, Vec3};
+            use mathbench::glam_support::Isometry3Ext;
+            bench_binop!(b, size, op => transform_point3, ty1 => (Quat, Vec3), ty2 => Vec3)
+        });
+        bench_cgmath!(group, size, |b, size| {
+            use cgmath::{Decomposed, Point3, Quaternion, Transform, Vector3};
+            bench_binop!(b, size, op => transform_point, ty1 => Decomposed<Vector3<f32>, Quaternion<f32>>, ty2 => Point3<f32>)
+        });
         bench_ultraviolet!(group, size, |b, size| {
             use ultraviolet::{Isometry3, Vec3};
             bench_binop!(b, size, op => transform_vec, ty1 => Isometry3, ty2 => Vec3)
@@ -34,12 +43,17 @@ fn bench_transform_point3(c: &mut Criterion) {
             use simba::simd::f32x16;
             bench_binop16!(b, size, op => transform_point, ty1 => Isometry3<f32x16>, ty2 => Point3<f32x16>, param => by_ref)
         });
+        bench_vek!(group, size, |b, size| {
+            use mathbench::vek_support::Isometry3Ext;
+            use vek::{Transform, Vec3};
+            bench_binop!(b, size, op => mul_point, ty1 => Transform<f32, f32, f32>, ty2 => Vec3<f32>)
+        });
     }
     group.finish();
 }
 
 fn bench_transform_point2(c: &mut Criterion) {
-    let mut group = c.benchmark_group("isometry transform point2");
+    let mut group = benchmark_group!(c, "isometry transform point2");
     for size in [1, 100].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench_ultraviolet!(group, size, |b, size| {
@@ -5,7 +5,7 @@ use criterion::{criterion_group, criterion_main, Criterion};
 
 fn bench_isometry2_ret_self(c: &mut Criterion) {
     use mathbench::BenchValue;
-    let mut group = c.benchmark_group("isometry2 return self");
+    let mut group = benchmark_group!(c, "isometry2 return self");
     bench_ultraviolet!(group, |b| {
         use ultraviolet::Isometry2;
         bench_unop!(b, op => ret_self, ty => Isometry2)
@@ -37,7 +37,7 @@ fn bench_isometry2_ret_self(c: &mut Criterion) {
 }
 
 fn bench_isometry2_inverse(c: &mut Criterion) {
-    let mut group = c.benchmark_group("isometry2 inverse");
+    let mut group = benchmark_group!(c, "isometry2 inverse");
     bench_ultraviolet!(group, |b| {
         use ultraviolet::Isometry2;
         bench_unop!(b, op => inversed, ty => Isometry2)
@@ -70,7 +70,7 @@ fn bench_isometry2_inverse(c: &mut Criterion) {
 
 fn bench_isometry2_mul_isometry2(c: &mut Criterion) {
     use std::ops::Mul;
-    let mut group = c.benchmark_group("isometry2 mul isometry2");
+    let mut group = benchmark_group!(c, "isometry2 mul isometry2");
     bench_ultraviolet!(group, |b| {
         use ultraviolet::Isometry2;
         bench_binop!(b, op => mul, ty1 => Isometry2, ty2 => Isometry2)
@@ -5,7 +5,15 @@ use criterion::{criterion_group, criterion_main, Criterion};
 
 fn bench_isometry3_ret_self(c: &mut Criterion) {
     use mathbench::BenchValue;
-    let mut group = c.benchmark_group("isometry3 return self");
+    let mut group = benchmark_group!(c, "isometry3 return self");
+    bench_glam!(group, |b| {
+        use glam::{Quat, Vec3};
+        bench_unop!(b, op => ret_self, ty => (Quat, Vec3))
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::{Decomposed, Quaternion, Vector3};
+        bench_unop!(b, op => ret_self, ty => Decomposed<Vector3<f32>, Quaternion<f32>>)
+    });
     bench_ultraviolet!(group, |b| {
         use ultraviolet::Isometry3;
         bench_unop!(b, op => ret_self, ty => Isometry3)
@@ -33,11 +41,24 @@ fn bench_isometry3_ret_self(c: &mut Criterion) {
         use simba::simd::f32x16;
         bench_unop16!(b, op => ret_self, ty => Isometry3<f32x16>)
     });
+    bench_vek!(group, |b| {
+        use vek::Transform;
+        bench_unop!(b, op => ret_self, ty => Transform<f32, f32, f32>)
+    });
     group.finish();
 }
 
 fn bench_isometry3_inverse(c: &mut Criterion) {
-    let mut group = c.benchmark_group("isometry3 inverse");
+    let mut group = benchmark_group!(c, "isometry3 inverse");
+    bench_glam!(group, |b| {
+        use glam::{Quat, Vec3};
+        use mathbench::glam_support::Isometry3Ext;
+        bench_unop!(b, op => inverse, ty => (Quat, Vec3))
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::{Decomposed, Quaternion, Transform, Vector3};
+        bench_unop!(b, op => inverse_transform, ty => Decomposed<Vector3<f32>, Quaternion<f32>>)
+    });
     bench_ultraviolet!(group, |b| {
         use ultraviolet::Isometry3;
         bench_unop!(b, op => inversed, ty => Isometry3)
@@ -65,12 +86,26 @@ fn bench_isometry3_inverse(c: &mut Criterion) {
         use simba::simd::f32x16;
         bench_unop16!(b, op => inverse, ty => Isometry3<f32x16>)
     });
+    bench_vek!(group, |b| {
+        use mathbench::vek_support::Isometry3Ext;
+        use vek::Transform;
+        bench_unop!(b, op => inverted, ty => Transform<f32, f32, f32>)
+    });
     group.finish();
 }
 
 fn bench_isometry3_mul_isometry3(c: &mut Criterion) {
     use std::ops::Mul;
-    let mut group = c.benchmark_group("isometry3 mul isometry3");
+    let mut group = benchmark_group!(c, "isometry3 mul isometry3");
+    bench_glam!(group, |b| {
+        use glam::{Quat, Vec3};
+        use mathbench::glam_support::Isometry3Ext;
+        bench_binop!(b, op => mul_isometry3, ty1 => (Quat, Vec3), ty2 => (Quat, Vec3))
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::{Decomposed, Quaternion, Transform, Vector3};
+        bench_binop!(b, op => concat, ty => Decomposed<Vector3<f32>, Quaternion<f32>>, param => by_ref)
+    });
     bench_ultraviolet!(group, |b| {
         use ultraviolet::Isometry3;
         bench_binop!(b, op => mul, ty1 => Isometry3, ty2 => Isometry3)
@@ -98,6 +133,11 @@ fn bench_isometry3_mul_isometry3(c: &mut Criterion) {
         use simba::simd::f32x16;
         bench_binop16!(b, op => mul, ty1 => Isometry3<f32x16>, ty2 => Isometry3<f32x16>, param => by_ref)
     });
+    bench_vek!(group, |b| {
+        use mathbench::vek_support::Isometry3Ext;
+        use vek::Transform;
+        bench_binop!(b, op => mul_isometry, ty1 => Transform<f32, f32, f32>, ty2 => Transform<f32, f32, f32>)
+    });
     group.finish();
 }
 
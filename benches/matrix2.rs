@@ -6,7 +6,7 @@ use criterion::{criterion_group, criterion_main, Criterion};
 // returns self to check overhead of benchmark
 fn bench_matrix2_ret_self(c: &mut Criterion) {
     use mathbench::BenchValue;
-    let mut group = c.benchmark_group("matrix2 return self");
+    let mut group = benchmark_group!(c, "matrix2 return self");
     bench_glam!(group, |b| {
         use glam::Mat2;
         bench_unop!(b, op => ret_self, ty => Mat2)
@@ -50,11 +50,15 @@ fn bench_matrix2_ret_self(c: &mut Criterion) {
         use pathfinder_geometry::transform2d::Matrix2x2F;
         bench_unop!(b, op => ret_self, ty => Matrix2x2F)
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat2;
+        bench_unop!(b, op => ret_self, ty => Mat2)
+    });
     group.finish();
 }
 
 fn bench_matrix2_transpose(c: &mut Criterion) {
-    let mut group = c.benchmark_group("matrix2 transpose");
+    let mut group = benchmark_group!(c, "matrix2 transpose");
     bench_glam!(group, |b| {
         use glam::Mat2;
         bench_unop!(b, op => transpose, ty => Mat2)
@@ -75,6 +79,11 @@ fn bench_matrix2_transpose(c: &mut Criterion) {
         use nalgebra::Matrix2;
         bench_unop!(b, op => transpose, ty => Matrix2<f32>)
     });
+    bench_nalgebra_glm!(group, |b| {
+        use mathbench::nalgebra_glm_support::MatrixExt;
+        use nalgebra_glm as glm;
+        bench_unop!(b, op => glm_transpose, ty => glm::Mat2)
+    });
     bench_nalgebra_f32x4!(group, |b| {
         use nalgebra::Matrix2;
         use simba::simd::f32x4;
@@ -94,11 +103,15 @@ fn bench_matrix2_transpose(c: &mut Criterion) {
         use vek::Mat2;
         bench_unop!(b, op => transposed, ty => Mat2<f32>)
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat2;
+        bench_unop!(b, op => transpose, ty => Mat2)
+    });
     group.finish();
 }
 
 fn bench_matrix2_determinant(c: &mut Criterion) {
-    let mut group = c.benchmark_group("matrix2 determinant");
+    let mut group = benchmark_group!(c, "matrix2 determinant");
     bench_glam!(group, |b| {
         use glam::Mat2;
         bench_unop!(b, op => determinant, ty => Mat2)
@@ -111,6 +124,11 @@ fn bench_matrix2_determinant(c: &mut Criterion) {
         use nalgebra::Matrix2;
         bench_unop!(b, op => determinant, ty => Matrix2<f32>)
     });
+    bench_nalgebra_glm!(group, |b| {
+        use mathbench::nalgebra_glm_support::MatrixExt;
+        use nalgebra_glm as glm;
+        bench_unop!(b, op => glm_determinant, ty => glm::Mat2)
+    });
     bench_vek!(group, |b| {
         use vek::Mat2;
         bench_unop!(b, op => determinant, ty => Mat2<f32>)
@@ -119,11 +137,15 @@ fn bench_matrix2_determinant(c: &mut Criterion) {
         use pathfinder_geometry::transform2d::Matrix2x2F;
         bench_unop!(b, op => det, ty => Matrix2x2F)
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat2;
+        bench_unop!(b, op => determinant, ty => Mat2)
+    });
     group.finish();
 }
 
 fn bench_matrix2_inverse(c: &mut Criterion) {
-    let mut group = c.benchmark_group("matrix2 inverse");
+    let mut group = benchmark_group!(c, "matrix2 inverse");
     bench_glam!(group, |b| {
         use glam::Mat2;
         bench_unop!(b, op => inverse, ty => Mat2)
@@ -144,16 +166,25 @@ fn bench_matrix2_inverse(c: &mut Criterion) {
         use nalgebra::Matrix2;
         bench_unop!(b, op => try_inverse, ty => Matrix2<f32>)
     });
+    bench_nalgebra_glm!(group, |b| {
+        use mathbench::nalgebra_glm_support::MatrixExt;
+        use nalgebra_glm as glm;
+        bench_unop!(b, op => glm_inverse, ty => glm::Mat2)
+    });
     bench_pathfinder!(group, |b| {
         use pathfinder_geometry::transform2d::Matrix2x2F;
         bench_unop!(b, op => inverse, ty => Matrix2x2F)
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat2;
+        bench_unop!(b, op => inverse, ty => Mat2)
+    });
     group.finish();
 }
 
 fn bench_matrix2_mul_matrix2(c: &mut Criterion) {
     use std::ops::Mul;
-    let mut group = c.benchmark_group("matrix2 mul matrix2");
+    let mut group = benchmark_group!(c, "matrix2 mul matrix2");
     bench_glam!(group, |b| {
         use glam::Mat2;
         bench_binop!(b, op => mul, ty1 => Mat2, ty2 => Mat2)
@@ -197,12 +228,16 @@ fn bench_matrix2_mul_matrix2(c: &mut Criterion) {
         use pathfinder_geometry::transform2d::Matrix2x2F;
         bench_binop!(b, op => mul, ty1 => Matrix2x2F, ty2 => Matrix2x2F)
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat2;
+        bench_binop!(b, op => mul, ty1 => Mat2, ty2 => Mat2)
+    });
     group.finish();
 }
 
 fn bench_matrix2_mul_vector2(c: &mut Criterion) {
     use std::ops::Mul;
-    let mut group = c.benchmark_group("matrix2 mul vector2");
+    let mut group = benchmark_group!(c, "matrix2 mul vector2");
     for size in [1, 100].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench_glam!(group, size, |b, size| {
@@ -248,10 +283,308 @@ fn bench_matrix2_mul_vector2(c: &mut Criterion) {
             use pathfinder_geometry::{transform2d::Matrix2x2F, vector::Vector2F};
             bench_binop!(b, size, op => mul, ty1 => Matrix2x2F, ty2 => Vector2F)
         });
+        bench_reference!(group, size, |b, size| {
+            use mathbench::reference::{Mat2, Vec2};
+            bench_binop!(b, size, op => mul, ty1 => Mat2, ty2 => Vec2)
+        });
     }
     group.finish();
 }
 
+fn bench_matrix2_add_matrix2(c: &mut Criterion) {
+    use std::ops::Add;
+    let mut group = benchmark_group!(c, "matrix2 add matrix2");
+    bench_glam!(group, |b| {
+        use glam::Mat2;
+        bench_binop!(b, op => add, ty1 => Mat2, ty2 => Mat2)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::Matrix2;
+        bench_binop!(b, op => add, ty1 => Matrix2<f32>, ty2 => Matrix2<f32>)
+    });
+    bench_ultraviolet!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Mat2;
+        bench_binop!(b, op => add_matrix, ty1 => Mat2, ty2 => Mat2)
+    });
+    bench_ultraviolet_f32x4!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Wat2;
+        bench_binop4!(b, op => add_matrix, ty1 => Wat2, ty2 => Wat2)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix2;
+        bench_binop!(b, op => add, ty1 => Matrix2<f32>, ty2 => Matrix2<f32>)
+    });
+    bench_nalgebra_f32x4!(group, |b| {
+        use nalgebra::Matrix2;
+        use simba::simd::f32x4;
+        bench_binop4!(b, op => add, ty1 => Matrix2<f32x4>, ty2 => Matrix2<f32x4>)
+    });
+    bench_nalgebra_f32x8!(group, |b| {
+        use nalgebra::Matrix2;
+        use simba::simd::f32x8;
+        bench_binop8!(b, op => add, ty1 => Matrix2<f32x8>, ty2 => Matrix2<f32x8>)
+    });
+    bench_nalgebra_f32x16!(group, |b| {
+        use nalgebra::Matrix2;
+        use simba::simd::f32x16;
+        bench_binop16!(b, op => add, ty1 => Matrix2<f32x16>, ty2 => Matrix2<f32x16>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat2;
+        bench_binop!(b, op => add, ty1 => Mat2<f32>, ty2 => Mat2<f32>)
+    });
+    bench_pathfinder!(group, |b| {
+        use mathbench::pathfinder_support::Matrix2Ext;
+        use pathfinder_geometry::transform2d::Matrix2x2F;
+        bench_binop!(b, op => add_matrix, ty1 => Matrix2x2F, ty2 => Matrix2x2F)
+    });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat2;
+        bench_binop!(b, op => add, ty1 => Mat2, ty2 => Mat2)
+    });
+    group.finish();
+}
+
+fn bench_matrix2_sub_matrix2(c: &mut Criterion) {
+    use std::ops::Sub;
+    let mut group = benchmark_group!(c, "matrix2 sub matrix2");
+    bench_glam!(group, |b| {
+        use glam::Mat2;
+        bench_binop!(b, op => sub, ty1 => Mat2, ty2 => Mat2)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::Matrix2;
+        bench_binop!(b, op => sub, ty1 => Matrix2<f32>, ty2 => Matrix2<f32>)
+    });
+    bench_ultraviolet!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Mat2;
+        bench_binop!(b, op => sub_matrix, ty1 => Mat2, ty2 => Mat2)
+    });
+    bench_ultraviolet_f32x4!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Wat2;
+        bench_binop4!(b, op => sub_matrix, ty1 => Wat2, ty2 => Wat2)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix2;
+        bench_binop!(b, op => sub, ty1 => Matrix2<f32>, ty2 => Matrix2<f32>)
+    });
+    bench_nalgebra_f32x4!(group, |b| {
+        use nalgebra::Matrix2;
+        use simba::simd::f32x4;
+        bench_binop4!(b, op => sub, ty1 => Matrix2<f32x4>, ty2 => Matrix2<f32x4>)
+    });
+    bench_nalgebra_f32x8!(group, |b| {
+        use nalgebra::Matrix2;
+        use simba::simd::f32x8;
+        bench_binop8!(b, op => sub, ty1 => Matrix2<f32x8>, ty2 => Matrix2<f32x8>)
+    });
+    bench_nalgebra_f32x16!(group, |b| {
+        use nalgebra::Matrix2;
+        use simba::simd::f32x16;
+        bench_binop16!(b, op => sub, ty1 => Matrix2<f32x16>, ty2 => Matrix2<f32x16>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat2;
+        bench_binop!(b, op => sub, ty1 => Mat2<f32>, ty2 => Mat2<f32>)
+    });
+    bench_pathfinder!(group, |b| {
+        use pathfinder_geometry::transform2d::Matrix2x2F;
+        bench_binop!(b, op => sub, ty1 => Matrix2x2F, ty2 => Matrix2x2F)
+    });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat2;
+        bench_binop!(b, op => sub, ty1 => Mat2, ty2 => Mat2)
+    });
+    group.finish();
+}
+
+fn bench_matrix2_mul_scalar(c: &mut Criterion) {
+    use std::ops::Mul;
+    let mut group = benchmark_group!(c, "matrix2 mul scalar");
+    bench_glam!(group, |b| {
+        use glam::Mat2;
+        bench_binop!(b, op => mul, ty1 => Mat2, ty2 => f32)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::Matrix2;
+        bench_binop!(b, op => mul, ty1 => Matrix2<f32>, ty2 => f32)
+    });
+    bench_ultraviolet!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Mat2;
+        bench_binop!(b, op => mul_scalar, ty1 => Mat2, ty2 => f32)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix2;
+        bench_binop!(b, op => mul, ty1 => Matrix2<f32>, ty2 => f32)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat2;
+        bench_binop!(b, op => mul, ty1 => Mat2<f32>, ty2 => f32)
+    });
+    bench_pathfinder!(group, |b| {
+        use mathbench::pathfinder_support::Matrix2Ext;
+        use pathfinder_geometry::transform2d::Matrix2x2F;
+        bench_binop!(b, op => mul_scalar, ty1 => Matrix2x2F, ty2 => f32)
+    });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat2;
+        bench_binop!(b, op => mul, ty1 => Mat2, ty2 => f32)
+    });
+    group.finish();
+}
+
+fn bench_matrix2_transpose_mul_matrix2(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "matrix2 transpose mul matrix2");
+    bench_glam!(group, |b| {
+        use glam::Mat2;
+        use mathbench::glam_support::MatrixExt;
+        bench_binop!(b, op => transpose_mul, ty => Mat2, param => by_ref)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::Matrix2;
+        use mathbench::cgmath_support::MatrixExt;
+        bench_binop!(b, op => transpose_mul, ty => Matrix2<f32>, param => by_ref)
+    });
+    bench_ultraviolet!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Mat2;
+        bench_binop!(b, op => transpose_mul, ty1 => Mat2, ty2 => Mat2)
+    });
+    bench_ultraviolet_f32x4!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Wat2;
+        bench_binop4!(b, op => transpose_mul, ty1 => Wat2, ty2 => Wat2)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix2;
+        bench_binop!(b, op => tr_mul, ty => Matrix2<f32>, param => by_ref)
+    });
+    bench_nalgebra_f32x4!(group, |b| {
+        use nalgebra::Matrix2;
+        use simba::simd::f32x4;
+        bench_binop4!(b, op => tr_mul, ty => Matrix2<f32x4>, param => by_ref)
+    });
+    bench_nalgebra_f32x8!(group, |b| {
+        use nalgebra::Matrix2;
+        use simba::simd::f32x8;
+        bench_binop8!(b, op => tr_mul, ty => Matrix2<f32x8>, param => by_ref)
+    });
+    bench_nalgebra_f32x16!(group, |b| {
+        use nalgebra::Matrix2;
+        use simba::simd::f32x16;
+        bench_binop16!(b, op => tr_mul, ty => Matrix2<f32x16>, param => by_ref)
+    });
+    bench_vek!(group, |b| {
+        use mathbench::vek_support::MatrixExt;
+        use vek::Mat2;
+        bench_binop!(b, op => transposed_mul, ty1 => Mat2<f32>, ty2 => Mat2<f32>)
+    });
+    bench_pathfinder!(group, |b| {
+        use mathbench::pathfinder_support::Matrix2Ext;
+        use pathfinder_geometry::transform2d::Matrix2x2F;
+        bench_binop!(b, op => transpose_mul, ty1 => Matrix2x2F, ty2 => Matrix2x2F)
+    });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat2;
+        bench_binop!(b, op => transpose_mul, ty1 => Mat2, ty2 => Mat2)
+    });
+    group.finish();
+}
+
+fn bench_matrix2_trace(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "matrix2 trace");
+    bench_glam!(group, |b| {
+        use glam::Mat2;
+        use mathbench::glam_support::MatrixExt;
+        bench_unop!(b, op => trace, ty => Mat2)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::{prelude::*, Matrix2};
+        bench_unop!(b, op => trace, ty => Matrix2<f32>)
+    });
+    bench_ultraviolet!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Mat2;
+        bench_unop!(b, op => trace, ty => Mat2)
+    });
+    bench_ultraviolet_f32x4!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Wat2;
+        bench_unop4!(b, op => trace, ty => Wat2)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix2;
+        bench_unop!(b, op => trace, ty => Matrix2<f32>)
+    });
+    bench_nalgebra_f32x4!(group, |b| {
+        use nalgebra::Matrix2;
+        use simba::simd::f32x4;
+        bench_unop4!(b, op => trace, ty => Matrix2<f32x4>)
+    });
+    bench_nalgebra_f32x8!(group, |b| {
+        use nalgebra::Matrix2;
+        use simba::simd::f32x8;
+        bench_unop8!(b, op => trace, ty => Matrix2<f32x8>)
+    });
+    bench_nalgebra_f32x16!(group, |b| {
+        use nalgebra::Matrix2;
+        use simba::simd::f32x16;
+        bench_unop16!(b, op => trace, ty => Matrix2<f32x16>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat2;
+        bench_unop!(b, op => trace, ty => Mat2<f32>)
+    });
+    bench_pathfinder!(group, |b| {
+        use mathbench::pathfinder_support::Matrix2Ext;
+        use pathfinder_geometry::transform2d::Matrix2x2F;
+        bench_unop!(b, op => trace, ty => Matrix2x2F)
+    });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat2;
+        bench_unop!(b, op => trace, ty => Mat2)
+    });
+    group.finish();
+}
+
+fn bench_matrix2_from_cols(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "matrix2 from cols");
+    bench_glam!(group, |b| {
+        use glam::{Mat2, Vec2};
+        bench_ctor!(b, ctor => Mat2::from_cols, ty => Vec2, args => (x, y))
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::{Matrix2, Vector2};
+        bench_ctor!(b, ctor => Matrix2::from_cols, ty => Vector2<f32>, args => (x, y))
+    });
+    bench_ultraviolet!(group, |b| {
+        use ultraviolet::{Mat2, Vec2};
+        bench_ctor!(b, ctor => Mat2::new, ty => Vec2, args => (x, y))
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::{Matrix2, Vector2};
+        bench_ctor!(b, ctor => |x, y| Matrix2::from_columns(&[x, y]), ty => Vector2<f32>, args => (x, y))
+    });
+    bench_vek!(group, |b| {
+        use vek::{Mat2, Vec2};
+        bench_ctor!(b, ctor => |x, y| Mat2 { cols: Vec2::new(x, y) }, ty => Vec2<f32>, args => (x, y))
+    });
+    bench_pathfinder!(group, |b| {
+        use pathfinder_geometry::{transform2d::Matrix2x2F, vector::Vector2F};
+        bench_ctor!(b, ctor => |x: Vector2F, y: Vector2F| Matrix2x2F(x.0.concat_xy_xy(y.0)), ty => Vector2F, args => (x, y))
+    });
+    bench_reference!(group, |b| {
+        use mathbench::reference::{Mat2, Vec2};
+        bench_ctor!(b, ctor => Mat2::from_cols, ty => Vec2, args => (x, y))
+    });
+    group.finish();
+}
+
 criterion_group!(
     matrix2_benches,
     bench_matrix2_ret_self,
@@ -260,5 +593,11 @@ criterion_group!(
     bench_matrix2_inverse,
     bench_matrix2_mul_matrix2,
     bench_matrix2_mul_vector2,
+    bench_matrix2_add_matrix2,
+    bench_matrix2_sub_matrix2,
+    bench_matrix2_mul_scalar,
+    bench_matrix2_transpose_mul_matrix2,
+    bench_matrix2_trace,
+    bench_matrix2_from_cols,
 );
 criterion_main!(matrix2_benches);
@@ -5,7 +5,7 @@ use criterion::{criterion_group, criterion_main, Criterion};
 
 fn bench_matrix3_ret_self(c: &mut Criterion) {
     use mathbench::BenchValue;
-    let mut group = c.benchmark_group("matrix3 return self");
+    let mut group = benchmark_group!(c, "matrix3 return self");
     bench_glam!(group, |b| {
         use glam::Mat3;
         bench_unop!(b, op => ret_self, ty => Mat3)
@@ -45,11 +45,15 @@ fn bench_matrix3_ret_self(c: &mut Criterion) {
         use vek::Mat3;
         bench_unop!(b, op => ret_self, ty => Mat3<f32>)
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat3;
+        bench_unop!(b, op => ret_self, ty => Mat3)
+    });
     group.finish();
 }
 
 fn bench_matrix3_transpose(c: &mut Criterion) {
-    let mut group = c.benchmark_group("matrix3 transpose");
+    let mut group = benchmark_group!(c, "matrix3 transpose");
     bench_glam!(group, |b| {
         use glam::Mat3;
         bench_unop!(b, op => transpose, ty => Mat3)
@@ -70,6 +74,11 @@ fn bench_matrix3_transpose(c: &mut Criterion) {
         use nalgebra::Matrix3;
         bench_unop!(b, op => transpose, ty => Matrix3<f32>)
     });
+    bench_nalgebra_glm!(group, |b| {
+        use mathbench::nalgebra_glm_support::MatrixExt;
+        use nalgebra_glm as glm;
+        bench_unop!(b, op => glm_transpose, ty => glm::Mat3)
+    });
     bench_nalgebra_f32x4!(group, |b| {
         use nalgebra::Matrix3;
         use simba::simd::f32x4;
@@ -89,11 +98,15 @@ fn bench_matrix3_transpose(c: &mut Criterion) {
         use vek::Mat3;
         bench_unop!(b, op => transposed, ty => Mat3<f32>)
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat3;
+        bench_unop!(b, op => transpose, ty => Mat3)
+    });
     group.finish();
 }
 
 fn bench_matrix3_determinant(c: &mut Criterion) {
-    let mut group = c.benchmark_group("matrix3 determinant");
+    let mut group = benchmark_group!(c, "matrix3 determinant");
     bench_glam!(group, |b| {
         use glam::Mat3;
         bench_unop!(b, op => determinant, ty => Mat3)
@@ -106,15 +119,24 @@ fn bench_matrix3_determinant(c: &mut Criterion) {
         use nalgebra::Matrix3;
         bench_unop!(b, op => determinant, ty => Matrix3<f32>)
     });
+    bench_nalgebra_glm!(group, |b| {
+        use mathbench::nalgebra_glm_support::MatrixExt;
+        use nalgebra_glm as glm;
+        bench_unop!(b, op => glm_determinant, ty => glm::Mat3)
+    });
     bench_vek!(group, |b| {
         use vek::Mat3;
         bench_unop!(b, op => determinant, ty => Mat3<f32>)
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat3;
+        bench_unop!(b, op => determinant, ty => Mat3)
+    });
     group.finish();
 }
 
 fn bench_matrix3_inverse(c: &mut Criterion) {
-    let mut group = c.benchmark_group("matrix3 inverse");
+    let mut group = benchmark_group!(c, "matrix3 inverse");
     bench_glam!(group, |b| {
         use glam::Mat3;
         bench_unop!(b, op => inverse, ty => Mat3)
@@ -135,12 +157,21 @@ fn bench_matrix3_inverse(c: &mut Criterion) {
         use nalgebra::Matrix3;
         bench_unop!(b, op => try_inverse, ty => Matrix3<f32>)
     });
+    bench_nalgebra_glm!(group, |b| {
+        use mathbench::nalgebra_glm_support::MatrixExt;
+        use nalgebra_glm as glm;
+        bench_unop!(b, op => glm_inverse, ty => glm::Mat3)
+    });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat3;
+        bench_unop!(b, op => inverse, ty => Mat3)
+    });
     group.finish();
 }
 
 fn bench_matrix3_mul_matrix3(c: &mut Criterion) {
     use std::ops::Mul;
-    let mut group = c.benchmark_group("matrix3 mul matrix3");
+    let mut group = benchmark_group!(c, "matrix3 mul matrix3");
     bench_glam!(group, |b| {
         use glam::Mat3;
         bench_binop!(b, op => mul, ty1 => Mat3, ty2 => Mat3)
@@ -180,12 +211,16 @@ fn bench_matrix3_mul_matrix3(c: &mut Criterion) {
         use vek::Mat3;
         bench_binop!(b, op => mul, ty1 => Mat3<f32>, ty2 => Mat3<f32>)
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat3;
+        bench_binop!(b, op => mul, ty1 => Mat3, ty2 => Mat3)
+    });
     group.finish();
 }
 
 fn bench_matrix3_mul_vector3(c: &mut Criterion) {
     use std::ops::Mul;
-    let mut group = c.benchmark_group("matrix3 mul vector3");
+    let mut group = benchmark_group!(c, "matrix3 mul vector3");
     for size in [1, 100].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench_glam!(group, size, |b, size| {
@@ -227,10 +262,280 @@ fn bench_matrix3_mul_vector3(c: &mut Criterion) {
             use vek::{Mat3, Vec3};
             bench_binop!(b, size, op => mul, ty1 => Mat3<f32>, ty2 => Vec3<f32>)
         });
+        bench_reference!(group, size, |b, size| {
+            use mathbench::reference::{Mat3, Vec3};
+            bench_binop!(b, size, op => mul, ty1 => Mat3, ty2 => Vec3)
+        });
     }
     group.finish();
 }
 
+fn bench_matrix3_add_matrix3(c: &mut Criterion) {
+    use std::ops::Add;
+    let mut group = benchmark_group!(c, "matrix3 add matrix3");
+    bench_glam!(group, |b| {
+        use glam::Mat3;
+        bench_binop!(b, op => add, ty1 => Mat3, ty2 => Mat3)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::Matrix3;
+        bench_binop!(b, op => add, ty1 => Matrix3<f32>, ty2 => Matrix3<f32>)
+    });
+    bench_ultraviolet!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Mat3;
+        bench_binop!(b, op => add_matrix, ty1 => Mat3, ty2 => Mat3)
+    });
+    bench_ultraviolet_f32x4!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Wat3;
+        bench_binop4!(b, op => add_matrix, ty1 => Wat3, ty2 => Wat3)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix3;
+        bench_binop!(b, op => add, ty1 => Matrix3<f32>, ty2 => Matrix3<f32>)
+    });
+    bench_nalgebra_f32x4!(group, |b| {
+        use nalgebra::Matrix3;
+        use simba::simd::f32x4;
+        bench_binop4!(b, op => add, ty1 => Matrix3<f32x4>, ty2 => Matrix3<f32x4>)
+    });
+    bench_nalgebra_f32x8!(group, |b| {
+        use nalgebra::Matrix3;
+        use simba::simd::f32x8;
+        bench_binop8!(b, op => add, ty1 => Matrix3<f32x8>, ty2 => Matrix3<f32x8>)
+    });
+    bench_nalgebra_f32x16!(group, |b| {
+        use nalgebra::Matrix3;
+        use simba::simd::f32x16;
+        bench_binop16!(b, op => add, ty1 => Matrix3<f32x16>, ty2 => Matrix3<f32x16>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat3;
+        bench_binop!(b, op => add, ty1 => Mat3<f32>, ty2 => Mat3<f32>)
+    });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat3;
+        bench_binop!(b, op => add, ty1 => Mat3, ty2 => Mat3)
+    });
+    group.finish();
+}
+
+fn bench_matrix3_sub_matrix3(c: &mut Criterion) {
+    use std::ops::Sub;
+    let mut group = benchmark_group!(c, "matrix3 sub matrix3");
+    bench_glam!(group, |b| {
+        use glam::Mat3;
+        bench_binop!(b, op => sub, ty1 => Mat3, ty2 => Mat3)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::Matrix3;
+        bench_binop!(b, op => sub, ty1 => Matrix3<f32>, ty2 => Matrix3<f32>)
+    });
+    bench_ultraviolet!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Mat3;
+        bench_binop!(b, op => sub_matrix, ty1 => Mat3, ty2 => Mat3)
+    });
+    bench_ultraviolet_f32x4!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Wat3;
+        bench_binop4!(b, op => sub_matrix, ty1 => Wat3, ty2 => Wat3)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix3;
+        bench_binop!(b, op => sub, ty1 => Matrix3<f32>, ty2 => Matrix3<f32>)
+    });
+    bench_nalgebra_f32x4!(group, |b| {
+        use nalgebra::Matrix3;
+        use simba::simd::f32x4;
+        bench_binop4!(b, op => sub, ty1 => Matrix3<f32x4>, ty2 => Matrix3<f32x4>)
+    });
+    bench_nalgebra_f32x8!(group, |b| {
+        use nalgebra::Matrix3;
+        use simba::simd::f32x8;
+        bench_binop8!(b, op => sub, ty1 => Matrix3<f32x8>, ty2 => Matrix3<f32x8>)
+    });
+    bench_nalgebra_f32x16!(group, |b| {
+        use nalgebra::Matrix3;
+        use simba::simd::f32x16;
+        bench_binop16!(b, op => sub, ty1 => Matrix3<f32x16>, ty2 => Matrix3<f32x16>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat3;
+        bench_binop!(b, op => sub, ty1 => Mat3<f32>, ty2 => Mat3<f32>)
+    });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat3;
+        bench_binop!(b, op => sub, ty1 => Mat3, ty2 => Mat3)
+    });
+    group.finish();
+}
+
+fn bench_matrix3_mul_scalar(c: &mut Criterion) {
+    use std::ops::Mul;
+    let mut group = benchmark_group!(c, "matrix3 mul scalar");
+    bench_glam!(group, |b| {
+        use glam::Mat3;
+        bench_binop!(b, op => mul, ty1 => Mat3, ty2 => f32)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::Matrix3;
+        bench_binop!(b, op => mul, ty1 => Matrix3<f32>, ty2 => f32)
+    });
+    bench_ultraviolet!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Mat3;
+        bench_binop!(b, op => mul_scalar, ty1 => Mat3, ty2 => f32)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix3;
+        bench_binop!(b, op => mul, ty1 => Matrix3<f32>, ty2 => f32)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat3;
+        bench_binop!(b, op => mul, ty1 => Mat3<f32>, ty2 => f32)
+    });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat3;
+        bench_binop!(b, op => mul, ty1 => Mat3, ty2 => f32)
+    });
+    group.finish();
+}
+
+fn bench_matrix3_transpose_mul_matrix3(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "matrix3 transpose mul matrix3");
+    bench_glam!(group, |b| {
+        use glam::Mat3;
+        use mathbench::glam_support::MatrixExt;
+        bench_binop!(b, op => transpose_mul, ty => Mat3, param => by_ref)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::Matrix3;
+        use mathbench::cgmath_support::MatrixExt;
+        bench_binop!(b, op => transpose_mul, ty => Matrix3<f32>, param => by_ref)
+    });
+    bench_ultraviolet!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Mat3;
+        bench_binop!(b, op => transpose_mul, ty1 => Mat3, ty2 => Mat3)
+    });
+    bench_ultraviolet_f32x4!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Wat3;
+        bench_binop4!(b, op => transpose_mul, ty1 => Wat3, ty2 => Wat3)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix3;
+        bench_binop!(b, op => tr_mul, ty => Matrix3<f32>, param => by_ref)
+    });
+    bench_nalgebra_f32x4!(group, |b| {
+        use nalgebra::Matrix3;
+        use simba::simd::f32x4;
+        bench_binop4!(b, op => tr_mul, ty => Matrix3<f32x4>, param => by_ref)
+    });
+    bench_nalgebra_f32x8!(group, |b| {
+        use nalgebra::Matrix3;
+        use simba::simd::f32x8;
+        bench_binop8!(b, op => tr_mul, ty => Matrix3<f32x8>, param => by_ref)
+    });
+    bench_nalgebra_f32x16!(group, |b| {
+        use nalgebra::Matrix3;
+        use simba::simd::f32x16;
+        bench_binop16!(b, op => tr_mul, ty => Matrix3<f32x16>, param => by_ref)
+    });
+    bench_vek!(group, |b| {
+        use mathbench::vek_support::MatrixExt;
+        use vek::Mat3;
+        bench_binop!(b, op => transposed_mul, ty1 => Mat3<f32>, ty2 => Mat3<f32>)
+    });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat3;
+        bench_binop!(b, op => transpose_mul, ty1 => Mat3, ty2 => Mat3)
+    });
+    group.finish();
+}
+
+fn bench_matrix3_trace(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "matrix3 trace");
+    bench_glam!(group, |b| {
+        use glam::Mat3;
+        use mathbench::glam_support::MatrixExt;
+        bench_unop!(b, op => trace, ty => Mat3)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::{prelude::*, Matrix3};
+        bench_unop!(b, op => trace, ty => Matrix3<f32>)
+    });
+    bench_ultraviolet!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Mat3;
+        bench_unop!(b, op => trace, ty => Mat3)
+    });
+    bench_ultraviolet_f32x4!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Wat3;
+        bench_unop4!(b, op => trace, ty => Wat3)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix3;
+        bench_unop!(b, op => trace, ty => Matrix3<f32>)
+    });
+    bench_nalgebra_f32x4!(group, |b| {
+        use nalgebra::Matrix3;
+        use simba::simd::f32x4;
+        bench_unop4!(b, op => trace, ty => Matrix3<f32x4>)
+    });
+    bench_nalgebra_f32x8!(group, |b| {
+        use nalgebra::Matrix3;
+        use simba::simd::f32x8;
+        bench_unop8!(b, op => trace, ty => Matrix3<f32x8>)
+    });
+    bench_nalgebra_f32x16!(group, |b| {
+        use nalgebra::Matrix3;
+        use simba::simd::f32x16;
+        bench_unop16!(b, op => trace, ty => Matrix3<f32x16>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat3;
+        bench_unop!(b, op => trace, ty => Mat3<f32>)
+    });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat3;
+        bench_unop!(b, op => trace, ty => Mat3)
+    });
+    group.finish();
+}
+
+fn bench_matrix3_from_cols(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "matrix3 from cols");
+    bench_glam!(group, |b| {
+        use glam::{Mat3, Vec3};
+        bench_ctor!(b, ctor => Mat3::from_cols, ty => Vec3, args => (x, y, z))
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::{Matrix3, Vector3};
+        bench_ctor!(b, ctor => Matrix3::from_cols, ty => Vector3<f32>, args => (x, y, z))
+    });
+    bench_ultraviolet!(group, |b| {
+        use ultraviolet::{Mat3, Vec3};
+        bench_ctor!(b, ctor => Mat3::new, ty => Vec3, args => (x, y, z))
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::{Matrix3, Vector3};
+        bench_ctor!(b, ctor => |x, y, z| Matrix3::from_columns(&[x, y, z]), ty => Vector3<f32>, args => (x, y, z))
+    });
+    bench_vek!(group, |b| {
+        use vek::{Mat3, Vec3};
+        bench_ctor!(b, ctor => |x, y, z| Mat3 { cols: Vec3::new(x, y, z) }, ty => Vec3<f32>, args => (x, y, z))
+    });
+    bench_reference!(group, |b| {
+        use mathbench::reference::{Mat3, Vec3};
+        bench_ctor!(b, ctor => Mat3::from_cols, ty => Vec3, args => (x, y, z))
+    });
+    group.finish();
+}
+
 criterion_group!(
     matrix3_benches,
     bench_matrix3_ret_self,
@@ -239,5 +544,11 @@ criterion_group!(
     bench_matrix3_inverse,
     bench_matrix3_mul_matrix3,
     bench_matrix3_mul_vector3,
+    bench_matrix3_add_matrix3,
+    bench_matrix3_sub_matrix3,
+    bench_matrix3_mul_scalar,
+    bench_matrix3_transpose_mul_matrix3,
+    bench_matrix3_trace,
+    bench_matrix3_from_cols,
 );
 criterion_main!(matrix3_benches);
@@ -6,7 +6,7 @@ use criterion::{criterion_group, criterion_main, Criterion};
 // returns self to check overhead of benchmark
 fn bench_matrix4_ret_self(c: &mut Criterion) {
     use mathbench::BenchValue;
-    let mut group = c.benchmark_group("matrix4 return self");
+    let mut group = benchmark_group!(c, "matrix4 return self");
     bench_glam!(group, |b| {
         use glam::Mat4;
         bench_unop!(b, op => ret_self, ty => Mat4)
@@ -46,11 +46,15 @@ fn bench_matrix4_ret_self(c: &mut Criterion) {
         use vek::Mat4;
         bench_unop!(b, op => ret_self, ty => Mat4<f32>)
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat4;
+        bench_unop!(b, op => ret_self, ty => Mat4)
+    });
     group.finish();
 }
 
 fn bench_matrix4_transpose(c: &mut Criterion) {
-    let mut group = c.benchmark_group("matrix4 transpose");
+    let mut group = benchmark_group!(c, "matrix4 transpose");
     bench_glam!(group, |b| {
         use glam::Mat4;
         bench_unop!(b, op => transpose, ty => Mat4);
@@ -71,6 +75,11 @@ fn bench_matrix4_transpose(c: &mut Criterion) {
         use nalgebra::Matrix4;
         bench_unop!(b, op => transpose, ty => Matrix4<f32>)
     });
+    bench_nalgebra_glm!(group, |b| {
+        use mathbench::nalgebra_glm_support::MatrixExt;
+        use nalgebra_glm as glm;
+        bench_unop!(b, op => glm_transpose, ty => glm::Mat4)
+    });
     bench_nalgebra_f32x4!(group, |b| {
         use nalgebra::Matrix4;
         use simba::simd::f32x4;
@@ -90,11 +99,15 @@ fn bench_matrix4_transpose(c: &mut Criterion) {
         use vek::Mat4;
         bench_unop!(b, op => transposed, ty => Mat4<f32>)
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat4;
+        bench_unop!(b, op => transpose, ty => Mat4)
+    });
     group.finish();
 }
 
 fn bench_matrix4_determinant(c: &mut Criterion) {
-    let mut group = c.benchmark_group("matrix4 determinant");
+    let mut group = benchmark_group!(c, "matrix4 determinant");
     bench_glam!(group, |b| {
         use glam::Mat4;
         bench_unop!(b, op => determinant, ty => Mat4)
@@ -107,6 +120,11 @@ fn bench_matrix4_determinant(c: &mut Criterion) {
         use nalgebra::Matrix4;
         bench_unop!(b, op => determinant, ty => Matrix4<f32>)
     });
+    bench_nalgebra_glm!(group, |b| {
+        use mathbench::nalgebra_glm_support::MatrixExt;
+        use nalgebra_glm as glm;
+        bench_unop!(b, op => glm_determinant, ty => glm::Mat4)
+    });
     bench_euclid!(group, |b| {
         use euclid::{Transform3D, UnknownUnit};
         bench_unop!(b, op => determinant, ty => Transform3D<f32, UnknownUnit, UnknownUnit>)
@@ -115,11 +133,15 @@ fn bench_matrix4_determinant(c: &mut Criterion) {
         use vek::Mat4;
         bench_unop!(b, op => determinant, ty => Mat4<f32>)
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat4;
+        bench_unop!(b, op => determinant, ty => Mat4)
+    });
     group.finish();
 }
 
 fn bench_matrix4_inverse(c: &mut Criterion) {
-    let mut group = c.benchmark_group("matrix4 inverse");
+    let mut group = benchmark_group!(c, "matrix4 inverse");
     bench_glam!(group, |b| {
         use glam::Mat4;
         bench_unop!(b, op => inverse, ty => Mat4)
@@ -140,6 +162,11 @@ fn bench_matrix4_inverse(c: &mut Criterion) {
         use nalgebra::Matrix4;
         bench_unop!(b, op => try_inverse, ty => Matrix4<f32>)
     });
+    bench_nalgebra_glm!(group, |b| {
+        use mathbench::nalgebra_glm_support::MatrixExt;
+        use nalgebra_glm as glm;
+        bench_unop!(b, op => glm_inverse, ty => glm::Mat4)
+    });
     bench_euclid!(group, |b| {
         use euclid::{Transform3D, UnknownUnit};
         bench_unop!(b, op => inverse, ty => Transform3D<f32, UnknownUnit, UnknownUnit>)
@@ -148,12 +175,20 @@ fn bench_matrix4_inverse(c: &mut Criterion) {
         use vek::Mat4;
         bench_unop!(b, op => inverted, ty => Mat4<f32>)
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat4;
+        bench_unop!(b, op => inverse, ty => Mat4)
+    });
+    bench_intrinsics!(group, |b| {
+        use mathbench::intrinsics::Mat4;
+        bench_unop!(b, op => inverse, ty => Mat4)
+    });
     group.finish();
 }
 
 fn bench_matrix4_mul_matrix4(c: &mut Criterion) {
     use std::ops::Mul;
-    let mut group = c.benchmark_group("matrix4 mul matrix4");
+    let mut group = benchmark_group!(c, "matrix4 mul matrix4");
     bench_glam!(group, |b| {
         use glam::Mat4;
         bench_binop!(b, op => mul, ty1 => Mat4, ty2 => Mat4)
@@ -197,12 +232,28 @@ fn bench_matrix4_mul_matrix4(c: &mut Criterion) {
         use vek::Mat4;
         bench_binop!(b, op => mul, ty1 => Mat4<f32>, ty2 => Mat4<f32>)
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat4;
+        bench_binop!(b, op => mul, ty1 => Mat4, ty2 => Mat4)
+    });
+    bench_intrinsics!(group, |b| {
+        use mathbench::intrinsics::Mat4;
+        bench_binop!(b, op => mul, ty1 => Mat4, ty2 => Mat4)
+    });
+    bench_intrinsics_avx!(group, |b| {
+        use mathbench::intrinsics::Mat4;
+        #[target_feature(enable = "avx")]
+        unsafe fn bench(b: &mut criterion::Bencher) {
+            bench_binop!(b, op => mul_avx, ty1 => Mat4, ty2 => Mat4)
+        }
+        unsafe { bench(b) }
+    });
     group.finish();
 }
 
 fn bench_matrix4_mul_vector4(c: &mut Criterion) {
     use std::ops::Mul;
-    let mut group = c.benchmark_group("matrix4 mul vector4");
+    let mut group = benchmark_group!(c, "matrix4 mul vector4");
     for size in [1, 100].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench_glam!(group, size, |b, size| {
@@ -244,10 +295,357 @@ fn bench_matrix4_mul_vector4(c: &mut Criterion) {
             use vek::{Mat4, Vec4};
             bench_binop!(b, size, op => mul, ty1 => Mat4<f32>, ty2 => Vec4<f32>)
         });
+        bench_reference!(group, size, |b, size| {
+            use mathbench::reference::{Mat4, Vec4};
+            bench_binop!(b, size, op => mul, ty1 => Mat4, ty2 => Vec4)
+        });
     }
     group.finish();
 }
 
+fn bench_matrix4_add_matrix4(c: &mut Criterion) {
+    use std::ops::Add;
+    let mut group = benchmark_group!(c, "matrix4 add matrix4");
+    bench_glam!(group, |b| {
+        use glam::Mat4;
+        bench_binop!(b, op => add, ty1 => Mat4, ty2 => Mat4)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::Matrix4;
+        bench_binop!(b, op => add, ty1 => Matrix4<f32>, ty2 => Matrix4<f32>)
+    });
+    bench_ultraviolet!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Mat4;
+        bench_binop!(b, op => add_matrix, ty1 => Mat4, ty2 => Mat4)
+    });
+    bench_ultraviolet_f32x4!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Wat4;
+        bench_binop4!(b, op => add_matrix, ty1 => Wat4, ty2 => Wat4)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix4;
+        bench_binop!(b, op => add, ty1 => Matrix4<f32>, ty2 => Matrix4<f32>)
+    });
+    bench_nalgebra_f32x4!(group, |b| {
+        use nalgebra::Matrix4;
+        use simba::simd::f32x4;
+        bench_binop4!(b, op => add, ty1 => Matrix4<f32x4>, ty2 => Matrix4<f32x4>)
+    });
+    bench_nalgebra_f32x8!(group, |b| {
+        use nalgebra::Matrix4;
+        use simba::simd::f32x8;
+        bench_binop8!(b, op => add, ty1 => Matrix4<f32x8>, ty2 => Matrix4<f32x8>)
+    });
+    bench_nalgebra_f32x16!(group, |b| {
+        use nalgebra::Matrix4;
+        use simba::simd::f32x16;
+        bench_binop16!(b, op => add, ty1 => Matrix4<f32x16>, ty2 => Matrix4<f32x16>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat4;
+        bench_binop!(b, op => add, ty1 => Mat4<f32>, ty2 => Mat4<f32>)
+    });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat4;
+        bench_binop!(b, op => add, ty1 => Mat4, ty2 => Mat4)
+    });
+    group.finish();
+}
+
+fn bench_matrix4_sub_matrix4(c: &mut Criterion) {
+    use std::ops::Sub;
+    let mut group = benchmark_group!(c, "matrix4 sub matrix4");
+    bench_glam!(group, |b| {
+        use glam::Mat4;
+        bench_binop!(b, op => sub, ty1 => Mat4, ty2 => Mat4)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::Matrix4;
+        bench_binop!(b, op => sub, ty1 => Matrix4<f32>, ty2 => Matrix4<f32>)
+    });
+    bench_ultraviolet!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Mat4;
+        bench_binop!(b, op => sub_matrix, ty1 => Mat4, ty2 => Mat4)
+    });
+    bench_ultraviolet_f32x4!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Wat4;
+        bench_binop4!(b, op => sub_matrix, ty1 => Wat4, ty2 => Wat4)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix4;
+        bench_binop!(b, op => sub, ty1 => Matrix4<f32>, ty2 => Matrix4<f32>)
+    });
+    bench_nalgebra_f32x4!(group, |b| {
+        use nalgebra::Matrix4;
+        use simba::simd::f32x4;
+        bench_binop4!(b, op => sub, ty1 => Matrix4<f32x4>, ty2 => Matrix4<f32x4>)
+    });
+    bench_nalgebra_f32x8!(group, |b| {
+        use nalgebra::Matrix4;
+        use simba::simd::f32x8;
+        bench_binop8!(b, op => sub, ty1 => Matrix4<f32x8>, ty2 => Matrix4<f32x8>)
+    });
+    bench_nalgebra_f32x16!(group, |b| {
+        use nalgebra::Matrix4;
+        use simba::simd::f32x16;
+        bench_binop16!(b, op => sub, ty1 => Matrix4<f32x16>, ty2 => Matrix4<f32x16>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat4;
+        bench_binop!(b, op => sub, ty1 => Mat4<f32>, ty2 => Mat4<f32>)
+    });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat4;
+        bench_binop!(b, op => sub, ty1 => Mat4, ty2 => Mat4)
+    });
+    group.finish();
+}
+
+fn bench_matrix4_mul_scalar(c: &mut Criterion) {
+    use std::ops::Mul;
+    let mut group = benchmark_group!(c, "matrix4 mul scalar");
+    bench_glam!(group, |b| {
+        use glam::Mat4;
+        bench_binop!(b, op => mul, ty1 => Mat4, ty2 => f32)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::Matrix4;
+        bench_binop!(b, op => mul, ty1 => Matrix4<f32>, ty2 => f32)
+    });
+    bench_ultraviolet!(group, |b| {
+        use ultraviolet::Mat4;
+        bench_binop!(b, op => mul, ty1 => Mat4, ty2 => f32)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix4;
+        bench_binop!(b, op => mul, ty1 => Matrix4<f32>, ty2 => f32)
+    });
+    bench_euclid!(group, |b| {
+        use euclid::{Transform3D, UnknownUnit};
+        bench_binop!(b, op => mul_s, ty1 => Transform3D<f32, UnknownUnit, UnknownUnit>, ty2 => f32)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat4;
+        bench_binop!(b, op => mul, ty1 => Mat4<f32>, ty2 => f32)
+    });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat4;
+        bench_binop!(b, op => mul, ty1 => Mat4, ty2 => f32)
+    });
+    group.finish();
+}
+
+fn bench_matrix4_transpose_mul_matrix4(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "matrix4 transpose mul matrix4");
+    bench_glam!(group, |b| {
+        use glam::Mat4;
+        use mathbench::glam_support::MatrixExt;
+        bench_binop!(b, op => transpose_mul, ty => Mat4, param => by_ref)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::Matrix4;
+        use mathbench::cgmath_support::MatrixExt;
+        bench_binop!(b, op => transpose_mul, ty => Matrix4<f32>, param => by_ref)
+    });
+    bench_ultraviolet!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Mat4;
+        bench_binop!(b, op => transpose_mul, ty1 => Mat4, ty2 => Mat4)
+    });
+    bench_ultraviolet_f32x4!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Wat4;
+        bench_binop4!(b, op => transpose_mul, ty1 => Wat4, ty2 => Wat4)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix4;
+        bench_binop!(b, op => tr_mul, ty => Matrix4<f32>, param => by_ref)
+    });
+    bench_nalgebra_f32x4!(group, |b| {
+        use nalgebra::Matrix4;
+        use simba::simd::f32x4;
+        bench_binop4!(b, op => tr_mul, ty => Matrix4<f32x4>, param => by_ref)
+    });
+    bench_nalgebra_f32x8!(group, |b| {
+        use nalgebra::Matrix4;
+        use simba::simd::f32x8;
+        bench_binop8!(b, op => tr_mul, ty => Matrix4<f32x8>, param => by_ref)
+    });
+    bench_nalgebra_f32x16!(group, |b| {
+        use nalgebra::Matrix4;
+        use simba::simd::f32x16;
+        bench_binop16!(b, op => tr_mul, ty => Matrix4<f32x16>, param => by_ref)
+    });
+    bench_vek!(group, |b| {
+        use mathbench::vek_support::MatrixExt;
+        use vek::Mat4;
+        bench_binop!(b, op => transposed_mul, ty1 => Mat4<f32>, ty2 => Mat4<f32>)
+    });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat4;
+        bench_binop!(b, op => transpose_mul, ty1 => Mat4, ty2 => Mat4)
+    });
+    group.finish();
+}
+
+fn bench_matrix4_trace(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "matrix4 trace");
+    bench_glam!(group, |b| {
+        use glam::Mat4;
+        use mathbench::glam_support::MatrixExt;
+        bench_unop!(b, op => trace, ty => Mat4)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::{prelude::*, Matrix4};
+        bench_unop!(b, op => trace, ty => Matrix4<f32>)
+    });
+    bench_ultraviolet!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Mat4;
+        bench_unop!(b, op => trace, ty => Mat4)
+    });
+    bench_ultraviolet_f32x4!(group, |b| {
+        use mathbench::ultraviolet_support::MatrixExt;
+        use ultraviolet::Wat4;
+        bench_unop4!(b, op => trace, ty => Wat4)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix4;
+        bench_unop!(b, op => trace, ty => Matrix4<f32>)
+    });
+    bench_nalgebra_f32x4!(group, |b| {
+        use nalgebra::Matrix4;
+        use simba::simd::f32x4;
+        bench_unop4!(b, op => trace, ty => Matrix4<f32x4>)
+    });
+    bench_nalgebra_f32x8!(group, |b| {
+        use nalgebra::Matrix4;
+        use simba::simd::f32x8;
+        bench_unop8!(b, op => trace, ty => Matrix4<f32x8>)
+    });
+    bench_nalgebra_f32x16!(group, |b| {
+        use nalgebra::Matrix4;
+        use simba::simd::f32x16;
+        bench_unop16!(b, op => trace, ty => Matrix4<f32x16>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat4;
+        bench_unop!(b, op => trace, ty => Mat4<f32>)
+    });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat4;
+        bench_unop!(b, op => trace, ty => Mat4)
+    });
+    group.finish();
+}
+
+fn bench_matrix4_from_cols(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "matrix4 from cols");
+    bench_glam!(group, |b| {
+        use glam::{Mat4, Vec4};
+        bench_ctor!(b, ctor => Mat4::from_cols, ty => Vec4, args => (x, y, z, w))
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::{Matrix4, Vector4};
+        bench_ctor!(b, ctor => Matrix4::from_cols, ty => Vector4<f32>, args => (x, y, z, w))
+    });
+    bench_ultraviolet!(group, |b| {
+        use ultraviolet::{Mat4, Vec4};
+        bench_ctor!(b, ctor => Mat4::new, ty => Vec4, args => (x, y, z, w))
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::{Matrix4, Vector4};
+        bench_ctor!(b, ctor => |x, y, z, w| Matrix4::from_columns(&[x, y, z, w]), ty => Vector4<f32>, args => (x, y, z, w))
+    });
+    bench_vek!(group, |b| {
+        use vek::{Mat4, Vec4};
+        bench_ctor!(b, ctor => |x, y, z, w| Mat4 { cols: Vec4::new(x, y, z, w) }, ty => Vec4<f32>, args => (x, y, z, w))
+    });
+    bench_reference!(group, |b| {
+        use mathbench::reference::{Mat4, Vec4};
+        bench_ctor!(b, ctor => Mat4::from_cols, ty => Vec4, args => (x, y, z, w))
+    });
+    group.finish();
+}
+
+fn bench_matrix4_affine_inverse(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "matrix4 affine inverse");
+    bench_glam!(group, |b| {
+        use glam::Mat4;
+        use mathbench::glam_support::Matrix4Ext;
+        bench_unop!(b, op => affine_inverse, ty => Mat4)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::Matrix4;
+        use mathbench::cgmath_support::Matrix4Ext;
+        bench_unop!(b, op => affine_invert, ty => Matrix4<f32>)
+    });
+    bench_ultraviolet!(group, |b| {
+        use mathbench::ultraviolet_support::Matrix4Ext;
+        use ultraviolet::Mat4;
+        bench_unop!(b, op => affine_inversed, ty => Mat4)
+    });
+    bench_nalgebra!(group, |b| {
+        use mathbench::nalgebra_support::Matrix4Ext;
+        use nalgebra::Matrix4;
+        bench_unop!(b, op => affine_inverse, ty => Matrix4<f32>)
+    });
+    bench_nalgebra_glm!(group, |b| {
+        use mathbench::nalgebra_glm_support::MatrixExt;
+        use nalgebra_glm as glm;
+        bench_unop!(b, op => glm_affine_inverse, ty => glm::Mat4)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat4;
+        bench_unop!(b, op => inverted_affine_transform, ty => Mat4<f32>)
+    });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Mat4;
+        bench_unop!(b, op => affine_inverse, ty => Mat4)
+    });
+    group.finish();
+}
+
+fn bench_matrix4_project_point3(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "matrix4 project point3");
+    bench_glam!(group, |b| {
+        use glam::{Mat4, Vec3};
+        use mathbench::glam_support::Matrix4Ext;
+        bench_binop!(b, op => project_point3, ty1 => Mat4, ty2 => Vec3)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::{Matrix4, Point3, Transform};
+        bench_binop!(b, op => transform_point, ty1 => Matrix4<f32>, ty2 => Point3<f32>)
+    });
+    bench_ultraviolet!(group, |b| {
+        use mathbench::ultraviolet_support::Matrix4Ext;
+        use ultraviolet::{Mat4, Vec3};
+        bench_binop!(b, op => project_point3, ty1 => Mat4, ty2 => Vec3)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::{Matrix4, Point3};
+        bench_binop!(b, op => transform_point, ty1 => Matrix4<f32>, ty2 => Point3<f32>, param => by_ref)
+    });
+    bench_euclid!(group, |b| {
+        use euclid::{Point3D, Transform3D, UnknownUnit};
+        bench_binop!(b, op => transform_point3d, ty1 => Transform3D<f32, UnknownUnit, UnknownUnit>, ty2 => Point3D<f32, UnknownUnit>)
+    });
+    bench_vek!(group, |b| {
+        use mathbench::vek_support::Matrix4Ext;
+        use vek::{Mat4, Vec3};
+        bench_binop!(b, op => project_point, ty1 => Mat4<f32>, ty2 => Vec3<f32>)
+    });
+    bench_reference!(group, |b| {
+        use mathbench::reference::{Mat4, Vec3};
+        bench_binop!(b, op => project_point3, ty1 => Mat4, ty2 => Vec3)
+    });
+    group.finish();
+}
+
 criterion_group!(
     matrix4_benches,
     bench_matrix4_ret_self,
@@ -256,5 +654,13 @@ criterion_group!(
     bench_matrix4_inverse,
     bench_matrix4_mul_matrix4,
     bench_matrix4_mul_vector4,
+    bench_matrix4_add_matrix4,
+    bench_matrix4_sub_matrix4,
+    bench_matrix4_mul_scalar,
+    bench_matrix4_transpose_mul_matrix4,
+    bench_matrix4_trace,
+    bench_matrix4_from_cols,
+    bench_matrix4_affine_inverse,
+    bench_matrix4_project_point3,
 );
 criterion_main!(matrix4_benches);
@@ -0,0 +1,298 @@
+#[path = "support/macros.rs"]
+#[macro_use]
+mod macros;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench_matrix2_transpose(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 matrix2 transpose");
+    bench_cgmath!(group, |b| {
+        use cgmath::{prelude::*, Matrix2};
+        bench_unop!(b, op => transpose, ty => Matrix2<f64>)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix2;
+        bench_unop!(b, op => transpose, ty => Matrix2<f64>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat2;
+        bench_unop!(b, op => transposed, ty => Mat2<f64>)
+    });
+    group.finish();
+}
+
+fn bench_matrix2_determinant(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 matrix2 determinant");
+    bench_cgmath!(group, |b| {
+        use cgmath::{prelude::*, Matrix2};
+        bench_unop!(b, op => determinant, ty => Matrix2<f64>)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix2;
+        bench_unop!(b, op => determinant, ty => Matrix2<f64>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat2;
+        bench_unop!(b, op => determinant, ty => Mat2<f64>)
+    });
+    group.finish();
+}
+
+fn bench_matrix2_inverse(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 matrix2 inverse");
+    bench_cgmath!(group, |b| {
+        use cgmath::{prelude::*, Matrix2};
+        bench_unop!(b, op => invert, ty => Matrix2<f64>)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix2;
+        bench_unop!(b, op => try_inverse, ty => Matrix2<f64>)
+    });
+    group.finish();
+}
+
+fn bench_matrix2_mul_matrix2(c: &mut Criterion) {
+    use std::ops::Mul;
+    let mut group = benchmark_group!(c, "f64 matrix2 mul matrix2");
+    bench_cgmath!(group, |b| {
+        use cgmath::Matrix2;
+        bench_binop!(b, op => mul, ty1 => Matrix2<f64>, ty2 => Matrix2<f64>)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix2;
+        bench_binop!(b, op => mul, ty1 => Matrix2<f64>, ty2 => Matrix2<f64>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat2;
+        bench_binop!(b, op => mul, ty1 => Mat2<f64>, ty2 => Mat2<f64>)
+    });
+    group.finish();
+}
+
+fn bench_matrix2_mul_vector2(c: &mut Criterion) {
+    use std::ops::Mul;
+    let mut group = benchmark_group!(c, "f64 matrix2 mul vector2");
+    for size in [1, 100].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench_cgmath!(group, size, |b, size| {
+            use cgmath::{Matrix2, Vector2};
+            bench_binop!(b, size, op => mul, ty1 => Matrix2<f64>, ty2 => Vector2<f64>)
+        });
+        bench_nalgebra!(group, size, |b, size| {
+            use nalgebra::{Matrix2, Vector2};
+            bench_binop!(b, size, op => mul, ty1 => Matrix2<f64>, ty2 => Vector2<f64>)
+        });
+        bench_vek!(group, size, |b, size| {
+            use vek::{Mat2, Vec2};
+            bench_binop!(b, size, op => mul, ty1 => Mat2<f64>, ty2 => Vec2<f64>)
+        });
+    }
+    group.finish();
+}
+
+fn bench_matrix3_transpose(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 matrix3 transpose");
+    bench_cgmath!(group, |b| {
+        use cgmath::{prelude::*, Matrix3};
+        bench_unop!(b, op => transpose, ty => Matrix3<f64>)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix3;
+        bench_unop!(b, op => transpose, ty => Matrix3<f64>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat3;
+        bench_unop!(b, op => transposed, ty => Mat3<f64>)
+    });
+    group.finish();
+}
+
+fn bench_matrix3_determinant(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 matrix3 determinant");
+    bench_cgmath!(group, |b| {
+        use cgmath::{prelude::*, Matrix3};
+        bench_unop!(b, op => determinant, ty => Matrix3<f64>)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix3;
+        bench_unop!(b, op => determinant, ty => Matrix3<f64>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat3;
+        bench_unop!(b, op => determinant, ty => Mat3<f64>)
+    });
+    group.finish();
+}
+
+fn bench_matrix3_inverse(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 matrix3 inverse");
+    bench_cgmath!(group, |b| {
+        use cgmath::{prelude::*, Matrix3};
+        bench_unop!(b, op => invert, ty => Matrix3<f64>)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix3;
+        bench_unop!(b, op => try_inverse, ty => Matrix3<f64>)
+    });
+    group.finish();
+}
+
+fn bench_matrix3_mul_matrix3(c: &mut Criterion) {
+    use std::ops::Mul;
+    let mut group = benchmark_group!(c, "f64 matrix3 mul matrix3");
+    bench_cgmath!(group, |b| {
+        use cgmath::Matrix3;
+        bench_binop!(b, op => mul, ty1 => Matrix3<f64>, ty2 => Matrix3<f64>, param => by_ref)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix3;
+        bench_binop!(b, op => mul, ty1 => Matrix3<f64>, ty2 => Matrix3<f64>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat3;
+        bench_binop!(b, op => mul, ty1 => Mat3<f64>, ty2 => Mat3<f64>)
+    });
+    group.finish();
+}
+
+fn bench_matrix3_mul_vector3(c: &mut Criterion) {
+    use std::ops::Mul;
+    let mut group = benchmark_group!(c, "f64 matrix3 mul vector3");
+    for size in [1, 100].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench_cgmath!(group, size, |b, size| {
+            use cgmath::{Matrix3, Vector3};
+            bench_binop!(b, size, op => mul, ty1 => Matrix3<f64>, ty2 => Vector3<f64>)
+        });
+        bench_nalgebra!(group, size, |b, size| {
+            use nalgebra::{Matrix3, Vector3};
+            bench_binop!(b, size, op => mul, ty1 => Matrix3<f64>, ty2 => Vector3<f64>)
+        });
+        bench_vek!(group, size, |b, size| {
+            use vek::{Mat3, Vec3};
+            bench_binop!(b, size, op => mul, ty1 => Mat3<f64>, ty2 => Vec3<f64>)
+        });
+    }
+    group.finish();
+}
+
+fn bench_matrix4_transpose(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 matrix4 transpose");
+    bench_cgmath!(group, |b| {
+        use cgmath::{prelude::*, Matrix4};
+        bench_unop!(b, op => transpose, ty => Matrix4<f64>)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix4;
+        bench_unop!(b, op => transpose, ty => Matrix4<f64>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat4;
+        bench_unop!(b, op => transposed, ty => Mat4<f64>)
+    });
+    group.finish();
+}
+
+fn bench_matrix4_determinant(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 matrix4 determinant");
+    bench_cgmath!(group, |b| {
+        use cgmath::{Matrix4, SquareMatrix};
+        bench_unop!(b, op => determinant, ty => Matrix4<f64>)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix4;
+        bench_unop!(b, op => determinant, ty => Matrix4<f64>)
+    });
+    bench_euclid!(group, |b| {
+        use euclid::{Transform3D, UnknownUnit};
+        bench_unop!(b, op => determinant, ty => Transform3D<f64, UnknownUnit, UnknownUnit>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat4;
+        bench_unop!(b, op => determinant, ty => Mat4<f64>)
+    });
+    group.finish();
+}
+
+fn bench_matrix4_inverse(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 matrix4 inverse");
+    bench_cgmath!(group, |b| {
+        use cgmath::{Matrix4, SquareMatrix};
+        bench_unop!(b, op => invert, ty => Matrix4<f64>)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix4;
+        bench_unop!(b, op => try_inverse, ty => Matrix4<f64>)
+    });
+    bench_euclid!(group, |b| {
+        use euclid::{Transform3D, UnknownUnit};
+        bench_unop!(b, op => inverse, ty => Transform3D<f64, UnknownUnit, UnknownUnit>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat4;
+        bench_unop!(b, op => inverted, ty => Mat4<f64>)
+    });
+    group.finish();
+}
+
+fn bench_matrix4_mul_matrix4(c: &mut Criterion) {
+    use std::ops::Mul;
+    let mut group = benchmark_group!(c, "f64 matrix4 mul matrix4");
+    bench_cgmath!(group, |b| {
+        use cgmath::Matrix4;
+        bench_binop!(b, op => mul, ty1 => Matrix4<f64>, ty2 => Matrix4<f64>, param => by_ref)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Matrix4;
+        bench_binop!(b, op => mul, ty1 => Matrix4<f64>, ty2 => Matrix4<f64>)
+    });
+    bench_euclid!(group, |b| {
+        use euclid::{Transform3D, UnknownUnit};
+        bench_binop!(b, op => post_transform, ty => Transform3D<f64, UnknownUnit, UnknownUnit>, param => by_ref)
+    });
+    bench_vek!(group, |b| {
+        use vek::Mat4;
+        bench_binop!(b, op => mul, ty1 => Mat4<f64>, ty2 => Mat4<f64>)
+    });
+    group.finish();
+}
+
+fn bench_matrix4_mul_vector4(c: &mut Criterion) {
+    use std::ops::Mul;
+    let mut group = benchmark_group!(c, "f64 matrix4 mul vector4");
+    for size in [1, 100].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench_cgmath!(group, size, |b, size| {
+            use cgmath::{Matrix4, Vector4};
+            bench_binop!(b, size, op => mul, ty1 => Matrix4<f64>, ty2 => Vector4<f64>)
+        });
+        bench_nalgebra!(group, size, |b, size| {
+            use nalgebra::{Matrix4, Vector4};
+            bench_binop!(b, size, op => mul, ty1 => Matrix4<f64>, ty2 => Vector4<f64>)
+        });
+        bench_vek!(group, size, |b, size| {
+            use vek::{Mat4, Vec4};
+            bench_binop!(b, size, op => mul, ty1 => Mat4<f64>, ty2 => Vec4<f64>)
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    matrix_f64_benches,
+    bench_matrix2_transpose,
+    bench_matrix2_determinant,
+    bench_matrix2_inverse,
+    bench_matrix2_mul_matrix2,
+    bench_matrix2_mul_vector2,
+    bench_matrix3_transpose,
+    bench_matrix3_determinant,
+    bench_matrix3_inverse,
+    bench_matrix3_mul_matrix3,
+    bench_matrix3_mul_vector3,
+    bench_matrix4_transpose,
+    bench_matrix4_determinant,
+    bench_matrix4_inverse,
+    bench_matrix4_mul_matrix4,
+    bench_matrix4_mul_vector4,
+);
+criterion_main!(matrix_f64_benches);
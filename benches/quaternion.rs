@@ -6,7 +6,7 @@ use criterion::{criterion_group, criterion_main, Criterion};
 // returns self to check overhead of benchmark
 fn bench_quaternion_nop(c: &mut Criterion) {
     use mathbench::BenchValue;
-    let mut group = c.benchmark_group("quaternion return self");
+    let mut group = benchmark_group!(c, "quaternion return self");
     bench_glam!(group, |b| {
         use glam::Quat;
         bench_unop!(b, op => ret_self, ty => Quat)
@@ -46,11 +46,15 @@ fn bench_quaternion_nop(c: &mut Criterion) {
         use vek::Quaternion;
         bench_unop!(b, op => ret_self, ty => Quaternion<f32>)
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Quat;
+        bench_unop!(b, op => ret_self, ty => Quat)
+    });
     group.finish();
 }
 
 fn bench_quaternion_conjugate(c: &mut Criterion) {
-    let mut group = c.benchmark_group("quaternion conjugate");
+    let mut group = benchmark_group!(c, "quaternion conjugate");
     bench_glam!(group, |b| {
         use glam::Quat;
         bench_unop!(b, op => conjugate, ty => Quat)
@@ -71,6 +75,11 @@ fn bench_quaternion_conjugate(c: &mut Criterion) {
         use nalgebra::UnitQuaternion;
         bench_unop!(b, op => conjugate, ty => UnitQuaternion<f32>)
     });
+    bench_nalgebra_glm!(group, |b| {
+        use mathbench::nalgebra_glm_support::QuaternionExt;
+        use nalgebra_glm as glm;
+        bench_unop!(b, op => glm_quat_conjugate, ty => glm::Qua<f32>)
+    });
     bench_nalgebra_f32x4!(group, |b| {
         use nalgebra::UnitQuaternion;
         use simba::simd::f32x4;
@@ -95,12 +104,16 @@ fn bench_quaternion_conjugate(c: &mut Criterion) {
         use vek::Quaternion;
         bench_unop!(b, op => conjugate, ty => Quaternion<f32>)
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Quat;
+        bench_unop!(b, op => conjugate, ty => Quat)
+    });
     group.finish();
 }
 
 fn bench_quaternion_mul_quaternion(c: &mut Criterion) {
     use std::ops::Mul;
-    let mut group = c.benchmark_group("quaternion mul quaternion");
+    let mut group = benchmark_group!(c, "quaternion mul quaternion");
     bench_glam!(group, |b| {
         use glam::Quat;
         bench_binop!(b, op => mul, ty1 => Quat, ty2 => Quat)
@@ -121,6 +134,11 @@ fn bench_quaternion_mul_quaternion(c: &mut Criterion) {
         use nalgebra::UnitQuaternion;
         bench_binop!(b, op => mul, ty1 => UnitQuaternion<f32>, ty2 => UnitQuaternion<f32>)
     });
+    bench_nalgebra_glm!(group, |b| {
+        use mathbench::nalgebra_glm_support::QuaternionExt;
+        use nalgebra_glm as glm;
+        bench_binop!(b, op => glm_quat_cross, ty => glm::Qua<f32>, param => by_ref)
+    });
     bench_nalgebra_f32x4!(group, |b| {
         use nalgebra::UnitQuaternion;
         use simba::simd::f32x4;
@@ -144,12 +162,16 @@ fn bench_quaternion_mul_quaternion(c: &mut Criterion) {
         use vek::Quaternion;
         bench_binop!(b, op => mul, ty1 => Quaternion<f32>, ty2 => Quaternion<f32>);
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Quat;
+        bench_binop!(b, op => mul, ty1 => Quat, ty2 => Quat)
+    });
     group.finish();
 }
 
 fn bench_quaternion_mul_vector3(c: &mut Criterion) {
     use std::ops::Mul;
-    let mut group = c.benchmark_group("quaternion mul vector3");
+    let mut group = benchmark_group!(c, "quaternion mul vector3");
     for size in [1, 100].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench_glam!(group, |b| {
@@ -172,6 +194,11 @@ fn bench_quaternion_mul_vector3(c: &mut Criterion) {
             use nalgebra::{UnitQuaternion, Vector3};
             bench_binop!(b, op => mul, ty1 => UnitQuaternion<f32>, ty2 => Vector3<f32>)
         });
+        bench_nalgebra_glm!(group, |b| {
+            use mathbench::nalgebra_glm_support::QuaternionExt;
+            use nalgebra_glm as glm;
+            bench_binop!(b, op => glm_quat_rotate_vec3, ty1 => glm::Qua<f32>, ty2 => glm::Vec3, param => by_ref)
+        });
         bench_nalgebra_f32x4!(group, |b| {
             use nalgebra::{UnitQuaternion, Vector3};
             use simba::simd::f32x4;
@@ -195,6 +222,14 @@ fn bench_quaternion_mul_vector3(c: &mut Criterion) {
             use vek::{Quaternion, Vec3};
             bench_binop!(b, op => mul, ty1 => Quaternion<f32>, ty2 => Vec3<f32>)
         });
+        bench_reference!(group, |b| {
+            use mathbench::reference::{Quat, Vec3};
+            bench_binop!(b, op => mul, ty1 => Quat, ty2 => Vec3)
+        });
+        bench_intrinsics!(group, |b| {
+            use mathbench::intrinsics::{Quat, Vec3};
+            bench_binop!(b, op => mul, ty1 => Quat, ty2 => Vec3)
+        });
     }
     group.finish();
 }
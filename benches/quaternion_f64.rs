@@ -0,0 +1,81 @@
+#[path = "support/macros.rs"]
+#[macro_use]
+mod macros;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench_quaternion_conjugate(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 quaternion conjugate");
+    bench_cgmath!(group, |b| {
+        use cgmath::Quaternion;
+        bench_unop!(b, op => conjugate, ty => Quaternion<f64>)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::UnitQuaternion;
+        bench_unop!(b, op => conjugate, ty => UnitQuaternion<f64>)
+    });
+    bench_euclid!(group, |b| {
+        use euclid::{Rotation3D, UnknownUnit};
+        // euclid inverse assumes normalized quaternion, so it's just a conjugate
+        bench_unop!(b, op => inverse, ty => Rotation3D<f64, UnknownUnit, UnknownUnit>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Quaternion;
+        bench_unop!(b, op => conjugate, ty => Quaternion<f64>)
+    });
+    group.finish();
+}
+
+fn bench_quaternion_mul_quaternion(c: &mut Criterion) {
+    use std::ops::Mul;
+    let mut group = benchmark_group!(c, "f64 quaternion mul quaternion");
+    bench_cgmath!(group, |b| {
+        use cgmath::Quaternion;
+        bench_binop!(b, op => mul, ty1 => Quaternion<f64>, ty2 => Quaternion<f64>)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::UnitQuaternion;
+        bench_binop!(b, op => mul, ty1 => UnitQuaternion<f64>, ty2 => UnitQuaternion<f64>)
+    });
+    bench_euclid!(group, |b| {
+        use euclid::{Rotation3D, UnknownUnit};
+        bench_binop!(b, op => pre_rotate, ty => Rotation3D<f64, UnknownUnit, UnknownUnit>, param => by_ref)
+    });
+    bench_vek!(group, |b| {
+        use vek::Quaternion;
+        bench_binop!(b, op => mul, ty1 => Quaternion<f64>, ty2 => Quaternion<f64>);
+    });
+    group.finish();
+}
+
+fn bench_quaternion_mul_vector3(c: &mut Criterion) {
+    use std::ops::Mul;
+    let mut group = benchmark_group!(c, "f64 quaternion mul vector3");
+    for size in [1, 100].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench_cgmath!(group, size, |b, size| {
+            use cgmath::{Quaternion, Vector3};
+            bench_binop!(b, size, op => mul, ty1 => Quaternion<f64>, ty2 => Vector3<f64>)
+        });
+        bench_nalgebra!(group, size, |b, size| {
+            use nalgebra::{UnitQuaternion, Vector3};
+            bench_binop!(b, size, op => mul, ty1 => UnitQuaternion<f64>, ty2 => Vector3<f64>)
+        });
+        bench_euclid!(group, size, |b, size| {
+            use euclid::{Point3D, Rotation3D, UnknownUnit};
+            bench_binop!(b, size, op => transform_point3d, ty1 => Rotation3D<f64, UnknownUnit, UnknownUnit>, ty2 => Point3D<f64, UnknownUnit>)
+        });
+        bench_vek!(group, size, |b, size| {
+            use vek::{Quaternion, Vec3};
+            bench_binop!(b, size, op => mul, ty1 => Quaternion<f64>, ty2 => Vec3<f64>)
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    quaternion_f64_benches,
+    bench_quaternion_conjugate,
+    bench_quaternion_mul_quaternion,
+    bench_quaternion_mul_vector3,
+);
+criterion_main!(quaternion_f64_benches);
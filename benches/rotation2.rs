@@ -0,0 +1,225 @@
+#[path = "support/macros.rs"]
+#[macro_use]
+mod macros;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench_rotation2_ret_self(c: &mut Criterion) {
+    use mathbench::BenchValue;
+    let mut group = benchmark_group!(c, "rotation2 return self");
+    bench_glam!(group, |b| {
+        use glam::Mat2;
+        bench_unop!(b, op => ret_self, ty => Mat2)
+    });
+    bench_ultraviolet!(group, |b| {
+        use ultraviolet::Rotor2;
+        bench_unop!(b, op => ret_self, ty => Rotor2)
+    });
+    bench_ultraviolet_f32x4!(group, |b| {
+        use ultraviolet::WRotor2;
+        bench_unop4!(b, op => ret_self, ty => WRotor2)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::UnitComplex;
+        bench_unop!(b, op => ret_self, ty => UnitComplex<f32>)
+    });
+    bench_nalgebra_f32x4!(group, |b| {
+        use nalgebra::UnitComplex;
+        use simba::simd::f32x4;
+        bench_unop4!(b, op => ret_self, ty => UnitComplex<f32x4>)
+    });
+    bench_nalgebra_f32x8!(group, |b| {
+        use nalgebra::UnitComplex;
+        use simba::simd::f32x8;
+        bench_unop8!(b, op => ret_self, ty => UnitComplex<f32x8>)
+    });
+    bench_nalgebra_f32x16!(group, |b| {
+        use nalgebra::UnitComplex;
+        use simba::simd::f32x16;
+        bench_unop16!(b, op => ret_self, ty => UnitComplex<f32x16>)
+    });
+    bench_euclid!(group, |b| {
+        use euclid::{Rotation2D, UnknownUnit};
+        bench_unop!(b, op => ret_self, ty => Rotation2D<f32, UnknownUnit, UnknownUnit>)
+    });
+    group.finish();
+}
+
+fn bench_rotation2_inverse(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "rotation2 inverse");
+    bench_glam!(group, |b| {
+        use glam::Mat2;
+        // the inverse of a rotation matrix is its transpose
+        bench_unop!(b, op => transpose, ty => Mat2)
+    });
+    bench_ultraviolet!(group, |b| {
+        use ultraviolet::Rotor2;
+        bench_unop!(b, op => reversed, ty => Rotor2)
+    });
+    bench_ultraviolet_f32x4!(group, |b| {
+        use ultraviolet::WRotor2;
+        bench_unop4!(b, op => reversed, ty => WRotor2)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::UnitComplex;
+        bench_unop!(b, op => inverse, ty => UnitComplex<f32>)
+    });
+    bench_nalgebra_f32x4!(group, |b| {
+        use nalgebra::UnitComplex;
+        use simba::simd::f32x4;
+        bench_unop4!(b, op => inverse, ty => UnitComplex<f32x4>)
+    });
+    bench_nalgebra_f32x8!(group, |b| {
+        use nalgebra::UnitComplex;
+        use simba::simd::f32x8;
+        bench_unop8!(b, op => inverse, ty => UnitComplex<f32x8>)
+    });
+    bench_nalgebra_f32x16!(group, |b| {
+        use nalgebra::UnitComplex;
+        use simba::simd::f32x16;
+        bench_unop16!(b, op => inverse, ty => UnitComplex<f32x16>)
+    });
+    bench_euclid!(group, |b| {
+        use euclid::{Rotation2D, UnknownUnit};
+        bench_unop!(b, op => inverse, ty => Rotation2D<f32, UnknownUnit, UnknownUnit>)
+    });
+    group.finish();
+}
+
+fn bench_rotation2_mul_rotation2(c: &mut Criterion) {
+    use std::ops::Mul;
+    let mut group = benchmark_group!(c, "rotation2 mul rotation2");
+    bench_glam!(group, |b| {
+        use glam::Mat2;
+        bench_binop!(b, op => mul_mat2, ty => Mat2, param => by_ref)
+    });
+    bench_ultraviolet!(group, |b| {
+        use ultraviolet::Rotor2;
+        bench_binop!(b, op => mul, ty1 => Rotor2, ty2 => Rotor2)
+    });
+    bench_ultraviolet_f32x4!(group, |b| {
+        use ultraviolet::WRotor2;
+        bench_binop4!(b, op => mul, ty1 => WRotor2, ty2 => WRotor2)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::UnitComplex;
+        bench_binop!(b, op => mul, ty1 => UnitComplex<f32>, ty2 => UnitComplex<f32>)
+    });
+    bench_nalgebra_f32x4!(group, |b| {
+        use nalgebra::UnitComplex;
+        use simba::simd::f32x4;
+        bench_binop4!(b, op => mul, ty1 => UnitComplex<f32x4>, ty2 => UnitComplex<f32x4>)
+    });
+    bench_nalgebra_f32x8!(group, |b| {
+        use nalgebra::UnitComplex;
+        use simba::simd::f32x8;
+        bench_binop8!(b, op => mul, ty1 => UnitComplex<f32x8>, ty2 => UnitComplex<f32x8>)
+    });
+    bench_nalgebra_f32x16!(group, |b| {
+        use nalgebra::UnitComplex;
+        use simba::simd::f32x16;
+        bench_binop16!(b, op => mul, ty1 => UnitComplex<f32x16>, ty2 => UnitComplex<f32x16>)
+    });
+    bench_euclid!(group, |b| {
+        use euclid::{Rotation2D, UnknownUnit};
+        bench_binop!(b, op => pre_rotate, ty => Rotation2D<f32, UnknownUnit, UnknownUnit>, param => by_ref)
+    });
+    group.finish();
+}
+
+fn bench_rotation2_rotate_vector2(c: &mut Criterion) {
+    use std::ops::Mul;
+    let mut group = benchmark_group!(c, "rotation2 rotate vector2");
+    bench_glam!(group, |b| {
+        use glam::{Mat2, Vec2};
+        bench_binop!(b, op => mul_vec2, ty1 => Mat2, ty2 => Vec2)
+    });
+    bench_ultraviolet!(group, |b| {
+        use ultraviolet::{Rotor2, Vec2};
+        bench_binop!(b, op => mul, ty1 => Rotor2, ty2 => Vec2)
+    });
+    bench_ultraviolet_f32x4!(group, |b| {
+        use ultraviolet::{WRotor2, Wec2};
+        bench_binop4!(b, op => mul, ty1 => WRotor2, ty2 => Wec2)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::{UnitComplex, Vector2};
+        bench_binop!(b, op => transform_vector, ty1 => UnitComplex<f32>, ty2 => Vector2<f32>, param => by_ref)
+    });
+    bench_nalgebra_f32x4!(group, |b| {
+        use nalgebra::{UnitComplex, Vector2};
+        use simba::simd::f32x4;
+        bench_binop4!(b, op => transform_vector, ty1 => UnitComplex<f32x4>, ty2 => Vector2<f32x4>, param => by_ref)
+    });
+    bench_nalgebra_f32x8!(group, |b| {
+        use nalgebra::{UnitComplex, Vector2};
+        use simba::simd::f32x8;
+        bench_binop8!(b, op => transform_vector, ty1 => UnitComplex<f32x8>, ty2 => Vector2<f32x8>, param => by_ref)
+    });
+    bench_nalgebra_f32x16!(group, |b| {
+        use nalgebra::{UnitComplex, Vector2};
+        use simba::simd::f32x16;
+        bench_binop16!(b, op => transform_vector, ty1 => UnitComplex<f32x16>, ty2 => Vector2<f32x16>, param => by_ref)
+    });
+    bench_euclid!(group, |b| {
+        use euclid::{Rotation2D, UnknownUnit, Vector2D};
+        bench_binop!(b, op => transform_vector, ty1 => Rotation2D<f32, UnknownUnit, UnknownUnit>, ty2 => Vector2D<f32, UnknownUnit>)
+    });
+    group.finish();
+}
+
+fn bench_rotation2_slerp(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "rotation2 slerp");
+    bench_glam!(group, |b| {
+        use glam::Mat2;
+        use mathbench::glam_support::Rotation2Ext;
+        bench_ternop!(b, op => slerp, ty => Mat2, ty3 => f32)
+    });
+    bench_ultraviolet!(group, |b| {
+        use mathbench::ultraviolet_support::Rotation2Ext;
+        use ultraviolet::Rotor2;
+        bench_ternop!(b, op => slerp, ty => Rotor2, ty3 => f32)
+    });
+    bench_nalgebra!(group, |b| {
+        use mathbench::nalgebra_support::Rotation2Ext;
+        use nalgebra::UnitComplex;
+        bench_ternop!(b, op => slerp, ty => UnitComplex<f32>, ty3 => f32, param => by_ref)
+    });
+    bench_euclid!(group, |b| {
+        use euclid::{Rotation2D, UnknownUnit};
+        use mathbench::euclid_support::Rotation2Ext;
+        bench_ternop!(b, op => slerp, ty => Rotation2D<f32, UnknownUnit, UnknownUnit>, ty3 => f32, param => by_ref)
+    });
+    group.finish();
+}
+
+fn bench_rotation2_from_angle(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "rotation2 from angle");
+    bench_glam!(group, |b| {
+        use glam::Mat2;
+        bench_ctor!(b, ctor => Mat2::from_angle, ty => f32)
+    });
+    bench_ultraviolet!(group, |b| {
+        use ultraviolet::Rotor2;
+        bench_ctor!(b, ctor => Rotor2::from_angle, ty => f32)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::UnitComplex;
+        bench_ctor!(b, ctor => UnitComplex::<f32>::new, ty => f32)
+    });
+    bench_euclid!(group, |b| {
+        use euclid::{Rotation2D, UnknownUnit};
+        bench_ctor!(b, ctor => Rotation2D::<f32, UnknownUnit, UnknownUnit>::radians, ty => f32)
+    });
+    group.finish();
+}
+
+criterion_group!(
+    rotation2_benches,
+    bench_rotation2_ret_self,
+    bench_rotation2_inverse,
+    bench_rotation2_mul_rotation2,
+    bench_rotation2_rotate_vector2,
+    bench_rotation2_slerp,
+    bench_rotation2_from_angle,
+);
+criterion_main!(rotation2_benches);
@@ -0,0 +1,176 @@
+#[path = "support/macros.rs"]
+#[macro_use]
+mod macros;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench_similarity3_ret_self(c: &mut Criterion) {
+    use mathbench::BenchValue;
+    let mut group = benchmark_group!(c, "similarity3 return self");
+    bench_glam!(group, |b| {
+        use glam::{Quat, Vec3};
+        bench_unop!(b, op => ret_self, ty => (Vec3, Quat, Vec3))
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::{Decomposed, Quaternion, Vector3};
+        bench_unop!(b, op => ret_self, ty => Decomposed<Vector3<f32>, Quaternion<f32>>)
+    });
+    bench_ultraviolet!(group, |b| {
+        use ultraviolet::Similarity3;
+        bench_unop!(b, op => ret_self, ty => Similarity3)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Similarity3;
+        bench_unop!(b, op => ret_self, ty => Similarity3<f32>)
+    });
+    group.finish();
+}
+
+fn bench_similarity3_inverse(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "similarity3 inverse");
+    bench_glam!(group, |b| {
+        use glam::{Quat, Vec3};
+        use mathbench::glam_support::Similarity3Ext;
+        bench_unop!(b, op => inverse, ty => (Vec3, Quat, Vec3))
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::{Decomposed, Quaternion, Transform, Vector3};
+        bench_unop!(b, op => inverse_transform, ty => Decomposed<Vector3<f32>, Quaternion<f32>>)
+    });
+    bench_ultraviolet!(group, |b| {
+        use ultraviolet::Similarity3;
+        bench_unop!(b, op => inversed, ty => Similarity3)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Similarity3;
+        bench_unop!(b, op => inverse, ty => Similarity3<f32>)
+    });
+    group.finish();
+}
+
+fn bench_similarity3_mul_similarity3(c: &mut Criterion) {
+    use std::ops::Mul;
+    let mut group = benchmark_group!(c, "similarity3 mul similarity3");
+    bench_glam!(group, |b| {
+        use glam::{Quat, Vec3};
+        use mathbench::glam_support::Similarity3Ext;
+        bench_binop!(b, op => mul_similarity3, ty => (Vec3, Quat, Vec3), param => by_value)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::{Decomposed, Quaternion, Transform, Vector3};
+        bench_binop!(b, op => concat, ty => Decomposed<Vector3<f32>, Quaternion<f32>>, param => by_ref)
+    });
+    bench_ultraviolet!(group, |b| {
+        use ultraviolet::Similarity3;
+        bench_binop!(b, op => mul, ty => Similarity3, param => by_value)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Similarity3;
+        bench_binop!(b, op => mul, ty => Similarity3<f32>, param => by_ref)
+    });
+    group.finish();
+}
+
+fn bench_similarity3_transform_point3(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "similarity3 transform point3");
+    bench_glam!(group, |b| {
+        use glam::{Quat, Vec3};
+        use mathbench::glam_support::Similarity3Ext;
+        bench_binop!(b, op => transform_point3, ty1 => (Vec3, Quat, Vec3), ty2 => Vec3)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::{Decomposed, Point3, Quaternion, Transform, Vector3};
+        bench_binop!(b, op => transform_point, ty1 => Decomposed<Vector3<f32>, Quaternion<f32>>, ty2 => Point3<f32>)
+    });
+    bench_ultraviolet!(group, |b| {
+        use ultraviolet::{Similarity3, Vec3};
+        bench_binop!(b, op => transform_vec, ty1 => Similarity3, ty2 => Vec3)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::{Point3, Similarity3};
+        bench_binop!(b, op => transform_point, ty1 => Similarity3<f32>, ty2 => Point3<f32>, param => by_ref)
+    });
+    group.finish();
+}
+
+fn bench_similarity3_transform_vector3(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "similarity3 transform vector3");
+    bench_glam!(group, |b| {
+        use glam::{Quat, Vec3};
+        use mathbench::glam_support::Similarity3Ext;
+        bench_binop!(b, op => transform_vector3, ty1 => (Vec3, Quat, Vec3), ty2 => Vec3)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::{Decomposed, Quaternion, Transform, Vector3};
+        bench_binop!(b, op => transform_vector, ty1 => Decomposed<Vector3<f32>, Quaternion<f32>>, ty2 => Vector3<f32>)
+    });
+    bench_ultraviolet!(group, |b| {
+        use mathbench::ultraviolet_support::Similarity3Ext;
+        use ultraviolet::{Similarity3, Vec3};
+        bench_binop!(b, op => transform_vec_no_translation, ty1 => Similarity3, ty2 => Vec3)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::{Similarity3, Vector3};
+        bench_binop!(b, op => transform_vector, ty1 => Similarity3<f32>, ty2 => Vector3<f32>, param => by_ref)
+    });
+    group.finish();
+}
+
+fn bench_similarity3_interpolate(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "similarity3 interpolate");
+    bench_glam!(group, |b| {
+        use glam::{Quat, Vec3};
+        use mathbench::glam_support::Similarity3Ext;
+        bench_ternop!(b, op => lerp, ty => (Vec3, Quat, Vec3), ty3 => f32)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::{Decomposed, Quaternion, Vector3};
+        use mathbench::cgmath_support::Similarity3Ext;
+        bench_ternop!(b, op => lerp, ty => Decomposed<Vector3<f32>, Quaternion<f32>>, ty3 => f32)
+    });
+    bench_ultraviolet!(group, |b| {
+        use mathbench::ultraviolet_support::Similarity3Ext;
+        use ultraviolet::Similarity3;
+        bench_ternop!(b, op => lerp, ty => Similarity3, ty3 => f32)
+    });
+    bench_nalgebra!(group, |b| {
+        use mathbench::nalgebra_support::Similarity3Ext;
+        use nalgebra::Similarity3;
+        bench_ternop!(b, op => lerp, ty => Similarity3<f32>, ty3 => f32, param => by_ref)
+    });
+    group.finish();
+}
+
+fn bench_similarity3_to_matrix4(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "similarity3 to matrix4");
+    bench_glam!(group, |b| {
+        use glam::{Quat, Vec3};
+        use mathbench::glam_support::Similarity3Ext;
+        bench_unop!(b, op => to_mat4, ty => (Vec3, Quat, Vec3))
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::{Decomposed, Quaternion, Vector3};
+        use mathbench::cgmath_support::Similarity3Ext;
+        bench_unop!(b, op => to_matrix4, ty => Decomposed<Vector3<f32>, Quaternion<f32>>)
+    });
+    bench_ultraviolet!(group, |b| {
+        use ultraviolet::Similarity3;
+        bench_unop!(b, op => into_homogeneous_matrix, ty => Similarity3)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Similarity3;
+        bench_unop!(b, op => to_homogeneous, ty => Similarity3<f32>)
+    });
+    group.finish();
+}
+
+criterion_group!(
+    similarity3d_benches,
+    bench_similarity3_ret_self,
+    bench_similarity3_inverse,
+    bench_similarity3_mul_similarity3,
+    bench_similarity3_transform_point3,
+    bench_similarity3_transform_vector3,
+    bench_similarity3_interpolate,
+    bench_similarity3_to_matrix4,
+);
+criterion_main!(similarity3d_benches);
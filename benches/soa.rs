@@ -0,0 +1,189 @@
+#[path = "support/macros.rs"]
+#[macro_use]
+mod macros;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+// Runs `op` on an array of `size` random `glam::Vec3`, the storage type of
+// the data, and an array of wide values with the same number of lanes. `size`
+// is rounded up to a multiple of `lanes`. Without `soa` only the `glam` array
+// is passed to `op`.
+macro_rules! bench_soa {
+    ($b: ident, $size:expr, soa => $soa:ty, lanes => $lanes:expr, op => $op:expr) => {{
+        use mathbench::BenchValue;
+        let mut rng = rand_pcg::Pcg64Mcg::new(rand::random());
+        let len = (*$size as f32 / $lanes as f32).ceil() as usize;
+        let mut aos: Vec<glam::Vec3> = (0..len * $lanes)
+            .map(|_| glam::Vec3::random_value(&mut rng))
+            .collect();
+        let mut soa: Vec<$soa> = (0..len)
+            .map(|_| <$soa as BenchValue>::random_value(&mut rng))
+            .collect();
+        let op = $op;
+        $b.iter(|| op(&mut aos, &mut soa))
+    }};
+    ($b: ident, $size:expr, op => $op:expr) => {{
+        use mathbench::BenchValue;
+        let mut rng = rand_pcg::Pcg64Mcg::new(rand::random());
+        let mut aos: Vec<glam::Vec3> = (0..*$size)
+            .map(|_| glam::Vec3::random_value(&mut rng))
+            .collect();
+        let op = $op;
+        $b.iter(|| op(&mut aos))
+    }};
+}
+
+fn bench_vector3_aos_to_soa(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "vector3 aos to soa");
+    for size in [100, 10000].iter() {
+        group.throughput(Throughput::Elements(*size as u64));
+        bench_ultraviolet_f32x4!(group, size, |b, size| {
+            use mathbench::ultraviolet_support::Vector3SoaExt;
+            use ultraviolet::Wec3;
+            bench_soa!(b, size, soa => Wec3, lanes => 4, op => |aos: &mut Vec<glam::Vec3>, soa: &mut Vec<Wec3>| Wec3::from_aos_slice(aos, soa))
+        });
+        bench_nalgebra_f32x4!(group, size, |b, size| {
+            use mathbench::nalgebra_support::Vector3SoaExt;
+            use nalgebra::Vector3;
+            use simba::simd::f32x4;
+            bench_soa!(b, size, soa => Vector3<f32x4>, lanes => 4, op => |aos: &mut Vec<glam::Vec3>, soa: &mut Vec<Vector3<f32x4>>| Vector3::from_aos_slice(aos, soa))
+        });
+        bench_nalgebra_f32x8!(group, size, |b, size| {
+            use mathbench::nalgebra_support::Vector3SoaExt;
+            use nalgebra::Vector3;
+            use simba::simd::f32x8;
+            bench_soa!(b, size, soa => Vector3<f32x8>, lanes => 8, op => |aos: &mut Vec<glam::Vec3>, soa: &mut Vec<Vector3<f32x8>>| Vector3::from_aos_slice(aos, soa))
+        });
+        bench_nalgebra_f32x16!(group, size, |b, size| {
+            use mathbench::nalgebra_support::Vector3SoaExt;
+            use nalgebra::Vector3;
+            use simba::simd::f32x16;
+            bench_soa!(b, size, soa => Vector3<f32x16>, lanes => 16, op => |aos: &mut Vec<glam::Vec3>, soa: &mut Vec<Vector3<f32x16>>| Vector3::from_aos_slice(aos, soa))
+        });
+    }
+    group.finish();
+}
+
+fn bench_vector3_soa_to_aos(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "vector3 soa to aos");
+    for size in [100, 10000].iter() {
+        group.throughput(Throughput::Elements(*size as u64));
+        bench_ultraviolet_f32x4!(group, size, |b, size| {
+            use mathbench::ultraviolet_support::Vector3SoaExt;
+            use ultraviolet::Wec3;
+            bench_soa!(b, size, soa => Wec3, lanes => 4, op => |aos: &mut Vec<glam::Vec3>, soa: &mut Vec<Wec3>| Wec3::to_aos_slice(soa, aos))
+        });
+        bench_nalgebra_f32x4!(group, size, |b, size| {
+            use mathbench::nalgebra_support::Vector3SoaExt;
+            use nalgebra::Vector3;
+            use simba::simd::f32x4;
+            bench_soa!(b, size, soa => Vector3<f32x4>, lanes => 4, op => |aos: &mut Vec<glam::Vec3>, soa: &mut Vec<Vector3<f32x4>>| Vector3::to_aos_slice(soa, aos))
+        });
+        bench_nalgebra_f32x8!(group, size, |b, size| {
+            use mathbench::nalgebra_support::Vector3SoaExt;
+            use nalgebra::Vector3;
+            use simba::simd::f32x8;
+            bench_soa!(b, size, soa => Vector3<f32x8>, lanes => 8, op => |aos: &mut Vec<glam::Vec3>, soa: &mut Vec<Vector3<f32x8>>| Vector3::to_aos_slice(soa, aos))
+        });
+        bench_nalgebra_f32x16!(group, size, |b, size| {
+            use mathbench::nalgebra_support::Vector3SoaExt;
+            use nalgebra::Vector3;
+            use simba::simd::f32x16;
+            bench_soa!(b, size, soa => Vector3<f32x16>, lanes => 16, op => |aos: &mut Vec<glam::Vec3>, soa: &mut Vec<Vector3<f32x16>>| Vector3::to_aos_slice(soa, aos))
+        });
+    }
+    group.finish();
+}
+
+// Packs the points, transforms them by a matrix splatted into every lane and
+// unpacks them. The `glam` baseline transforms the points in place.
+fn bench_transform_point3_aos_via_soa(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "transform point3 aos via soa");
+    for size in [100, 10000].iter() {
+        group.throughput(Throughput::Elements(*size as u64));
+        bench_glam!(group, size, |b, size| {
+            use glam::Mat4;
+            use mathbench::BenchValue;
+            let mut rng = rand_pcg::Pcg64Mcg::new(rand::random());
+            let m = Mat4::random_value(&mut rng);
+            bench_soa!(b, size, op => |aos: &mut Vec<glam::Vec3>| {
+                for point in aos.iter_mut() {
+                    *point = m.transform_point3(*point);
+                }
+            })
+        });
+        bench_ultraviolet_f32x4!(group, size, |b, size| {
+            use mathbench::ultraviolet_support::{Matrix4Ext, Vector3SoaExt};
+            use mathbench::BenchValue;
+            use ultraviolet::{Mat4, Wat4, Wec3, Wec4};
+            let mut rng = rand_pcg::Pcg64Mcg::new(rand::random());
+            let m = Mat4::random_value(&mut rng);
+            let m = Wat4::new(
+                Wec4::splat(m.cols[0]),
+                Wec4::splat(m.cols[1]),
+                Wec4::splat(m.cols[2]),
+                Wec4::splat(m.cols[3]),
+            );
+            bench_soa!(b, size, soa => Wec3, lanes => 4, op => |aos: &mut Vec<glam::Vec3>, soa: &mut Vec<Wec3>| {
+                Wec3::from_aos_slice(aos, soa);
+                for point in soa.iter_mut() {
+                    *point = m.project_point3(*point);
+                }
+                Wec3::to_aos_slice(soa, aos);
+            })
+        });
+        bench_nalgebra_f32x4!(group, size, |b, size| {
+            use mathbench::nalgebra_support::{SimdMatrix4Ext, Vector3SoaExt};
+            use mathbench::BenchValue;
+            use nalgebra::{Matrix4, Point3, Vector3};
+            use simba::simd::{f32x4, SimdValue};
+            let mut rng = rand_pcg::Pcg64Mcg::new(rand::random());
+            let m = Matrix4::<f32>::random_value(&mut rng).map(f32x4::splat);
+            bench_soa!(b, size, soa => Vector3<f32x4>, lanes => 4, op => |aos: &mut Vec<glam::Vec3>, soa: &mut Vec<Vector3<f32x4>>| {
+                Vector3::from_aos_slice(aos, soa);
+                for point in soa.iter_mut() {
+                    *point = m.transform_point3(&Point3::from(*point)).coords;
+                }
+                Vector3::to_aos_slice(soa, aos);
+            })
+        });
+        bench_nalgebra_f32x8!(group, size, |b, size| {
+            use mathbench::nalgebra_support::{SimdMatrix4Ext, Vector3SoaExt};
+            use mathbench::BenchValue;
+            use nalgebra::{Matrix4, Point3, Vector3};
+            use simba::simd::{f32x8, SimdValue};
+            let mut rng = rand_pcg::Pcg64Mcg::new(rand::random());
+            let m = Matrix4::<f32>::random_value(&mut rng).map(f32x8::splat);
+            bench_soa!(b, size, soa => Vector3<f32x8>, lanes => 8, op => |aos: &mut Vec<glam::Vec3>, soa: &mut Vec<Vector3<f32x8>>| {
+                Vector3::from_aos_slice(aos, soa);
+                for point in soa.iter_mut() {
+                    *point = m.transform_point3(&Point3::from(*point)).coords;
+                }
+                Vector3::to_aos_slice(soa, aos);
+            })
+        });
+        bench_nalgebra_f32x16!(group, size, |b, size| {
+            use mathbench::nalgebra_support::{SimdMatrix4Ext, Vector3SoaExt};
+            use mathbench::BenchValue;
+            use nalgebra::{Matrix4, Point3, Vector3};
+            use simba::simd::{f32x16, SimdValue};
+            let mut rng = rand_pcg::Pcg64Mcg::new(rand::random());
+            let m = Matrix4::<f32>::random_value(&mut rng).map(f32x16::splat);
+            bench_soa!(b, size, soa => Vector3<f32x16>, lanes => 16, op => |aos: &mut Vec<glam::Vec3>, soa: &mut Vec<Vector3<f32x16>>| {
+                Vector3::from_aos_slice(aos, soa);
+                for point in soa.iter_mut() {
+                    *point = m.transform_point3(&Point3::from(*point)).coords;
+                }
+                Vector3::to_aos_slice(soa, aos);
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    soa_benches,
+    bench_vector3_aos_to_soa,
+    bench_vector3_soa_to_aos,
+    bench_transform_point3_aos_via_soa,
+);
+criterion_main!(soa_benches);
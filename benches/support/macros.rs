@@ -1,3 +1,12 @@
+// Creates a benchmark group named after `$name` and the input distribution
+// selected by `MATHBENCH_INPUTS`, see `InputDistribution::group_name`.
+#[macro_export]
+macro_rules! benchmark_group {
+    ($c:ident, $name:literal) => {
+        $c.benchmark_group(mathbench::input_distribution().group_name($name))
+    };
+}
+
 #[macro_export]
 macro_rules! bench_lib {
     ($libname:literal, $group:ident, $size:expr, $closure:expr) => {
@@ -17,12 +26,17 @@ macro_rules! bench_lib {
 #[macro_export]
 macro_rules! bench_glam {
     ($group:ident, $closure:expr) => {
-        // bench_lib!("glam", $group, $closure)
-        $group.bench_function("glam", $closure)
+        #[cfg(not(feature = "glam_scalar"))]
+        bench_lib!("glam", $group, $closure);
+        // glam built with scalar-math is reported as a separate library
+        #[cfg(feature = "glam_scalar")]
+        bench_lib!("glam_scalar", $group, $closure)
     };
     ($group:ident, $size:expr, $closure:expr) => {
-        // bench_lib!("glam", $group, $size, $closure)
-        $group.bench_with_input(criterion::BenchmarkId::new("glam", $size), $size, $closure)
+        #[cfg(not(feature = "glam_scalar"))]
+        bench_lib!("glam", $group, $size, $closure);
+        #[cfg(feature = "glam_scalar")]
+        bench_lib!("glam_scalar", $group, $size, $closure)
     };
 }
 
@@ -106,6 +120,16 @@ macro_rules! bench_nalgebra_f32x16 {
     };
 }
 
+#[macro_export]
+macro_rules! bench_nalgebra_glm {
+    ($group:ident, $closure:expr) => {
+        bench_lib!("nalgebra_glm", $group, $closure)
+    };
+    ($group:ident, $size:expr, $closure:expr) => {
+        bench_lib!("nalgebra_glm", $group, $size, $closure)
+    };
+}
+
 #[macro_export]
 macro_rules! bench_vek {
     ($group:ident, $closure:expr) => {
@@ -132,6 +156,46 @@ macro_rules! bench_pathfinder {
     };
 }
 
+#[macro_export]
+macro_rules! bench_reference {
+    ($group:ident, $closure:expr) => {
+        bench_lib!("reference", $group, $closure)
+    };
+    ($group:ident, $size:expr, $closure:expr) => {
+        bench_lib!("reference", $group, $size, $closure)
+    };
+}
+
+#[macro_export]
+macro_rules! bench_intrinsics {
+    ($group:ident, $closure:expr) => {
+        #[cfg(all(feature = "intrinsics", target_arch = "x86_64"))]
+        $group.bench_function("intrinsics", $closure)
+    };
+    ($group:ident, $size:expr, $closure:expr) => {
+        #[cfg(all(feature = "intrinsics", target_arch = "x86_64"))]
+        $group.bench_with_input(
+            criterion::BenchmarkId::new("intrinsics", $size),
+            $size,
+            $closure,
+        )
+    };
+}
+
+// Only benchmarks if AVX is detected at runtime. The closure must call a
+// `#[target_feature(enable = "avx")]` function for the AVX code to be inlined.
+#[macro_export]
+macro_rules! bench_intrinsics_avx {
+    ($group:ident, $closure:expr) => {
+        #[cfg(all(feature = "intrinsics", target_arch = "x86_64"))]
+        {
+            if is_x86_feature_detected!("avx") {
+                $group.bench_function("intrinsics_avx", $closure);
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! bench_unop {
     ($b: ident, op => $unop: ident, ty => $t:ty) => {{
@@ -235,6 +299,80 @@ macro_rules! bench_unop16 {
     }};
 }
 
+#[macro_export]
+macro_rules! bench_ctor {
+    ($b: ident, ctor => $ctor: expr, ty => $t:ty, args => ($($arg:ident),+)) => {{
+        const SIZE: usize = 1 << 13;
+        let ctor = $ctor;
+        let mut rng = rand_pcg::Pcg64Mcg::new(rand::random());
+        // each input is a tuple of constructor arguments
+        let inputs = criterion::black_box(
+            (0..SIZE)
+                .map(|_| ($({
+                    let $arg = <$t as mathbench::BenchValue>::random_value(&mut rng);
+                    $arg
+                },)+))
+                .collect::<Vec<_>>(),
+        );
+        // pre-fill output vector with some random value
+        let ($($arg,)+) = inputs[0];
+        let mut outputs = vec![ctor($($arg),+); SIZE];
+        let mut i = 0;
+        $b.iter(|| {
+            i = (i + 16) & (SIZE - 1);
+            unsafe { let ($($arg,)+) = *inputs.get_unchecked(i + 00); *outputs.get_unchecked_mut(i + 00) = ctor($($arg),+) }
+            unsafe { let ($($arg,)+) = *inputs.get_unchecked(i + 01); *outputs.get_unchecked_mut(i + 01) = ctor($($arg),+) }
+            unsafe { let ($($arg,)+) = *inputs.get_unchecked(i + 02); *outputs.get_unchecked_mut(i + 02) = ctor($($arg),+) }
+            unsafe { let ($($arg,)+) = *inputs.get_unchecked(i + 03); *outputs.get_unchecked_mut(i + 03) = ctor($($arg),+) }
+            unsafe { let ($($arg,)+) = *inputs.get_unchecked(i + 04); *outputs.get_unchecked_mut(i + 04) = ctor($($arg),+) }
+            unsafe { let ($($arg,)+) = *inputs.get_unchecked(i + 05); *outputs.get_unchecked_mut(i + 05) = ctor($($arg),+) }
+            unsafe { let ($($arg,)+) = *inputs.get_unchecked(i + 06); *outputs.get_unchecked_mut(i + 06) = ctor($($arg),+) }
+            unsafe { let ($($arg,)+) = *inputs.get_unchecked(i + 07); *outputs.get_unchecked_mut(i + 07) = ctor($($arg),+) }
+            unsafe { let ($($arg,)+) = *inputs.get_unchecked(i + 08); *outputs.get_unchecked_mut(i + 08) = ctor($($arg),+) }
+            unsafe { let ($($arg,)+) = *inputs.get_unchecked(i + 09); *outputs.get_unchecked_mut(i + 09) = ctor($($arg),+) }
+            unsafe { let ($($arg,)+) = *inputs.get_unchecked(i + 10); *outputs.get_unchecked_mut(i + 10) = ctor($($arg),+) }
+            unsafe { let ($($arg,)+) = *inputs.get_unchecked(i + 11); *outputs.get_unchecked_mut(i + 11) = ctor($($arg),+) }
+            unsafe { let ($($arg,)+) = *inputs.get_unchecked(i + 12); *outputs.get_unchecked_mut(i + 12) = ctor($($arg),+) }
+            unsafe { let ($($arg,)+) = *inputs.get_unchecked(i + 13); *outputs.get_unchecked_mut(i + 13) = ctor($($arg),+) }
+            unsafe { let ($($arg,)+) = *inputs.get_unchecked(i + 14); *outputs.get_unchecked_mut(i + 14) = ctor($($arg),+) }
+            unsafe { let ($($arg,)+) = *inputs.get_unchecked(i + 15); *outputs.get_unchecked_mut(i + 15) = ctor($($arg),+) }
+        });
+        criterion::black_box(outputs);
+    }};
+    ($b: ident, ctor => $ctor: path, ty => $t:ty) => {{
+        const SIZE: usize = 1 << 13;
+        let mut rng = rand_pcg::Pcg64Mcg::new(rand::random());
+        let inputs = criterion::black_box(
+            (0..SIZE)
+                .map(|_| <$t as mathbench::BenchValue>::random_value(&mut rng))
+                .collect::<Vec<_>>(),
+        );
+        // pre-fill output vector with some random value
+        let mut outputs = vec![$ctor(<$t as mathbench::BenchValue>::random_value(&mut rng)); SIZE];
+        let mut i = 0;
+        $b.iter(|| {
+            i = (i + 16) & (SIZE - 1);
+            unsafe { *outputs.get_unchecked_mut(i + 00) = $ctor(*inputs.get_unchecked(i + 00)) }
+            unsafe { *outputs.get_unchecked_mut(i + 01) = $ctor(*inputs.get_unchecked(i + 01)) }
+            unsafe { *outputs.get_unchecked_mut(i + 02) = $ctor(*inputs.get_unchecked(i + 02)) }
+            unsafe { *outputs.get_unchecked_mut(i + 03) = $ctor(*inputs.get_unchecked(i + 03)) }
+            unsafe { *outputs.get_unchecked_mut(i + 04) = $ctor(*inputs.get_unchecked(i + 04)) }
+            unsafe { *outputs.get_unchecked_mut(i + 05) = $ctor(*inputs.get_unchecked(i + 05)) }
+            unsafe { *outputs.get_unchecked_mut(i + 06) = $ctor(*inputs.get_unchecked(i + 06)) }
+            unsafe { *outputs.get_unchecked_mut(i + 07) = $ctor(*inputs.get_unchecked(i + 07)) }
+            unsafe { *outputs.get_unchecked_mut(i + 08) = $ctor(*inputs.get_unchecked(i + 08)) }
+            unsafe { *outputs.get_unchecked_mut(i + 09) = $ctor(*inputs.get_unchecked(i + 09)) }
+            unsafe { *outputs.get_unchecked_mut(i + 10) = $ctor(*inputs.get_unchecked(i + 10)) }
+            unsafe { *outputs.get_unchecked_mut(i + 11) = $ctor(*inputs.get_unchecked(i + 11)) }
+            unsafe { *outputs.get_unchecked_mut(i + 12) = $ctor(*inputs.get_unchecked(i + 12)) }
+            unsafe { *outputs.get_unchecked_mut(i + 13) = $ctor(*inputs.get_unchecked(i + 13)) }
+            unsafe { *outputs.get_unchecked_mut(i + 14) = $ctor(*inputs.get_unchecked(i + 14)) }
+            unsafe { *outputs.get_unchecked_mut(i + 15) = $ctor(*inputs.get_unchecked(i + 15)) }
+        });
+        criterion::black_box(outputs);
+    }};
+}
+
 #[macro_export]
 macro_rules! by_value {
     ($e:expr) => {
@@ -341,6 +479,65 @@ macro_rules! bench_binop {
     }};
 }
 
+#[macro_export]
+macro_rules! bench_ternop {
+    ($b: ident, op => $ternop: ident, ty1 => $t1:ty, ty2 => $t2:ty, ty3 => $t3:ty, param => $param:tt) => {{
+        const SIZE: usize = 1 << 13;
+        let mut rng = rand_pcg::Pcg64Mcg::new(rand::random());
+        // generate input arrays
+        let inputs1 = criterion::black_box(
+            (0..SIZE)
+                .map(|_| <$t1 as mathbench::BenchValue>::random_value(&mut rng))
+                .collect::<Vec<_>>(),
+        );
+        let inputs2 = criterion::black_box(
+            (0..SIZE)
+                .map(|_| <$t2 as mathbench::BenchValue>::random_value(&mut rng))
+                .collect::<Vec<_>>(),
+        );
+        let inputs3 = criterion::black_box(
+            (0..SIZE)
+                .map(|_| <$t3 as mathbench::BenchValue>::random_value(&mut rng))
+                .collect::<Vec<_>>(),
+        );
+        // pre-fill output vector with some random value
+        let mut outputs = vec![<$t1 as mathbench::BenchValue>::random_value(&mut rng).$ternop($param!(&<$t2 as mathbench::BenchValue>::random_value(&mut rng)), <$t3 as mathbench::BenchValue>::random_value(&mut rng)); SIZE];
+        let mut i = 0;
+        $b.iter(|| {
+            // minimise overhead of accessing random data using get unchecked
+            i = (i + 16) & (SIZE - 1);
+            unsafe {
+                *outputs.get_unchecked_mut(i + 0) = inputs1.get_unchecked(i + 0).$ternop($param!(inputs2.get_unchecked(i + 0)), *inputs3.get_unchecked(i + 0));
+                *outputs.get_unchecked_mut(i + 1) = inputs1.get_unchecked(i + 1).$ternop($param!(inputs2.get_unchecked(i + 1)), *inputs3.get_unchecked(i + 1));
+                *outputs.get_unchecked_mut(i + 2) = inputs1.get_unchecked(i + 2).$ternop($param!(inputs2.get_unchecked(i + 2)), *inputs3.get_unchecked(i + 2));
+                *outputs.get_unchecked_mut(i + 3) = inputs1.get_unchecked(i + 3).$ternop($param!(inputs2.get_unchecked(i + 3)), *inputs3.get_unchecked(i + 3));
+                *outputs.get_unchecked_mut(i + 4) = inputs1.get_unchecked(i + 4).$ternop($param!(inputs2.get_unchecked(i + 4)), *inputs3.get_unchecked(i + 4));
+                *outputs.get_unchecked_mut(i + 5) = inputs1.get_unchecked(i + 5).$ternop($param!(inputs2.get_unchecked(i + 5)), *inputs3.get_unchecked(i + 5));
+                *outputs.get_unchecked_mut(i + 6) = inputs1.get_unchecked(i + 6).$ternop($param!(inputs2.get_unchecked(i + 6)), *inputs3.get_unchecked(i + 6));
+                *outputs.get_unchecked_mut(i + 7) = inputs1.get_unchecked(i + 7).$ternop($param!(inputs2.get_unchecked(i + 7)), *inputs3.get_unchecked(i + 7));
+                *outputs.get_unchecked_mut(i + 8) = inputs1.get_unchecked(i + 8).$ternop($param!(inputs2.get_unchecked(i + 8)), *inputs3.get_unchecked(i + 8));
+                *outputs.get_unchecked_mut(i + 9) = inputs1.get_unchecked(i + 9).$ternop($param!(inputs2.get_unchecked(i + 9)), *inputs3.get_unchecked(i + 9));
+                *outputs.get_unchecked_mut(i + 10) = inputs1.get_unchecked(i + 10).$ternop($param!(inputs2.get_unchecked(i + 10)), *inputs3.get_unchecked(i + 10));
+                *outputs.get_unchecked_mut(i + 11) = inputs1.get_unchecked(i + 11).$ternop($param!(inputs2.get_unchecked(i + 11)), *inputs3.get_unchecked(i + 11));
+                *outputs.get_unchecked_mut(i + 12) = inputs1.get_unchecked(i + 12).$ternop($param!(inputs2.get_unchecked(i + 12)), *inputs3.get_unchecked(i + 12));
+                *outputs.get_unchecked_mut(i + 13) = inputs1.get_unchecked(i + 13).$ternop($param!(inputs2.get_unchecked(i + 13)), *inputs3.get_unchecked(i + 13));
+                *outputs.get_unchecked_mut(i + 14) = inputs1.get_unchecked(i + 14).$ternop($param!(inputs2.get_unchecked(i + 14)), *inputs3.get_unchecked(i + 14));
+                *outputs.get_unchecked_mut(i + 15) = inputs1.get_unchecked(i + 15).$ternop($param!(inputs2.get_unchecked(i + 15)), *inputs3.get_unchecked(i + 15));
+            }
+        });
+        criterion::black_box(outputs);
+    }};
+    ($b: ident, op => $ternop: ident, ty1 => $t1:ty, ty2 => $t2:ty, ty3 => $t3:ty) => {{
+        bench_ternop!($b, op => $ternop, ty1 => $t1, ty2 => $t2, ty3 => $t3, param => by_value)
+    }};
+    ($b: ident, op => $ternop: ident, ty => $ty:ty, ty3 => $t3:ty, param => $param:tt) => {{
+        bench_ternop!($b, op => $ternop, ty1 => $ty, ty2 => $ty, ty3 => $t3, param => $param)
+    }};
+    ($b: ident, op => $ternop: ident, ty => $ty:ty, ty3 => $t3:ty) => {{
+        bench_ternop!($b, op => $ternop, ty1 => $ty, ty2 => $ty, ty3 => $t3, param => by_value)
+    }};
+}
+
 #[macro_export]
 macro_rules! bench_binop4 {
     ($b: ident, $size:expr, op => $binop: ident, ty1 => $t1:ty, ty2 => $t2:ty, param => $param:tt) => {{
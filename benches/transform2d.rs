@@ -5,7 +5,7 @@ use criterion::{criterion_group, criterion_main, Criterion};
 
 fn bench_transform2_ret_self(c: &mut Criterion) {
     use mathbench::BenchValue;
-    let mut group = c.benchmark_group("transform2 return self");
+    let mut group = benchmark_group!(c, "transform2 return self");
     bench_nalgebra!(group, |b| {
         use nalgebra::Transform2;
         bench_unop!(b, op => ret_self, ty => Transform2<f32>)
@@ -22,7 +22,7 @@ fn bench_transform2_ret_self(c: &mut Criterion) {
 }
 
 fn bench_transform2_inverse(c: &mut Criterion) {
-    let mut group = c.benchmark_group("transform2 inverse");
+    let mut group = benchmark_group!(c, "transform2 inverse");
     bench_nalgebra!(group, |b| {
         use nalgebra::Transform2;
         bench_unop!(b, op => try_inverse, ty => Transform2<f32>)
@@ -40,7 +40,7 @@ fn bench_transform2_inverse(c: &mut Criterion) {
 
 fn bench_transform2_mul_transform2(c: &mut Criterion) {
     use std::ops::Mul;
-    let mut group = c.benchmark_group("transform2 mul transform2");
+    let mut group = benchmark_group!(c, "transform2 mul transform2");
     bench_nalgebra!(group, |b| {
         use nalgebra::Transform2;
         bench_binop!(b, op => mul, ty1 => Transform2<f32>, ty2 => Transform2<f32>, param => by_ref)
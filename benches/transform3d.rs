@@ -6,7 +6,15 @@ use criterion::{criterion_group, criterion_main, Criterion};
 // returns self to check overhead of benchmark
 fn bench_transform3_ret_self(c: &mut Criterion) {
     use mathbench::BenchValue;
-    let mut group = c.benchmark_group("transform3 return self");
+    let mut group = benchmark_group!(c, "transform3 return self");
+    bench_glam!(group, |b| {
+        use glam::Mat4;
+        bench_unop!(b, op => ret_self, ty => Mat4)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::Matrix4;
+        bench_unop!(b, op => ret_self, ty => Matrix4<f32>)
+    });
     bench_nalgebra!(group, |b| {
         use nalgebra::Transform3;
         bench_unop!(b, op => ret_self, ty => Transform3<f32>)
@@ -15,6 +23,10 @@ fn bench_transform3_ret_self(c: &mut Criterion) {
         use euclid::{Transform3D, UnknownUnit};
         bench_unop!(b, op => ret_self, ty => Transform3D<f32, UnknownUnit, UnknownUnit>)
     });
+    bench_vek!(group, |b| {
+        use vek::Mat4;
+        bench_unop!(b, op => ret_self, ty => Mat4<f32>)
+    });
     bench_pathfinder!(group, |b| {
         use pathfinder_geometry::transform3d::Transform4F;
         bench_unop!(b, op => ret_self, ty => Transform4F)
@@ -23,7 +35,15 @@ fn bench_transform3_ret_self(c: &mut Criterion) {
 }
 
 fn bench_transform3_inverse(c: &mut Criterion) {
-    let mut group = c.benchmark_group("transform3 inverse");
+    let mut group = benchmark_group!(c, "transform3 inverse");
+    bench_glam!(group, |b| {
+        use glam::Mat4;
+        bench_unop!(b, op => inverse, ty => Mat4)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::{Matrix4, SquareMatrix};
+        bench_unop!(b, op => invert, ty => Matrix4<f32>)
+    });
     bench_nalgebra!(group, |b| {
         use nalgebra::Transform3;
         bench_unop!(b, op => try_inverse, ty => Transform3<f32>)
@@ -32,6 +52,10 @@ fn bench_transform3_inverse(c: &mut Criterion) {
         use euclid::{Transform3D, UnknownUnit};
         bench_unop!(b, op => inverse, ty => Transform3D<f32, UnknownUnit, UnknownUnit>)
     });
+    bench_vek!(group, |b| {
+        use vek::Mat4;
+        bench_unop!(b, op => inverted, ty => Mat4<f32>)
+    });
     bench_pathfinder!(group, |b| {
         use pathfinder_geometry::transform3d::Transform4F;
         bench_unop!(b, op => inverse, ty => Transform4F)
@@ -41,7 +65,15 @@ fn bench_transform3_inverse(c: &mut Criterion) {
 
 fn bench_transform3_mul_transform3(c: &mut Criterion) {
     use std::ops::Mul;
-    let mut group = c.benchmark_group("transform3 mul transform3d");
+    let mut group = benchmark_group!(c, "transform3 mul transform3d");
+    bench_glam!(group, |b| {
+        use glam::Mat4;
+        bench_binop!(b, op => mul, ty1 => Mat4, ty2 => Mat4)
+    });
+    bench_cgmath!(group, |b| {
+        use cgmath::Matrix4;
+        bench_binop!(b, op => mul, ty1 => Matrix4<f32>, ty2 => Matrix4<f32>)
+    });
     bench_nalgebra!(group, |b| {
         use nalgebra::Transform3;
         bench_binop!(b, op => mul, ty1 => Transform3<f32>, ty2 => Transform3<f32>, param => by_ref)
@@ -50,6 +82,10 @@ fn bench_transform3_mul_transform3(c: &mut Criterion) {
         use euclid::{Transform3D, UnknownUnit};
         bench_binop!(b, op => post_transform, ty => Transform3D<f32, UnknownUnit, UnknownUnit>, param => by_ref)
     });
+    bench_vek!(group, |b| {
+        use vek::Mat4;
+        bench_binop!(b, op => mul, ty1 => Mat4<f32>, ty2 => Mat4<f32>)
+    });
     bench_pathfinder!(group, |b| {
         use pathfinder_geometry::transform3d::Transform4F;
         bench_binop!(b, op => mul, ty1 => Transform4F, ty2 => Transform4F)
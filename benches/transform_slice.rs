@@ -0,0 +1,140 @@
+#[path = "support/macros.rs"]
+#[macro_use]
+mod macros;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+// Transforms a slice of `size` random points in place by a single random
+// transform. The wide variants splat the transform into every lane and
+// transform `size / lanes` wide points.
+macro_rules! bench_transform_slice {
+    ($b: ident, $size:expr, ty1 => $t1:ty, splat => $splat:expr, ty2 => $t2:ty, op => $op:expr) => {{
+        use mathbench::BenchValue;
+        let mut rng = rand_pcg::Pcg64Mcg::new(rand::random());
+        let splat = $splat;
+        let transform = splat(<$t1 as BenchValue>::random_value(&mut rng));
+        let mut points: Vec<$t2> = (0..*$size)
+            .map(|_| <$t2 as BenchValue>::random_value(&mut rng))
+            .collect();
+        let op = $op;
+        $b.iter(|| {
+            for point in points.iter_mut() {
+                *point = op(&transform, *point);
+            }
+        })
+    }};
+    ($b: ident, $size:expr, ty1 => $t1:ty, ty2 => $t2:ty, op => $op:expr) => {{
+        bench_transform_slice!($b, $size, ty1 => $t1, splat => |t: $t1| t, ty2 => $t2, op => $op)
+    }};
+}
+
+fn bench_transform_point3_slice(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "transform point3 slice");
+    for size in [100, 10000].iter() {
+        group.throughput(Throughput::Elements(*size as u64));
+        bench_glam!(group, size, |b, size| {
+            use glam::{Mat4, Vec3};
+            bench_transform_slice!(b, size, ty1 => Mat4, ty2 => Vec3, op => |m: &Mat4, p| m.transform_point3(p))
+        });
+        bench_cgmath!(group, size, |b, size| {
+            use cgmath::{Matrix4, Point3, Transform};
+            bench_transform_slice!(b, size, ty1 => Matrix4<f32>, ty2 => Point3<f32>, op => |m: &Matrix4<f32>, p| m.transform_point(p))
+        });
+        bench_ultraviolet!(group, size, |b, size| {
+            use mathbench::ultraviolet_support::Matrix4Ext;
+            use ultraviolet::{Mat4, Vec3};
+            bench_transform_slice!(b, size, ty1 => Mat4, ty2 => Vec3, op => |m: &Mat4, p| m.project_point3(p))
+        });
+        bench_ultraviolet_f32x4!(group, size, |b, size| {
+            use mathbench::ultraviolet_support::Matrix4Ext;
+            use ultraviolet::{Mat4, Wat4, Wec3, Wec4};
+            let splat = |m: Mat4| {
+                Wat4::new(
+                    Wec4::splat(m.cols[0]),
+                    Wec4::splat(m.cols[1]),
+                    Wec4::splat(m.cols[2]),
+                    Wec4::splat(m.cols[3]),
+                )
+            };
+            bench_transform_slice!(b, &((*size as f32 / 4.0).ceil() as usize), ty1 => Mat4, splat => splat, ty2 => Wec3, op => |m: &Wat4, p| m.project_point3(p))
+        });
+        bench_nalgebra!(group, size, |b, size| {
+            use nalgebra::{Matrix4, Point3};
+            bench_transform_slice!(b, size, ty1 => Matrix4<f32>, ty2 => Point3<f32>, op => |m: &Matrix4<f32>, p| m.transform_point(&p))
+        });
+        bench_nalgebra_f32x4!(group, size, |b, size| {
+            use mathbench::nalgebra_support::SimdMatrix4Ext;
+            use nalgebra::{Matrix4, Point3};
+            use simba::simd::{f32x4, SimdValue};
+            bench_transform_slice!(b, &((*size as f32 / 4.0).ceil() as usize), ty1 => Matrix4<f32>, splat => |m: Matrix4<f32>| m.map(f32x4::splat), ty2 => Point3<f32x4>, op => |m: &Matrix4<f32x4>, p| m.transform_point3(&p))
+        });
+        bench_nalgebra_f32x8!(group, size, |b, size| {
+            use mathbench::nalgebra_support::SimdMatrix4Ext;
+            use nalgebra::{Matrix4, Point3};
+            use simba::simd::{f32x8, SimdValue};
+            bench_transform_slice!(b, &((*size as f32 / 8.0).ceil() as usize), ty1 => Matrix4<f32>, splat => |m: Matrix4<f32>| m.map(f32x8::splat), ty2 => Point3<f32x8>, op => |m: &Matrix4<f32x8>, p| m.transform_point3(&p))
+        });
+        bench_nalgebra_f32x16!(group, size, |b, size| {
+            use mathbench::nalgebra_support::SimdMatrix4Ext;
+            use nalgebra::{Matrix4, Point3};
+            use simba::simd::{f32x16, SimdValue};
+            bench_transform_slice!(b, &((*size as f32 / 16.0).ceil() as usize), ty1 => Matrix4<f32>, splat => |m: Matrix4<f32>| m.map(f32x16::splat), ty2 => Point3<f32x16>, op => |m: &Matrix4<f32x16>, p| m.transform_point3(&p))
+        });
+        bench_euclid!(group, size, |b, size| {
+            use euclid::{Point3D, Transform3D, UnknownUnit};
+            // `transform_point3d` returns `None` if w is zero, which it never
+            // is for the homogeneous bench inputs
+            bench_transform_slice!(b, size, ty1 => Transform3D<f32, UnknownUnit, UnknownUnit>, ty2 => Point3D<f32, UnknownUnit>, op => |m: &Transform3D<f32, UnknownUnit, UnknownUnit>, p| m.transform_point3d(p).unwrap())
+        });
+        bench_vek!(group, size, |b, size| {
+            use vek::{Mat4, Vec3};
+            bench_transform_slice!(b, size, ty1 => Mat4<f32>, ty2 => Vec3<f32>, op => |m: &Mat4<f32>, p| m.mul_point(p))
+        });
+        bench_pathfinder!(group, size, |b, size| {
+            // pathfinder doesn't have a point3 or vector3 type
+            use pathfinder_geometry::{transform3d::Transform4F, vector::Vector4F};
+            bench_transform_slice!(b, size, ty1 => Transform4F, ty2 => Vector4F, op => |m: &Transform4F, p| *m * p)
+        });
+        bench_reference!(group, size, |b, size| {
+            use mathbench::reference::{Mat4, Vec3};
+            bench_transform_slice!(b, size, ty1 => Mat4, ty2 => Vec3, op => |m: &Mat4, p| m.transform_point3(p))
+        });
+    }
+    group.finish();
+}
+
+fn bench_isometry_transform_point3_slice(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "isometry transform point3 slice");
+    for size in [100, 10000].iter() {
+        group.throughput(Throughput::Elements(*size as u64));
+        bench_glam!(group, size, |b, size| {
+            use glam::{Quat, Vec3};
+            use mathbench::glam_support::Isometry3Ext;
+            bench_transform_slice!(b, size, ty1 => (Quat, Vec3), ty2 => Vec3, op => |m: &(Quat, Vec3), p| m.transform_point3(p))
+        });
+        bench_cgmath!(group, size, |b, size| {
+            use cgmath::{Decomposed, Point3, Quaternion, Transform, Vector3};
+            bench_transform_slice!(b, size, ty1 => Decomposed<Vector3<f32>, Quaternion<f32>>, ty2 => Point3<f32>, op => |m: &Decomposed<Vector3<f32>, Quaternion<f32>>, p| m.transform_point(p))
+        });
+        bench_ultraviolet!(group, size, |b, size| {
+            use ultraviolet::{Isometry3, Vec3};
+            bench_transform_slice!(b, size, ty1 => Isometry3, ty2 => Vec3, op => |m: &Isometry3, p| m.transform_vec(p))
+        });
+        bench_nalgebra!(group, size, |b, size| {
+            use nalgebra::{Isometry3, Point3};
+            bench_transform_slice!(b, size, ty1 => Isometry3<f32>, ty2 => Point3<f32>, op => |m: &Isometry3<f32>, p| m.transform_point(&p))
+        });
+        bench_vek!(group, size, |b, size| {
+            use mathbench::vek_support::Isometry3Ext;
+            use vek::{Transform, Vec3};
+            bench_transform_slice!(b, size, ty1 => Transform<f32, f32, f32>, ty2 => Vec3<f32>, op => |m: &Transform<f32, f32, f32>, p| m.mul_point(p))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    transform_slice_benches,
+    bench_transform_point3_slice,
+    bench_isometry_transform_point3_slice,
+);
+criterion_main!(transform_slice_benches);
@@ -4,7 +4,7 @@ mod macros;
 use criterion::{criterion_group, criterion_main, Criterion};
 
 fn bench_transform_vector3(c: &mut Criterion) {
-    let mut group = c.benchmark_group("transform vector3");
+    let mut group = benchmark_group!(c, "transform vector3");
     for size in [1, 100].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench_glam!(group, size, |b, size| {
@@ -27,12 +27,16 @@ fn bench_transform_vector3(c: &mut Criterion) {
             use vek::{Mat4, Vec3};
             bench_binop!(b, size, op => mul_direction, ty1 => Mat4<f32>, ty2 => Vec3<f32>)
         });
+        bench_reference!(group, size, |b, size| {
+            use mathbench::reference::{Mat4, Vec3};
+            bench_binop!(b, size, op => transform_vector3, ty1 => Mat4, ty2 => Vec3)
+        });
     }
     group.finish();
 }
 
 fn bench_transform_point3(c: &mut Criterion) {
-    let mut group = c.benchmark_group("transform point3");
+    let mut group = benchmark_group!(c, "transform point3");
     for size in [1, 100].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench_glam!(group, size, |b, size| {
@@ -61,12 +65,16 @@ fn bench_transform_point3(c: &mut Criterion) {
             use std::ops::Mul;
             bench_binop!(b, size, op => mul, ty1 => Transform4F, ty2 => Vector4F)
         });
+        bench_reference!(group, size, |b, size| {
+            use mathbench::reference::{Mat4, Vec3};
+            bench_binop!(b, size, op => transform_point3, ty1 => Mat4, ty2 => Vec3)
+        });
     }
     group.finish();
 }
 
 fn bench_transform_point2(c: &mut Criterion) {
-    let mut group = c.benchmark_group("transform point2");
+    let mut group = benchmark_group!(c, "transform point2");
     for size in [1, 100].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench_glam!(group, size, |b, size| {
@@ -95,12 +103,16 @@ fn bench_transform_point2(c: &mut Criterion) {
             use std::ops::Mul;
             bench_binop!(b, size, op => mul, ty1 => Transform2F, ty2 => Vector2F);
         });
+        bench_reference!(group, size, |b, size| {
+            use mathbench::reference::{Mat3, Vec2};
+            bench_binop!(b, size, op => transform_point2, ty1 => Mat3, ty2 => Vec2)
+        });
     }
     group.finish();
 }
 
 fn bench_transform_vector2(c: &mut Criterion) {
-    let mut group = c.benchmark_group("transform vector2");
+    let mut group = benchmark_group!(c, "transform vector2");
     for size in [1, 100].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench_glam!(group, size, |b, size| {
@@ -124,6 +136,10 @@ fn bench_transform_vector2(c: &mut Criterion) {
             use vek::{Mat3, Vec2};
             bench_binop!(b, size, op => mul_direction_2d, ty1 => Mat3<f32>, ty2 => Vec2<f32>)
         });
+        bench_reference!(group, size, |b, size| {
+            use mathbench::reference::{Mat3, Vec2};
+            bench_binop!(b, size, op => transform_vector2, ty1 => Mat3, ty2 => Vec2)
+        });
     }
     group.finish();
 }
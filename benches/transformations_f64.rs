@@ -0,0 +1,163 @@
+#[path = "support/macros.rs"]
+#[macro_use]
+mod macros;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench_transform_vector3(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 transform vector3");
+    for size in [1, 100].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench_cgmath!(group, size, |b, size| {
+            use cgmath::{Matrix4, Transform, Vector3};
+            bench_binop!(b, size, op => transform_vector, ty1 => Matrix4<f64>, ty2 => Vector3<f64>)
+        });
+        bench_nalgebra!(group, size, |b, size| {
+            use nalgebra::{Transform3, Vector3};
+            bench_binop!(b, size, op => transform_vector, ty1 => Transform3<f64>, ty2 => Vector3<f64>, param => by_ref)
+        });
+        bench_euclid!(group, size, |b, size| {
+            use euclid::{Transform3D, UnknownUnit, Vector3D};
+            bench_binop!(b, size, op => transform_vector3d, ty1 => Transform3D<f64, UnknownUnit, UnknownUnit>, ty2 => Vector3D<f64, UnknownUnit>)
+        });
+        bench_vek!(group, size, |b, size| {
+            use vek::{Mat4, Vec3};
+            bench_binop!(b, size, op => mul_direction, ty1 => Mat4<f64>, ty2 => Vec3<f64>)
+        });
+    }
+    group.finish();
+}
+
+fn bench_transform_point3(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 transform point3");
+    for size in [1, 100].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench_cgmath!(group, size, |b, size| {
+            use cgmath::{Matrix4, Point3, Transform};
+            bench_binop!(b, size, op => transform_point, ty1 => Matrix4<f64>, ty2 => Point3<f64>)
+        });
+        bench_nalgebra!(group, size, |b, size| {
+            use nalgebra::{Point3, Transform3};
+            bench_binop!(b, size, op => transform_point, ty1 => Transform3<f64>, ty2 => Point3<f64>, param => by_ref)
+        });
+        bench_euclid!(group, size, |b, size| {
+            use euclid::{Point3D, Transform3D, UnknownUnit};
+            bench_binop!(b, size, op => transform_point3d, ty1 => Transform3D<f64, UnknownUnit, UnknownUnit>, ty2 => Point3D<f64, UnknownUnit>)
+        });
+        bench_vek!(group, size, |b, size| {
+            use vek::{Mat4, Vec3};
+            bench_binop!(b, size, op => mul_point, ty1 => Mat4<f64>, ty2 => Vec3<f64>)
+        });
+    }
+    group.finish();
+}
+
+fn bench_transform_point2(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 transform point2");
+    for size in [1, 100].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench_cgmath!(group, size, |b, size| {
+            use cgmath::{Matrix3, Point2, Transform};
+            bench_binop!(b, size, op => transform_point, ty1 => Matrix3<f64>, ty2 => Point2<f64>)
+        });
+        bench_nalgebra!(group, size, |b, size| {
+            use nalgebra::{Point2, Transform2};
+            bench_binop!(b, size, op => transform_point, ty1 => Transform2<f64>, ty2 => Point2<f64>, param => by_ref)
+        });
+        bench_euclid!(group, size, |b, size| {
+            use euclid::{Point2D, Transform2D, UnknownUnit};
+            bench_binop!(b, size, op => transform_point, ty1 => Transform2D<f64, UnknownUnit, UnknownUnit>, ty2 => Point2D<f64, UnknownUnit>)
+        });
+        bench_vek!(group, size, |b, size| {
+            use vek::{Mat3, Vec2};
+            bench_binop!(b, size, op => mul_point_2d, ty1 => Mat3<f64>, ty2 => Vec2<f64>)
+        });
+    }
+    group.finish();
+}
+
+fn bench_transform_vector2(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 transform vector2");
+    for size in [1, 100].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench_nalgebra!(group, size, |b, size| {
+            use nalgebra::{Transform2, Vector2};
+            bench_binop!(b, size, op => transform_vector, ty1 => Transform2<f64>, ty2 => Vector2<f64>, param => by_ref)
+        });
+        bench_euclid!(group, size, |b, size| {
+            use euclid::{Transform2D, UnknownUnit, Vector2D};
+            bench_binop!(b, size, op => transform_vector, ty1 => Transform2D<f64, UnknownUnit, UnknownUnit>, ty2 => Vector2D<f64, UnknownUnit>)
+        });
+        bench_vek!(group, size, |b, size| {
+            use vek::{Mat3, Vec2};
+            bench_binop!(b, size, op => mul_direction_2d, ty1 => Mat3<f64>, ty2 => Vec2<f64>)
+        });
+    }
+    group.finish();
+}
+
+fn bench_transform2_inverse(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 transform2 inverse");
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Transform2;
+        bench_unop!(b, op => try_inverse, ty => Transform2<f64>)
+    });
+    bench_euclid!(group, |b| {
+        use euclid::{Transform2D, UnknownUnit};
+        bench_unop!(b, op => inverse, ty => Transform2D<f64, UnknownUnit, UnknownUnit>)
+    });
+    group.finish();
+}
+
+fn bench_transform2_mul_transform2(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 transform2 mul transform2");
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Transform2;
+        use std::ops::Mul;
+        bench_binop!(b, op => mul, ty1 => Transform2<f64>, ty2 => Transform2<f64>, param => by_ref)
+    });
+    bench_euclid!(group, |b| {
+        use euclid::{Transform2D, UnknownUnit};
+        bench_binop!(b, op => post_transform, ty => Transform2D<f64, UnknownUnit, UnknownUnit>, param => by_ref)
+    });
+    group.finish();
+}
+
+fn bench_transform3_inverse(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 transform3 inverse");
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Transform3;
+        bench_unop!(b, op => try_inverse, ty => Transform3<f64>)
+    });
+    bench_euclid!(group, |b| {
+        use euclid::{Transform3D, UnknownUnit};
+        bench_unop!(b, op => inverse, ty => Transform3D<f64, UnknownUnit, UnknownUnit>)
+    });
+    group.finish();
+}
+
+fn bench_transform3_mul_transform3(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 transform3 mul transform3d");
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Transform3;
+        use std::ops::Mul;
+        bench_binop!(b, op => mul, ty1 => Transform3<f64>, ty2 => Transform3<f64>, param => by_ref)
+    });
+    bench_euclid!(group, |b| {
+        use euclid::{Transform3D, UnknownUnit};
+        bench_binop!(b, op => post_transform, ty => Transform3D<f64, UnknownUnit, UnknownUnit>, param => by_ref)
+    });
+    group.finish();
+}
+
+criterion_group!(
+    transformation_f64_benches,
+    bench_transform_vector2,
+    bench_transform_point2,
+    bench_transform_vector3,
+    bench_transform_point3,
+    bench_transform2_inverse,
+    bench_transform2_mul_transform2,
+    bench_transform3_inverse,
+    bench_transform3_mul_transform3,
+);
+criterion_main!(transformation_f64_benches);
@@ -6,7 +6,7 @@ use criterion::{criterion_group, criterion_main, Criterion};
 // returns self to check overhead of benchmark
 fn bench_vector3_ret_self(c: &mut Criterion) {
     use mathbench::BenchValue;
-    let mut group = c.benchmark_group("vector3 return self");
+    let mut group = benchmark_group!(c, "vector3 return self");
     bench_glam!(group, |b| {
         use glam::Vec3;
         bench_unop!(b, op => ret_self, ty => Vec3)
@@ -46,11 +46,15 @@ fn bench_vector3_ret_self(c: &mut Criterion) {
         use vek::Vec3;
         bench_unop!(b, op => ret_self, ty => Vec3<f32>)
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Vec3;
+        bench_unop!(b, op => ret_self, ty => Vec3)
+    });
     group.finish();
 }
 
 fn bench_vector3_length(c: &mut Criterion) {
-    let mut group = c.benchmark_group("vector3 length");
+    let mut group = benchmark_group!(c, "vector3 length");
     bench_glam!(group, |b| {
         use glam::Vec3;
         bench_unop!(b, op => length, ty => Vec3)
@@ -71,6 +75,11 @@ fn bench_vector3_length(c: &mut Criterion) {
         use nalgebra::Vector3;
         bench_unop!(b, op => magnitude, ty => Vector3<f32>)
     });
+    bench_nalgebra_glm!(group, |b| {
+        use mathbench::nalgebra_glm_support::Vector3Ext;
+        use nalgebra_glm as glm;
+        bench_unop!(b, op => glm_length, ty => glm::Vec3)
+    });
     bench_nalgebra_f32x4!(group, |b| {
         use nalgebra::Vector3;
         use simba::simd::f32x4;
@@ -94,11 +103,15 @@ fn bench_vector3_length(c: &mut Criterion) {
         use vek::Vec3;
         bench_unop!(b, op => magnitude, ty => Vec3<f32>)
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Vec3;
+        bench_unop!(b, op => length, ty => Vec3)
+    });
     group.finish();
 }
 
 fn bench_vector3_normalize(c: &mut Criterion) {
-    let mut group = c.benchmark_group("vector3 normalize");
+    let mut group = benchmark_group!(c, "vector3 normalize");
     bench_glam!(group, |b| {
         use glam::Vec3;
         bench_unop!(b, op => normalize, ty => Vec3)
@@ -119,6 +132,11 @@ fn bench_vector3_normalize(c: &mut Criterion) {
         use nalgebra::Vector3;
         bench_unop!(b, op => normalize, ty => Vector3<f32>)
     });
+    bench_nalgebra_glm!(group, |b| {
+        use mathbench::nalgebra_glm_support::Vector3Ext;
+        use nalgebra_glm as glm;
+        bench_unop!(b, op => glm_normalize, ty => glm::Vec3)
+    });
     bench_nalgebra_f32x4!(group, |b| {
         use nalgebra::Vector3;
         use simba::simd::f32x4;
@@ -142,11 +160,19 @@ fn bench_vector3_normalize(c: &mut Criterion) {
         use vek::Vec3;
         bench_unop!(b, op => normalized, ty => Vec3<f32>)
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Vec3;
+        bench_unop!(b, op => normalize, ty => Vec3)
+    });
+    bench_intrinsics!(group, |b| {
+        use mathbench::intrinsics::Vec3;
+        bench_unop!(b, op => normalize, ty => Vec3)
+    });
     group.finish();
 }
 
 fn bench_vector3_dot(c: &mut Criterion) {
-    let mut group = c.benchmark_group("vector3 dot");
+    let mut group = benchmark_group!(c, "vector3 dot");
     bench_glam!(group, |b| {
         use glam::Vec3;
         bench_binop!(b, op => dot, ty1 => Vec3, ty2 => Vec3)
@@ -156,8 +182,8 @@ fn bench_vector3_dot(c: &mut Criterion) {
         bench_binop!(b, op => dot, ty1 => Vector3<f32>, ty2 => Vector3<f32>)
     });
     bench_ultraviolet!(group, |b| {
-        use ultraviolet::Wec3;
-        bench_binop!(b, op => dot, ty1 => Wec3, ty2 => Wec3)
+        use ultraviolet::Vec3;
+        bench_binop!(b, op => dot, ty1 => Vec3, ty2 => Vec3)
     });
     bench_ultraviolet_f32x4!(group, |b| {
         use ultraviolet::Wec3;
@@ -167,6 +193,11 @@ fn bench_vector3_dot(c: &mut Criterion) {
         use nalgebra::Vector3;
         bench_binop!(b, op => dot, ty1 => Vector3<f32>, ty2 => Vector3<f32>, param => by_ref)
     });
+    bench_nalgebra_glm!(group, |b| {
+        use mathbench::nalgebra_glm_support::Vector3Ext;
+        use nalgebra_glm as glm;
+        bench_binop!(b, op => glm_dot, ty1 => glm::Vec3, ty2 => glm::Vec3, param => by_ref)
+    });
     bench_nalgebra_f32x4!(group, |b| {
         use nalgebra::Vector3;
         use simba::simd::f32x4;
@@ -190,11 +221,15 @@ fn bench_vector3_dot(c: &mut Criterion) {
         use vek::Vec3;
         bench_binop!(b, op => dot, ty1 => Vec3<f32>, ty2 => Vec3<f32>)
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Vec3;
+        bench_binop!(b, op => dot, ty1 => Vec3, ty2 => Vec3)
+    });
     group.finish();
 }
 
 fn bench_vector3_cross(c: &mut Criterion) {
-    let mut group = c.benchmark_group("vector3 cross");
+    let mut group = benchmark_group!(c, "vector3 cross");
     bench_glam!(group, |b| {
         use glam::Vec3;
         bench_binop!(b, op => cross, ty1 => Vec3, ty2 => Vec3)
@@ -205,30 +240,35 @@ fn bench_vector3_cross(c: &mut Criterion) {
     });
     bench_ultraviolet!(group, |b| {
         use ultraviolet::Vec3;
-        bench_binop!(b, op => dot, ty1 => Vec3, ty2 => Vec3)
+        bench_binop!(b, op => cross, ty1 => Vec3, ty2 => Vec3)
     });
     bench_ultraviolet_f32x4!(group, |b| {
         use ultraviolet::Wec3;
-        bench_binop4!(b, op => dot, ty1 => Wec3, ty2 => Wec3)
+        bench_binop4!(b, op => cross, ty1 => Wec3, ty2 => Wec3)
     });
     bench_nalgebra!(group, |b| {
         use nalgebra::Vector3;
-        bench_binop!(b, op => dot, ty1 => Vector3<f32>, ty2 => Vector3<f32>, param => by_ref)
+        bench_binop!(b, op => cross, ty1 => Vector3<f32>, ty2 => Vector3<f32>, param => by_ref)
+    });
+    bench_nalgebra_glm!(group, |b| {
+        use mathbench::nalgebra_glm_support::Vector3Ext;
+        use nalgebra_glm as glm;
+        bench_binop!(b, op => glm_cross, ty1 => glm::Vec3, ty2 => glm::Vec3, param => by_ref)
     });
     bench_nalgebra_f32x4!(group, |b| {
         use nalgebra::Vector3;
         use simba::simd::f32x4;
-        bench_binop4!(b, op => dot, ty1 => Vector3<f32x4>, ty2 => Vector3<f32x4>, param => by_ref)
+        bench_binop4!(b, op => cross, ty1 => Vector3<f32x4>, ty2 => Vector3<f32x4>, param => by_ref)
     });
     bench_nalgebra_f32x8!(group, |b| {
         use nalgebra::Vector3;
         use simba::simd::f32x8;
-        bench_binop8!(b, op => dot, ty1 => Vector3<f32x8>, ty2 => Vector3<f32x8>, param => by_ref)
+        bench_binop8!(b, op => cross, ty1 => Vector3<f32x8>, ty2 => Vector3<f32x8>, param => by_ref)
     });
     bench_nalgebra_f32x16!(group, |b| {
         use nalgebra::Vector3;
         use simba::simd::f32x16;
-        bench_binop16!(b, op => dot, ty1 => Vector3<f32x16>, ty2 => Vector3<f32x16>, param => by_ref)
+        bench_binop16!(b, op => cross, ty1 => Vector3<f32x16>, ty2 => Vector3<f32x16>, param => by_ref)
     });
     bench_euclid!(group, |b| {
         use euclid::{UnknownUnit, Vector3D};
@@ -238,6 +278,10 @@ fn bench_vector3_cross(c: &mut Criterion) {
         use vek::Vec3;
         bench_binop!(b, op => cross, ty1 => Vec3<f32>, ty2 => Vec3<f32>)
     });
+    bench_reference!(group, |b| {
+        use mathbench::reference::Vec3;
+        bench_binop!(b, op => cross, ty1 => Vec3, ty2 => Vec3)
+    });
     group.finish();
 }
 
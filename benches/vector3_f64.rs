@@ -0,0 +1,97 @@
+#[path = "support/macros.rs"]
+#[macro_use]
+mod macros;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench_vector3_length(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 vector3 length");
+    bench_cgmath!(group, |b| {
+        use cgmath::{InnerSpace, Vector3};
+        bench_unop!(b, op => magnitude, ty => Vector3<f64>)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Vector3;
+        bench_unop!(b, op => magnitude, ty => Vector3<f64>)
+    });
+    bench_euclid!(group, |b| {
+        use euclid::{UnknownUnit, Vector3D};
+        bench_unop!(b, op => length, ty => Vector3D<f64, UnknownUnit>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Vec3;
+        bench_unop!(b, op => magnitude, ty => Vec3<f64>)
+    });
+    group.finish();
+}
+
+fn bench_vector3_normalize(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 vector3 normalize");
+    bench_cgmath!(group, |b| {
+        use cgmath::{InnerSpace, Vector3};
+        bench_unop!(b, op => normalize, ty => Vector3<f64>)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Vector3;
+        bench_unop!(b, op => normalize, ty => Vector3<f64>)
+    });
+    bench_euclid!(group, |b| {
+        use euclid::{UnknownUnit, Vector3D};
+        bench_unop!(b, op => normalize, ty => Vector3D<f64, UnknownUnit>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Vec3;
+        bench_unop!(b, op => normalized, ty => Vec3<f64>)
+    });
+    group.finish();
+}
+
+fn bench_vector3_dot(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 vector3 dot");
+    bench_cgmath!(group, |b| {
+        use cgmath::{InnerSpace, Vector3};
+        bench_binop!(b, op => dot, ty1 => Vector3<f64>, ty2 => Vector3<f64>)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Vector3;
+        bench_binop!(b, op => dot, ty1 => Vector3<f64>, ty2 => Vector3<f64>, param => by_ref)
+    });
+    bench_euclid!(group, |b| {
+        use euclid::{UnknownUnit, Vector3D};
+        bench_binop!(b, op => dot, ty1 => Vector3D<f64, UnknownUnit>, ty2 => Vector3D<f64, UnknownUnit>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Vec3;
+        bench_binop!(b, op => dot, ty1 => Vec3<f64>, ty2 => Vec3<f64>)
+    });
+    group.finish();
+}
+
+fn bench_vector3_cross(c: &mut Criterion) {
+    let mut group = benchmark_group!(c, "f64 vector3 cross");
+    bench_cgmath!(group, |b| {
+        use cgmath::Vector3;
+        bench_binop!(b, op => cross, ty1 => Vector3<f64>, ty2 => Vector3<f64>)
+    });
+    bench_nalgebra!(group, |b| {
+        use nalgebra::Vector3;
+        bench_binop!(b, op => cross, ty1 => Vector3<f64>, ty2 => Vector3<f64>, param => by_ref)
+    });
+    bench_euclid!(group, |b| {
+        use euclid::{UnknownUnit, Vector3D};
+        bench_binop!(b, op => cross, ty1 => Vector3D<f64, UnknownUnit>, ty2 => Vector3D<f64, UnknownUnit>)
+    });
+    bench_vek!(group, |b| {
+        use vek::Vec3;
+        bench_binop!(b, op => cross, ty1 => Vec3<f64>, ty2 => Vec3<f64>)
+    });
+    group.finish();
+}
+
+criterion_group!(
+    vector3_f64_benches,
+    bench_vector3_length,
+    bench_vector3_normalize,
+    bench_vector3_dot,
+    bench_vector3_cross
+);
+criterion_main!(vector3_f64_benches);
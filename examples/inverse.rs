@@ -0,0 +1,31 @@
+//! Prints how each library's matrix inverse handles singular, near singular
+//! and non-finite matrices, see `tests/support/inverse.rs`.
+//!
+//! Run with `cargo run --example inverse`. The report is printed as markdown
+//! and written to `target/reports/inverse.md`. The outcomes for exactly
+//! singular and non-finite inputs are checked by the `inverse` test.
+#[allow(dead_code)]
+#[path = "../tests/support/mod.rs"]
+mod support;
+
+use support::inverse::{
+    format_table, mat2_cases, mat2_libs, mat3_cases, mat3_libs, mat4_cases, mat4_libs, outcomes,
+};
+
+fn main() {
+    let mut report = String::from("## Inverse of singular and near singular matrices\n");
+
+    let (cases, libs) = (mat2_cases(), mat2_libs());
+    report += &format_table("Mat2", &cases, &libs, &outcomes(2, &cases, &libs));
+
+    let (cases, libs) = (mat3_cases(), mat3_libs());
+    report += &format_table("Mat3", &cases, &libs, &outcomes(3, &cases, &libs));
+
+    let (cases, libs) = (mat4_cases(), mat4_libs());
+    report += &format_table("Mat4", &cases, &libs, &outcomes(4, &cases, &libs));
+
+    println!("{}", report);
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("target/reports");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("inverse.md"), &report).unwrap();
+}
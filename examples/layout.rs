@@ -0,0 +1,1141 @@
+//! Prints the memory layout of every type that implements `BenchValue`.
+//!
+//! The size, alignment, number of SIMD lanes, padding and element order of
+//! each type are measured by building a value whose elements are numbered
+//! `1, 2, 3, ...` and reading back its memory. Whether a type is `#[repr(C)]`
+//! is recorded by hand. Types listed with a `mint` type are built by converting
+//! from it, so the compiler checks that the conversion exists.
+//!
+//! Element orders are given for column vectors. `euclid` uses row vectors, so
+//! its "row major" transforms store their basis vectors contiguously and are
+//! reported as column-major, which is the same memory order as `glam`.
+//!
+//! Only types that implement `BenchValue` can be measured. The `coverage` test
+//! includes this file to check that every `BenchValue` type is measured.
+//!
+//! Run with `cargo run --example layout`. The report is printed as markdown and
+//! written to `target/reports/layout.md`. Sizes depend on the target and
+//! features, for example `glam` types are smaller with `--features glam_scalar`.
+use mathbench::BenchValue;
+use std::fmt::Write;
+use std::mem::{self, MaybeUninit};
+
+/// A floating point type that elements are stored as.
+trait Scalar: Copy {
+    const NAME: &'static str;
+    /// Returns the number stored for element `index`.
+    fn number(index: usize) -> Self;
+    /// Returns the element index of a stored number.
+    fn index(self) -> Option<usize>;
+}
+
+macro_rules! impl_scalar {
+    ($t:ty) => {
+        impl Scalar for $t {
+            const NAME: &'static str = stringify!($t);
+            fn number(index: usize) -> Self {
+                (index + 1) as $t
+            }
+            fn index(self) -> Option<usize> {
+                if (1.0..=64.0).contains(&self) && self.fract() == 0.0 {
+                    Some(self as usize - 1)
+                } else {
+                    None
+                }
+            }
+        }
+    };
+}
+
+impl_scalar!(f32);
+impl_scalar!(f64);
+
+/// A `mint` type that values are built from.
+trait Sample {
+    type Scalar: Scalar;
+    /// Names of the elements, in the order they are numbered.
+    fn labels() -> Vec<String>;
+    fn sample(v: &[Self::Scalar]) -> Self;
+}
+
+impl<S: Scalar> Sample for mint::Vector2<S> {
+    type Scalar = S;
+    fn labels() -> Vec<String> {
+        labels(&["x", "y"])
+    }
+    fn sample(v: &[S]) -> Self {
+        [v[0], v[1]].into()
+    }
+}
+
+impl<S: Scalar> Sample for mint::Vector3<S> {
+    type Scalar = S;
+    fn labels() -> Vec<String> {
+        labels(&["x", "y", "z"])
+    }
+    fn sample(v: &[S]) -> Self {
+        [v[0], v[1], v[2]].into()
+    }
+}
+
+impl<S: Scalar> Sample for mint::Vector4<S> {
+    type Scalar = S;
+    fn labels() -> Vec<String> {
+        labels(&["x", "y", "z", "w"])
+    }
+    fn sample(v: &[S]) -> Self {
+        [v[0], v[1], v[2], v[3]].into()
+    }
+}
+
+impl<S: Scalar> Sample for mint::Point2<S> {
+    type Scalar = S;
+    fn labels() -> Vec<String> {
+        labels(&["x", "y"])
+    }
+    fn sample(v: &[S]) -> Self {
+        [v[0], v[1]].into()
+    }
+}
+
+impl<S: Scalar> Sample for mint::Point3<S> {
+    type Scalar = S;
+    fn labels() -> Vec<String> {
+        labels(&["x", "y", "z"])
+    }
+    fn sample(v: &[S]) -> Self {
+        [v[0], v[1], v[2]].into()
+    }
+}
+
+impl<S: Scalar> Sample for mint::Quaternion<S> {
+    type Scalar = S;
+    fn labels() -> Vec<String> {
+        labels(&["x", "y", "z", "w"])
+    }
+    fn sample(v: &[S]) -> Self {
+        mint::Quaternion {
+            v: [v[0], v[1], v[2]].into(),
+            s: v[3],
+        }
+    }
+}
+
+impl<S: Scalar> Sample for mint::ColumnMatrix2<S> {
+    type Scalar = S;
+    fn labels() -> Vec<String> {
+        matrix(2, 2)
+    }
+    fn sample(v: &[S]) -> Self {
+        [[v[0], v[1]], [v[2], v[3]]].into()
+    }
+}
+
+impl<S: Scalar> Sample for mint::ColumnMatrix3<S> {
+    type Scalar = S;
+    fn labels() -> Vec<String> {
+        matrix(3, 3)
+    }
+    fn sample(v: &[S]) -> Self {
+        [[v[0], v[1], v[2]], [v[3], v[4], v[5]], [v[6], v[7], v[8]]].into()
+    }
+}
+
+impl<S: Scalar> Sample for mint::ColumnMatrix4<S> {
+    type Scalar = S;
+    fn labels() -> Vec<String> {
+        matrix(4, 4)
+    }
+    fn sample(v: &[S]) -> Self {
+        [
+            [v[0], v[1], v[2], v[3]],
+            [v[4], v[5], v[6], v[7]],
+            [v[8], v[9], v[10], v[11]],
+            [v[12], v[13], v[14], v[15]],
+        ]
+        .into()
+    }
+}
+
+// The row matrices are only used by `euclid` which uses row vectors, so each
+// row is a column of the equivalent column vector matrix.
+
+impl<S: Scalar> Sample for mint::RowMatrix3x2<S> {
+    type Scalar = S;
+    fn labels() -> Vec<String> {
+        matrix(2, 3)
+    }
+    fn sample(v: &[S]) -> Self {
+        [[v[0], v[1]], [v[2], v[3]], [v[4], v[5]]].into()
+    }
+}
+
+impl<S: Scalar> Sample for mint::RowMatrix4<S> {
+    type Scalar = S;
+    fn labels() -> Vec<String> {
+        matrix(4, 4)
+    }
+    fn sample(v: &[S]) -> Self {
+        [
+            [v[0], v[1], v[2], v[3]],
+            [v[4], v[5], v[6], v[7]],
+            [v[8], v[9], v[10], v[11]],
+            [v[12], v[13], v[14], v[15]],
+        ]
+        .into()
+    }
+}
+
+fn labels(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+#[allow(dead_code)]
+fn prefixed(prefix: &str, names: &[&str]) -> Vec<String> {
+    names
+        .iter()
+        .map(|name| format!("{}.{}", prefix, name))
+        .collect()
+}
+
+/// Labels of a `rows` x `cols` matrix numbered in column-major order.
+fn matrix(rows: usize, cols: usize) -> Vec<String> {
+    let mut labels = Vec::new();
+    for col in 0..cols {
+        for row in 0..rows {
+            labels.push(format!("m{}{}", row, col));
+        }
+    }
+    labels
+}
+
+pub(crate) struct Layout {
+    /// the type as written, without whitespace, read by the `coverage` test
+    #[allow(dead_code)]
+    pub(crate) path: String,
+    library: String,
+    name: String,
+    size: usize,
+    align: usize,
+    scalar: &'static str,
+    elements: usize,
+    lanes: usize,
+    padding: usize,
+    order: String,
+    repr: &'static str,
+    mint: String,
+}
+
+/// Measures the layout of `T` from a value built by `build` from numbered
+/// elements.
+fn measure<T: BenchValue, S: Scalar>(
+    path: &str,
+    repr: &'static str,
+    mint: &str,
+    labels: Vec<String>,
+    build: fn(&[S]) -> T,
+) -> Layout {
+    let numbers: Vec<S> = (0..labels.len()).map(S::number).collect();
+    let size = mem::size_of::<T>();
+    // zero the memory first so padding isn't mistaken for an element
+    let mut value = MaybeUninit::<T>::zeroed();
+    let slots = unsafe {
+        value.as_mut_ptr().write(build(&numbers));
+        std::slice::from_raw_parts(value.as_ptr() as *const S, size / mem::size_of::<S>())
+    };
+    let indices: Vec<usize> = slots
+        .iter()
+        .filter_map(|slot| slot.index())
+        .filter(|&index| index < labels.len())
+        .collect();
+    let lanes = indices.iter().filter(|&&index| index == 0).count();
+    let mut stored = Vec::new();
+    for index in indices {
+        if !stored.contains(&index) {
+            stored.push(index);
+        }
+    }
+    assert_eq!(
+        stored.len(),
+        labels.len(),
+        "{} doesn't store every element",
+        path
+    );
+
+    let full_path = path.replace(' ', "");
+    let path = full_path.replace(",euclid::UnknownUnit", "");
+    // tuples such as `(glam::Quat, glam::Vec3)` take the library of their first element
+    let library = path
+        .trim_start_matches('(')
+        .split("::")
+        .next()
+        .unwrap()
+        .to_string();
+    let name = path.replace(&format!("{}::", library), "");
+    let mint = mint.replace(' ', "").replace("mint::", "");
+    let order: Vec<&str> = stored.iter().map(|&index| labels[index].as_str()).collect();
+    Layout {
+        path: full_path,
+        library,
+        name,
+        size,
+        align: mem::align_of::<T>(),
+        scalar: S::NAME,
+        elements: labels.len(),
+        lanes,
+        padding: size - labels.len() * lanes * mem::size_of::<S>(),
+        order: element_order(&order),
+        repr,
+        mint,
+    }
+}
+
+/// Describes the order elements are stored in.
+fn element_order(labels: &[&str]) -> String {
+    let cells: Option<Vec<(usize, usize)>> = labels
+        .iter()
+        .map(|label| {
+            let digits = label.strip_prefix('m')?.as_bytes();
+            if digits.len() == 2 {
+                Some(((digits[0] - b'0') as usize, (digits[1] - b'0') as usize))
+            } else {
+                None
+            }
+        })
+        .collect();
+    match cells {
+        Some(cells) => {
+            let rows = cells.iter().map(|cell| cell.0).max().unwrap() + 1;
+            let cols = cells.iter().map(|cell| cell.1).max().unwrap() + 1;
+            let order = if cells
+                .windows(2)
+                .all(|w| (w[0].1, w[0].0) < (w[1].1, w[1].0))
+            {
+                "column-major"
+            } else if cells.windows(2).all(|w| w[0] < w[1]) {
+                "row-major"
+            } else {
+                return labels.join(" ");
+            };
+            format!("{} {}x{}", order, rows, cols)
+        }
+        None => labels.join(" "),
+    }
+}
+
+/// Adds the layout of a type. The first form builds the type from a `mint`
+/// type, the second with a function given the element labels.
+macro_rules! layout {
+    ($layouts:ident, $t:ty, $repr:literal, mint $m:ty) => {
+        $layouts.push(measure::<$t, _>(
+            stringify!($t),
+            $repr,
+            stringify!($m),
+            <$m as Sample>::labels(),
+            |v| <$t>::from(<$m as Sample>::sample(v)),
+        ));
+    };
+    ($layouts:ident, $t:ty, $repr:literal, $labels:expr, $build:expr) => {
+        $layouts.push(measure::<$t, _>(stringify!($t), $repr, "", $labels, $build));
+    };
+}
+
+#[cfg(feature = "glam")]
+fn glam_layouts(layouts: &mut Vec<Layout>) {
+    layout!(layouts, glam::Mat2, "C", mint mint::ColumnMatrix2<f32>);
+    layout!(layouts, glam::Mat3, "C", mint mint::ColumnMatrix3<f32>);
+    layout!(layouts, glam::Mat4, "C", mint mint::ColumnMatrix4<f32>);
+    layout!(layouts, glam::Quat, "C", mint mint::Quaternion<f32>);
+    layout!(layouts, glam::Vec2, "C", mint mint::Vector2<f32>);
+    layout!(layouts, glam::Vec3, "C", mint mint::Vector3<f32>);
+    layout!(layouts, glam::Vec4, "C", mint mint::Vector4<f32>);
+    layout!(
+        layouts,
+        (glam::Quat, glam::Vec3),
+        "Rust",
+        [
+            prefixed("0", &["x", "y", "z", "w"]),
+            prefixed("1", &["x", "y", "z"])
+        ]
+        .concat(),
+        |v: &[f32]| (
+            glam::Quat::from_xyzw(v[0], v[1], v[2], v[3]),
+            glam::Vec3::new(v[4], v[5], v[6]),
+        )
+    );
+    layout!(
+        layouts,
+        (glam::Vec3, glam::Quat, glam::Vec3),
+        "Rust",
+        [
+            prefixed("0", &["x", "y", "z"]),
+            prefixed("1", &["x", "y", "z", "w"]),
+            prefixed("2", &["x", "y", "z"]),
+        ]
+        .concat(),
+        |v: &[f32]| (
+            glam::Vec3::new(v[0], v[1], v[2]),
+            glam::Quat::from_xyzw(v[3], v[4], v[5], v[6]),
+            glam::Vec3::new(v[7], v[8], v[9]),
+        )
+    );
+}
+
+#[cfg(feature = "cgmath")]
+macro_rules! cgmath_layouts {
+    ($layouts:ident, $s:ty) => {
+        layout!($layouts, cgmath::Matrix2<$s>, "C", mint mint::ColumnMatrix2<$s>);
+        layout!($layouts, cgmath::Matrix3<$s>, "C", mint mint::ColumnMatrix3<$s>);
+        layout!($layouts, cgmath::Matrix4<$s>, "C", mint mint::ColumnMatrix4<$s>);
+        layout!($layouts, cgmath::Point2<$s>, "C", mint mint::Point2<$s>);
+        layout!($layouts, cgmath::Point3<$s>, "C", mint mint::Point3<$s>);
+        layout!($layouts, cgmath::Quaternion<$s>, "C", mint mint::Quaternion<$s>);
+        layout!($layouts, cgmath::Vector2<$s>, "C", mint mint::Vector2<$s>);
+        layout!($layouts, cgmath::Vector3<$s>, "C", mint mint::Vector3<$s>);
+        layout!($layouts, cgmath::Vector4<$s>, "C", mint mint::Vector4<$s>);
+    };
+}
+
+#[cfg(feature = "cgmath")]
+fn cgmath_layouts(layouts: &mut Vec<Layout>) {
+    layout!(
+        layouts,
+        cgmath::Decomposed<cgmath::Vector3<f32>, cgmath::Quaternion<f32>>,
+        "Rust",
+        [
+            labels(&["scale"]),
+            prefixed("rot", &["x", "y", "z", "w"]),
+            prefixed("disp", &["x", "y", "z"]),
+        ]
+        .concat(),
+        |v: &[f32]| cgmath::Decomposed {
+            scale: v[0],
+            rot: cgmath::Quaternion::new(v[4], v[1], v[2], v[3]),
+            disp: cgmath::Vector3::new(v[5], v[6], v[7]),
+        }
+    );
+    cgmath_layouts!(layouts, f32);
+    cgmath_layouts!(layouts, f64);
+}
+
+#[cfg(feature = "nalgebra")]
+macro_rules! nalgebra_layouts {
+    ($layouts:ident, $s:ty) => {
+        layout!($layouts, nalgebra::Matrix2<$s>, "C", mint mint::ColumnMatrix2<$s>);
+        layout!($layouts, nalgebra::Matrix3<$s>, "C", mint mint::ColumnMatrix3<$s>);
+        layout!($layouts, nalgebra::Matrix4<$s>, "C", mint mint::ColumnMatrix4<$s>);
+        layout!($layouts, nalgebra::Point2<$s>, "C", mint mint::Point2<$s>);
+        layout!($layouts, nalgebra::Point3<$s>, "C", mint mint::Point3<$s>);
+        layout!($layouts, nalgebra::Transform2<$s>, "C", matrix(3, 3), |v: &[$s]| {
+            nalgebra::Transform2::from_matrix_unchecked(
+                mint::ColumnMatrix3::<$s>::sample(v).into(),
+            )
+        });
+        layout!($layouts, nalgebra::Transform3<$s>, "C", matrix(4, 4), |v: &[$s]| {
+            nalgebra::Transform3::from_matrix_unchecked(
+                mint::ColumnMatrix4::<$s>::sample(v).into(),
+            )
+        });
+        layout!(
+            $layouts,
+            nalgebra::UnitQuaternion<$s>,
+            "transparent",
+            labels(&["x", "y", "z", "w"]),
+            |v: &[$s]| nalgebra::UnitQuaternion::new_unchecked(nalgebra::Quaternion::new(
+                v[3], v[0], v[1], v[2]
+            ))
+        );
+        layout!($layouts, nalgebra::Vector2<$s>, "C", mint mint::Vector2<$s>);
+        layout!($layouts, nalgebra::Vector3<$s>, "C", mint mint::Vector3<$s>);
+        layout!($layouts, nalgebra::Vector4<$s>, "C", mint mint::Vector4<$s>);
+    };
+}
+
+/// Adds the `nalgebra` types with every lane of each element set to the
+/// element's number.
+#[cfg(any(
+    feature = "nalgebra_f32x4",
+    feature = "nalgebra_f32x8",
+    feature = "nalgebra_f32x16"
+))]
+macro_rules! nalgebra_wide_layouts {
+    ($layouts:ident, $w:ident, $lanes:expr) => {{
+        use simba::simd::$w;
+        fn splat(v: &[f32]) -> Vec<$w> {
+            v.iter().map(|&x| $w::from([x; $lanes])).collect()
+        }
+        layout!(
+            $layouts,
+            nalgebra::Point2<$w>,
+            "C",
+            labels(&["x", "y"]),
+            |v: &[f32]| {
+                let v = splat(v);
+                nalgebra::Point2::new(v[0], v[1])
+            }
+        );
+        layout!(
+            $layouts,
+            nalgebra::Point3<$w>,
+            "C",
+            labels(&["x", "y", "z"]),
+            |v: &[f32]| {
+                let v = splat(v);
+                nalgebra::Point3::new(v[0], v[1], v[2])
+            }
+        );
+        layout!(
+            $layouts,
+            nalgebra::UnitQuaternion<$w>,
+            "transparent",
+            labels(&["x", "y", "z", "w"]),
+            |v: &[f32]| {
+                let v = splat(v);
+                nalgebra::UnitQuaternion::new_unchecked(nalgebra::Quaternion::new(
+                    v[3], v[0], v[1], v[2],
+                ))
+            }
+        );
+        layout!(
+            $layouts,
+            nalgebra::UnitComplex<$w>,
+            "transparent",
+            labels(&["re", "im"]),
+            |v: &[f32]| {
+                let v = splat(v);
+                nalgebra::UnitComplex::new_unchecked(nalgebra::Complex::new(v[0], v[1]))
+            }
+        );
+        layout!(
+            $layouts,
+            nalgebra::Vector2<$w>,
+            "C",
+            labels(&["x", "y"]),
+            |v: &[f32]| {
+                let v = splat(v);
+                nalgebra::Vector2::new(v[0], v[1])
+            }
+        );
+        layout!(
+            $layouts,
+            nalgebra::Vector3<$w>,
+            "C",
+            labels(&["x", "y", "z"]),
+            |v: &[f32]| {
+                let v = splat(v);
+                nalgebra::Vector3::new(v[0], v[1], v[2])
+            }
+        );
+        layout!(
+            $layouts,
+            nalgebra::Vector4<$w>,
+            "C",
+            labels(&["x", "y", "z", "w"]),
+            |v: &[f32]| {
+                let v = splat(v);
+                nalgebra::Vector4::new(v[0], v[1], v[2], v[3])
+            }
+        );
+        layout!(
+            $layouts,
+            nalgebra::Matrix2<$w>,
+            "C",
+            matrix(2, 2),
+            |v: &[f32]| {
+                let v = splat(v);
+                nalgebra::Matrix2::from_fn(|row, col| v[col * 2 + row])
+            }
+        );
+        layout!(
+            $layouts,
+            nalgebra::Matrix3<$w>,
+            "C",
+            matrix(3, 3),
+            |v: &[f32]| {
+                let v = splat(v);
+                nalgebra::Matrix3::from_fn(|row, col| v[col * 3 + row])
+            }
+        );
+        layout!(
+            $layouts,
+            nalgebra::Matrix4<$w>,
+            "C",
+            matrix(4, 4),
+            |v: &[f32]| {
+                let v = splat(v);
+                nalgebra::Matrix4::from_fn(|row, col| v[col * 4 + row])
+            }
+        );
+        layout!(
+            $layouts,
+            nalgebra::Isometry2<$w>,
+            "Rust",
+            [prefixed("rot", &["re", "im"]), prefixed("tra", &["x", "y"])].concat(),
+            |v: &[f32]| {
+                let v = splat(v);
+                nalgebra::Isometry2::from_parts(
+                    nalgebra::Translation2::new(v[2], v[3]),
+                    nalgebra::UnitComplex::new_unchecked(nalgebra::Complex::new(v[0], v[1])),
+                )
+            }
+        );
+        layout!(
+            $layouts,
+            nalgebra::Isometry3<$w>,
+            "Rust",
+            [
+                prefixed("rot", &["x", "y", "z", "w"]),
+                prefixed("tra", &["x", "y", "z"]),
+            ]
+            .concat(),
+            |v: &[f32]| {
+                let v = splat(v);
+                nalgebra::Isometry3::from_parts(
+                    nalgebra::Translation3::new(v[4], v[5], v[6]),
+                    nalgebra::UnitQuaternion::new_unchecked(nalgebra::Quaternion::new(
+                        v[3], v[0], v[1], v[2],
+                    )),
+                )
+            }
+        );
+    }};
+}
+
+#[cfg(feature = "nalgebra")]
+fn nalgebra_layouts(layouts: &mut Vec<Layout>) {
+    nalgebra_layouts!(layouts, f32);
+    layout!(
+        layouts,
+        nalgebra::UnitComplex<f32>,
+        "transparent",
+        labels(&["re", "im"]),
+        |v: &[f32]| nalgebra::UnitComplex::new_unchecked(nalgebra::Complex::new(v[0], v[1]))
+    );
+    layout!(
+        layouts,
+        nalgebra::Isometry2<f32>,
+        "Rust",
+        [prefixed("rot", &["re", "im"]), prefixed("tra", &["x", "y"])].concat(),
+        |v: &[f32]| nalgebra::Isometry2::from_parts(
+            nalgebra::Translation2::new(v[2], v[3]),
+            nalgebra::UnitComplex::new_unchecked(nalgebra::Complex::new(v[0], v[1])),
+        )
+    );
+    layout!(
+        layouts,
+        nalgebra::Isometry3<f32>,
+        "Rust",
+        [
+            prefixed("rot", &["x", "y", "z", "w"]),
+            prefixed("tra", &["x", "y", "z"]),
+        ]
+        .concat(),
+        |v: &[f32]| nalgebra::Isometry3::from_parts(
+            nalgebra::Translation3::new(v[4], v[5], v[6]),
+            nalgebra::UnitQuaternion::new_unchecked(nalgebra::Quaternion::new(
+                v[3], v[0], v[1], v[2]
+            )),
+        )
+    );
+    layout!(
+        layouts,
+        nalgebra::Similarity3<f32>,
+        "Rust",
+        [
+            prefixed("rot", &["x", "y", "z", "w"]),
+            prefixed("tra", &["x", "y", "z"]),
+            labels(&["scaling"]),
+        ]
+        .concat(),
+        |v: &[f32]| nalgebra::Similarity3::from_parts(
+            nalgebra::Translation3::new(v[4], v[5], v[6]),
+            nalgebra::UnitQuaternion::new_unchecked(nalgebra::Quaternion::new(
+                v[3], v[0], v[1], v[2]
+            )),
+            v[7],
+        )
+    );
+    nalgebra_layouts!(layouts, f64);
+    #[cfg(feature = "nalgebra_f32x4")]
+    nalgebra_wide_layouts!(layouts, f32x4, 4);
+    #[cfg(feature = "nalgebra_f32x8")]
+    nalgebra_wide_layouts!(layouts, f32x8, 8);
+    #[cfg(feature = "nalgebra_f32x16")]
+    nalgebra_wide_layouts!(layouts, f32x16, 16);
+}
+
+#[cfg(feature = "nalgebra_glm")]
+fn nalgebra_glm_layouts(layouts: &mut Vec<Layout>) {
+    // the other glm types are aliases of nalgebra types
+    layout!(layouts, nalgebra_glm::Qua<f32>, "C", mint mint::Quaternion<f32>);
+}
+
+#[cfg(feature = "ultraviolet")]
+fn ultraviolet_layouts(layouts: &mut Vec<Layout>) {
+    use ultraviolet::{
+        f32x4, Bivec2, Bivec3, Rotor2, Rotor3, Vec2, Vec3, Vec4, WBivec2, WBivec3, WRotor2,
+        WRotor3, Wec2, Wec3, Wec4,
+    };
+    fn splat(v: &[f32]) -> Vec<f32x4> {
+        v.iter().map(|&x| f32x4::from([x; 4])).collect()
+    }
+    layout!(
+        layouts,
+        ultraviolet::Vec2,
+        "C",
+        labels(&["x", "y"]),
+        |v: &[f32]| { Vec2::new(v[0], v[1]) }
+    );
+    layout!(
+        layouts,
+        ultraviolet::Vec3,
+        "C",
+        labels(&["x", "y", "z"]),
+        |v: &[f32]| { Vec3::new(v[0], v[1], v[2]) }
+    );
+    layout!(
+        layouts,
+        ultraviolet::Vec4,
+        "C",
+        labels(&["x", "y", "z", "w"]),
+        |v: &[f32]| Vec4::new(v[0], v[1], v[2], v[3])
+    );
+    layout!(
+        layouts,
+        ultraviolet::Mat2,
+        "C",
+        matrix(2, 2),
+        |v: &[f32]| { ultraviolet::Mat2::new(Vec2::new(v[0], v[1]), Vec2::new(v[2], v[3])) }
+    );
+    layout!(
+        layouts,
+        ultraviolet::Mat3,
+        "C",
+        matrix(3, 3),
+        |v: &[f32]| {
+            ultraviolet::Mat3::new(
+                Vec3::new(v[0], v[1], v[2]),
+                Vec3::new(v[3], v[4], v[5]),
+                Vec3::new(v[6], v[7], v[8]),
+            )
+        }
+    );
+    layout!(
+        layouts,
+        ultraviolet::Mat4,
+        "C",
+        matrix(4, 4),
+        |v: &[f32]| {
+            ultraviolet::Mat4::new(
+                Vec4::new(v[0], v[1], v[2], v[3]),
+                Vec4::new(v[4], v[5], v[6], v[7]),
+                Vec4::new(v[8], v[9], v[10], v[11]),
+                Vec4::new(v[12], v[13], v[14], v[15]),
+            )
+        }
+    );
+    layout!(
+        layouts,
+        ultraviolet::Rotor2,
+        "C",
+        labels(&["s", "xy"]),
+        |v: &[f32]| { Rotor2::new(v[0], Bivec2::new(v[1])) }
+    );
+    layout!(
+        layouts,
+        ultraviolet::Rotor3,
+        "C",
+        labels(&["s", "xy", "xz", "yz"]),
+        |v: &[f32]| Rotor3::new(v[0], Bivec3::new(v[1], v[2], v[3]))
+    );
+    layout!(
+        layouts,
+        ultraviolet::Isometry2,
+        "C",
+        [prefixed("tra", &["x", "y"]), prefixed("rot", &["s", "xy"])].concat(),
+        |v: &[f32]| ultraviolet::Isometry2::new(
+            Vec2::new(v[0], v[1]),
+            Rotor2::new(v[2], Bivec2::new(v[3]))
+        )
+    );
+    layout!(
+        layouts,
+        ultraviolet::Isometry3,
+        "C",
+        [
+            prefixed("tra", &["x", "y", "z"]),
+            prefixed("rot", &["s", "xy", "xz", "yz"]),
+        ]
+        .concat(),
+        |v: &[f32]| ultraviolet::Isometry3::new(
+            Vec3::new(v[0], v[1], v[2]),
+            Rotor3::new(v[3], Bivec3::new(v[4], v[5], v[6]))
+        )
+    );
+    layout!(
+        layouts,
+        ultraviolet::Similarity3,
+        "C",
+        [
+            prefixed("tra", &["x", "y", "z"]),
+            prefixed("rot", &["s", "xy", "xz", "yz"]),
+            labels(&["scale"]),
+        ]
+        .concat(),
+        |v: &[f32]| ultraviolet::Similarity3::new(
+            Vec3::new(v[0], v[1], v[2]),
+            Rotor3::new(v[3], Bivec3::new(v[4], v[5], v[6])),
+            v[7],
+        )
+    );
+    layout!(
+        layouts,
+        ultraviolet::Wec2,
+        "C",
+        labels(&["x", "y"]),
+        |v: &[f32]| {
+            let v = splat(v);
+            Wec2::new(v[0], v[1])
+        }
+    );
+    layout!(
+        layouts,
+        ultraviolet::Wec3,
+        "C",
+        labels(&["x", "y", "z"]),
+        |v: &[f32]| {
+            let v = splat(v);
+            Wec3::new(v[0], v[1], v[2])
+        }
+    );
+    layout!(
+        layouts,
+        ultraviolet::Wec4,
+        "C",
+        labels(&["x", "y", "z", "w"]),
+        |v: &[f32]| {
+            let v = splat(v);
+            Wec4::new(v[0], v[1], v[2], v[3])
+        }
+    );
+    layout!(
+        layouts,
+        ultraviolet::Wat2,
+        "C",
+        matrix(2, 2),
+        |v: &[f32]| {
+            let v = splat(v);
+            ultraviolet::Wat2::new(Wec2::new(v[0], v[1]), Wec2::new(v[2], v[3]))
+        }
+    );
+    layout!(
+        layouts,
+        ultraviolet::Wat3,
+        "C",
+        matrix(3, 3),
+        |v: &[f32]| {
+            let v = splat(v);
+            ultraviolet::Wat3::new(
+                Wec3::new(v[0], v[1], v[2]),
+                Wec3::new(v[3], v[4], v[5]),
+                Wec3::new(v[6], v[7], v[8]),
+            )
+        }
+    );
+    layout!(
+        layouts,
+        ultraviolet::Wat4,
+        "C",
+        matrix(4, 4),
+        |v: &[f32]| {
+            let v = splat(v);
+            ultraviolet::Wat4::new(
+                Wec4::new(v[0], v[1], v[2], v[3]),
+                Wec4::new(v[4], v[5], v[6], v[7]),
+                Wec4::new(v[8], v[9], v[10], v[11]),
+                Wec4::new(v[12], v[13], v[14], v[15]),
+            )
+        }
+    );
+    layout!(
+        layouts,
+        ultraviolet::WRotor2,
+        "C",
+        labels(&["s", "xy"]),
+        |v: &[f32]| {
+            let v = splat(v);
+            WRotor2::new(v[0], WBivec2::new(v[1]))
+        }
+    );
+    layout!(
+        layouts,
+        ultraviolet::WRotor3,
+        "C",
+        labels(&["s", "xy", "xz", "yz"]),
+        |v: &[f32]| {
+            let v = splat(v);
+            WRotor3::new(v[0], WBivec3::new(v[1], v[2], v[3]))
+        }
+    );
+    layout!(
+        layouts,
+        ultraviolet::WIsometry2,
+        "C",
+        [prefixed("tra", &["x", "y"]), prefixed("rot", &["s", "xy"])].concat(),
+        |v: &[f32]| {
+            let v = splat(v);
+            ultraviolet::WIsometry2::new(
+                Wec2::new(v[0], v[1]),
+                WRotor2::new(v[2], WBivec2::new(v[3])),
+            )
+        }
+    );
+    layout!(
+        layouts,
+        ultraviolet::WIsometry3,
+        "C",
+        [
+            prefixed("tra", &["x", "y", "z"]),
+            prefixed("rot", &["s", "xy", "xz", "yz"]),
+        ]
+        .concat(),
+        |v: &[f32]| {
+            let v = splat(v);
+            ultraviolet::WIsometry3::new(
+                Wec3::new(v[0], v[1], v[2]),
+                WRotor3::new(v[3], WBivec3::new(v[4], v[5], v[6])),
+            )
+        }
+    );
+}
+
+#[cfg(feature = "euclid")]
+macro_rules! euclid_layouts {
+    ($layouts:ident, $s:ty) => {
+        layout!(
+            $layouts,
+            euclid::Point2D<$s, euclid::UnknownUnit>,
+            "C",
+            mint mint::Point2<$s>
+        );
+        layout!(
+            $layouts,
+            euclid::Point3D<$s, euclid::UnknownUnit>,
+            "C",
+            mint mint::Point3<$s>
+        );
+        layout!(
+            $layouts,
+            euclid::Rotation3D<$s, euclid::UnknownUnit, euclid::UnknownUnit>,
+            "C",
+            labels(&["x", "y", "z", "w"]),
+            |v: &[$s]| euclid::Rotation3D::quaternion(v[0], v[1], v[2], v[3])
+        );
+        layout!(
+            $layouts,
+            euclid::Transform2D<$s, euclid::UnknownUnit, euclid::UnknownUnit>,
+            "C",
+            mint mint::RowMatrix3x2<$s>
+        );
+        layout!(
+            $layouts,
+            euclid::Transform3D<$s, euclid::UnknownUnit, euclid::UnknownUnit>,
+            "C",
+            mint mint::RowMatrix4<$s>
+        );
+        layout!(
+            $layouts,
+            euclid::Vector2D<$s, euclid::UnknownUnit>,
+            "C",
+            mint mint::Vector2<$s>
+        );
+        layout!(
+            $layouts,
+            euclid::Vector3D<$s, euclid::UnknownUnit>,
+            "C",
+            mint mint::Vector3<$s>
+        );
+    };
+}
+
+#[cfg(feature = "euclid")]
+fn euclid_layouts(layouts: &mut Vec<Layout>) {
+    euclid_layouts!(layouts, f32);
+    euclid_layouts!(layouts, f64);
+    layout!(
+        layouts,
+        euclid::Rotation2D<f32, euclid::UnknownUnit, euclid::UnknownUnit>,
+        "C",
+        labels(&["angle"]),
+        |v: &[f32]| euclid::Rotation2D::radians(v[0])
+    );
+}
+
+#[cfg(feature = "vek")]
+macro_rules! vek_layouts {
+    ($layouts:ident, $s:ty) => {
+        layout!($layouts, vek::Mat2<$s>, "C", mint mint::ColumnMatrix2<$s>);
+        layout!($layouts, vek::Mat3<$s>, "C", mint mint::ColumnMatrix3<$s>);
+        layout!($layouts, vek::Mat4<$s>, "C", mint mint::ColumnMatrix4<$s>);
+        layout!($layouts, vek::Quaternion<$s>, "C", mint mint::Quaternion<$s>);
+        layout!($layouts, vek::Vec2<$s>, "C", mint mint::Vector2<$s>);
+        layout!($layouts, vek::Vec3<$s>, "C", mint mint::Vector3<$s>);
+        layout!($layouts, vek::Vec4<$s>, "C", mint mint::Vector4<$s>);
+    };
+}
+
+#[cfg(feature = "vek")]
+fn vek_layouts(layouts: &mut Vec<Layout>) {
+    vek_layouts!(layouts, f32);
+    layout!(
+        layouts,
+        vek::Transform<f32, f32, f32>,
+        "Rust",
+        [
+            prefixed("position", &["x", "y", "z"]),
+            prefixed("orientation", &["x", "y", "z", "w"]),
+            prefixed("scale", &["x", "y", "z"]),
+        ]
+        .concat(),
+        |v: &[f32]| vek::Transform {
+            position: vek::Vec3::new(v[0], v[1], v[2]),
+            orientation: vek::Quaternion::from_xyzw(v[3], v[4], v[5], v[6]),
+            scale: vek::Vec3::new(v[7], v[8], v[9]),
+        }
+    );
+    vek_layouts!(layouts, f64);
+}
+
+#[cfg(feature = "pathfinder_geometry")]
+fn pathfinder_layouts(layouts: &mut Vec<Layout>) {
+    use pathfinder_geometry::transform2d::Matrix2x2F;
+    use pathfinder_geometry::vector::{Vector2F, Vector4F};
+    layout!(
+        layouts,
+        pathfinder_geometry::vector::Vector2F,
+        "Rust",
+        labels(&["x", "y"]),
+        |v: &[f32]| Vector2F::new(v[0], v[1])
+    );
+    layout!(
+        layouts,
+        pathfinder_geometry::vector::Vector4F,
+        "Rust",
+        labels(&["x", "y", "z", "w"]),
+        |v: &[f32]| Vector4F::new(v[0], v[1], v[2], v[3])
+    );
+    layout!(
+        layouts,
+        pathfinder_geometry::transform2d::Matrix2x2F,
+        "Rust",
+        matrix(2, 2),
+        |v: &[f32]| Matrix2x2F::row_major(v[0], v[2], v[1], v[3])
+    );
+    layout!(
+        layouts,
+        pathfinder_geometry::transform2d::Transform2F,
+        "Rust",
+        matrix(2, 3),
+        |v: &[f32]| pathfinder_geometry::transform2d::Transform2F {
+            matrix: Matrix2x2F::row_major(v[0], v[2], v[1], v[3]),
+            vector: Vector2F::new(v[4], v[5]),
+        }
+    );
+    layout!(
+        layouts,
+        pathfinder_geometry::transform3d::Transform4F,
+        "C",
+        matrix(4, 4),
+        |v: &[f32]| pathfinder_geometry::transform3d::Transform4F::row_major(
+            v[0], v[4], v[8], v[12], v[1], v[5], v[9], v[13], v[2], v[6], v[10], v[14], v[3], v[7],
+            v[11], v[15],
+        )
+    );
+}
+
+#[cfg(feature = "reference")]
+fn reference_layouts(layouts: &mut Vec<Layout>) {
+    use mathbench::reference;
+    layout!(layouts, reference::Mat2, "Rust", mint mint::ColumnMatrix2<f32>);
+    layout!(layouts, reference::Mat3, "Rust", mint mint::ColumnMatrix3<f32>);
+    layout!(layouts, reference::Mat4, "Rust", mint mint::ColumnMatrix4<f32>);
+    layout!(layouts, reference::Quat, "Rust", mint mint::Quaternion<f32>);
+    layout!(layouts, reference::Vec2, "Rust", mint mint::Vector2<f32>);
+    layout!(layouts, reference::Vec3, "Rust", mint mint::Vector3<f32>);
+    layout!(layouts, reference::Vec4, "Rust", mint mint::Vector4<f32>);
+}
+
+#[cfg(all(feature = "intrinsics", target_arch = "x86_64"))]
+fn intrinsics_layouts(layouts: &mut Vec<Layout>) {
+    use mathbench::intrinsics;
+    layout!(layouts, intrinsics::Mat4, "Rust", mint mint::ColumnMatrix4<f32>);
+    layout!(layouts, intrinsics::Quat, "Rust", mint mint::Quaternion<f32>);
+    layout!(layouts, intrinsics::Vec3, "Rust", mint mint::Vector3<f32>);
+}
+
+fn format_report(layouts: &[Layout]) -> String {
+    let mut report = String::from("## Memory layout of benchmarked types\n");
+    let mut library = "";
+    for layout in layouts {
+        if layout.library != library {
+            library = &layout.library;
+            writeln!(report, "\n### {}\n", library).unwrap();
+            writeln!(
+                report,
+                "| type | size | align | scalar | elements | lanes | padding | order | repr | mint |"
+            )
+            .unwrap();
+            writeln!(report, "|---|---|---|---|---|---|---|---|---|---|").unwrap();
+        }
+        writeln!(
+            report,
+            "| `{}` | {} | {} | {} | {} | {} | {} | {} | {} | {} |",
+            layout.name,
+            layout.size,
+            layout.align,
+            layout.scalar,
+            layout.elements,
+            layout.lanes,
+            layout.padding,
+            layout.order,
+            layout.repr,
+            layout.mint
+        )
+        .unwrap();
+    }
+    report
+}
+
+/// Measures every type that implements `BenchValue` in the enabled features.
+pub(crate) fn layouts() -> Vec<Layout> {
+    let mut layouts = Vec::new();
+    #[cfg(feature = "glam")]
+    glam_layouts(&mut layouts);
+    #[cfg(feature = "cgmath")]
+    cgmath_layouts(&mut layouts);
+    #[cfg(feature = "nalgebra")]
+    nalgebra_layouts(&mut layouts);
+    #[cfg(feature = "nalgebra_glm")]
+    nalgebra_glm_layouts(&mut layouts);
+    #[cfg(feature = "ultraviolet")]
+    ultraviolet_layouts(&mut layouts);
+    #[cfg(feature = "euclid")]
+    euclid_layouts(&mut layouts);
+    #[cfg(feature = "vek")]
+    vek_layouts(&mut layouts);
+    #[cfg(feature = "pathfinder_geometry")]
+    pathfinder_layouts(&mut layouts);
+    #[cfg(feature = "reference")]
+    reference_layouts(&mut layouts);
+    #[cfg(all(feature = "intrinsics", target_arch = "x86_64"))]
+    intrinsics_layouts(&mut layouts);
+    layouts
+}
+
+fn main() {
+    let report = format_report(&layouts());
+    println!("{}", report);
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("target/reports");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("layout.md"), &report).unwrap();
+}
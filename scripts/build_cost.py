@@ -0,0 +1,162 @@
+#!/usr/bin/env python3
+
+# Measures the build cost of each library by building the `workload` crate in
+# `workload/` from scratch with one library feature enabled at a time. The `none`
+# row builds the workload with no library and is the baseline shared by every
+# build.
+
+import argparse
+import json
+import os
+import shutil
+import struct
+import subprocess
+import sys
+import time
+import prettytable
+
+
+# `intrinsics` only has the types of the benchmarks it is an upper bound for,
+# which can't run the euler workload
+DEFAULT = ['glam', 'cgmath', 'nalgebra', 'euclid', 'vek', 'ultraviolet', 'reference']
+OPTIONAL = ['glam_scalar', 'pathfinder_geometry', 'ultraviolet_f32x4', 'nalgebra_f32x4', 'nalgebra_f32x8', 'nalgebra_f32x16',
+            'nalgebra_glm']
+CHOICES = DEFAULT + OPTIONAL
+PROFILES = ['debug', 'release']
+
+# cargo's default codegen units for non-incremental builds
+DEFAULT_CODEGEN_UNITS = 16
+
+
+class DefaultListAction(argparse.Action):
+    def __call__(self, parser, namespace, values, option_string=None):
+        if values:
+            for value in values:
+                if value not in CHOICES:
+                    message = ("invalid choice: {0!r} (choose from {1})"
+                               .format(value,
+                                       ', '.join([repr(action)
+                                                  for action in CHOICES])))
+
+                    raise argparse.ArgumentError(self, message)
+            setattr(namespace, self.dest, values)
+
+
+def text_size(path):
+    """Returns the size of the .text section of an ELF file or None."""
+    with open(path, 'rb') as f:
+        data = f.read()
+    if data[:4] != b'\x7fELF':
+        return None
+    is_64 = data[4] == 2
+    endian = '<' if data[5] == 1 else '>'
+    if is_64:
+        shoff, = struct.unpack_from(endian + 'Q', data, 0x28)
+        shentsize, shnum, shstrndx = struct.unpack_from(endian + 'HHH', data, 0x3a)
+        fmt = endian + 'IIQQQQ'
+    else:
+        shoff, = struct.unpack_from(endian + 'I', data, 0x20)
+        shentsize, shnum, shstrndx = struct.unpack_from(endian + 'HHH', data, 0x2e)
+        fmt = endian + 'IIIIII'
+    sections = [struct.unpack_from(fmt, data, shoff + i * shentsize) for i in range(shnum)]
+    names_offset = sections[shstrndx][4]
+    for name, _, _, _, _, size in sections:
+        start = names_offset + name
+        if data[start:data.index(b'\0', start)] == b'.text':
+            return size
+    return None
+
+
+def build(root_dir, target_dir, feature, profile, codegen_units):
+    """Builds the workload from scratch and returns its build cost."""
+    shutil.rmtree(target_dir, ignore_errors=True)
+    command = ['cargo', 'build', '--manifest-path', os.path.join(root_dir, 'workload', 'Cargo.toml'),
+               '--target-dir', target_dir]
+    if feature != 'none':
+        command += ['--features', feature]
+    if profile == 'release':
+        command.append('--release')
+    env = dict(os.environ, CARGO_INCREMENTAL='0')
+    env['CARGO_PROFILE_{}_CODEGEN_UNITS'.format('DEV' if profile == 'debug' else 'RELEASE')] = str(codegen_units)
+
+    start = time.monotonic()
+    result = subprocess.run(command, cwd=root_dir, env=env,
+                            stdout=subprocess.DEVNULL, stderr=subprocess.PIPE)
+    seconds = time.monotonic() - start
+    if result.returncode != 0:
+        sys.stderr.write(result.stderr.decode())
+        sys.exit('building the workload with {} failed'.format(feature))
+
+    binary = os.path.join(target_dir, profile, 'workload')
+    if not os.path.isfile(binary):
+        binary += '.exe'
+    return {
+        'feature': feature,
+        'profile': profile,
+        'seconds': seconds,
+        'binary_size': os.path.getsize(binary),
+        'text_size': text_size(binary),
+    }
+
+
+def fmt_size(x):
+    return f'{x/1024:.1f} KiB' if x is not None else 'N/A'
+
+
+def print_table(results):
+    baseline = {x['profile']: x for x in results if x['feature'] == 'none'}
+    pt = prettytable.PrettyTable(['feature', 'profile', 'build time', 'over baseline', 'binary size', '.text size',
+                                  '.text over baseline'])
+    for x in results:
+        base = baseline.get(x['profile'])
+        time_delta = f"{x['seconds'] - base['seconds']:+.1f} s" if base else 'N/A'
+        if base and x['text_size'] is not None and base['text_size'] is not None:
+            text_delta = fmt_size(x['text_size'] - base['text_size'])
+        else:
+            text_delta = 'N/A'
+        pt.add_row([x['feature'], x['profile'], f"{x['seconds']:.1f} s", time_delta, fmt_size(x['binary_size']),
+                    fmt_size(x['text_size']), text_delta])
+    pt.align = 'r'
+    pt.align['feature'] = 'l'
+    pt.align['profile'] = 'l'
+    pt.hrules = prettytable.HEADER
+    pt.junction_char = '|'
+    print(pt)
+
+
+def main():
+    default_libs = DEFAULT
+    parser = argparse.ArgumentParser()
+    parser.add_argument('-a', '--all', action='store_true', help='include all features')
+    parser.add_argument('-p', '--profile', choices=PROFILES, action='append',
+                        help='profile to build, may be repeated (default: debug and release)')
+    parser.add_argument('-c', '--codegen-units', type=int, default=DEFAULT_CODEGEN_UNITS,
+                        help='codegen units for both profiles (default: %(default)s)')
+    parser.add_argument('--json', metavar='PATH', help='also write the results as JSON')
+    parser.add_argument('libs', nargs='*', action=DefaultListAction,
+                        default=default_libs,
+                        help='choose from {0}'.format(CHOICES))
+    args = parser.parse_args()
+
+    if args.all:
+        libs = CHOICES
+    else:
+        libs = list(dict.fromkeys(args.libs))
+    profiles = args.profile or PROFILES
+
+    root_dir = os.path.normpath(os.path.join(os.path.dirname(__file__), '..'))
+    results = []
+    for feature in ['none'] + libs:
+        for profile in profiles:
+            target_dir = os.path.join(root_dir, 'target', 'build-cost', feature)
+            print('building {} {}...'.format(feature, profile), file=sys.stderr)
+            results.append(build(root_dir, target_dir, feature, profile, args.codegen_units))
+    shutil.rmtree(os.path.join(root_dir, 'target', 'build-cost'), ignore_errors=True)
+
+    print_table(results)
+    if args.json:
+        with open(args.json, 'w') as f:
+            json.dump(results, f, indent=2)
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,95 @@
+#!/usr/bin/env python3
+
+# Cross-compiles the correctness tests for an ARM target and runs them under
+# qemu-user. Several libraries have separate SIMD and scalar code paths for ARM,
+# so this checks the results match `nalgebra` there too. Timing under emulation
+# is meaningless so only the tests are run, never the benchmarks.
+#
+# Requires the rustup target, a cross linker and qemu-user, for example on
+# Debian or Ubuntu:
+#
+#   rustup target add aarch64-unknown-linux-gnu
+#   apt install gcc-aarch64-linux-gnu libc6-dev-arm64-cross qemu-user
+
+import argparse
+import os
+import shutil
+import subprocess
+import sys
+
+
+TARGETS = {
+    'aarch64-unknown-linux-gnu': {
+        'linker': 'aarch64-linux-gnu-gcc',
+        'qemu': 'qemu-aarch64',
+        'sysroot': '/usr/aarch64-linux-gnu',
+    },
+}
+DEFAULT_TARGET = 'aarch64-unknown-linux-gnu'
+TESTS = ['mat', 'quat', 'vec']
+
+
+def installed_targets():
+    """Returns the targets installed with rustup or None if rustup isn't available."""
+    try:
+        result = subprocess.run(['rustup', 'target', 'list', '--installed'],
+                                stdout=subprocess.PIPE, stderr=subprocess.DEVNULL)
+    except FileNotFoundError:
+        return None
+    return result.stdout.decode().split()
+
+
+def check_tools(target, linker, qemu):
+    errors = []
+    targets = installed_targets()
+    if targets is not None and target not in targets:
+        errors.append('the {0} target is not installed, run `rustup target add {0}`'.format(target))
+    if shutil.which(linker) is None:
+        errors.append("the linker '{}' was not found".format(linker))
+    if qemu is not None and shutil.which(qemu) is None:
+        errors.append("'{}' was not found, install qemu-user".format(qemu))
+    if errors:
+        sys.exit('\n'.join(errors))
+
+
+def main():
+    parser = argparse.ArgumentParser()
+    parser.add_argument('--target', choices=list(TARGETS), default=DEFAULT_TARGET,
+                        help='target to test (default: %(default)s)')
+    parser.add_argument('--linker', help='cross linker (default: depends on the target)')
+    parser.add_argument('--qemu', help='qemu-user binary (default: depends on the target)')
+    parser.add_argument('--sysroot', help='sysroot passed to qemu with -L (default: depends on the target)')
+    parser.add_argument('--features', help='extra features to enable, e.g. "pathfinder_geometry"')
+    parser.add_argument('-t', '--test', action='append',
+                        help='test target to run, may be repeated (default: {})'.format(', '.join(TESTS)))
+    parser.add_argument('--no-run', action='store_true', help='only cross-compile the tests')
+    parser.add_argument('args', nargs='*', help='arguments passed to the test binaries, e.g. a test name filter')
+    args = parser.parse_args()
+
+    config = TARGETS[args.target]
+    linker = args.linker or config['linker']
+    qemu = None if args.no_run else args.qemu or config['qemu']
+    sysroot = args.sysroot or config['sysroot']
+    check_tools(args.target, linker, qemu)
+
+    command = ['cargo', 'test', '--target', args.target]
+    if args.features:
+        command += ['--features', args.features]
+    for test in args.test or TESTS:
+        command += ['--test', test]
+    if args.no_run:
+        command.append('--no-run')
+    elif args.args:
+        command += ['--'] + args.args
+
+    env_target = args.target.upper().replace('-', '_')
+    env = dict(os.environ)
+    env['CARGO_TARGET_{}_LINKER'.format(env_target)] = linker
+    if qemu is not None:
+        env['CARGO_TARGET_{}_RUNNER'.format(env_target)] = '{} -L {}'.format(qemu, sysroot)
+
+    root_dir = os.path.normpath(os.path.join(os.path.dirname(__file__), '..'))
+    sys.exit(subprocess.run(command, cwd=root_dir, env=env).returncode)
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,190 @@
+#!/usr/bin/env python3
+
+# Runs the benchmarks under different build profiles and reports how much slower
+# or faster each library is than in the default bench profile. Each
+# configuration overrides the bench profile with cargo's `CARGO_PROFILE_BENCH_*`
+# environment variables and saves its results as a criterion baseline of the
+# same name, which are then loaded with `summary.load_benches`.
+#
+# The `debug` set runs debug build profiles and the `lto` set runs the link
+# time optimization and codegen configurations used by shipping builds.
+
+import argparse
+import collections
+import math
+import os
+import re
+import subprocess
+import sys
+import prettytable
+
+import summary
+
+
+Config = collections.namedtuple('Config', ['name', 'profile', 'glam_assert', 'rustflags'])
+Config.__new__.__defaults__ = (False, None)
+
+DEBUG = {'DEBUG_ASSERTIONS': 'true', 'OVERFLOW_CHECKS': 'true'}
+
+# the `release` baseline is the bench profile in Cargo.toml, codegen-units = 1 without LTO
+BASELINE = Config('release', {})
+SETS = {
+    'debug': [
+        Config('opt1', dict(DEBUG, OPT_LEVEL='1')),
+        Config('opt1-glam-assert', dict(DEBUG, OPT_LEVEL='1'), glam_assert=True),
+        Config('opt0', dict(DEBUG, OPT_LEVEL='0')),
+        Config('opt0-glam-assert', dict(DEBUG, OPT_LEVEL='0'), glam_assert=True),
+    ],
+    'lto': [
+        Config('cgu16', {'CODEGEN_UNITS': '16'}),
+        Config('thin-lto', {'LTO': 'thin'}),
+        Config('fat-lto', {'LTO': 'fat'}),
+        Config('cgu16-thin-lto', {'CODEGEN_UNITS': '16', 'LTO': 'thin'}),
+        # cargo ignores the panic setting of the bench profile so it is passed to rustc
+        Config('panic-abort', {}, rustflags='-C panic=abort'),
+    ],
+}
+CONFIGS = [BASELINE] + [x for configs in SETS.values() for x in configs]
+CONFIG_NAMES = [x.name for x in CONFIGS]
+
+
+def default_features(root_dir):
+    """Returns the default features of the crate from its Cargo.toml."""
+    with open(os.path.join(root_dir, 'Cargo.toml')) as f:
+        match = re.search(r'^default\s*=\s*\[([^\]]*)\]', f.read(), re.MULTILINE)
+    return re.findall(r'"([^"]+)"', match.group(1)) if match else []
+
+
+def host_target():
+    """Returns the target triple of the host."""
+    output = subprocess.run(['rustc', '-vV'], stdout=subprocess.PIPE, check=True).stdout.decode()
+    return re.search(r'^host: (\S+)$', output, re.MULTILINE).group(1)
+
+
+def run(root_dir, config, benches, filter):
+    """Runs the benchmarks for a configuration and saves them as a baseline."""
+    command = ['cargo', 'bench']
+    # glam_assert is a default feature, debug assertions alone don't enable it
+    if not config.glam_assert:
+        features = [x for x in default_features(root_dir) if x != 'glam_assert']
+        command += ['--no-default-features', '--features', ' '.join(features)]
+    env = dict(os.environ)
+    if config.rustflags:
+        # with an explicit target RUSTFLAGS aren't applied to build scripts and proc macros
+        command += ['--target', host_target()]
+        env['RUSTFLAGS'] = ' '.join(x for x in [env.get('RUSTFLAGS'), config.rustflags] if x)
+    for bench in benches:
+        command += ['--bench', bench]
+    command += ['--']
+    if filter:
+        command.append(filter)
+    command += ['--save-baseline', config.name, '--noplot']
+
+    for key, value in config.profile.items():
+        env['CARGO_PROFILE_BENCH_{}'.format(key)] = value
+
+    print('running {}...'.format(config.name), file=sys.stderr)
+    result = subprocess.run(command, cwd=root_dir, env=env, stdout=subprocess.DEVNULL)
+    if result.returncode != 0:
+        sys.exit('running the benchmarks with {} failed'.format(config.name))
+
+
+def fmt_factor(x):
+    return f'{x:.2f}x' if x is not None else 'N/A'
+
+
+def slowdown(results, config, bench, lib):
+    base = results[BASELINE.name].get(bench, {}).get(lib)
+    value = results[config].get(bench, {}).get(lib)
+    if base is None or value is None or base <= 0.0:
+        return None
+    return value / base
+
+
+def mean_slowdown(results, config, lib):
+    """Returns the geometric mean slowdown of a library over every benchmark."""
+    factors = [slowdown(results, config, bench, lib) for bench in results[config]]
+    factors = [x for x in factors if x]
+    if not factors:
+        return None
+    return math.exp(sum(math.log(x) for x in factors) / len(factors))
+
+
+def print_bench_table(results, configs, libs):
+    for config in configs:
+        benches = sorted(set(results[config]) & set(results[BASELINE.name]))
+        table_libs = [x for x in libs if any(slowdown(results, config, bench, x) for bench in benches)]
+        if not table_libs:
+            continue
+        pt = prettytable.PrettyTable(['benchmark'] + [f'  {x:}  ' for x in table_libs])
+        for bench in benches:
+            pt.add_row([bench] + [fmt_factor(slowdown(results, config, bench, lib)) for lib in table_libs])
+        pt.align = 'r'
+        pt.align['benchmark'] = 'l'
+        pt.hrules = prettytable.HEADER
+        pt.junction_char = '|'
+        print('{} versus {}'.format(config, BASELINE.name))
+        print(pt)
+        print()
+
+
+def print_summary_table(results, configs, libs):
+    # the spread is the slowest over the fastest configuration including the
+    # baseline, libraries with a large spread are sensitive to the profile
+    pt = prettytable.PrettyTable(['library'] + configs + ['spread'])
+    for lib in libs:
+        row = [mean_slowdown(results, config, lib) for config in configs]
+        if not any(row):
+            continue
+        factors = [1.0] + [x for x in row if x]
+        pt.add_row([lib] + [fmt_factor(x) for x in row] + [fmt_factor(max(factors) / min(factors))])
+    pt.align = 'r'
+    pt.align['library'] = 'l'
+    pt.hrules = prettytable.HEADER
+    pt.junction_char = '|'
+    print('geometric mean slowdown versus {}'.format(BASELINE.name))
+    print(pt)
+
+
+def main():
+    default_libs = summary.DEFAULT
+    parser = argparse.ArgumentParser()
+    parser.add_argument('-a', '--all', action='store_true', help='include all libraries')
+    parser.add_argument('-s', '--set', choices=list(SETS), action='append',
+                        help='set of configurations to run, may be repeated (default: debug)')
+    parser.add_argument('-c', '--config', choices=CONFIG_NAMES, action='append',
+                        help='configuration to run, may be repeated, overrides --set')
+    parser.add_argument('-b', '--bench', action='append', default=[],
+                        help='only run this bench target, may be repeated')
+    parser.add_argument('-f', '--filter', help='criterion benchmark filter, e.g. "vec3 length"')
+    parser.add_argument('-r', '--report-only', action='store_true',
+                        help="don't run the benchmarks, report previously saved baselines")
+    parser.add_argument('libs', nargs='*', action=summary.DefaultListAction,
+                        default=default_libs,
+                        help='choose from {0}'.format(summary.CHOICES))
+    args = parser.parse_args()
+
+    libs = list(dict.fromkeys(summary.CHOICES if args.all else args.libs))
+    if args.config:
+        configs = [x for x in CONFIGS if x.name in args.config and x != BASELINE]
+    else:
+        sets = list(dict.fromkeys(args.set or ['debug']))
+        configs = [x for name in sets for x in SETS[name]]
+    configs = [BASELINE] + configs
+
+    root_dir = os.path.normpath(os.path.join(os.path.dirname(__file__), '..'))
+    if not args.report_only:
+        for config in configs:
+            run(root_dir, config, args.bench, args.filter)
+
+    criterion_dir = os.path.join(root_dir, 'target', 'criterion')
+    if not os.path.isdir(criterion_dir):
+        sys.exit("'{}' directory doesn't exist, run the benchmarks first.".format(criterion_dir))
+    results = {x.name: summary.load_benches(criterion_dir, libs, x.name) for x in configs}
+
+    compared = [x.name for x in configs if x != BASELINE]
+    print_bench_table(results, compared, libs)
+    print_summary_table(results, compared, libs)
+
+if __name__ == '__main__':
+    main()
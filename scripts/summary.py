@@ -7,10 +7,22 @@ import sys
 import prettytable
 
 
-DEFAULT = ['glam', 'cgmath', 'ultraviolet', 'ultraviolet_f32x4', 'nalgebra', 'nalgebra_f32x4', 'nalgebra_f32x8', 'nalgebra_f32x16', 'ultraviolet', 'ultraviolet_f32x4', 'euclid', 'vek']
-OPTIONAL = ['pathfinder']
+DEFAULT = ['glam', 'cgmath', 'ultraviolet', 'ultraviolet_f32x4', 'nalgebra', 'nalgebra_f32x4', 'nalgebra_f32x8', 'nalgebra_f32x16', 'ultraviolet', 'ultraviolet_f32x4', 'euclid', 'vek', 'reference', 'intrinsics', 'intrinsics_avx']
+OPTIONAL = ['glam_scalar', 'pathfinder', 'nalgebra_glm']
 CHOICES = DEFAULT + OPTIONAL
 
+# number of f32 lanes in each operation of the wide libraries
+LANES = {'ultraviolet_f32x4': 4, 'nalgebra_f32x4': 4, 'nalgebra_f32x8': 8, 'nalgebra_f32x16': 16}
+# every library processes 16 logical elements per iteration of the operation
+# benchmarks, e.g. `bench_unop!` does 16 operations and `bench_unop4!` does 4
+ITERATION_ELEMENTS = 16
+# the workload and slice benchmarks process one logical element per entry
+WORKLOADS = ['euler 2d', 'euler 3d', 'transform point3 slice', 'isometry transform point3 slice',
+             'vector3 aos to soa', 'vector3 soa to aos', 'transform point3 aos via soa']
+# the `MATHBENCH_INPUTS` distributions, other than `standard` their name is
+# appended to the benchmark group name, e.g. "vector3 length (subnormal)"
+INPUTS = ['standard', 'degenerate', 'extreme', 'subnormal', 'non-finite']
+
 class DefaultListAction(argparse.Action):
     def __call__(self, parser, namespace, values, option_string=None):
         if values:
@@ -34,13 +46,24 @@ def fmt_bench(x, max_value, min_value, threshold):
     return '   N/A      '
 
 
-def parse_bench(json_dir, benches):
+def split_inputs(group_id):
+    """Returns the benchmark group name without its input distribution and the distribution."""
+    for inputs in INPUTS[1:]:
+        suffix = ' ({})'.format(inputs)
+        if group_id.endswith(suffix):
+            return group_id[:-len(suffix)], inputs
+    return group_id, INPUTS[0]
+
+
+def parse_bench(json_dir, benches, inputs):
     benchmark_path = os.path.join(json_dir, 'benchmark.json')
     estimates_path = os.path.join(json_dir, 'estimates.json')
     try:
         with open(benchmark_path) as f:
             benchmarks = json.load(f)
-            bench_name = benchmarks['group_id']
+            bench_name, bench_inputs = split_inputs(benchmarks['group_id'])
+            if bench_inputs != inputs:
+                return
             input_size = benchmarks['value_str'] 
             try:
                 if input_size is not None:
@@ -56,27 +79,53 @@ def parse_bench(json_dir, benches):
         pass
 
 
-def main():
-    default_libs = DEFAULT
-    parser = argparse.ArgumentParser()
-    parser.add_argument('-a', '--all', action='store_true', help='include all libraries')
-    parser.add_argument('-t', '--threshold', type=float, default=2.5, help='percent of minimum value to highlight')
-    parser.add_argument('libs', nargs='*', action=DefaultListAction,
-                        default=default_libs,
-                        help='choose from {0}'.format(CHOICES))
-    args = parser.parse_args()
+def fmt_op(x):
+    return f'{x/1000:3.4} us' if x >= 1000 else f'{x:3.4f} ns'
 
-    if args.all:
-        libs = CHOICES
-    else:
-        libs = list(dict.fromkeys(args.libs))
-    threshold = 1.0 + args.threshold / 100.0
 
-    root_dir = os.path.normpath(os.path.join(os.path.dirname(__file__), '..'))
-    criterion_dir = os.path.join(root_dir, 'target', 'criterion')
-    if not os.path.isdir(criterion_dir):
-        sys.exit("'{}' directory doesn't exist, run `cargo bench` first.".format(criterion_dir))
+def logical_elements(bench_name):
+    """Returns the number of logical elements processed by one iteration of a benchmark."""
+    name, _, size = bench_name.rpartition(' x')
+    if not name or not size.isdigit():
+        name, size = bench_name, 1
+    if name.startswith('f64 '):
+        name = name[len('f64 '):]
+    return int(size) if name in WORKLOADS else ITERATION_ELEMENTS * int(size)
 
+
+def per_element(benches):
+    """Returns the benchmark results as the time per logical element."""
+    return {name: {lib: value / logical_elements(name) for lib, value in bench.items()}
+            for name, bench in benches.items()}
+
+
+def print_table(benches, libs, threshold, per_op=False):
+    pt = prettytable.PrettyTable(['benchmark'] + [f'  {x:}  ' for x in libs])
+    for bench_name in benches:
+        bench = benches[bench_name]
+        values = [bench[x] for x in libs if x in bench]
+        max_value = max(values)
+        min_value = min(values)
+        # hack so nothing is highlighted if there's only one lib to display
+        if len(libs) == 1:
+            min_value = max_value + 1
+        value_strs = [fmt_bench(bench.get(x, None), max_value, min_value, threshold) for x in libs]
+        if per_op:
+            # the time of one operation on each library's own type
+            value_strs = [x + (f' ({fmt_op(bench[lib] * LANES[lib])}/op)' if lib in LANES and lib in bench else '')
+                          for x, lib in zip(value_strs, libs)]
+        pt.add_row([bench_name] + value_strs)
+    pt.sortby = 'benchmark'
+    pt.align = 'r'
+    pt.align['benchmark'] = 'l'
+    pt.hrules = prettytable.HEADER
+    pt.junction_char = '|'
+    print(pt)
+
+
+def load_benches(criterion_dir, libs, baseline='new', inputs=INPUTS[0]):
+    """Returns the results of the given criterion baseline and input distribution for each
+    benchmark and library."""
     benches = {}
     for bench_dir in os.listdir(criterion_dir):
         if bench_dir == 'report':
@@ -87,7 +136,7 @@ def main():
             if not os.path.isdir(lib_path):
                 continue
             input_sizes = []
-            new_path = os.path.join(lib_path, 'new')
+            new_path = os.path.join(lib_path, baseline)
             if not os.path.isdir(new_path):
                 # check for input sizes
                 for input_dir in os.listdir(lib_path):
@@ -98,29 +147,58 @@ def main():
 
             if input_sizes:
                 for input_size in input_sizes:
-                    new_path = os.path.join(lib_path, str(input_size), 'new')
-                    parse_bench(new_path, benches)
+                    new_path = os.path.join(lib_path, str(input_size), baseline)
+                    parse_bench(new_path, benches, inputs)
             else:
-                parse_bench(new_path, benches)
+                parse_bench(new_path, benches, inputs)
+    return benches
 
-    pt = prettytable.PrettyTable(['benchmark'] + [f'  {x:}  ' for x in libs])
-    for bench_name in benches:
-        bench = benches[bench_name]
-        values = [bench[x] for x in libs if x in bench]
-        max_value = max(values)
-        min_value = min(values)
-        # hack so nothing is highlighted if there's only one lib to display
-        if len(libs) == 1:
-            min_value = max_value + 1
-        value_strs = [fmt_bench(bench.get(x, None), max_value, min_value, threshold) for x in libs]
-        pt.add_row([bench_name] + value_strs)
-    pt.sortby = 'benchmark'
-    pt.align = 'r'
-    pt.align['benchmark'] = 'l'
-    pt.hrules = prettytable.HEADER
-    pt.junction_char = '|'
-    print(pt)
 
+def main():
+    default_libs = DEFAULT
+    parser = argparse.ArgumentParser()
+    parser.add_argument('-a', '--all', action='store_true', help='include all libraries')
+    parser.add_argument('-t', '--threshold', type=float, default=2.5, help='percent of minimum value to highlight')
+    parser.add_argument('-e', '--per-element', action='store_true',
+                        help='show the time per logical element instead of per iteration')
+    parser.add_argument('-r', '--raw', action='store_true',
+                        help='show the time per logical element and of one operation on each wide type')
+    parser.add_argument('-i', '--inputs', choices=INPUTS, default=INPUTS[0],
+                        help='show the results for a MATHBENCH_INPUTS input distribution')
+    parser.add_argument('libs', nargs='*', action=DefaultListAction,
+                        default=default_libs,
+                        help='choose from {0}'.format(CHOICES))
+    args = parser.parse_args()
+
+    if args.all:
+        libs = CHOICES
+    else:
+        libs = list(dict.fromkeys(args.libs))
+    threshold = 1.0 + args.threshold / 100.0
+
+    root_dir = os.path.normpath(os.path.join(os.path.dirname(__file__), '..'))
+    criterion_dir = os.path.join(root_dir, 'target', 'criterion')
+    if not os.path.isdir(criterion_dir):
+        sys.exit("'{}' directory doesn't exist, run `cargo bench` first.".format(criterion_dir))
+
+    benches = load_benches(criterion_dir, libs, inputs=args.inputs)
+    args.per_element = args.per_element or args.raw
+    if args.per_element:
+        benches = per_element(benches)
+
+    # f64 benchmark groups are prefixed with "f64 " and reported in their own table
+    f32_benches = {k: v for k, v in benches.items() if not k.startswith('f64 ')}
+    f64_benches = {k[len('f64 '):]: v for k, v in benches.items() if k.startswith('f64 ')}
+    print_table(f32_benches, libs, threshold, args.raw)
+    wide_libs = [x for x in libs if x in LANES]
+    if args.per_element and wide_libs:
+        print('times are per logical element, one operation processes {}'.format(
+            ', '.join('{} elements for {}'.format(LANES[x], x) for x in wide_libs)))
+    if f64_benches:
+        f64_libs = [x for x in libs if any(x in bench for bench in f64_benches.values())]
+        print()
+        print('f64')
+        print_table(f64_benches, f64_libs, threshold)
 
 if __name__ == '__main__':
     main()
@@ -0,0 +1,152 @@
+#!/usr/bin/env python3
+
+# Builds the library for wasm32-wasi with one library feature enabled at a time
+# and runs the tests under wasmtime, reporting which libraries compile and which
+# tests pass. The `simd128` variant builds with the `simd128` target feature so
+# libraries with wasm SIMD code paths use them.
+#
+# Requires the rustup target and wasmtime:
+#
+#   rustup target add wasm32-wasi
+#   curl https://wasmtime.dev/install.sh -sSf | bash
+
+import argparse
+import os
+import shutil
+import subprocess
+import sys
+import prettytable
+
+
+LIBRARIES = ['glam', 'cgmath', 'nalgebra', 'nalgebra_f32x4', 'nalgebra_f32x8', 'nalgebra_f32x16', 'nalgebra_glm', 'euclid',
+             'vek', 'ultraviolet', 'ultraviolet_f32x4', 'pathfinder_geometry', 'reference']
+# the tests need every default library and use pathfinder_geometry if it builds
+TEST_FEATURES = ['glam', 'glam_assert', 'cgmath', 'euclid', 'nalgebra', 'ultraviolet', 'ultraviolet_f32x4', 'vek', 'reference']
+OPTIONAL_TEST_FEATURES = ['nalgebra_f32x4', 'nalgebra_f32x8', 'nalgebra_f32x16', 'nalgebra_glm', 'pathfinder_geometry']
+# panics abort on wasm32-wasi, so tests which catch panics can't run
+TESTS = ['coverage', 'euler', 'f64', 'isometry', 'laws', 'mat', 'quat', 'rotation', 'similarity', 'transform', 'vec', 'wide']
+# wasm32-wasi was renamed wasm32-wasip1 in newer toolchains
+TARGETS = ['wasm32-wasi', 'wasm32-wasip1']
+VARIANTS = {
+    'scalar': None,
+    'simd128': '-C target-feature=+simd128',
+}
+
+
+class DefaultListAction(argparse.Action):
+    def __call__(self, parser, namespace, values, option_string=None):
+        if values:
+            for value in values:
+                if value not in LIBRARIES:
+                    message = ("invalid choice: {0!r} (choose from {1})"
+                               .format(value,
+                                       ', '.join([repr(action)
+                                                  for action in LIBRARIES])))
+
+                    raise argparse.ArgumentError(self, message)
+            setattr(namespace, self.dest, values)
+
+
+def installed_targets():
+    """Returns the targets installed with rustup or None if rustup isn't available."""
+    try:
+        result = subprocess.run(['rustup', 'target', 'list', '--installed'],
+                                stdout=subprocess.PIPE, stderr=subprocess.DEVNULL)
+    except FileNotFoundError:
+        return None
+    return result.stdout.decode().split()
+
+
+def cargo(root_dir, target, variant, args, quiet, runner=None):
+    """Runs a cargo command for the target and variant and returns True if it succeeded."""
+    command = ['cargo'] + args + ['--target', target,
+                                  '--target-dir', os.path.join(root_dir, 'target', 'wasm', variant)]
+    env = dict(os.environ)
+    if VARIANTS[variant]:
+        env['RUSTFLAGS'] = ' '.join(x for x in [env.get('RUSTFLAGS'), VARIANTS[variant]] if x)
+    if runner:
+        env['CARGO_TARGET_{}_RUNNER'.format(target.upper().replace('-', '_'))] = runner
+    output = subprocess.DEVNULL if quiet else None
+    return subprocess.run(command, cwd=root_dir, env=env, stdout=output, stderr=output).returncode == 0
+
+
+def run_variant(root_dir, target, variant, libs, tests, runner, quiet):
+    """Builds each library and runs each test for a variant."""
+    built = {}
+    for lib in libs:
+        print('building {} {}...'.format(lib, variant), file=sys.stderr)
+        built[lib] = cargo(root_dir, target, variant, ['build', '--lib', '--no-default-features', '--features', lib], quiet)
+
+    results = {}
+    missing = [x for x in TEST_FEATURES if x in built and not built[x]]
+    features = TEST_FEATURES + [x for x in OPTIONAL_TEST_FEATURES if built.get(x)]
+    test_args = ['test', '--no-default-features', '--features', ' '.join(features)]
+    for test in tests:
+        test_args += ['--test', test]
+    print('building tests {}...'.format(variant), file=sys.stderr)
+    if missing or not cargo(root_dir, target, variant, test_args + ['--no-run'], quiet):
+        return built, {test: 'not built' for test in tests}
+    for test in tests:
+        print('running {} {}...'.format(test, variant), file=sys.stderr)
+        args = test_args[:test_args.index('--test')] + ['--test', test]
+        results[test] = 'passed' if cargo(root_dir, target, variant, args, quiet, runner) else 'failed'
+    return built, results
+
+
+def print_table(name, rows, results, variants):
+    pt = prettytable.PrettyTable([name] + variants)
+    for row in rows:
+        pt.add_row([row] + [results[variant].get(row, 'N/A') for variant in variants])
+    pt.align = 'l'
+    pt.hrules = prettytable.HEADER
+    pt.junction_char = '|'
+    print(pt)
+
+
+def main():
+    parser = argparse.ArgumentParser()
+    parser.add_argument('--target', choices=TARGETS,
+                        help='wasi target (default: whichever is installed)')
+    parser.add_argument('--simd128', action='store_true', help='also build and test the simd128 variant')
+    parser.add_argument('--wasmtime', default='wasmtime', help='wasmtime binary (default: %(default)s)')
+    parser.add_argument('-t', '--test', choices=TESTS, action='append',
+                        help='test target to run, may be repeated (default: all that can run)')
+    parser.add_argument('-v', '--verbose', action='store_true', help='show cargo and test output')
+    parser.add_argument('libs', nargs='*', action=DefaultListAction,
+                        default=LIBRARIES,
+                        help='choose from {0}'.format(LIBRARIES))
+    args = parser.parse_args()
+
+    targets = installed_targets()
+    target = args.target
+    if target is None:
+        target = next((x for x in TARGETS if targets is None or x in targets), None)
+        if target is None:
+            sys.exit('no wasi target is installed, run `rustup target add {}`'.format(TARGETS[0]))
+    elif targets is not None and target not in targets:
+        sys.exit('the {0} target is not installed, run `rustup target add {0}`'.format(target))
+    if shutil.which(args.wasmtime) is None:
+        sys.exit("'{}' was not found, see https://wasmtime.dev".format(args.wasmtime))
+
+    root_dir = os.path.normpath(os.path.join(os.path.dirname(__file__), '..'))
+    # the coverage test reads the benches and tests directories
+    runner = '{} run --dir {}'.format(args.wasmtime, root_dir)
+    libs = list(dict.fromkeys(args.libs))
+    tests = args.test or TESTS
+    variants = ['scalar', 'simd128'] if args.simd128 else ['scalar']
+
+    built, results = {}, {}
+    for variant in variants:
+        built[variant], results[variant] = run_variant(root_dir, target, variant, libs, tests, runner, not args.verbose)
+    built = {v: {k: 'ok' if x else 'failed' for k, x in b.items()} for v, b in built.items()}
+
+    print('{} build'.format(target))
+    print_table('library', libs, built, variants)
+    print()
+    print('{} tests'.format(target))
+    print_table('test', tests, results, variants)
+    if any(x != 'passed' for r in results.values() for x in r.values()):
+        sys.exit(1)
+
+if __name__ == '__main__':
+    main()
@@ -17,6 +17,14 @@ impl BenchValue for f32 {
     }
 }
 
+impl BenchValue for f64 {
+    fn random_value<R: Rng>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+}
+
+// only used by the library support modules, which are all optional
+#[allow(unused_macros)]
 macro_rules! impl_bench_value {
     ($t:ty, $f:expr) => {
         impl BenchValue for $t {
@@ -27,38 +35,183 @@ macro_rules! impl_bench_value {
     };
 }
 
+/// The distribution random benchmark and test inputs are drawn from.
+///
+/// The default is `Standard`. Benchmarks can use a different distribution by
+/// setting the `MATHBENCH_INPUTS` environment variable to the distribution name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputDistribution {
+    /// Invertible matrices, unit quaternions and vector components in `0.0..1.0`.
+    Standard,
+    /// Near singular matrices, near zero length vectors and quaternions which are
+    /// nearly parallel or antiparallel to each other.
+    Degenerate,
+    /// Huge or tiny components whose squares overflow or underflow.
+    Extreme,
+    /// Subnormal (denormal) components.
+    Subnormal,
+    /// Components which may be NaN or infinite.
+    NonFinite,
+}
+
+impl InputDistribution {
+    pub const ALL: [InputDistribution; 5] = [
+        InputDistribution::Standard,
+        InputDistribution::Degenerate,
+        InputDistribution::Extreme,
+        InputDistribution::Subnormal,
+        InputDistribution::NonFinite,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            InputDistribution::Standard => "standard",
+            InputDistribution::Degenerate => "degenerate",
+            InputDistribution::Extreme => "extreme",
+            InputDistribution::Subnormal => "subnormal",
+            InputDistribution::NonFinite => "non-finite",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|dist| dist.name() == name)
+    }
+
+    /// Returns the benchmark group name for `name` on this distribution. Other
+    /// than `Standard` the distribution name is appended so that results for
+    /// different distributions are saved separately.
+    pub fn group_name(self, name: &str) -> String {
+        match self {
+            InputDistribution::Standard => name.to_string(),
+            _ => format!("{} ({})", name, self.name()),
+        }
+    }
+}
+
+thread_local! {
+    static INPUT_DISTRIBUTION: std::cell::Cell<Option<InputDistribution>> =
+        const { std::cell::Cell::new(None) };
+}
+
+/// Returns the distribution random inputs are currently drawn from on this thread.
+pub fn input_distribution() -> InputDistribution {
+    INPUT_DISTRIBUTION.with(|cell| {
+        cell.get().unwrap_or_else(|| {
+            let dist = match std::env::var("MATHBENCH_INPUTS") {
+                Ok(name) => InputDistribution::from_name(&name).unwrap_or_else(|| {
+                    let names: Vec<_> = InputDistribution::ALL.iter().map(|d| d.name()).collect();
+                    panic!(
+                        "unknown MATHBENCH_INPUTS distribution `{}`, expected one of {}",
+                        name,
+                        names.join(", ")
+                    )
+                }),
+                Err(_) => InputDistribution::Standard,
+            };
+            cell.set(Some(dist));
+            dist
+        })
+    })
+}
+
+/// Calls `f` with random inputs on this thread drawn from `dist`. The previous
+/// distribution is restored afterwards, even if `f` panics.
+pub fn with_input_distribution<T, F>(dist: InputDistribution, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    struct Restore(Option<InputDistribution>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            INPUT_DISTRIBUTION.with(|cell| cell.set(self.0));
+        }
+    }
+
+    let _restore = Restore(INPUT_DISTRIBUTION.with(|cell| cell.replace(Some(dist))));
+    f()
+}
+
 pub mod mint_support {
-    use super::glam_support::*;
+    use super::{input_distribution, InputDistribution};
     use mint;
     use rand::Rng;
 
+    // f32 random functions  ------------------------------------------------------
+    pub fn random_f32<R>(rng: &mut R) -> f32
+    where
+        R: Rng,
+    {
+        rng.gen::<f32>().distribute(input_distribution(), rng)
+    }
+
+    pub fn random_nonzero_f32<R>(rng: &mut R) -> f32
+    where
+        R: Rng,
+    {
+        rng.gen_range(0.1, 1.0)
+    }
+
+    pub fn random_angle_radians<R>(rng: &mut R) -> f32
+    where
+        R: Rng,
+    {
+        rng.gen_range(-std::f32::consts::PI, std::f32::consts::PI)
+    }
+
     // mint random functions  -----------------------------------------------------
     pub fn random_mint_quat<R>(rng: &mut R) -> mint::Quaternion<f32>
     where
         R: Rng,
     {
-        random_glam_quat(rng).into()
+        let q = standard_mint_quat(rng);
+        distribute_mint_quat(q, input_distribution(), rng)
     }
 
     pub fn random_mint_vec2<R>(rng: &mut R) -> mint::Vector2<f32>
     where
         R: Rng,
     {
-        rng.gen::<[f32; 2]>().into()
+        [random_f32(rng), random_f32(rng)].into()
     }
 
     pub fn random_mint_vec3<R>(rng: &mut R) -> mint::Vector3<f32>
     where
         R: Rng,
     {
-        rng.gen::<[f32; 3]>().into()
+        [random_f32(rng), random_f32(rng), random_f32(rng)].into()
     }
 
     pub fn random_mint_vec4<R>(rng: &mut R) -> mint::Vector4<f32>
     where
         R: Rng,
     {
-        rng.gen::<[f32; 4]>().into()
+        [
+            random_f32(rng),
+            random_f32(rng),
+            random_f32(rng),
+            random_f32(rng),
+        ]
+        .into()
+    }
+
+    pub fn random_nonzero_mint_vec2<R>(rng: &mut R) -> mint::Vector2<f32>
+    where
+        R: Rng,
+    {
+        [random_nonzero_f32(rng), random_nonzero_f32(rng)].into()
+    }
+
+    pub fn random_nonzero_mint_vec3<R>(rng: &mut R) -> mint::Vector3<f32>
+    where
+        R: Rng,
+    {
+        [
+            random_nonzero_f32(rng),
+            random_nonzero_f32(rng),
+            random_nonzero_f32(rng),
+        ]
+        .into()
     }
 
     pub fn random_mint_mat2<R>(rng: &mut R) -> mint::ColumnMatrix2<f32>
@@ -83,50 +236,492 @@ pub mod mint_support {
     }
 
     pub fn random_mint_invertible_mat2<R>(rng: &mut R) -> mint::ColumnMatrix2<f32>
+    where
+        R: Rng,
+    {
+        let m = standard_mint_invertible_mat2(rng);
+        distribute_mint_mat2(m, input_distribution(), rng)
+    }
+
+    pub fn random_mint_homogeneous_mat3<R>(rng: &mut R) -> mint::ColumnMatrix3<f32>
+    where
+        R: Rng,
+    {
+        let m = standard_mint_homogeneous_mat3(rng);
+        distribute_mint_homogeneous_mat3(m, input_distribution(), rng)
+    }
+
+    pub fn random_mint_homogeneous_mat4<R>(rng: &mut R) -> mint::ColumnMatrix4<f32>
+    where
+        R: Rng,
+    {
+        let m = standard_mint_homogeneous_mat4(rng);
+        distribute_mint_homogeneous_mat4(m, input_distribution(), rng)
+    }
+
+    fn standard_mint_quat<R>(rng: &mut R) -> mint::Quaternion<f32>
+    where
+        R: Rng,
+    {
+        let yaw = random_angle_radians(rng);
+        let pitch = random_angle_radians(rng);
+        let roll = random_angle_radians(rng);
+        mint_quat_from_rotation_ypr(yaw, pitch, roll)
+    }
+
+    fn standard_mint_invertible_mat2<R>(rng: &mut R) -> mint::ColumnMatrix2<f32>
     where
         R: Rng,
     {
         loop {
-            let m = glam::Mat2::from_cols_array(&rng.gen::<[f32; 4]>());
-            if m.determinant().abs() > std::f32::EPSILON {
-                return m.into();
+            let m: mint::ColumnMatrix2<f32> = rng.gen::<[f32; 4]>().into();
+            let det = m.x.x * m.y.y - m.y.x * m.x.y;
+            if det.abs() > std::f32::EPSILON {
+                return m;
             }
         }
     }
 
-    pub fn random_mint_homogeneous_mat3<R>(rng: &mut R) -> mint::ColumnMatrix3<f32>
+    fn standard_mint_homogeneous_mat3<R>(rng: &mut R) -> mint::ColumnMatrix3<f32>
     where
         R: Rng,
     {
         loop {
-            let m = glam::Mat3::from_scale_angle_translation(
-                random_nonzero_glam_vec2(rng),
-                random_angle_radians(rng),
-                random_glam_vec2(rng),
-            );
-            if m.determinant().abs() > std::f32::EPSILON {
-                return m.into();
+            let scale = random_nonzero_mint_vec2(rng);
+            let angle = random_angle_radians(rng);
+            let translation: mint::Vector2<f32> = rng.gen::<[f32; 2]>().into();
+            // the rotation has a determinant of 1 so only the scale contributes
+            if (scale.x * scale.y).abs() > std::f32::EPSILON {
+                return mint_mat3_from_scale_angle_translation(scale, angle, translation);
             }
         }
     }
 
-    pub fn random_mint_homogeneous_mat4<R>(rng: &mut R) -> mint::ColumnMatrix4<f32>
+    fn standard_mint_homogeneous_mat4<R>(rng: &mut R) -> mint::ColumnMatrix4<f32>
     where
         R: Rng,
     {
         loop {
-            let m = glam::Mat4::from_scale_rotation_translation(
-                random_glam_nonzero_vec3(rng),
-                random_glam_quat(rng),
-                random_glam_vec3(rng),
-            );
-            if m.determinant().abs() > std::f32::EPSILON {
-                return m.into();
+            let scale = random_nonzero_mint_vec3(rng);
+            let rotation = standard_mint_quat(rng);
+            let translation: mint::Vector3<f32> = rng.gen::<[f32; 3]>().into();
+            // the rotation has a determinant of 1 so only the scale contributes
+            if (scale.x * scale.y * scale.z).abs() > std::f32::EPSILON {
+                return mint_mat4_from_scale_rotation_translation(scale, rotation, translation);
             }
         }
     }
+
+    // mint f64 random functions  -------------------------------------------------
+    // Structured values are generated as f32 and widened so the f64 benchmarks use
+    // the same distribution of inputs as the f32 benchmarks. The input distribution
+    // is applied after widening so subnormal and extreme values are relative to f64.
+    pub fn random_f64<R>(rng: &mut R) -> f64
+    where
+        R: Rng,
+    {
+        rng.gen::<f64>().distribute(input_distribution(), rng)
+    }
+
+    pub fn random_mint_dquat<R>(rng: &mut R) -> mint::Quaternion<f64>
+    where
+        R: Rng,
+    {
+        let q = standard_mint_quat(rng);
+        let q = mint::Quaternion {
+            v: widen_mint_vec3(q.v),
+            s: q.s.into(),
+        };
+        distribute_mint_quat(q, input_distribution(), rng)
+    }
+
+    pub fn random_mint_dvec2<R>(rng: &mut R) -> mint::Vector2<f64>
+    where
+        R: Rng,
+    {
+        [random_f64(rng), random_f64(rng)].into()
+    }
+
+    pub fn random_mint_dvec3<R>(rng: &mut R) -> mint::Vector3<f64>
+    where
+        R: Rng,
+    {
+        [random_f64(rng), random_f64(rng), random_f64(rng)].into()
+    }
+
+    pub fn random_mint_dvec4<R>(rng: &mut R) -> mint::Vector4<f64>
+    where
+        R: Rng,
+    {
+        [
+            random_f64(rng),
+            random_f64(rng),
+            random_f64(rng),
+            random_f64(rng),
+        ]
+        .into()
+    }
+
+    pub fn random_mint_invertible_dmat2<R>(rng: &mut R) -> mint::ColumnMatrix2<f64>
+    where
+        R: Rng,
+    {
+        let m = standard_mint_invertible_mat2(rng);
+        let m = mint::ColumnMatrix2 {
+            x: widen_mint_vec2(m.x),
+            y: widen_mint_vec2(m.y),
+        };
+        distribute_mint_mat2(m, input_distribution(), rng)
+    }
+
+    pub fn random_mint_homogeneous_dmat3<R>(rng: &mut R) -> mint::ColumnMatrix3<f64>
+    where
+        R: Rng,
+    {
+        let m = standard_mint_homogeneous_mat3(rng);
+        let m = mint::ColumnMatrix3 {
+            x: widen_mint_vec3(m.x),
+            y: widen_mint_vec3(m.y),
+            z: widen_mint_vec3(m.z),
+        };
+        distribute_mint_homogeneous_mat3(m, input_distribution(), rng)
+    }
+
+    pub fn random_mint_homogeneous_dmat4<R>(rng: &mut R) -> mint::ColumnMatrix4<f64>
+    where
+        R: Rng,
+    {
+        let m = standard_mint_homogeneous_mat4(rng);
+        let m = mint::ColumnMatrix4 {
+            x: widen_mint_vec4(m.x),
+            y: widen_mint_vec4(m.y),
+            z: widen_mint_vec4(m.z),
+            w: widen_mint_vec4(m.w),
+        };
+        distribute_mint_homogeneous_mat4(m, input_distribution(), rng)
+    }
+
+    fn widen_mint_vec2(v: mint::Vector2<f32>) -> mint::Vector2<f64> {
+        [v.x.into(), v.y.into()].into()
+    }
+
+    fn widen_mint_vec3(v: mint::Vector3<f32>) -> mint::Vector3<f64> {
+        [v.x.into(), v.y.into(), v.z.into()].into()
+    }
+
+    fn widen_mint_vec4(v: mint::Vector4<f32>) -> mint::Vector4<f64> {
+        [v.x.into(), v.y.into(), v.z.into(), v.w.into()].into()
+    }
+
+    // input distribution functions -----------------------------------------------
+    // These take a value from the standard distribution and move it into `dist`.
+    // Components are changed independently except for `Degenerate` matrices and
+    // quaternions which need their structure preserved. The last row of
+    // homogeneous matrices is left unchanged so they remain affine transforms.
+    trait InputFloat:
+        Copy
+        + std::ops::Add<Output = Self>
+        + std::ops::Mul<Output = Self>
+        + std::ops::Div<Output = Self>
+    {
+        fn distribute<R: Rng>(self, dist: InputDistribution, rng: &mut R) -> Self;
+        /// Noise small enough that a matrix with a column perturbed by it is
+        /// singular to within a few ulps.
+        fn ulp_noise<R: Rng>(rng: &mut R) -> Self;
+        fn from_f32(value: f32) -> Self;
+        fn sqrt(self) -> Self;
+    }
+
+    macro_rules! impl_input_float {
+        ($t:ident, extreme_exp => $extreme:expr, degenerate_exp => $degenerate:expr) => {
+            impl InputFloat for $t {
+                fn distribute<R: Rng>(self, dist: InputDistribution, rng: &mut R) -> Self {
+                    match dist {
+                        InputDistribution::Standard => self,
+                        InputDistribution::Degenerate => {
+                            self * (2.0 as $t).powi(-rng.gen_range($degenerate.0, $degenerate.1))
+                        }
+                        InputDistribution::Extreme => {
+                            let exp = rng.gen_range($extreme.0, $extreme.1);
+                            let exp = if rng.gen() { exp } else { -exp };
+                            self * (2.0 as $t).powi(exp)
+                        }
+                        InputDistribution::Subnormal => self * std::$t::MIN_POSITIVE,
+                        InputDistribution::NonFinite => match rng.gen_range(0, 8) {
+                            0 => std::$t::NAN,
+                            1 => std::$t::INFINITY,
+                            2 => std::$t::NEG_INFINITY,
+                            _ => self,
+                        },
+                    }
+                }
+
+                fn ulp_noise<R: Rng>(rng: &mut R) -> Self {
+                    rng.gen_range(-4.0, 4.0) * std::$t::EPSILON
+                }
+
+                fn from_f32(value: f32) -> Self {
+                    value.into()
+                }
+
+                fn sqrt(self) -> Self {
+                    $t::sqrt(self)
+                }
+            }
+        };
+    }
+
+    // the squares of most extreme values overflow or underflow, degenerate
+    // vectors have a squared length around the smallest normal value
+    impl_input_float!(f32, extreme_exp => (40, 80), degenerate_exp => (56, 72));
+    impl_input_float!(f64, extreme_exp => (400, 640), degenerate_exp => (480, 540));
+
+    fn distribute_mint_quat<T, R>(
+        q: mint::Quaternion<T>,
+        dist: InputDistribution,
+        rng: &mut R,
+    ) -> mint::Quaternion<T>
+    where
+        T: InputFloat,
+        R: Rng,
+    {
+        match dist {
+            InputDistribution::Standard => q,
+            InputDistribution::Degenerate => {
+                // a slightly perturbed fixed rotation or its negation, so any two
+                // quaternions are either nearly parallel or nearly antiparallel
+                let fixed = mint_quat_from_rotation_ypr(0.5, 1.0, 1.5);
+                let sign = if rng.gen() { 1.0 } else { -1.0 };
+                let mut perturb = |x: f32| T::from_f32(sign * (x + rng.gen_range(-1.0e-3, 1.0e-3)));
+                let (x, y, z, w) = (
+                    perturb(fixed.v.x),
+                    perturb(fixed.v.y),
+                    perturb(fixed.v.z),
+                    perturb(fixed.s),
+                );
+                let len = (x * x + y * y + z * z + w * w).sqrt();
+                mint::Quaternion {
+                    v: [x / len, y / len, z / len].into(),
+                    s: w / len,
+                }
+            }
+            _ => mint::Quaternion {
+                v: [
+                    q.v.x.distribute(dist, rng),
+                    q.v.y.distribute(dist, rng),
+                    q.v.z.distribute(dist, rng),
+                ]
+                .into(),
+                s: q.s.distribute(dist, rng),
+            },
+        }
+    }
+
+    fn distribute_mint_mat2<T, R>(
+        m: mint::ColumnMatrix2<T>,
+        dist: InputDistribution,
+        rng: &mut R,
+    ) -> mint::ColumnMatrix2<T>
+    where
+        T: InputFloat,
+        R: Rng,
+    {
+        match dist {
+            InputDistribution::Standard => m,
+            InputDistribution::Degenerate => {
+                // make the x axis a multiple of the y axis
+                let a = T::from_f32(rng.gen_range(0.5, 2.0));
+                mint::ColumnMatrix2 {
+                    x: [m.y.x * a + T::ulp_noise(rng), m.y.y * a + T::ulp_noise(rng)].into(),
+                    y: m.y,
+                }
+            }
+            _ => mint::ColumnMatrix2 {
+                x: [m.x.x.distribute(dist, rng), m.x.y.distribute(dist, rng)].into(),
+                y: [m.y.x.distribute(dist, rng), m.y.y.distribute(dist, rng)].into(),
+            },
+        }
+    }
+
+    fn distribute_mint_homogeneous_mat3<T, R>(
+        m: mint::ColumnMatrix3<T>,
+        dist: InputDistribution,
+        rng: &mut R,
+    ) -> mint::ColumnMatrix3<T>
+    where
+        T: InputFloat,
+        R: Rng,
+    {
+        match dist {
+            InputDistribution::Standard => m,
+            InputDistribution::Degenerate => {
+                // make the x axis a multiple of the y axis
+                let a = T::from_f32(rng.gen_range(0.5, 2.0));
+                mint::ColumnMatrix3 {
+                    x: [
+                        m.y.x * a + T::ulp_noise(rng),
+                        m.y.y * a + T::ulp_noise(rng),
+                        m.x.z,
+                    ]
+                    .into(),
+                    y: m.y,
+                    z: m.z,
+                }
+            }
+            _ => {
+                let mut column = |v: mint::Vector3<T>| -> mint::Vector3<T> {
+                    [v.x.distribute(dist, rng), v.y.distribute(dist, rng), v.z].into()
+                };
+                mint::ColumnMatrix3 {
+                    x: column(m.x),
+                    y: column(m.y),
+                    z: column(m.z),
+                }
+            }
+        }
+    }
+
+    fn distribute_mint_homogeneous_mat4<T, R>(
+        m: mint::ColumnMatrix4<T>,
+        dist: InputDistribution,
+        rng: &mut R,
+    ) -> mint::ColumnMatrix4<T>
+    where
+        T: InputFloat,
+        R: Rng,
+    {
+        match dist {
+            InputDistribution::Standard => m,
+            InputDistribution::Degenerate => {
+                // make the x axis a linear combination of the y and z axes
+                let a = T::from_f32(rng.gen_range(0.5, 2.0));
+                let b = T::from_f32(rng.gen_range(0.5, 2.0));
+                mint::ColumnMatrix4 {
+                    x: [
+                        m.y.x * a + m.z.x * b + T::ulp_noise(rng),
+                        m.y.y * a + m.z.y * b + T::ulp_noise(rng),
+                        m.y.z * a + m.z.z * b + T::ulp_noise(rng),
+                        m.x.w,
+                    ]
+                    .into(),
+                    y: m.y,
+                    z: m.z,
+                    w: m.w,
+                }
+            }
+            _ => {
+                let mut column = |v: mint::Vector4<T>| -> mint::Vector4<T> {
+                    [
+                        v.x.distribute(dist, rng),
+                        v.y.distribute(dist, rng),
+                        v.z.distribute(dist, rng),
+                        v.w,
+                    ]
+                    .into()
+                };
+                mint::ColumnMatrix4 {
+                    x: column(m.x),
+                    y: column(m.y),
+                    z: column(m.z),
+                    w: column(m.w),
+                }
+            }
+        }
+    }
+
+    // mint construction functions, these match the glam implementations ---------
+    pub fn mint_quat_from_rotation_ypr(yaw: f32, pitch: f32, roll: f32) -> mint::Quaternion<f32> {
+        // from_rotation_y(yaw) * from_rotation_x(pitch) * from_rotation_z(roll)
+        let (y0, w0) = (yaw * 0.5).sin_cos();
+        let (x1, w1) = (pitch * 0.5).sin_cos();
+        let (z2, w2) = (roll * 0.5).sin_cos();
+
+        let x3 = w0 * x1;
+        let y3 = y0 * w1;
+        let z3 = -y0 * x1;
+        let w3 = w0 * w1;
+
+        mint::Quaternion {
+            v: mint::Vector3 {
+                x: x3 * w2 + y3 * z2,
+                y: -x3 * z2 + y3 * w2,
+                z: w3 * z2 + z3 * w2,
+            },
+            s: w3 * w2 - z3 * z2,
+        }
+    }
+
+    pub fn mint_quat_to_axes(
+        rotation: mint::Quaternion<f32>,
+    ) -> (mint::Vector3<f32>, mint::Vector3<f32>, mint::Vector3<f32>) {
+        let (x, y, z, w) = (rotation.v.x, rotation.v.y, rotation.v.z, rotation.s);
+        let x2 = x + x;
+        let y2 = y + y;
+        let z2 = z + z;
+        let xx = x * x2;
+        let xy = x * y2;
+        let xz = x * z2;
+        let yy = y * y2;
+        let yz = y * z2;
+        let zz = z * z2;
+        let wx = w * x2;
+        let wy = w * y2;
+        let wz = w * z2;
+
+        (
+            [1.0 - (yy + zz), xy + wz, xz - wy].into(),
+            [xy - wz, 1.0 - (xx + zz), yz + wx].into(),
+            [xz + wy, yz - wx, 1.0 - (xx + yy)].into(),
+        )
+    }
+
+    pub fn mint_mat3_from_scale_angle_translation(
+        scale: mint::Vector2<f32>,
+        angle: f32,
+        translation: mint::Vector2<f32>,
+    ) -> mint::ColumnMatrix3<f32> {
+        let (sin, cos) = angle.sin_cos();
+        mint::ColumnMatrix3 {
+            x: [cos * scale.x, sin * scale.x, 0.0].into(),
+            y: [-sin * scale.y, cos * scale.y, 0.0].into(),
+            z: [translation.x, translation.y, 1.0].into(),
+        }
+    }
+
+    pub fn mint_mat4_from_scale_rotation_translation(
+        scale: mint::Vector3<f32>,
+        rotation: mint::Quaternion<f32>,
+        translation: mint::Vector3<f32>,
+    ) -> mint::ColumnMatrix4<f32> {
+        let (x_axis, y_axis, z_axis) = mint_quat_to_axes(rotation);
+        mint::ColumnMatrix4 {
+            x: [
+                x_axis.x * scale.x,
+                x_axis.y * scale.x,
+                x_axis.z * scale.x,
+                0.0,
+            ]
+            .into(),
+            y: [
+                y_axis.x * scale.y,
+                y_axis.y * scale.y,
+                y_axis.z * scale.y,
+                0.0,
+            ]
+            .into(),
+            z: [
+                z_axis.x * scale.z,
+                z_axis.y * scale.z,
+                z_axis.z * scale.z,
+                0.0,
+            ]
+            .into(),
+            w: [translation.x, translation.y, translation.z, 1.0].into(),
+        }
+    }
 }
 
+#[cfg(feature = "glam")]
 pub mod glam_support {
     use super::mint_support::*;
     use super::BenchValue;
@@ -139,20 +734,189 @@ pub mod glam_support {
     impl_bench_value!(glam::Vec2, random_mint_vec2);
     impl_bench_value!(glam::Vec3, random_mint_vec3);
     impl_bench_value!(glam::Vec4, random_mint_vec4);
+    impl_bench_value!((glam::Quat, glam::Vec3), random_glam_isometry3);
+    impl_bench_value!(
+        (glam::Vec3, glam::Quat, glam::Vec3),
+        random_glam_similarity3
+    );
 
-    // f32 random functions  ------------------------------------------------------
-    fn random_nonzero_f32<R>(rng: &mut R) -> f32
-    where
-        R: Rng,
-    {
-        rng.gen_range(0.1, 1.0)
+    /// Isometry operations on a rotation and translation tuple, glam doesn't
+    /// have an isometry type.
+    pub trait Isometry3Ext {
+        fn inverse(&self) -> Self;
+        fn mul_isometry3(&self, rhs: Self) -> Self;
+        fn transform_point3(&self, point: glam::Vec3) -> glam::Vec3;
     }
 
-    pub fn random_angle_radians<R>(rng: &mut R) -> f32
-    where
-        R: Rng,
-    {
-        rng.gen_range(-std::f32::consts::PI, std::f32::consts::PI)
+    impl Isometry3Ext for (glam::Quat, glam::Vec3) {
+        #[inline]
+        fn inverse(&self) -> Self {
+            let rotation = self.0.conjugate();
+            (rotation, -(rotation * self.1))
+        }
+
+        #[inline]
+        fn mul_isometry3(&self, rhs: Self) -> Self {
+            (self.0 * rhs.0, self.0 * rhs.1 + self.1)
+        }
+
+        #[inline]
+        fn transform_point3(&self, point: glam::Vec3) -> glam::Vec3 {
+            self.0 * point + self.1
+        }
+    }
+
+    /// Similarity operations on a scale, rotation and translation tuple in the
+    /// order returned by `Mat4::to_scale_rotation_translation`. The scale is
+    /// assumed to be uniform like the similarity types of other libraries.
+    pub trait Similarity3Ext {
+        fn inverse(&self) -> Self;
+        fn mul_similarity3(&self, rhs: Self) -> Self;
+        fn transform_point3(&self, point: glam::Vec3) -> glam::Vec3;
+        fn transform_vector3(&self, vector: glam::Vec3) -> glam::Vec3;
+        fn lerp(&self, end: Self, s: f32) -> Self;
+        fn to_mat4(&self) -> glam::Mat4;
+    }
+
+    impl Similarity3Ext for (glam::Vec3, glam::Quat, glam::Vec3) {
+        #[inline]
+        fn inverse(&self) -> Self {
+            let scale = self.0.reciprocal();
+            let rotation = self.1.conjugate();
+            (scale, rotation, -(rotation * self.2) * scale)
+        }
+
+        #[inline]
+        fn mul_similarity3(&self, rhs: Self) -> Self {
+            (
+                self.0 * rhs.0,
+                self.1 * rhs.1,
+                self.1 * (self.0 * rhs.2) + self.2,
+            )
+        }
+
+        #[inline]
+        fn transform_point3(&self, point: glam::Vec3) -> glam::Vec3 {
+            self.1 * (self.0 * point) + self.2
+        }
+
+        #[inline]
+        fn transform_vector3(&self, vector: glam::Vec3) -> glam::Vec3 {
+            self.1 * (self.0 * vector)
+        }
+
+        #[inline]
+        fn lerp(&self, end: Self, s: f32) -> Self {
+            (
+                self.0.lerp(end.0, s),
+                self.1.lerp(end.1, s),
+                self.2.lerp(end.2, s),
+            )
+        }
+
+        #[inline]
+        fn to_mat4(&self) -> glam::Mat4 {
+            glam::Mat4::from_scale_rotation_translation(self.0, self.1, self.2)
+        }
+    }
+
+    /// Rotation operations on a `Mat2` which is assumed to be a rotation
+    /// matrix, glam doesn't have a 2D rotation type.
+    pub trait Rotation2Ext {
+        fn slerp(&self, end: Self, s: f32) -> Self;
+    }
+
+    impl Rotation2Ext for glam::Mat2 {
+        #[inline]
+        fn slerp(&self, end: Self, s: f32) -> Self {
+            // the inverse of a rotation matrix is its transpose
+            let delta = end.mul_mat2(&self.transpose());
+            let x_axis = delta.x_axis();
+            let angle = x_axis.y().atan2(x_axis.x());
+            glam::Mat2::from_angle(angle * s).mul_mat2(self)
+        }
+    }
+
+    /// Matrix operations glam doesn't provide.
+    pub trait MatrixExt {
+        fn transpose_mul(&self, rhs: &Self) -> Self;
+        fn trace(&self) -> f32;
+    }
+
+    impl MatrixExt for glam::Mat2 {
+        #[inline]
+        fn transpose_mul(&self, rhs: &Self) -> Self {
+            self.transpose().mul_mat2(rhs)
+        }
+
+        #[inline]
+        fn trace(&self) -> f32 {
+            self.x_axis().x() + self.y_axis().y()
+        }
+    }
+
+    impl MatrixExt for glam::Mat3 {
+        #[inline]
+        fn transpose_mul(&self, rhs: &Self) -> Self {
+            self.transpose().mul_mat3(rhs)
+        }
+
+        #[inline]
+        fn trace(&self) -> f32 {
+            self.x_axis().x() + self.y_axis().y() + self.z_axis().z()
+        }
+    }
+
+    impl MatrixExt for glam::Mat4 {
+        #[inline]
+        fn transpose_mul(&self, rhs: &Self) -> Self {
+            self.transpose().mul_mat4(rhs)
+        }
+
+        #[inline]
+        fn trace(&self) -> f32 {
+            self.x_axis().x() + self.y_axis().y() + self.z_axis().z() + self.w_axis().w()
+        }
+    }
+
+    /// Operations on a `Mat4` holding a homogeneous transform.
+    /// `affine_inverse` assumes the columns of the upper 3x3 are orthogonal,
+    /// as they are for a rotation and a nonuniform scale, and
+    /// `project_point3` applies the perspective divide that
+    /// `Mat4::transform_point3` skips.
+    pub trait Matrix4Ext {
+        fn affine_inverse(&self) -> glam::Mat4;
+        fn project_point3(&self, point: glam::Vec3) -> glam::Vec3;
+    }
+
+    impl Matrix4Ext for glam::Mat4 {
+        #[inline]
+        fn affine_inverse(&self) -> glam::Mat4 {
+            // the inverse of the upper 3x3 is its transpose with each row
+            // divided by the squared length of the matching column
+            let x_axis = self.x_axis().truncate();
+            let y_axis = self.y_axis().truncate();
+            let z_axis = self.z_axis().truncate();
+            let linear = glam::Mat3::from_cols(
+                x_axis / x_axis.dot(x_axis),
+                y_axis / y_axis.dot(y_axis),
+                z_axis / z_axis.dot(z_axis),
+            )
+            .transpose();
+            let translation = -(linear * self.w_axis().truncate());
+            glam::Mat4::from_cols(
+                linear.x_axis().extend(0.0),
+                linear.y_axis().extend(0.0),
+                linear.z_axis().extend(0.0),
+                translation.extend(1.0),
+            )
+        }
+
+        #[inline]
+        fn project_point3(&self, point: glam::Vec3) -> glam::Vec3 {
+            let v = self.mul_vec4(point.extend(1.0));
+            v.truncate() / v.w()
+        }
     }
 
     // glam random functions ------------------------------------------------------
@@ -160,42 +924,53 @@ pub mod glam_support {
     where
         R: Rng,
     {
-        rng.gen::<[f32; 2]>().into()
+        random_mint_vec2(rng).into()
     }
 
     pub fn random_glam_vec3<R>(rng: &mut R) -> glam::Vec3
     where
         R: Rng,
     {
-        rng.gen::<[f32; 3]>().into()
+        random_mint_vec3(rng).into()
     }
 
     pub fn random_nonzero_glam_vec2<R>(rng: &mut R) -> glam::Vec2
     where
         R: Rng,
     {
-        glam::Vec2::new(random_nonzero_f32(rng), random_nonzero_f32(rng))
+        random_nonzero_mint_vec2(rng).into()
     }
 
     pub fn random_glam_nonzero_vec3<R>(rng: &mut R) -> glam::Vec3
     where
         R: Rng,
     {
-        glam::Vec3::new(
-            random_nonzero_f32(rng),
-            random_nonzero_f32(rng),
-            random_nonzero_f32(rng),
-        )
+        random_nonzero_mint_vec3(rng).into()
     }
 
     pub fn random_glam_quat<R>(rng: &mut R) -> glam::Quat
     where
         R: Rng,
     {
-        let yaw = random_angle_radians(rng);
-        let pitch = random_angle_radians(rng);
-        let roll = random_angle_radians(rng);
-        glam::Quat::from_rotation_ypr(yaw, pitch, roll)
+        random_mint_quat(rng).into()
+    }
+
+    pub fn random_glam_isometry3<R>(rng: &mut R) -> (glam::Quat, glam::Vec3)
+    where
+        R: Rng,
+    {
+        (random_mint_quat(rng).into(), random_mint_vec3(rng).into())
+    }
+
+    pub fn random_glam_similarity3<R>(rng: &mut R) -> (glam::Vec3, glam::Quat, glam::Vec3)
+    where
+        R: Rng,
+    {
+        (
+            glam::Vec3::splat(random_nonzero_f32(rng)),
+            random_mint_quat(rng).into(),
+            random_mint_vec3(rng).into(),
+        )
     }
 
     // public non-inlined functions for cargo asm
@@ -239,6 +1014,95 @@ pub mod cgmath_support {
     impl_bench_value!(cgmath::Vector2<f32>, random_mint_vec2);
     impl_bench_value!(cgmath::Vector3<f32>, random_mint_vec3);
     impl_bench_value!(cgmath::Vector4<f32>, random_mint_vec4);
+    impl_bench_value!(cgmath::Matrix2<f64>, random_mint_invertible_dmat2);
+    impl_bench_value!(cgmath::Matrix3<f64>, random_mint_homogeneous_dmat3);
+    impl_bench_value!(cgmath::Matrix4<f64>, random_mint_homogeneous_dmat4);
+    impl_bench_value!(cgmath::Point2<f64>, random_cgmath_dpoint2);
+    impl_bench_value!(cgmath::Point3<f64>, random_cgmath_dpoint3);
+    impl_bench_value!(cgmath::Quaternion<f64>, random_mint_dquat);
+    impl_bench_value!(cgmath::Vector2<f64>, random_mint_dvec2);
+    impl_bench_value!(cgmath::Vector3<f64>, random_mint_dvec3);
+    impl_bench_value!(cgmath::Vector4<f64>, random_mint_dvec4);
+
+    /// Interpolation and conversion of `Decomposed`, which cgmath only provides
+    /// for its parts and with `From`.
+    pub trait Similarity3Ext {
+        fn lerp(&self, other: Self, amount: f32) -> Self;
+        fn to_matrix4(&self) -> cgmath::Matrix4<f32>;
+    }
+
+    impl Similarity3Ext for cgmath::Decomposed<cgmath::Vector3<f32>, cgmath::Quaternion<f32>> {
+        #[inline]
+        fn lerp(&self, other: Self, amount: f32) -> Self {
+            use cgmath::VectorSpace;
+            cgmath::Decomposed {
+                scale: self.scale + (other.scale - self.scale) * amount,
+                rot: self.rot.nlerp(other.rot, amount),
+                disp: self.disp.lerp(other.disp, amount),
+            }
+        }
+
+        #[inline]
+        fn to_matrix4(&self) -> cgmath::Matrix4<f32> {
+            (*self).into()
+        }
+    }
+
+    /// Multiplication by the transpose of a matrix, which cgmath doesn't
+    /// provide.
+    pub trait MatrixExt {
+        fn transpose_mul(&self, rhs: &Self) -> Self;
+    }
+
+    macro_rules! impl_matrix_ext {
+        ($($m:ty),+) => {
+            $(
+                impl MatrixExt for $m {
+                    #[inline]
+                    fn transpose_mul(&self, rhs: &Self) -> Self {
+                        use cgmath::Matrix;
+                        self.transpose() * rhs
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_matrix_ext!(
+        cgmath::Matrix2<f32>,
+        cgmath::Matrix3<f32>,
+        cgmath::Matrix4<f32>
+    );
+
+    /// Inverse of a `Matrix4` holding a homogeneous transform, assuming the
+    /// columns of the upper 3x3 are orthogonal as they are for a rotation and
+    /// a nonuniform scale.
+    pub trait Matrix4Ext {
+        fn affine_invert(&self) -> cgmath::Matrix4<f32>;
+    }
+
+    impl Matrix4Ext for cgmath::Matrix4<f32> {
+        #[inline]
+        fn affine_invert(&self) -> cgmath::Matrix4<f32> {
+            use cgmath::{InnerSpace, Matrix};
+            // the inverse of the upper 3x3 is its transpose with each row
+            // divided by the squared length of the matching column
+            let (x, y, z) = (self.x.truncate(), self.y.truncate(), self.z.truncate());
+            let linear = cgmath::Matrix3::from_cols(
+                x / x.magnitude2(),
+                y / y.magnitude2(),
+                z / z.magnitude2(),
+            )
+            .transpose();
+            let translation = -(linear * self.w.truncate());
+            cgmath::Matrix4::from_cols(
+                linear.x.extend(0.0),
+                linear.y.extend(0.0),
+                linear.z.extend(0.0),
+                translation.extend(1.0),
+            )
+        }
+    }
 
     // cgmath random functions ----------------------------------------------------
     fn random_cgmath_decomposed3<R>(
@@ -258,15 +1122,31 @@ pub mod cgmath_support {
     where
         R: Rng,
     {
-        let v = random_mint_vec2(rng);
+        let v = random_mint_vec2(rng);
+        cgmath::Point2::new(v.x, v.y)
+    }
+
+    fn random_cgmath_point3<R>(rng: &mut R) -> cgmath::Point3<f32>
+    where
+        R: Rng,
+    {
+        let v = random_mint_vec3(rng);
+        cgmath::Point3::new(v.x, v.y, v.z)
+    }
+
+    fn random_cgmath_dpoint2<R>(rng: &mut R) -> cgmath::Point2<f64>
+    where
+        R: Rng,
+    {
+        let v = random_mint_dvec2(rng);
         cgmath::Point2::new(v.x, v.y)
     }
 
-    fn random_cgmath_point3<R>(rng: &mut R) -> cgmath::Point3<f32>
+    fn random_cgmath_dpoint3<R>(rng: &mut R) -> cgmath::Point3<f64>
     where
         R: Rng,
     {
-        let v = random_mint_vec3(rng);
+        let v = random_mint_dvec3(rng);
         cgmath::Point3::new(v.x, v.y, v.z)
     }
 
@@ -298,210 +1178,60 @@ pub mod cgmath_support {
 pub mod nalgebra_support {
     use super::mint_support::*;
     use super::BenchValue;
-    use rand::{Rng, SeedableRng};
-    use simba::simd::{f32x16, f32x4, f32x8};
+    use rand::Rng;
     impl_bench_value!(nalgebra::Matrix2<f32>, random_mint_invertible_mat2);
     impl_bench_value!(nalgebra::Matrix3<f32>, random_mint_homogeneous_mat3);
     impl_bench_value!(nalgebra::Matrix4<f32>, random_mint_homogeneous_mat4);
     impl_bench_value!(nalgebra::Point2<f32>, random_na_point2);
     impl_bench_value!(nalgebra::Point3<f32>, random_na_point3);
-    impl_bench_value!(nalgebra::Point2<f32x4>, random_na_point2x4);
-    impl_bench_value!(nalgebra::Point3<f32x4>, random_na_point3x4);
-    impl_bench_value!(nalgebra::Point2<f32x8>, random_na_point2x8);
-    impl_bench_value!(nalgebra::Point3<f32x8>, random_na_point3x8);
-    impl_bench_value!(nalgebra::Point2<f32x16>, random_na_point2x16);
-    impl_bench_value!(nalgebra::Point3<f32x16>, random_na_point3x16);
     impl_bench_value!(nalgebra::Transform2<f32>, random_na_transform2);
     impl_bench_value!(nalgebra::Transform3<f32>, random_na_transform3);
     impl_bench_value!(nalgebra::UnitQuaternion<f32>, random_na_quat);
-    impl_bench_value!(nalgebra::UnitQuaternion<f32x4>, random_na_quat4);
-    impl_bench_value!(nalgebra::UnitQuaternion<f32x8>, random_na_quat8);
-    impl_bench_value!(nalgebra::UnitQuaternion<f32x16>, random_na_quat16);
     impl_bench_value!(nalgebra::UnitComplex<f32>, random_na_cplx);
-    impl_bench_value!(nalgebra::UnitComplex<f32x4>, random_na_cplx4);
-    impl_bench_value!(nalgebra::UnitComplex<f32x8>, random_na_cplx8);
-    impl_bench_value!(nalgebra::UnitComplex<f32x16>, random_na_cplx16);
     impl_bench_value!(nalgebra::Vector2<f32>, random_na_vec2);
     impl_bench_value!(nalgebra::Vector3<f32>, random_na_vec3);
     impl_bench_value!(nalgebra::Vector4<f32>, random_na_vec4);
     impl_bench_value!(nalgebra::Isometry2<f32>, random_na_iso2);
     impl_bench_value!(nalgebra::Isometry3<f32>, random_na_iso3);
-    impl_bench_value!(nalgebra::Vector2<f32x4>, random_na_vec2x4);
-    impl_bench_value!(nalgebra::Vector3<f32x4>, random_na_vec3x4);
-    impl_bench_value!(nalgebra::Vector4<f32x4>, random_na_vec4x4);
-    impl_bench_value!(nalgebra::Vector2<f32x8>, random_na_vec2x8);
-    impl_bench_value!(nalgebra::Vector3<f32x8>, random_na_vec3x8);
-    impl_bench_value!(nalgebra::Vector4<f32x8>, random_na_vec4x8);
-    impl_bench_value!(nalgebra::Vector2<f32x16>, random_na_vec2x16);
-    impl_bench_value!(nalgebra::Vector3<f32x16>, random_na_vec3x16);
-    impl_bench_value!(nalgebra::Vector4<f32x16>, random_na_vec4x16);
-    impl_bench_value!(nalgebra::Matrix2<f32x4>, random_na_mat2x4);
-    impl_bench_value!(nalgebra::Matrix3<f32x4>, random_na_mat3x4);
-    impl_bench_value!(nalgebra::Matrix4<f32x4>, random_na_mat4x4);
-    impl_bench_value!(nalgebra::Matrix2<f32x8>, random_na_mat2x8);
-    impl_bench_value!(nalgebra::Matrix3<f32x8>, random_na_mat3x8);
-    impl_bench_value!(nalgebra::Matrix4<f32x8>, random_na_mat4x8);
-    impl_bench_value!(nalgebra::Matrix2<f32x16>, random_na_mat2x16);
-    impl_bench_value!(nalgebra::Matrix3<f32x16>, random_na_mat3x16);
-    impl_bench_value!(nalgebra::Matrix4<f32x16>, random_na_mat4x16);
-    impl_bench_value!(nalgebra::Isometry2<f32x4>, random_na_iso2x4);
-    impl_bench_value!(nalgebra::Isometry3<f32x4>, random_na_iso3x4);
-    impl_bench_value!(nalgebra::Isometry2<f32x8>, random_na_iso2x8);
-    impl_bench_value!(nalgebra::Isometry3<f32x8>, random_na_iso3x8);
-    impl_bench_value!(nalgebra::Isometry2<f32x16>, random_na_iso2x16);
-    impl_bench_value!(nalgebra::Isometry3<f32x16>, random_na_iso3x16);
+    impl_bench_value!(nalgebra::Similarity3<f32>, random_na_sim3);
+    impl_bench_value!(nalgebra::Matrix2<f64>, random_mint_invertible_dmat2);
+    impl_bench_value!(nalgebra::Matrix3<f64>, random_mint_homogeneous_dmat3);
+    impl_bench_value!(nalgebra::Matrix4<f64>, random_mint_homogeneous_dmat4);
+    impl_bench_value!(nalgebra::Point2<f64>, random_na_dpoint2);
+    impl_bench_value!(nalgebra::Point3<f64>, random_na_dpoint3);
+    impl_bench_value!(nalgebra::Transform2<f64>, random_na_dtransform2);
+    impl_bench_value!(nalgebra::Transform3<f64>, random_na_dtransform3);
+    impl_bench_value!(nalgebra::UnitQuaternion<f64>, random_na_dquat);
+    impl_bench_value!(nalgebra::Vector2<f64>, random_mint_dvec2);
+    impl_bench_value!(nalgebra::Vector3<f64>, random_mint_dvec3);
+    impl_bench_value!(nalgebra::Vector4<f64>, random_mint_dvec4);
 
     // nalgebra random functions --------------------------------------------------
     fn random_na_cplx<R: Rng>(rng: &mut R) -> nalgebra::UnitComplex<f32> {
-        let angle = crate::glam_support::random_angle_radians(rng);
+        let angle = crate::mint_support::random_angle_radians(rng);
         nalgebra::UnitComplex::new(angle)
     }
 
-    fn random_na_cplx4<R: Rng>(rng: &mut R) -> nalgebra::UnitComplex<f32x4> {
-        [
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-        ]
-        .into()
-    }
-
-    fn random_na_cplx8<R: Rng>(rng: &mut R) -> nalgebra::UnitComplex<f32x8> {
-        [
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-        ]
-        .into()
-    }
-
-    fn random_na_cplx16<R: Rng>(rng: &mut R) -> nalgebra::UnitComplex<f32x16> {
-        [
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-            nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
-        ]
-        .into()
-    }
-
-    fn random_na_quat<R: Rng>(rng: &mut R) -> nalgebra::UnitQuaternion<f32> {
+    pub(crate) fn random_na_quat<R: Rng>(rng: &mut R) -> nalgebra::UnitQuaternion<f32> {
         nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into())
     }
 
-    fn random_na_quat4<R: Rng>(rng: &mut R) -> nalgebra::UnitQuaternion<f32x4> {
-        [
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-        ]
-        .into()
-    }
-
-    fn random_na_quat8<R: Rng>(rng: &mut R) -> nalgebra::UnitQuaternion<f32x8> {
-        [
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-        ]
-        .into()
-    }
-
-    fn random_na_quat16<R: Rng>(rng: &mut R) -> nalgebra::UnitQuaternion<f32x16> {
-        [
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-            nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
-        ]
-        .into()
-    }
-
     fn random_na_iso2<R: Rng>(rng: &mut R) -> nalgebra::Isometry2<f32> {
         let rot = nalgebra::UnitComplex::random_value(rng);
-        let tra = nalgebra::Vector2::random_value(rng);
-        nalgebra::Isometry2::from_parts(tra.into(), rot)
-    }
-
-    fn random_na_iso2x4<R: Rng>(rng: &mut R) -> nalgebra::Isometry2<f32x4> {
-        let rot = nalgebra::UnitComplex::random_value(rng);
-        let tra = nalgebra::Vector2::random_value(rng);
-        nalgebra::Isometry2::from_parts(tra.into(), rot)
-    }
-
-    fn random_na_iso2x8<R: Rng>(rng: &mut R) -> nalgebra::Isometry2<f32x8> {
-        let rot = nalgebra::UnitComplex::random_value(rng);
-        let tra = nalgebra::Vector2::random_value(rng);
-        nalgebra::Isometry2::from_parts(tra.into(), rot)
-    }
-
-    fn random_na_iso2x16<R>(rng: &mut R) -> nalgebra::Isometry2<f32x16>
-    where
-        R: Rng,
-    {
-        let rot = nalgebra::UnitComplex::random_value(rng);
-        let tra = nalgebra::Vector2::random_value(rng);
+        let tra = nalgebra::Vector2::<f32>::random_value(rng);
         nalgebra::Isometry2::from_parts(tra.into(), rot)
     }
 
     fn random_na_iso3<R: Rng>(rng: &mut R) -> nalgebra::Isometry3<f32> {
         let rot = nalgebra::UnitQuaternion::random_value(rng);
-        let tra = nalgebra::Vector3::random_value(rng);
-        nalgebra::Isometry3::from_parts(tra.into(), rot)
-    }
-
-    fn random_na_iso3x4<R: Rng>(rng: &mut R) -> nalgebra::Isometry3<f32x4> {
-        let rot = nalgebra::UnitQuaternion::random_value(rng);
-        let tra = nalgebra::Vector3::random_value(rng);
-        nalgebra::Isometry3::from_parts(tra.into(), rot)
-    }
-
-    fn random_na_iso3x8<R: Rng>(rng: &mut R) -> nalgebra::Isometry3<f32x8> {
-        let rot = nalgebra::UnitQuaternion::random_value(rng);
-        let tra = nalgebra::Vector3::random_value(rng);
+        let tra = nalgebra::Vector3::<f32>::random_value(rng);
         nalgebra::Isometry3::from_parts(tra.into(), rot)
     }
 
-    fn random_na_iso3x16<R: Rng>(rng: &mut R) -> nalgebra::Isometry3<f32x16> {
+    fn random_na_sim3<R: Rng>(rng: &mut R) -> nalgebra::Similarity3<f32> {
         let rot = nalgebra::UnitQuaternion::random_value(rng);
-        let tra = nalgebra::Vector3::random_value(rng);
-        nalgebra::Isometry3::from_parts(tra.into(), rot)
+        let tra = nalgebra::Vector3::<f32>::random_value(rng);
+        nalgebra::Similarity3::from_parts(tra.into(), rot, random_nonzero_f32(rng))
     }
 
     fn random_na_transform2<R: Rng>(rng: &mut R) -> nalgebra::Transform2<f32> {
@@ -512,234 +1242,752 @@ pub mod nalgebra_support {
         nalgebra::Transform3::from_matrix_unchecked(random_mint_homogeneous_mat4(rng).into())
     }
 
-    fn random_na_point2<R: Rng>(rng: &mut R) -> nalgebra::Point2<f32> {
-        rng.gen::<[f32; 2]>().into()
-    }
-
-    fn random_na_point3<R: Rng>(rng: &mut R) -> nalgebra::Point3<f32> {
-        rng.gen::<[f32; 3]>().into()
+    fn random_na_dquat<R: Rng>(rng: &mut R) -> nalgebra::UnitQuaternion<f64> {
+        nalgebra::UnitQuaternion::from_quaternion(random_mint_dquat(rng).into())
     }
 
-    fn random_na_point2x4<R: Rng>(rng: &mut R) -> nalgebra::Point2<f32x4> {
-        random_na_vec2x4(rng).into()
+    fn random_na_dtransform2<R: Rng>(rng: &mut R) -> nalgebra::Transform2<f64> {
+        nalgebra::Transform2::from_matrix_unchecked(random_mint_homogeneous_dmat3(rng).into())
     }
 
-    fn random_na_point3x4<R: Rng>(rng: &mut R) -> nalgebra::Point3<f32x4> {
-        random_na_vec3x4(rng).into()
+    fn random_na_dtransform3<R: Rng>(rng: &mut R) -> nalgebra::Transform3<f64> {
+        nalgebra::Transform3::from_matrix_unchecked(random_mint_homogeneous_dmat4(rng).into())
     }
 
-    fn random_na_point2x8<R: Rng>(rng: &mut R) -> nalgebra::Point2<f32x8> {
-        random_na_vec2x8(rng).into()
+    fn random_na_dpoint2<R: Rng>(rng: &mut R) -> nalgebra::Point2<f64> {
+        let v = random_mint_dvec2(rng);
+        nalgebra::Point2::new(v.x, v.y)
     }
 
-    fn random_na_point3x8<R: Rng>(rng: &mut R) -> nalgebra::Point3<f32x8> {
-        random_na_vec3x8(rng).into()
+    fn random_na_dpoint3<R: Rng>(rng: &mut R) -> nalgebra::Point3<f64> {
+        let v = random_mint_dvec3(rng);
+        nalgebra::Point3::new(v.x, v.y, v.z)
     }
 
-    fn random_na_point2x16<R: Rng>(rng: &mut R) -> nalgebra::Point2<f32x16> {
-        random_na_vec2x16(rng).into()
+    fn random_na_point2<R: Rng>(rng: &mut R) -> nalgebra::Point2<f32> {
+        let v = random_mint_vec2(rng);
+        nalgebra::Point2::new(v.x, v.y)
     }
 
-    fn random_na_point3x16<R: Rng>(rng: &mut R) -> nalgebra::Point3<f32x16> {
-        random_na_vec3x16(rng).into()
+    fn random_na_point3<R: Rng>(rng: &mut R) -> nalgebra::Point3<f32> {
+        let v = random_mint_vec3(rng);
+        nalgebra::Point3::new(v.x, v.y, v.z)
     }
 
     fn random_na_vec2<R: Rng>(rng: &mut R) -> nalgebra::Vector2<f32> {
-        rng.gen::<[f32; 2]>().into()
+        random_mint_vec2(rng).into()
     }
 
     fn random_na_vec3<R: Rng>(rng: &mut R) -> nalgebra::Vector3<f32> {
-        rng.gen::<[f32; 3]>().into()
+        random_mint_vec3(rng).into()
     }
 
     fn random_na_vec4<R: Rng>(rng: &mut R) -> nalgebra::Vector4<f32> {
-        rng.gen::<[f32; 4]>().into()
+        random_mint_vec4(rng).into()
+    }
+
+    /// Interpolation of a `Similarity3`, which nalgebra only provides for its
+    /// parts.
+    pub trait Similarity3Ext {
+        fn lerp(&self, other: &Self, t: f32) -> Self;
+    }
+
+    impl Similarity3Ext for nalgebra::Similarity3<f32> {
+        #[inline]
+        fn lerp(&self, other: &Self, t: f32) -> Self {
+            let isometry = &self.isometry;
+            let translation = isometry
+                .translation
+                .vector
+                .lerp(&other.isometry.translation.vector, t);
+            let rotation = isometry.rotation.nlerp(&other.isometry.rotation, t);
+            let scaling = self.scaling() + (other.scaling() - self.scaling()) * t;
+            nalgebra::Similarity3::from_parts(translation.into(), rotation, scaling)
+        }
     }
 
-    fn random_na_vec2x4<R: Rng>(rng: &mut R) -> nalgebra::Vector2<f32x4> {
-        [random_f32x4(rng), random_f32x4(rng)].into()
+    /// Spherical interpolation of a `UnitComplex`, which nalgebra only
+    /// provides for `UnitQuaternion`.
+    pub trait Rotation2Ext {
+        fn slerp(&self, other: &Self, t: f32) -> Self;
     }
 
-    fn random_na_vec3x4<R: Rng>(rng: &mut R) -> nalgebra::Vector3<f32x4> {
-        [random_f32x4(rng), random_f32x4(rng), random_f32x4(rng)].into()
+    impl Rotation2Ext for nalgebra::UnitComplex<f32> {
+        #[inline]
+        fn slerp(&self, other: &Self, t: f32) -> Self {
+            self.rotation_to(other).powf(t) * self
+        }
     }
 
-    fn random_na_vec4x4<R: Rng>(rng: &mut R) -> nalgebra::Vector4<f32x4> {
-        [
-            random_f32x4(rng),
-            random_f32x4(rng),
-            random_f32x4(rng),
-            random_f32x4(rng),
-        ]
-        .into()
+    /// Inverse of a `Matrix4` holding a homogeneous transform, assuming the
+    /// columns of the upper 3x3 are orthogonal as they are for a rotation and
+    /// a nonuniform scale.
+    pub trait Matrix4Ext {
+        fn affine_inverse(&self) -> Self;
     }
 
-    fn random_na_vec2x8<R: Rng>(rng: &mut R) -> nalgebra::Vector2<f32x8> {
-        [random_f32x8(rng), random_f32x8(rng)].into()
+    impl Matrix4Ext for nalgebra::Matrix4<f32> {
+        #[inline]
+        fn affine_inverse(&self) -> Self {
+            use nalgebra::{U1, U3};
+            // the inverse of the upper 3x3 is its transpose with each row
+            // divided by the squared length of the matching column
+            let mut linear = self.fixed_slice::<U3, U3>(0, 0).transpose();
+            for i in 0..3 {
+                let norm_squared = linear.row(i).norm_squared();
+                linear.row_mut(i).unscale_mut(norm_squared);
+            }
+            let translation = -(linear * self.fixed_slice::<U3, U1>(0, 3));
+            let mut inverse = linear.to_homogeneous();
+            inverse
+                .fixed_slice_mut::<U3, U1>(0, 3)
+                .copy_from(&translation);
+            inverse
+        }
     }
 
-    fn random_na_vec3x8<R: Rng>(rng: &mut R) -> nalgebra::Vector3<f32x8> {
-        [random_f32x8(rng), random_f32x8(rng), random_f32x8(rng)].into()
+    /// Transform of a point by a `Matrix4` holding a homogeneous transform.
+    /// nalgebra's `Matrix4::transform_point` requires a `RealField` so isn't
+    /// available for the wide types.
+    pub trait SimdMatrix4Ext<N: nalgebra::SimdRealField> {
+        fn transform_point3(&self, point: &nalgebra::Point3<N>) -> nalgebra::Point3<N>;
     }
 
-    fn random_na_vec4x8<R: Rng>(rng: &mut R) -> nalgebra::Vector4<f32x8> {
-        [
-            random_f32x8(rng),
-            random_f32x8(rng),
-            random_f32x8(rng),
-            random_f32x8(rng),
-        ]
-        .into()
+    impl<N: nalgebra::SimdRealField> SimdMatrix4Ext<N> for nalgebra::Matrix4<N> {
+        #[inline]
+        fn transform_point3(&self, point: &nalgebra::Point3<N>) -> nalgebra::Point3<N> {
+            let v = self * point.to_homogeneous();
+            let w = v.w;
+            nalgebra::Point3::new(v.x / w, v.y / w, v.z / w)
+        }
     }
 
-    fn random_na_vec2x16<R: Rng>(rng: &mut R) -> nalgebra::Vector2<f32x16> {
-        [random_f32x16(rng), random_f32x16(rng)].into()
+    /// Conversion between an array of structures of `glam::Vec3`, as stored by
+    /// an ECS, and a structure of arrays of wide `Vector3`. The `aos` slice is
+    /// the number of lanes times the length of `soa`.
+    #[cfg(feature = "glam")]
+    pub trait Vector3SoaExt: Sized {
+        fn from_aos_slice(aos: &[glam::Vec3], soa: &mut [Self]);
+        fn to_aos_slice(soa: &[Self], aos: &mut [glam::Vec3]);
     }
 
-    fn random_na_vec3x16<R: Rng>(rng: &mut R) -> nalgebra::Vector3<f32x16> {
-        [random_f32x16(rng), random_f32x16(rng), random_f32x16(rng)].into()
+    pub fn nalgebra_mat4_det(m: &nalgebra::Matrix4<f32>) -> f32 {
+        m.determinant()
     }
 
-    fn random_na_vec4x16<R: Rng>(rng: &mut R) -> nalgebra::Vector4<f32x16> {
-        [
-            random_f32x16(rng),
-            random_f32x16(rng),
-            random_f32x16(rng),
-            random_f32x16(rng),
-        ]
-        .into()
+    pub fn nalgebra_mat4_inv(m: &nalgebra::Matrix4<f32>) -> nalgebra::Matrix4<f32> {
+        m.try_inverse().unwrap_or(*m)
     }
 
-    fn random_na_mat2x4<R: Rng>(rng: &mut R) -> nalgebra::Matrix2<f32x4> {
-        nalgebra::Matrix2::from_fn(|_, _| random_f32x4(rng))
+    pub fn nalgebra_mat4_try_inv(m: &nalgebra::Matrix4<f32>) -> Option<nalgebra::Matrix4<f32>> {
+        m.try_inverse()
     }
 
-    fn random_na_mat3x4<R: Rng>(rng: &mut R) -> nalgebra::Matrix3<f32x4> {
-        nalgebra::Matrix3::from_fn(|_, _| random_f32x4(rng))
+    pub fn nalgebra_mat4_mul(
+        lhs: &nalgebra::Matrix4<f32>,
+        rhs: &nalgebra::Matrix4<f32>,
+    ) -> nalgebra::Matrix4<f32> {
+        lhs * rhs
     }
 
-    fn random_na_mat4x4<R: Rng>(rng: &mut R) -> nalgebra::Matrix4<f32x4> {
-        nalgebra::Matrix4::from_fn(|_, _| random_f32x4(rng))
-    }
+    // the wide types use `simba`, which is only enabled by the
+    // `nalgebra_f32x*` features
+    #[cfg(any(
+        feature = "nalgebra_f32x4",
+        feature = "nalgebra_f32x8",
+        feature = "nalgebra_f32x16"
+    ))]
+    mod wide {
+        #[cfg(feature = "glam")]
+        use super::Vector3SoaExt;
+        use crate::mint_support::*;
+        use crate::BenchValue;
+        use rand::Rng;
+        use simba::simd::{f32x16, f32x4, f32x8};
+
+        impl_bench_value!(nalgebra::Point2<f32x4>, random_na_point2x4);
+        impl_bench_value!(nalgebra::Point3<f32x4>, random_na_point3x4);
+        impl_bench_value!(nalgebra::Point2<f32x8>, random_na_point2x8);
+        impl_bench_value!(nalgebra::Point3<f32x8>, random_na_point3x8);
+        impl_bench_value!(nalgebra::Point2<f32x16>, random_na_point2x16);
+        impl_bench_value!(nalgebra::Point3<f32x16>, random_na_point3x16);
+        impl_bench_value!(nalgebra::UnitQuaternion<f32x4>, random_na_quat4);
+        impl_bench_value!(nalgebra::UnitQuaternion<f32x8>, random_na_quat8);
+        impl_bench_value!(nalgebra::UnitQuaternion<f32x16>, random_na_quat16);
+        impl_bench_value!(nalgebra::UnitComplex<f32x4>, random_na_cplx4);
+        impl_bench_value!(nalgebra::UnitComplex<f32x8>, random_na_cplx8);
+        impl_bench_value!(nalgebra::UnitComplex<f32x16>, random_na_cplx16);
+        impl_bench_value!(nalgebra::Vector2<f32x4>, random_na_vec2x4);
+        impl_bench_value!(nalgebra::Vector3<f32x4>, random_na_vec3x4);
+        impl_bench_value!(nalgebra::Vector4<f32x4>, random_na_vec4x4);
+        impl_bench_value!(nalgebra::Vector2<f32x8>, random_na_vec2x8);
+        impl_bench_value!(nalgebra::Vector3<f32x8>, random_na_vec3x8);
+        impl_bench_value!(nalgebra::Vector4<f32x8>, random_na_vec4x8);
+        impl_bench_value!(nalgebra::Vector2<f32x16>, random_na_vec2x16);
+        impl_bench_value!(nalgebra::Vector3<f32x16>, random_na_vec3x16);
+        impl_bench_value!(nalgebra::Vector4<f32x16>, random_na_vec4x16);
+        impl_bench_value!(nalgebra::Matrix2<f32x4>, random_na_mat2x4);
+        impl_bench_value!(nalgebra::Matrix3<f32x4>, random_na_mat3x4);
+        impl_bench_value!(nalgebra::Matrix4<f32x4>, random_na_mat4x4);
+        impl_bench_value!(nalgebra::Matrix2<f32x8>, random_na_mat2x8);
+        impl_bench_value!(nalgebra::Matrix3<f32x8>, random_na_mat3x8);
+        impl_bench_value!(nalgebra::Matrix4<f32x8>, random_na_mat4x8);
+        impl_bench_value!(nalgebra::Matrix2<f32x16>, random_na_mat2x16);
+        impl_bench_value!(nalgebra::Matrix3<f32x16>, random_na_mat3x16);
+        impl_bench_value!(nalgebra::Matrix4<f32x16>, random_na_mat4x16);
+        impl_bench_value!(nalgebra::Isometry2<f32x4>, random_na_iso2x4);
+        impl_bench_value!(nalgebra::Isometry3<f32x4>, random_na_iso3x4);
+        impl_bench_value!(nalgebra::Isometry2<f32x8>, random_na_iso2x8);
+        impl_bench_value!(nalgebra::Isometry3<f32x8>, random_na_iso3x8);
+        impl_bench_value!(nalgebra::Isometry2<f32x16>, random_na_iso2x16);
+        impl_bench_value!(nalgebra::Isometry3<f32x16>, random_na_iso3x16);
+
+        fn random_na_cplx4<R: Rng>(rng: &mut R) -> nalgebra::UnitComplex<f32x4> {
+            [
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+            ]
+            .into()
+        }
 
-    fn random_na_mat2x8<R: Rng>(rng: &mut R) -> nalgebra::Matrix2<f32x8> {
-        nalgebra::Matrix2::from_fn(|_, _| random_f32x8(rng))
-    }
+        fn random_na_cplx8<R: Rng>(rng: &mut R) -> nalgebra::UnitComplex<f32x8> {
+            [
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+            ]
+            .into()
+        }
 
-    fn random_na_mat3x8<R: Rng>(rng: &mut R) -> nalgebra::Matrix3<f32x8> {
-        nalgebra::Matrix3::from_fn(|_, _| random_f32x8(rng))
-    }
+        fn random_na_cplx16<R: Rng>(rng: &mut R) -> nalgebra::UnitComplex<f32x16> {
+            [
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+                nalgebra::UnitComplex::new(crate::mint_support::random_angle_radians(rng)),
+            ]
+            .into()
+        }
 
-    fn random_na_mat4x8<R: Rng>(rng: &mut R) -> nalgebra::Matrix4<f32x8> {
-        nalgebra::Matrix4::from_fn(|_, _| random_f32x8(rng))
-    }
+        fn random_na_quat4<R: Rng>(rng: &mut R) -> nalgebra::UnitQuaternion<f32x4> {
+            [
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+            ]
+            .into()
+        }
 
-    fn random_na_mat2x16<R: Rng>(rng: &mut R) -> nalgebra::Matrix2<f32x16> {
-        nalgebra::Matrix2::from_fn(|_, _| random_f32x16(rng))
-    }
+        fn random_na_quat8<R: Rng>(rng: &mut R) -> nalgebra::UnitQuaternion<f32x8> {
+            [
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+            ]
+            .into()
+        }
 
-    fn random_na_mat3x16<R: Rng>(rng: &mut R) -> nalgebra::Matrix3<f32x16> {
-        nalgebra::Matrix3::from_fn(|_, _| random_f32x16(rng))
-    }
+        fn random_na_quat16<R: Rng>(rng: &mut R) -> nalgebra::UnitQuaternion<f32x16> {
+            [
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+                nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
+            ]
+            .into()
+        }
 
-    fn random_na_mat4x16<R: Rng>(rng: &mut R) -> nalgebra::Matrix4<f32x16> {
-        nalgebra::Matrix4::from_fn(|_, _| random_f32x16(rng))
-    }
+        fn random_na_iso2x4<R: Rng>(rng: &mut R) -> nalgebra::Isometry2<f32x4> {
+            let rot = nalgebra::UnitComplex::random_value(rng);
+            let tra = nalgebra::Vector2::<f32x4>::random_value(rng);
+            nalgebra::Isometry2::from_parts(tra.into(), rot)
+        }
 
-    fn random_f32x4<R: Rng>(rng: &mut R) -> f32x4 {
-        rng.gen::<[f32; 4]>().into()
-    }
+        fn random_na_iso2x8<R: Rng>(rng: &mut R) -> nalgebra::Isometry2<f32x8> {
+            let rot = nalgebra::UnitComplex::random_value(rng);
+            let tra = nalgebra::Vector2::<f32x8>::random_value(rng);
+            nalgebra::Isometry2::from_parts(tra.into(), rot)
+        }
+
+        fn random_na_iso2x16<R>(rng: &mut R) -> nalgebra::Isometry2<f32x16>
+        where
+            R: Rng,
+        {
+            let rot = nalgebra::UnitComplex::random_value(rng);
+            let tra = nalgebra::Vector2::<f32x16>::random_value(rng);
+            nalgebra::Isometry2::from_parts(tra.into(), rot)
+        }
+
+        fn random_na_iso3x4<R: Rng>(rng: &mut R) -> nalgebra::Isometry3<f32x4> {
+            let rot = nalgebra::UnitQuaternion::random_value(rng);
+            let tra = nalgebra::Vector3::<f32x4>::random_value(rng);
+            nalgebra::Isometry3::from_parts(tra.into(), rot)
+        }
+
+        fn random_na_iso3x8<R: Rng>(rng: &mut R) -> nalgebra::Isometry3<f32x8> {
+            let rot = nalgebra::UnitQuaternion::random_value(rng);
+            let tra = nalgebra::Vector3::<f32x8>::random_value(rng);
+            nalgebra::Isometry3::from_parts(tra.into(), rot)
+        }
+
+        fn random_na_iso3x16<R: Rng>(rng: &mut R) -> nalgebra::Isometry3<f32x16> {
+            let rot = nalgebra::UnitQuaternion::random_value(rng);
+            let tra = nalgebra::Vector3::<f32x16>::random_value(rng);
+            nalgebra::Isometry3::from_parts(tra.into(), rot)
+        }
+
+        fn random_na_point2x4<R: Rng>(rng: &mut R) -> nalgebra::Point2<f32x4> {
+            random_na_vec2x4(rng).into()
+        }
+
+        fn random_na_point3x4<R: Rng>(rng: &mut R) -> nalgebra::Point3<f32x4> {
+            random_na_vec3x4(rng).into()
+        }
+
+        fn random_na_point2x8<R: Rng>(rng: &mut R) -> nalgebra::Point2<f32x8> {
+            random_na_vec2x8(rng).into()
+        }
+
+        fn random_na_point3x8<R: Rng>(rng: &mut R) -> nalgebra::Point3<f32x8> {
+            random_na_vec3x8(rng).into()
+        }
+
+        fn random_na_point2x16<R: Rng>(rng: &mut R) -> nalgebra::Point2<f32x16> {
+            random_na_vec2x16(rng).into()
+        }
 
-    fn random_f32x8<R: Rng>(rng: &mut R) -> f32x8 {
-        rng.gen::<[f32; 8]>().into()
+        fn random_na_point3x16<R: Rng>(rng: &mut R) -> nalgebra::Point3<f32x16> {
+            random_na_vec3x16(rng).into()
+        }
+
+        fn random_na_vec2x4<R: Rng>(rng: &mut R) -> nalgebra::Vector2<f32x4> {
+            [random_f32x4(rng), random_f32x4(rng)].into()
+        }
+
+        fn random_na_vec3x4<R: Rng>(rng: &mut R) -> nalgebra::Vector3<f32x4> {
+            [random_f32x4(rng), random_f32x4(rng), random_f32x4(rng)].into()
+        }
+
+        fn random_na_vec4x4<R: Rng>(rng: &mut R) -> nalgebra::Vector4<f32x4> {
+            [
+                random_f32x4(rng),
+                random_f32x4(rng),
+                random_f32x4(rng),
+                random_f32x4(rng),
+            ]
+            .into()
+        }
+
+        fn random_na_vec2x8<R: Rng>(rng: &mut R) -> nalgebra::Vector2<f32x8> {
+            [random_f32x8(rng), random_f32x8(rng)].into()
+        }
+
+        fn random_na_vec3x8<R: Rng>(rng: &mut R) -> nalgebra::Vector3<f32x8> {
+            [random_f32x8(rng), random_f32x8(rng), random_f32x8(rng)].into()
+        }
+
+        fn random_na_vec4x8<R: Rng>(rng: &mut R) -> nalgebra::Vector4<f32x8> {
+            [
+                random_f32x8(rng),
+                random_f32x8(rng),
+                random_f32x8(rng),
+                random_f32x8(rng),
+            ]
+            .into()
+        }
+
+        fn random_na_vec2x16<R: Rng>(rng: &mut R) -> nalgebra::Vector2<f32x16> {
+            [random_f32x16(rng), random_f32x16(rng)].into()
+        }
+
+        fn random_na_vec3x16<R: Rng>(rng: &mut R) -> nalgebra::Vector3<f32x16> {
+            [random_f32x16(rng), random_f32x16(rng), random_f32x16(rng)].into()
+        }
+
+        fn random_na_vec4x16<R: Rng>(rng: &mut R) -> nalgebra::Vector4<f32x16> {
+            [
+                random_f32x16(rng),
+                random_f32x16(rng),
+                random_f32x16(rng),
+                random_f32x16(rng),
+            ]
+            .into()
+        }
+
+        // wide matrices are built from scalar lanes so that each lane has the
+        // structure of the input distribution, `Degenerate` lanes are near singular
+        fn random_na_mat2x4<R: Rng>(rng: &mut R) -> nalgebra::Matrix2<f32x4> {
+            let mut lanes = [nalgebra::Matrix2::zeros(); 4];
+            lanes
+                .iter_mut()
+                .for_each(|lane| *lane = nalgebra::Matrix2::random_value(rng));
+            lanes.into()
+        }
+
+        fn random_na_mat3x4<R: Rng>(rng: &mut R) -> nalgebra::Matrix3<f32x4> {
+            let mut lanes = [nalgebra::Matrix3::zeros(); 4];
+            lanes
+                .iter_mut()
+                .for_each(|lane| *lane = nalgebra::Matrix3::random_value(rng));
+            lanes.into()
+        }
+
+        fn random_na_mat4x4<R: Rng>(rng: &mut R) -> nalgebra::Matrix4<f32x4> {
+            let mut lanes = [nalgebra::Matrix4::zeros(); 4];
+            lanes
+                .iter_mut()
+                .for_each(|lane| *lane = nalgebra::Matrix4::random_value(rng));
+            lanes.into()
+        }
+
+        fn random_na_mat2x8<R: Rng>(rng: &mut R) -> nalgebra::Matrix2<f32x8> {
+            let mut lanes = [nalgebra::Matrix2::zeros(); 8];
+            lanes
+                .iter_mut()
+                .for_each(|lane| *lane = nalgebra::Matrix2::random_value(rng));
+            lanes.into()
+        }
+
+        fn random_na_mat3x8<R: Rng>(rng: &mut R) -> nalgebra::Matrix3<f32x8> {
+            let mut lanes = [nalgebra::Matrix3::zeros(); 8];
+            lanes
+                .iter_mut()
+                .for_each(|lane| *lane = nalgebra::Matrix3::random_value(rng));
+            lanes.into()
+        }
+
+        fn random_na_mat4x8<R: Rng>(rng: &mut R) -> nalgebra::Matrix4<f32x8> {
+            let mut lanes = [nalgebra::Matrix4::zeros(); 8];
+            lanes
+                .iter_mut()
+                .for_each(|lane| *lane = nalgebra::Matrix4::random_value(rng));
+            lanes.into()
+        }
+
+        fn random_na_mat2x16<R: Rng>(rng: &mut R) -> nalgebra::Matrix2<f32x16> {
+            let mut lanes = [nalgebra::Matrix2::zeros(); 16];
+            lanes
+                .iter_mut()
+                .for_each(|lane| *lane = nalgebra::Matrix2::random_value(rng));
+            lanes.into()
+        }
+
+        fn random_na_mat3x16<R: Rng>(rng: &mut R) -> nalgebra::Matrix3<f32x16> {
+            let mut lanes = [nalgebra::Matrix3::zeros(); 16];
+            lanes
+                .iter_mut()
+                .for_each(|lane| *lane = nalgebra::Matrix3::random_value(rng));
+            lanes.into()
+        }
+
+        fn random_na_mat4x16<R: Rng>(rng: &mut R) -> nalgebra::Matrix4<f32x16> {
+            let mut lanes = [nalgebra::Matrix4::zeros(); 16];
+            lanes
+                .iter_mut()
+                .for_each(|lane| *lane = nalgebra::Matrix4::random_value(rng));
+            lanes.into()
+        }
+
+        fn random_f32x4<R: Rng>(rng: &mut R) -> f32x4 {
+            let mut lanes = [0.0; 4];
+            lanes.iter_mut().for_each(|lane| *lane = random_f32(rng));
+            lanes.into()
+        }
+
+        fn random_f32x8<R: Rng>(rng: &mut R) -> f32x8 {
+            let mut lanes = [0.0; 8];
+            lanes.iter_mut().for_each(|lane| *lane = random_f32(rng));
+            lanes.into()
+        }
+
+        fn random_f32x16<R: Rng>(rng: &mut R) -> f32x16 {
+            let mut lanes = [0.0; 16];
+            lanes.iter_mut().for_each(|lane| *lane = random_f32(rng));
+            lanes.into()
+        }
+
+        #[cfg(feature = "glam")]
+        macro_rules! impl_vector3_soa_ext {
+            ($t:ident, $lanes:literal) => {
+                impl Vector3SoaExt for nalgebra::Vector3<$t> {
+                    #[inline]
+                    fn from_aos_slice(aos: &[glam::Vec3], soa: &mut [Self]) {
+                        for (wide, chunk) in soa.iter_mut().zip(aos.chunks_exact($lanes)) {
+                            let mut lanes = [[0.0; $lanes]; 3];
+                            for (i, v) in chunk.iter().enumerate() {
+                                lanes[0][i] = v.x();
+                                lanes[1][i] = v.y();
+                                lanes[2][i] = v.z();
+                            }
+                            *wide = nalgebra::Vector3::new(
+                                lanes[0].into(),
+                                lanes[1].into(),
+                                lanes[2].into(),
+                            );
+                        }
+                    }
+
+                    #[inline]
+                    fn to_aos_slice(soa: &[Self], aos: &mut [glam::Vec3]) {
+                        use simba::simd::SimdValue;
+                        for (wide, chunk) in soa.iter().zip(aos.chunks_exact_mut($lanes)) {
+                            for (i, lane) in chunk.iter_mut().enumerate() {
+                                *lane = glam::Vec3::new(
+                                    wide.x.extract(i),
+                                    wide.y.extract(i),
+                                    wide.z.extract(i),
+                                );
+                            }
+                        }
+                    }
+                }
+            };
+        }
+
+        #[cfg(feature = "glam")]
+        impl_vector3_soa_ext!(f32x4, 4);
+        #[cfg(feature = "glam")]
+        impl_vector3_soa_ext!(f32x8, 8);
+        #[cfg(feature = "glam")]
+        impl_vector3_soa_ext!(f32x16, 16);
     }
+}
 
-    fn random_f32x16<R: Rng>(rng: &mut R) -> f32x16 {
-        rng.gen::<[f32; 16]>().into()
+#[cfg(feature = "nalgebra_glm")]
+pub mod nalgebra_glm_support {
+    use super::nalgebra_support::random_na_quat;
+    use super::BenchValue;
+    use nalgebra_glm as glm;
+    use rand::Rng;
+
+    // normalized like the `nalgebra` `UnitQuaternion` so both get the same inputs
+    fn random_glm_quat<R: Rng>(rng: &mut R) -> glm::Qua<f32> {
+        random_na_quat(rng).into_inner()
+    }
+
+    // the other glm types are aliases of the nalgebra types which implement
+    // `BenchValue` in `nalgebra_support`
+    impl_bench_value!(nalgebra_glm::Qua<f32>, random_glm_quat);
+
+    // The glm free functions as methods so they can be benchmarked with
+    // `bench_unop!` and `bench_binop!`. They have a `glm_` prefix because the
+    // `nalgebra` methods of the same name would be called instead.
+
+    /// The glm matrix functions.
+    pub trait MatrixExt {
+        fn glm_transpose(&self) -> Self;
+        fn glm_determinant(&self) -> f32;
+        fn glm_inverse(&self) -> Self;
+        fn glm_affine_inverse(&self) -> Self;
+    }
+
+    macro_rules! impl_matrix_ext {
+        ($m:ty) => {
+            impl MatrixExt for $m {
+                #[inline]
+                fn glm_transpose(&self) -> Self {
+                    glm::transpose(self)
+                }
+
+                #[inline]
+                fn glm_determinant(&self) -> f32 {
+                    glm::determinant(self)
+                }
+
+                #[inline]
+                fn glm_inverse(&self) -> Self {
+                    glm::inverse(self)
+                }
+
+                #[inline]
+                fn glm_affine_inverse(&self) -> Self {
+                    glm::affine_inverse(*self)
+                }
+            }
+        };
     }
 
-    pub fn nalgebra_mat4_det(m: &nalgebra::Matrix4<f32>) -> f32 {
-        m.determinant()
+    impl_matrix_ext!(glm::Mat2);
+    impl_matrix_ext!(glm::Mat3);
+    impl_matrix_ext!(glm::Mat4);
+
+    /// The glm vector3 functions.
+    pub trait Vector3Ext {
+        fn glm_length(&self) -> f32;
+        fn glm_normalize(&self) -> Self;
+        fn glm_dot(&self, rhs: &Self) -> f32;
+        fn glm_cross(&self, rhs: &Self) -> Self;
     }
 
-    pub fn nalgebra_mat4_inv(m: &nalgebra::Matrix4<f32>) -> nalgebra::Matrix4<f32> {
-        m.try_inverse().unwrap_or(*m)
+    impl Vector3Ext for glm::Vec3 {
+        #[inline]
+        fn glm_length(&self) -> f32 {
+            glm::length(self)
+        }
+
+        #[inline]
+        fn glm_normalize(&self) -> Self {
+            glm::normalize(self)
+        }
+
+        #[inline]
+        fn glm_dot(&self, rhs: &Self) -> f32 {
+            glm::dot(self, rhs)
+        }
+
+        #[inline]
+        fn glm_cross(&self, rhs: &Self) -> Self {
+            glm::cross(self, rhs)
+        }
     }
 
-    pub fn nalgebra_mat4_try_inv(m: &nalgebra::Matrix4<f32>) -> Option<nalgebra::Matrix4<f32>> {
-        m.try_inverse()
+    /// The glm quaternion functions.
+    pub trait QuaternionExt {
+        fn glm_quat_conjugate(&self) -> Self;
+        fn glm_quat_cross(&self, rhs: &Self) -> Self;
+        fn glm_quat_rotate_vec3(&self, v: &glm::Vec3) -> glm::Vec3;
     }
 
-    pub fn nalgebra_mat4_mul(
-        lhs: &nalgebra::Matrix4<f32>,
-        rhs: &nalgebra::Matrix4<f32>,
-    ) -> nalgebra::Matrix4<f32> {
-        lhs * rhs
+    impl QuaternionExt for glm::Qua<f32> {
+        #[inline]
+        fn glm_quat_conjugate(&self) -> Self {
+            glm::quat_conjugate(self)
+        }
+
+        #[inline]
+        fn glm_quat_cross(&self, rhs: &Self) -> Self {
+            glm::quat_cross(self, rhs)
+        }
+
+        #[inline]
+        fn glm_quat_rotate_vec3(&self, v: &glm::Vec3) -> glm::Vec3 {
+            glm::quat_rotate_vec3(self, v)
+        }
     }
 }
 
 #[cfg(feature = "ultraviolet")]
 pub mod ultraviolet_support {
+    use super::mint_support::{
+        random_f32, random_mint_homogeneous_mat3, random_mint_homogeneous_mat4,
+        random_mint_invertible_mat2,
+    };
     use super::BenchValue;
-    use rand::{Rng, SeedableRng};
+    use rand::Rng;
     use ultraviolet::{
-        f32x4, Isometry2, Isometry3, Mat2, Mat3, Mat4, Rotor2, Rotor3, Vec2, Vec3, Vec4,
-        WIsometry2, WIsometry3, WRotor2, WRotor3, Wat2, Wat3, Wat4, Wec2, Wec3, Wec4,
+        f32x4, Isometry2, Isometry3, Mat2, Mat3, Mat4, Rotor2, Rotor3, Similarity3, Vec2, Vec3,
+        Vec4, WIsometry2, WIsometry3, WRotor2, WRotor3, Wat2, Wat3, Wat4, Wec2, Wec3, Wec4,
     };
 
+    fn random_f32x4<R: Rng>(rng: &mut R) -> f32x4 {
+        f32x4::from([
+            random_f32(rng),
+            random_f32(rng),
+            random_f32(rng),
+            random_f32(rng),
+        ])
+    }
+
+    // wide matrices are built from scalar lanes so that each lane has the
+    // structure of the input distribution, `Degenerate` lanes are near singular
+    fn random_lanes<T: BenchValue, R: Rng>(rng: &mut R) -> [T; 4] {
+        [
+            T::random_value(rng),
+            T::random_value(rng),
+            T::random_value(rng),
+            T::random_value(rng),
+        ]
+    }
+
     impl BenchValue for Wec2 {
         fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
-            Wec2::new(
-                ultraviolet::f32x4::from(rng.gen::<[f32; 4]>()),
-                ultraviolet::f32x4::from(rng.gen::<[f32; 4]>()),
-            )
+            Wec2::new(random_f32x4(rng), random_f32x4(rng))
         }
     }
 
     impl BenchValue for Wec3 {
         fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
-            Wec3::new(
-                ultraviolet::f32x4::from(rng.gen::<[f32; 4]>()),
-                ultraviolet::f32x4::from(rng.gen::<[f32; 4]>()),
-                ultraviolet::f32x4::from(rng.gen::<[f32; 4]>()),
-            )
+            Wec3::new(random_f32x4(rng), random_f32x4(rng), random_f32x4(rng))
         }
     }
 
     impl BenchValue for Wec4 {
         fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
             Wec4::new(
-                ultraviolet::f32x4::from(rng.gen::<[f32; 4]>()),
-                ultraviolet::f32x4::from(rng.gen::<[f32; 4]>()),
-                ultraviolet::f32x4::from(rng.gen::<[f32; 4]>()),
-                ultraviolet::f32x4::from(rng.gen::<[f32; 4]>()),
+                random_f32x4(rng),
+                random_f32x4(rng),
+                random_f32x4(rng),
+                random_f32x4(rng),
             )
         }
     }
 
     impl BenchValue for Wat2 {
         fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
-            Wat2::new(Wec2::random_value(rng), Wec2::random_value(rng))
+            let lanes = random_lanes::<Mat2, _>(rng);
+            Wat2::new(
+                [lanes[0].cols[0], lanes[1].cols[0], lanes[2].cols[0], lanes[3].cols[0]].into(),
+                [lanes[0].cols[1], lanes[1].cols[1], lanes[2].cols[1], lanes[3].cols[1]].into(),
+            )
         }
     }
 
     impl BenchValue for Wat3 {
         fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
+            let lanes = random_lanes::<Mat3, _>(rng);
             Wat3::new(
-                Wec3::random_value(rng),
-                Wec3::random_value(rng),
-                Wec3::random_value(rng),
+                [lanes[0].cols[0], lanes[1].cols[0], lanes[2].cols[0], lanes[3].cols[0]].into(),
+                [lanes[0].cols[1], lanes[1].cols[1], lanes[2].cols[1], lanes[3].cols[1]].into(),
+                [lanes[0].cols[2], lanes[1].cols[2], lanes[2].cols[2], lanes[3].cols[2]].into(),
             )
         }
     }
 
     impl BenchValue for Wat4 {
         fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
+            let lanes = random_lanes::<Mat4, _>(rng);
             Wat4::new(
-                Wec4::random_value(rng),
-                Wec4::random_value(rng),
-                Wec4::random_value(rng),
-                Wec4::random_value(rng),
+                [lanes[0].cols[0], lanes[1].cols[0], lanes[2].cols[0], lanes[3].cols[0]].into(),
+                [lanes[0].cols[1], lanes[1].cols[1], lanes[2].cols[1], lanes[3].cols[1]].into(),
+                [lanes[0].cols[2], lanes[1].cols[2], lanes[2].cols[2], lanes[3].cols[2]].into(),
+                [lanes[0].cols[3], lanes[1].cols[3], lanes[2].cols[3], lanes[3].cols[3]].into(),
             )
         }
     }
@@ -747,10 +1995,10 @@ pub mod ultraviolet_support {
     impl BenchValue for WRotor2 {
         fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
             let angle = f32x4::from([
-                crate::glam_support::random_angle_radians(rng),
-                crate::glam_support::random_angle_radians(rng),
-                crate::glam_support::random_angle_radians(rng),
-                crate::glam_support::random_angle_radians(rng),
+                crate::mint_support::random_angle_radians(rng),
+                crate::mint_support::random_angle_radians(rng),
+                crate::mint_support::random_angle_radians(rng),
+                crate::mint_support::random_angle_radians(rng),
             ]);
             WRotor2::from_angle(angle)
         }
@@ -759,22 +2007,22 @@ pub mod ultraviolet_support {
     impl BenchValue for WRotor3 {
         fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
             let yaw = f32x4::from([
-                crate::glam_support::random_angle_radians(rng),
-                crate::glam_support::random_angle_radians(rng),
-                crate::glam_support::random_angle_radians(rng),
-                crate::glam_support::random_angle_radians(rng),
+                crate::mint_support::random_angle_radians(rng),
+                crate::mint_support::random_angle_radians(rng),
+                crate::mint_support::random_angle_radians(rng),
+                crate::mint_support::random_angle_radians(rng),
             ]);
             let pitch = f32x4::from([
-                crate::glam_support::random_angle_radians(rng),
-                crate::glam_support::random_angle_radians(rng),
-                crate::glam_support::random_angle_radians(rng),
-                crate::glam_support::random_angle_radians(rng),
+                crate::mint_support::random_angle_radians(rng),
+                crate::mint_support::random_angle_radians(rng),
+                crate::mint_support::random_angle_radians(rng),
+                crate::mint_support::random_angle_radians(rng),
             ]);
             let roll = f32x4::from([
-                crate::glam_support::random_angle_radians(rng),
-                crate::glam_support::random_angle_radians(rng),
-                crate::glam_support::random_angle_radians(rng),
-                crate::glam_support::random_angle_radians(rng),
+                crate::mint_support::random_angle_radians(rng),
+                crate::mint_support::random_angle_radians(rng),
+                crate::mint_support::random_angle_radians(rng),
+                crate::mint_support::random_angle_radians(rng),
             ]);
             WRotor3::from_euler_angles(yaw, pitch, roll)
         }
@@ -798,78 +2046,288 @@ pub mod ultraviolet_support {
 
     impl BenchValue for Vec2 {
         fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
-            Vec2::new(rng.gen(), rng.gen())
+            Vec2::new(random_f32(rng), random_f32(rng))
         }
     }
 
     impl BenchValue for Vec3 {
         fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
-            Vec3::new(rng.gen(), rng.gen(), rng.gen())
+            Vec3::new(random_f32(rng), random_f32(rng), random_f32(rng))
         }
     }
 
     impl BenchValue for Vec4 {
         fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
-            Vec4::new(rng.gen::<f32>(), rng.gen(), rng.gen(), rng.gen())
+            Vec4::new(
+                random_f32(rng),
+                random_f32(rng),
+                random_f32(rng),
+                random_f32(rng),
+            )
         }
     }
 
     impl BenchValue for Mat2 {
         fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
-            Mat2::new(Vec2::random_value(rng), Vec2::random_value(rng))
+            let m = random_mint_invertible_mat2(rng);
+            Mat2::new(Vec2::new(m.x.x, m.x.y), Vec2::new(m.y.x, m.y.y))
         }
     }
 
     impl BenchValue for Mat3 {
         fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
+            let m = random_mint_homogeneous_mat3(rng);
             Mat3::new(
-                Vec3::random_value(rng),
-                Vec3::random_value(rng),
-                Vec3::random_value(rng),
+                Vec3::new(m.x.x, m.x.y, m.x.z),
+                Vec3::new(m.y.x, m.y.y, m.y.z),
+                Vec3::new(m.z.x, m.z.y, m.z.z),
             )
         }
     }
 
     impl BenchValue for Mat4 {
         fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
+            let m = random_mint_homogeneous_mat4(rng);
             Mat4::new(
-                Vec4::random_value(rng),
-                Vec4::random_value(rng),
-                Vec4::random_value(rng),
-                Vec4::random_value(rng),
+                Vec4::new(m.x.x, m.x.y, m.x.z, m.x.w),
+                Vec4::new(m.y.x, m.y.y, m.y.z, m.y.w),
+                Vec4::new(m.z.x, m.z.y, m.z.z, m.z.w),
+                Vec4::new(m.w.x, m.w.y, m.w.z, m.w.w),
             )
         }
     }
 
     impl BenchValue for Rotor2 {
         fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
-            let angle = crate::glam_support::random_angle_radians(rng);
+            let angle = crate::mint_support::random_angle_radians(rng);
             Rotor2::from_angle(angle)
         }
     }
 
-    impl BenchValue for Rotor3 {
-        fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
-            let yaw = crate::glam_support::random_angle_radians(rng);
-            let pitch = crate::glam_support::random_angle_radians(rng);
-            let roll = crate::glam_support::random_angle_radians(rng);
-            Rotor3::from_euler_angles(yaw, pitch, roll)
+    impl BenchValue for Rotor3 {
+        fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
+            let yaw = crate::mint_support::random_angle_radians(rng);
+            let pitch = crate::mint_support::random_angle_radians(rng);
+            let roll = crate::mint_support::random_angle_radians(rng);
+            Rotor3::from_euler_angles(yaw, pitch, roll)
+        }
+    }
+
+    impl BenchValue for Isometry2 {
+        fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
+            let tra = Vec2::random_value(rng);
+            let rot = Rotor2::random_value(rng);
+            Isometry2::new(tra, rot)
+        }
+    }
+
+    impl BenchValue for Isometry3 {
+        fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
+            let tra = Vec3::random_value(rng);
+            let rot = Rotor3::random_value(rng);
+            Isometry3::new(tra, rot)
+        }
+    }
+
+    impl BenchValue for Similarity3 {
+        fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
+            let tra = Vec3::random_value(rng);
+            let rot = Rotor3::random_value(rng);
+            let scale = crate::mint_support::random_nonzero_f32(rng);
+            Similarity3::new(tra, rot, scale)
+        }
+    }
+
+    /// Similarity operations ultraviolet doesn't provide, `transform_vec`
+    /// always applies the translation.
+    pub trait Similarity3Ext {
+        fn transform_vec_no_translation(&self, vec: Vec3) -> Vec3;
+        fn lerp(&self, end: Self, t: f32) -> Self;
+    }
+
+    impl Similarity3Ext for Similarity3 {
+        #[inline]
+        fn transform_vec_no_translation(&self, vec: Vec3) -> Vec3 {
+            self.scale * (self.rotation * vec)
+        }
+
+        #[inline]
+        fn lerp(&self, end: Self, t: f32) -> Self {
+            use ultraviolet::Lerp;
+            // ultraviolet 0.4.5 `Rotor3 * f32` divides, so `Rotor3::lerp` is
+            // computed from the rotor's parts
+            let (start, end_rotation) = (self.rotation, end.rotation);
+            let rotation = Rotor3::new(
+                start.s + (end_rotation.s - start.s) * t,
+                start.bv + (end_rotation.bv - start.bv) * t,
+            );
+            Similarity3::new(
+                self.translation.lerp(end.translation, t),
+                rotation.normalized(),
+                self.scale + (end.scale - self.scale) * t,
+            )
+        }
+    }
+
+    /// Spherical interpolation of a `Rotor2`, which ultraviolet doesn't
+    /// provide.
+    pub trait Rotation2Ext {
+        fn slerp(&self, end: Self, t: f32) -> Self;
+    }
+
+    impl Rotation2Ext for Rotor2 {
+        #[inline]
+        fn slerp(&self, end: Self, t: f32) -> Self {
+            // the rotor's bivector is minus the sine of the half angle, the
+            // arctangent of the ratio is the shortest path for either sign of
+            // the rotor
+            let delta = end * self.reversed();
+            let half_angle = (-delta.bv.xy / delta.s).atan();
+            Rotor2::from_angle(2.0 * half_angle * t) * *self
         }
     }
 
-    impl BenchValue for Isometry2 {
-        fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
-            let tra = Vec2::random_value(rng);
-            let rot = Rotor2::random_value(rng);
-            Isometry2::new(tra, rot)
+    /// Matrix operations ultraviolet doesn't provide. `Mat2` has no transpose
+    /// and its multiplication is incorrect so `transpose_mul` computes the
+    /// dot products of the columns directly.
+    pub trait MatrixExt {
+        type Scalar;
+        fn add_matrix(&self, rhs: Self) -> Self;
+        fn sub_matrix(&self, rhs: Self) -> Self;
+        fn mul_scalar(&self, rhs: Self::Scalar) -> Self;
+        fn transpose_mul(&self, rhs: Self) -> Self;
+        fn trace(&self) -> Self::Scalar;
+    }
+
+    macro_rules! impl_matrix_ext {
+        ($m:ident, $t:ty, [$($i:tt => $c:ident),+], $transpose_mul:expr) => {
+            impl MatrixExt for $m {
+                type Scalar = $t;
+
+                #[inline]
+                fn add_matrix(&self, rhs: Self) -> Self {
+                    $m::new($(self.cols[$i] + rhs.cols[$i]),+)
+                }
+
+                #[inline]
+                fn sub_matrix(&self, rhs: Self) -> Self {
+                    $m::new($(self.cols[$i] - rhs.cols[$i]),+)
+                }
+
+                #[inline]
+                fn mul_scalar(&self, rhs: $t) -> Self {
+                    $m::new($(self.cols[$i] * rhs),+)
+                }
+
+                #[inline]
+                fn transpose_mul(&self, rhs: Self) -> Self {
+                    let transpose_mul = $transpose_mul;
+                    transpose_mul(*self, rhs)
+                }
+
+                #[inline]
+                fn trace(&self) -> $t {
+                    let diagonal = [$(self.cols[$i].$c),+];
+                    diagonal[1..].iter().fold(diagonal[0], |sum, &d| sum + d)
+                }
+            }
+        };
+    }
+
+    // `Vec2::dot` uses `mul_add` which is slow without FMA, the matrix
+    // multiplications use separate multiplies and adds
+    macro_rules! mat2_transpose_mul {
+        ($m:ident, $v:ident) => {
+            |lhs: $m, rhs: $m| {
+                let ([a, b], [c, d]) = (lhs.cols, rhs.cols);
+                $m::new(
+                    $v::new(a.x * c.x + a.y * c.y, b.x * c.x + b.y * c.y),
+                    $v::new(a.x * d.x + a.y * d.y, b.x * d.x + b.y * d.y),
+                )
+            }
+        };
+    }
+
+    impl_matrix_ext!(Mat2, f32, [0 => x, 1 => y], mat2_transpose_mul!(Mat2, Vec2));
+    impl_matrix_ext!(Mat3, f32, [0 => x, 1 => y, 2 => z], |lhs: Mat3, rhs| lhs.transposed() * rhs);
+    impl_matrix_ext!(Mat4, f32, [0 => x, 1 => y, 2 => z, 3 => w], |lhs: Mat4, rhs| lhs.transposed() * rhs);
+    impl_matrix_ext!(Wat2, f32x4, [0 => x, 1 => y], mat2_transpose_mul!(Wat2, Wec2));
+    impl_matrix_ext!(Wat3, f32x4, [0 => x, 1 => y, 2 => z], |lhs: Wat3, rhs| lhs.transposed() * rhs);
+    impl_matrix_ext!(Wat4, f32x4, [0 => x, 1 => y, 2 => z, 3 => w], |lhs: Wat4, rhs| lhs.transposed() * rhs);
+
+    /// Operations on a `Mat4` or `Wat4` holding a homogeneous transform.
+    /// `affine_inversed` assumes the columns of the upper 3x3 are orthogonal,
+    /// as they are for a rotation and a nonuniform scale, and
+    /// `project_point3` applies the perspective divide.
+    pub trait Matrix4Ext {
+        type Vector3;
+        fn affine_inversed(&self) -> Self;
+        fn project_point3(&self, point: Self::Vector3) -> Self::Vector3;
+    }
+
+    macro_rules! impl_matrix4_ext {
+        ($m4:ident, $m3:ident, $v3:ident) => {
+            impl Matrix4Ext for $m4 {
+                type Vector3 = $v3;
+
+                #[inline]
+                fn affine_inversed(&self) -> Self {
+                    // the inverse of the upper 3x3 is its transpose with each row
+                    // divided by the squared length of the matching column
+                    let (x, y, z) = (self.cols[0].xyz(), self.cols[1].xyz(), self.cols[2].xyz());
+                    let linear =
+                        $m3::new(x / x.mag_sq(), y / y.mag_sq(), z / z.mag_sq()).transposed();
+                    let translation = -(linear * self.cols[3].xyz());
+                    $m4::new(
+                        linear.cols[0].into_homogeneous_vector(),
+                        linear.cols[1].into_homogeneous_vector(),
+                        linear.cols[2].into_homogeneous_vector(),
+                        translation.into_homogeneous_point(),
+                    )
+                }
+
+                #[inline]
+                fn project_point3(&self, point: $v3) -> $v3 {
+                    let v = *self * point.into_homogeneous_point();
+                    v.xyz() / v.w
+                }
+            }
+        };
+    }
+
+    impl_matrix4_ext!(Mat4, Mat3, Vec3);
+    impl_matrix4_ext!(Wat4, Wat3, Wec3);
+
+    /// Conversion between an array of structures of `glam::Vec3`, as stored by
+    /// an ECS, and a structure of arrays of `Wec3`. The `aos` slice is four
+    /// times the length of `soa`.
+    #[cfg(feature = "glam")]
+    pub trait Vector3SoaExt: Sized {
+        fn from_aos_slice(aos: &[glam::Vec3], soa: &mut [Self]);
+        fn to_aos_slice(soa: &[Self], aos: &mut [glam::Vec3]);
+    }
+
+    #[cfg(feature = "glam")]
+    impl Vector3SoaExt for Wec3 {
+        #[inline]
+        fn from_aos_slice(aos: &[glam::Vec3], soa: &mut [Wec3]) {
+            for (wide, c) in soa.iter_mut().zip(aos.chunks_exact(4)) {
+                *wide = Wec3::new(
+                    f32x4::from([c[0].x(), c[1].x(), c[2].x(), c[3].x()]),
+                    f32x4::from([c[0].y(), c[1].y(), c[2].y(), c[3].y()]),
+                    f32x4::from([c[0].z(), c[1].z(), c[2].z(), c[3].z()]),
+                );
+            }
         }
-    }
 
-    impl BenchValue for Isometry3 {
-        fn random_value<R: rand::Rng>(rng: &mut R) -> Self {
-            let tra = Vec3::random_value(rng);
-            let rot = Rotor3::random_value(rng);
-            Isometry3::new(tra, rot)
+        #[inline]
+        fn to_aos_slice(soa: &[Wec3], aos: &mut [glam::Vec3]) {
+            for (wide, chunk) in soa.iter().zip(aos.chunks_exact_mut(4)) {
+                let (xs, ys, zs) = (wide.x.as_ref(), wide.y.as_ref(), wide.z.as_ref());
+                for (i, lane) in chunk.iter_mut().enumerate() {
+                    *lane = glam::Vec3::new(xs[i], ys[i], zs[i]);
+                }
+            }
         }
     }
 }
@@ -882,19 +2340,41 @@ pub mod euclid_support {
 
     impl_bench_value!(euclid::Point2D<f32, euclid::UnknownUnit>, random_euclid_point2);
     impl_bench_value!(euclid::Point3D<f32, euclid::UnknownUnit>, random_euclid_point3);
+    impl_bench_value!(euclid::Rotation2D<f32, euclid::UnknownUnit, euclid::UnknownUnit>, random_euclid_rotation2);
     impl_bench_value!(euclid::Rotation3D<f32, euclid::UnknownUnit, euclid::UnknownUnit>, random_euclid_quat);
     impl_bench_value!(euclid::Transform2D<f32, euclid::UnknownUnit, euclid::UnknownUnit>, random_euclid_mat3);
     impl_bench_value!(euclid::Transform3D<f32, euclid::UnknownUnit, euclid::UnknownUnit>, random_euclid_mat4);
     impl_bench_value!(euclid::Vector2D<f32, euclid::UnknownUnit>, random_euclid_vec2);
     impl_bench_value!(euclid::Vector3D<f32, euclid::UnknownUnit>, random_euclid_vec3);
+    impl_bench_value!(euclid::Point2D<f64, euclid::UnknownUnit>, random_euclid_dpoint2);
+    impl_bench_value!(euclid::Point3D<f64, euclid::UnknownUnit>, random_euclid_dpoint3);
+    impl_bench_value!(euclid::Rotation3D<f64, euclid::UnknownUnit, euclid::UnknownUnit>, random_euclid_dquat);
+    impl_bench_value!(euclid::Transform2D<f64, euclid::UnknownUnit, euclid::UnknownUnit>, random_euclid_dmat3);
+    impl_bench_value!(euclid::Transform3D<f64, euclid::UnknownUnit, euclid::UnknownUnit>, random_euclid_dmat4);
+    impl_bench_value!(euclid::Vector2D<f64, euclid::UnknownUnit>, random_euclid_dvec2);
+    impl_bench_value!(euclid::Vector3D<f64, euclid::UnknownUnit>, random_euclid_dvec3);
+
+    /// Spherical interpolation of a `Rotation2D`, which euclid only provides
+    /// for `Rotation3D`.
+    pub trait Rotation2Ext {
+        fn slerp(&self, other: &Self, t: f32) -> Self;
+    }
+
+    impl Rotation2Ext for euclid::Rotation2D<f32, euclid::UnknownUnit, euclid::UnknownUnit> {
+        #[inline]
+        fn slerp(&self, other: &Self, t: f32) -> Self {
+            let delta = euclid::Angle::radians(other.angle - self.angle).signed();
+            euclid::Rotation2D::radians(self.angle + delta.get() * t)
+        }
+    }
 
     // euclid random functions ----------------------------------------------------
     fn random_euclid_vec2<R>(rng: &mut R) -> euclid::Vector2D<f32, euclid::UnknownUnit>
     where
         R: Rng,
     {
-        let (x, y) = rng.gen::<(f32, f32)>().into();
-        euclid::vec2(x, y)
+        let v = random_mint_vec2(rng);
+        euclid::vec2(v.x, v.y)
     }
 
     fn random_euclid_point2<R>(rng: &mut R) -> euclid::Point2D<f32, euclid::UnknownUnit>
@@ -908,8 +2388,8 @@ pub mod euclid_support {
     where
         R: Rng,
     {
-        let (x, y, z) = rng.gen::<(f32, f32, f32)>().into();
-        euclid::vec3(x, y, z)
+        let v = random_mint_vec3(rng);
+        euclid::vec3(v.x, v.y, v.z)
     }
 
     fn random_euclid_point3<R>(rng: &mut R) -> euclid::Point3D<f32, euclid::UnknownUnit>
@@ -919,6 +2399,15 @@ pub mod euclid_support {
         random_euclid_vec3(rng).to_point()
     }
 
+    fn random_euclid_rotation2<R>(
+        rng: &mut R,
+    ) -> euclid::Rotation2D<f32, euclid::UnknownUnit, euclid::UnknownUnit>
+    where
+        R: Rng,
+    {
+        euclid::Rotation2D::radians(random_angle_radians(rng))
+    }
+
     fn random_euclid_quat<R>(
         rng: &mut R,
     ) -> euclid::Rotation3D<f32, euclid::UnknownUnit, euclid::UnknownUnit>
@@ -936,7 +2425,8 @@ pub mod euclid_support {
         R: Rng,
     {
         let m = random_mint_homogeneous_mat3(rng);
-        euclid::Transform2D::column_major(m.x.x, m.x.y, m.x.z, m.y.x, m.y.y, m.y.z)
+        // euclid uses row vectors so the column major matrix is transposed
+        euclid::Transform2D::row_major(m.x.x, m.x.y, m.y.x, m.y.y, m.z.x, m.z.y)
     }
 
     fn random_euclid_mat4<R>(
@@ -946,7 +2436,70 @@ pub mod euclid_support {
         R: Rng,
     {
         let m = random_mint_homogeneous_mat4(rng);
-        euclid::Transform3D::column_major(
+        euclid::Transform3D::row_major(
+            m.x.x, m.x.y, m.x.z, m.x.w, m.y.x, m.y.y, m.y.z, m.y.w, m.z.x, m.z.y, m.z.z, m.z.w,
+            m.w.x, m.w.y, m.w.z, m.w.w,
+        )
+    }
+
+    fn random_euclid_dvec2<R>(rng: &mut R) -> euclid::Vector2D<f64, euclid::UnknownUnit>
+    where
+        R: Rng,
+    {
+        let v = random_mint_dvec2(rng);
+        euclid::vec2(v.x, v.y)
+    }
+
+    fn random_euclid_dpoint2<R>(rng: &mut R) -> euclid::Point2D<f64, euclid::UnknownUnit>
+    where
+        R: Rng,
+    {
+        random_euclid_dvec2(rng).to_point()
+    }
+
+    fn random_euclid_dvec3<R>(rng: &mut R) -> euclid::Vector3D<f64, euclid::UnknownUnit>
+    where
+        R: Rng,
+    {
+        let v = random_mint_dvec3(rng);
+        euclid::vec3(v.x, v.y, v.z)
+    }
+
+    fn random_euclid_dpoint3<R>(rng: &mut R) -> euclid::Point3D<f64, euclid::UnknownUnit>
+    where
+        R: Rng,
+    {
+        random_euclid_dvec3(rng).to_point()
+    }
+
+    fn random_euclid_dquat<R>(
+        rng: &mut R,
+    ) -> euclid::Rotation3D<f64, euclid::UnknownUnit, euclid::UnknownUnit>
+    where
+        R: Rng,
+    {
+        let mq = random_mint_dquat(rng);
+        euclid::Rotation3D::quaternion(mq.v.x, mq.v.y, mq.v.z, mq.s)
+    }
+
+    fn random_euclid_dmat3<R>(
+        rng: &mut R,
+    ) -> euclid::Transform2D<f64, euclid::UnknownUnit, euclid::UnknownUnit>
+    where
+        R: Rng,
+    {
+        let m = random_mint_homogeneous_dmat3(rng);
+        euclid::Transform2D::row_major(m.x.x, m.x.y, m.y.x, m.y.y, m.z.x, m.z.y)
+    }
+
+    fn random_euclid_dmat4<R>(
+        rng: &mut R,
+    ) -> euclid::Transform3D<f64, euclid::UnknownUnit, euclid::UnknownUnit>
+    where
+        R: Rng,
+    {
+        let m = random_mint_homogeneous_dmat4(rng);
+        euclid::Transform3D::row_major(
             m.x.x, m.x.y, m.x.z, m.x.w, m.y.x, m.y.y, m.y.z, m.y.w, m.z.x, m.z.y, m.z.z, m.z.w,
             m.w.x, m.w.y, m.w.z, m.w.w,
         )
@@ -965,6 +2518,94 @@ pub mod vek_support {
     impl_bench_value!(vek::Vec2<f32>, random_mint_vec2);
     impl_bench_value!(vek::Vec3<f32>, random_mint_vec3);
     impl_bench_value!(vek::Vec4<f32>, random_mint_vec4);
+    impl_bench_value!(vek::Mat2<f64>, random_mint_invertible_dmat2);
+    impl_bench_value!(vek::Mat3<f64>, random_mint_homogeneous_dmat3);
+    impl_bench_value!(vek::Mat4<f64>, random_mint_homogeneous_dmat4);
+    impl_bench_value!(vek::Quaternion<f64>, random_mint_dquat);
+    impl_bench_value!(vek::Vec2<f64>, random_mint_dvec2);
+    impl_bench_value!(vek::Vec3<f64>, random_mint_dvec3);
+    impl_bench_value!(vek::Vec4<f64>, random_mint_dvec4);
+    impl_bench_value!(vek::Transform<f32, f32, f32>, random_vek_isometry3);
+
+    /// Isometry operations on a `Transform`, which vek only supports
+    /// interpolating and converting to a `Mat4`. The scale is ignored.
+    pub trait Isometry3Ext {
+        fn inverted(&self) -> Self;
+        fn mul_isometry(&self, rhs: Self) -> Self;
+        fn mul_point(&self, point: vek::Vec3<f32>) -> vek::Vec3<f32>;
+    }
+
+    impl Isometry3Ext for vek::Transform<f32, f32, f32> {
+        #[inline]
+        fn inverted(&self) -> Self {
+            let orientation = self.orientation.conjugate();
+            vek::Transform {
+                position: -(orientation * self.position),
+                orientation,
+                scale: self.scale,
+            }
+        }
+
+        #[inline]
+        fn mul_isometry(&self, rhs: Self) -> Self {
+            vek::Transform {
+                position: self.orientation * rhs.position + self.position,
+                orientation: self.orientation * rhs.orientation,
+                scale: self.scale,
+            }
+        }
+
+        #[inline]
+        fn mul_point(&self, point: vek::Vec3<f32>) -> vek::Vec3<f32> {
+            self.orientation * point + self.position
+        }
+    }
+
+    /// Multiplication by the transpose of a matrix, which vek doesn't
+    /// provide.
+    pub trait MatrixExt {
+        fn transposed_mul(&self, rhs: Self) -> Self;
+    }
+
+    macro_rules! impl_matrix_ext {
+        ($($m:ty),+) => {
+            $(
+                impl MatrixExt for $m {
+                    #[inline]
+                    fn transposed_mul(&self, rhs: Self) -> Self {
+                        self.transposed() * rhs
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_matrix_ext!(vek::Mat2<f32>, vek::Mat3<f32>, vek::Mat4<f32>);
+
+    /// Projection of a point by a `Mat4` with the perspective divide, vek's
+    /// `mul_point` doesn't divide by w.
+    pub trait Matrix4Ext {
+        fn project_point(&self, point: vek::Vec3<f32>) -> vek::Vec3<f32>;
+    }
+
+    impl Matrix4Ext for vek::Mat4<f32> {
+        #[inline]
+        fn project_point(&self, point: vek::Vec3<f32>) -> vek::Vec3<f32> {
+            let v = *self * vek::Vec4::from_point(point);
+            vek::Vec3::from(v) / v.w
+        }
+    }
+
+    fn random_vek_isometry3<R>(rng: &mut R) -> vek::Transform<f32, f32, f32>
+    where
+        R: rand::Rng,
+    {
+        vek::Transform {
+            position: random_mint_vec3(rng).into(),
+            orientation: random_mint_quat(rng).into(),
+            scale: vek::Vec3::one(),
+        }
+    }
 
     // fn random_vek_invertible_mat4<R>(rng: &mut R) -> vek::mat::repr_simd::column_major::Mat4<f32>
     // where
@@ -1017,18 +2658,55 @@ pub mod pathfinder_support {
         random_pf_mat4
     );
 
+    /// Matrix operations pathfinder doesn't provide for `Matrix2x2F`.
+    pub trait Matrix2Ext {
+        fn add_matrix(&self, rhs: Self) -> Self;
+        fn mul_scalar(&self, rhs: f32) -> Self;
+        fn transpose_mul(&self, rhs: Self) -> Self;
+        fn trace(&self) -> f32;
+    }
+
+    impl Matrix2Ext for pathfinder_geometry::transform2d::Matrix2x2F {
+        #[inline]
+        fn add_matrix(&self, rhs: Self) -> Self {
+            pathfinder_geometry::transform2d::Matrix2x2F(self.0 + rhs.0)
+        }
+
+        #[inline]
+        fn mul_scalar(&self, rhs: f32) -> Self {
+            let rhs = pathfinder_geometry::vector::Vector2F::splat(rhs).0;
+            pathfinder_geometry::transform2d::Matrix2x2F(self.0 * rhs.concat_xy_xy(rhs))
+        }
+
+        #[inline]
+        fn transpose_mul(&self, rhs: Self) -> Self {
+            // each element is the dot product of a column of self and a
+            // column of rhs
+            pathfinder_geometry::transform2d::Matrix2x2F(
+                self.0.xzxz() * rhs.0.xxzz() + self.0.ywyw() * rhs.0.yyww(),
+            )
+        }
+
+        #[inline]
+        fn trace(&self) -> f32 {
+            self.m11() + self.m22()
+        }
+    }
+
     pub fn random_pf_vec2<R>(rng: &mut R) -> pathfinder_geometry::vector::Vector2F
     where
         R: Rng,
     {
-        pathfinder_geometry::vector::Vector2F::new(rng.gen(), rng.gen())
+        let v = random_mint_vec2(rng);
+        pathfinder_geometry::vector::Vector2F::new(v.x, v.y)
     }
 
     pub fn random_pf_vec4<R>(rng: &mut R) -> pathfinder_geometry::vector::Vector4F
     where
         R: Rng,
     {
-        pathfinder_geometry::vector::Vector4F::new(rng.gen(), rng.gen(), rng.gen(), rng.gen())
+        let v = random_mint_vec4(rng);
+        pathfinder_geometry::vector::Vector4F::new(v.x, v.y, v.z, v.w)
     }
 
     pub fn random_pf_mat2<R>(rng: &mut R) -> pathfinder_geometry::transform2d::Matrix2x2F
@@ -1045,7 +2723,7 @@ pub mod pathfinder_support {
     {
         let mat = random_mint_homogeneous_mat3(rng);
         pathfinder_geometry::transform2d::Transform2F::row_major(
-            mat.x.x, mat.y.x, mat.x.y, mat.y.y, mat.x.z, mat.y.z,
+            mat.x.x, mat.y.x, mat.x.y, mat.y.y, mat.z.x, mat.z.y,
         )
     }
 
@@ -1060,3 +2738,757 @@ pub mod pathfinder_support {
         )
     }
 }
+
+/// Plain scalar implementations of the benchmarked operations using `f32`
+/// arrays, with no SIMD or hand optimisation. They show what straightforward
+/// code compiled by `rustc` achieves and are simple enough to check by eye.
+///
+/// Vectors are column vectors and matrices store an array of columns.
+/// Inverses divide by the determinant without checking it.
+#[cfg(feature = "reference")]
+pub mod reference {
+    use std::ops::{Add, Mul, Sub};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Vec2(pub [f32; 2]);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Vec3(pub [f32; 3]);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Vec4(pub [f32; 4]);
+
+    /// A quaternion stored as `[x, y, z, w]`.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Quat(pub [f32; 4]);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Mat2(pub [[f32; 2]; 2]);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Mat3(pub [[f32; 3]; 3]);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Mat4(pub [[f32; 4]; 4]);
+
+    macro_rules! impl_vec {
+        ($v:ident, $mint:ident, [$($c:ident),+]) => {
+            impl $v {
+                #[inline]
+                pub fn dot(self, rhs: Self) -> f32 {
+                    self.0.iter().zip(&rhs.0).map(|(a, b)| a * b).sum()
+                }
+
+                #[inline]
+                pub fn length(self) -> f32 {
+                    self.dot(self).sqrt()
+                }
+
+                #[inline]
+                pub fn normalize(self) -> Self {
+                    self * (1.0 / self.length())
+                }
+            }
+
+            impl Add for $v {
+                type Output = Self;
+                #[inline]
+                fn add(self, rhs: Self) -> Self {
+                    let mut out = self;
+                    out.0.iter_mut().zip(&rhs.0).for_each(|(a, b)| *a += b);
+                    out
+                }
+            }
+
+            impl Sub for $v {
+                type Output = Self;
+                #[inline]
+                fn sub(self, rhs: Self) -> Self {
+                    let mut out = self;
+                    out.0.iter_mut().zip(&rhs.0).for_each(|(a, b)| *a -= b);
+                    out
+                }
+            }
+
+            impl Mul<f32> for $v {
+                type Output = Self;
+                #[inline]
+                fn mul(self, rhs: f32) -> Self {
+                    let mut out = self;
+                    out.0.iter_mut().for_each(|a| *a *= rhs);
+                    out
+                }
+            }
+
+            impl From<mint::$mint<f32>> for $v {
+                fn from(v: mint::$mint<f32>) -> Self {
+                    $v([$(v.$c),+])
+                }
+            }
+
+            impl From<$v> for mint::$mint<f32> {
+                fn from(v: $v) -> Self {
+                    let [$($c),+] = v.0;
+                    mint::$mint { $($c),+ }
+                }
+            }
+        };
+    }
+
+    impl_vec!(Vec2, Vector2, [x, y]);
+    impl_vec!(Vec3, Vector3, [x, y, z]);
+    impl_vec!(Vec4, Vector4, [x, y, z, w]);
+
+    impl Vec3 {
+        #[inline]
+        pub fn cross(self, rhs: Self) -> Self {
+            let [ax, ay, az] = self.0;
+            let [bx, by, bz] = rhs.0;
+            Vec3([ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx])
+        }
+    }
+
+    macro_rules! impl_mat {
+        ($m:ident, $v:ident, $n:literal, $mint:ident, [$($c:ident),+]) => {
+            impl $m {
+                #[inline]
+                pub fn transpose(self) -> Self {
+                    let mut out = self;
+                    for (j, col) in self.0.iter().enumerate() {
+                        for (i, value) in col.iter().enumerate() {
+                            out.0[i][j] = *value;
+                        }
+                    }
+                    out
+                }
+
+                #[inline]
+                pub fn transpose_mul(self, rhs: Self) -> Self {
+                    let mut out = rhs;
+                    for (out_col, rhs_col) in out.0.iter_mut().zip(&rhs.0) {
+                        for (value, lhs_col) in out_col.iter_mut().zip(&self.0) {
+                            *value = $v(*lhs_col).dot($v(*rhs_col));
+                        }
+                    }
+                    out
+                }
+
+                #[inline]
+                pub fn trace(self) -> f32 {
+                    self.0.iter().enumerate().map(|(i, col)| col[i]).sum()
+                }
+            }
+
+            impl Add for $m {
+                type Output = Self;
+                #[inline]
+                fn add(self, rhs: Self) -> Self {
+                    let mut out = self;
+                    for (a, b) in out.0.iter_mut().zip(&rhs.0) {
+                        *a = ($v(*a) + $v(*b)).0;
+                    }
+                    out
+                }
+            }
+
+            impl Sub for $m {
+                type Output = Self;
+                #[inline]
+                fn sub(self, rhs: Self) -> Self {
+                    let mut out = self;
+                    for (a, b) in out.0.iter_mut().zip(&rhs.0) {
+                        *a = ($v(*a) - $v(*b)).0;
+                    }
+                    out
+                }
+            }
+
+            impl Mul<f32> for $m {
+                type Output = Self;
+                #[inline]
+                fn mul(self, rhs: f32) -> Self {
+                    let mut out = self;
+                    for col in out.0.iter_mut() {
+                        *col = ($v(*col) * rhs).0;
+                    }
+                    out
+                }
+            }
+
+            impl Mul<$v> for $m {
+                type Output = $v;
+                #[inline]
+                fn mul(self, rhs: $v) -> $v {
+                    let mut out = $v([0.0; $n]);
+                    for (col, scale) in self.0.iter().zip(&rhs.0) {
+                        out = out + $v(*col) * *scale;
+                    }
+                    out
+                }
+            }
+
+            impl Mul for $m {
+                type Output = Self;
+                #[inline]
+                fn mul(self, rhs: Self) -> Self {
+                    let mut out = rhs;
+                    for col in out.0.iter_mut() {
+                        *col = (self * $v(*col)).0;
+                    }
+                    out
+                }
+            }
+
+            impl From<mint::$mint<f32>> for $m {
+                fn from(m: mint::$mint<f32>) -> Self {
+                    $m([$($v::from(m.$c).0),+])
+                }
+            }
+
+            impl From<$m> for mint::$mint<f32> {
+                fn from(m: $m) -> Self {
+                    let [$($c),+] = m.0;
+                    mint::$mint { $($c: $v($c).into()),+ }
+                }
+            }
+        };
+    }
+
+    impl_mat!(Mat2, Vec2, 2, ColumnMatrix2, [x, y]);
+    impl_mat!(Mat3, Vec3, 3, ColumnMatrix3, [x, y, z]);
+    impl_mat!(Mat4, Vec4, 4, ColumnMatrix4, [x, y, z, w]);
+
+    impl Mat2 {
+        #[inline]
+        pub fn from_cols(x: Vec2, y: Vec2) -> Self {
+            Mat2([x.0, y.0])
+        }
+
+        #[inline]
+        pub fn determinant(self) -> f32 {
+            let [[a, b], [c, d]] = self.0;
+            a * d - c * b
+        }
+
+        #[inline]
+        pub fn inverse(self) -> Self {
+            let [[a, b], [c, d]] = self.0;
+            Mat2([[d, -b], [-c, a]]) * (1.0 / self.determinant())
+        }
+    }
+
+    impl Mat3 {
+        #[inline]
+        pub fn from_cols(x: Vec3, y: Vec3, z: Vec3) -> Self {
+            Mat3([x.0, y.0, z.0])
+        }
+
+        #[inline]
+        pub fn determinant(self) -> f32 {
+            let [x, y, z] = self.0;
+            Vec3(x).dot(Vec3(y).cross(Vec3(z)))
+        }
+
+        #[inline]
+        pub fn inverse(self) -> Self {
+            // the rows of the inverse are the cross products of the columns
+            let [x, y, z] = self.0;
+            let (x, y, z) = (Vec3(x), Vec3(y), Vec3(z));
+            let rows = Mat3([y.cross(z).0, z.cross(x).0, x.cross(y).0]);
+            rows.transpose() * (1.0 / self.determinant())
+        }
+
+        #[inline]
+        pub fn transform_point2(self, point: Vec2) -> Vec2 {
+            let [x, y] = point.0;
+            let [x, y, _] = (self * Vec3([x, y, 1.0])).0;
+            Vec2([x, y])
+        }
+
+        #[inline]
+        pub fn transform_vector2(self, vector: Vec2) -> Vec2 {
+            let [x, y] = vector.0;
+            let [x, y, _] = (self * Vec3([x, y, 0.0])).0;
+            Vec2([x, y])
+        }
+    }
+
+    impl Mat4 {
+        #[inline]
+        pub fn from_cols(x: Vec4, y: Vec4, z: Vec4, w: Vec4) -> Self {
+            Mat4([x.0, y.0, z.0, w.0])
+        }
+
+        /// Returns the determinants of the 2x2 submatrices of the top two and
+        /// bottom two rows used by `determinant` and `inverse`.
+        #[inline]
+        fn subdeterminants(self) -> ([f32; 6], [f32; 6]) {
+            let [[a00, a10, a20, a30], [a01, a11, a21, a31], [a02, a12, a22, a32], [a03, a13, a23, a33]] =
+                self.0;
+            let s = [
+                a00 * a11 - a10 * a01,
+                a00 * a12 - a10 * a02,
+                a00 * a13 - a10 * a03,
+                a01 * a12 - a11 * a02,
+                a01 * a13 - a11 * a03,
+                a02 * a13 - a12 * a03,
+            ];
+            let c = [
+                a20 * a31 - a30 * a21,
+                a20 * a32 - a30 * a22,
+                a20 * a33 - a30 * a23,
+                a21 * a32 - a31 * a22,
+                a21 * a33 - a31 * a23,
+                a22 * a33 - a32 * a23,
+            ];
+            (s, c)
+        }
+
+        #[inline]
+        pub fn determinant(self) -> f32 {
+            let (s, c) = self.subdeterminants();
+            s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+        }
+
+        #[inline]
+        pub fn inverse(self) -> Self {
+            let [[a00, a10, a20, a30], [a01, a11, a21, a31], [a02, a12, a22, a32], [a03, a13, a23, a33]] =
+                self.0;
+            let (s, c) = self.subdeterminants();
+            let adjugate = Mat4([
+                [
+                    a11 * c[5] - a12 * c[4] + a13 * c[3],
+                    -a10 * c[5] + a12 * c[2] - a13 * c[1],
+                    a10 * c[4] - a11 * c[2] + a13 * c[0],
+                    -a10 * c[3] + a11 * c[1] - a12 * c[0],
+                ],
+                [
+                    -a01 * c[5] + a02 * c[4] - a03 * c[3],
+                    a00 * c[5] - a02 * c[2] + a03 * c[1],
+                    -a00 * c[4] + a01 * c[2] - a03 * c[0],
+                    a00 * c[3] - a01 * c[1] + a02 * c[0],
+                ],
+                [
+                    a31 * s[5] - a32 * s[4] + a33 * s[3],
+                    -a30 * s[5] + a32 * s[2] - a33 * s[1],
+                    a30 * s[4] - a31 * s[2] + a33 * s[0],
+                    -a30 * s[3] + a31 * s[1] - a32 * s[0],
+                ],
+                [
+                    -a21 * s[5] + a22 * s[4] - a23 * s[3],
+                    a20 * s[5] - a22 * s[2] + a23 * s[1],
+                    -a20 * s[4] + a21 * s[2] - a23 * s[0],
+                    a20 * s[3] - a21 * s[1] + a22 * s[0],
+                ],
+            ]);
+            adjugate * (1.0 / self.determinant())
+        }
+
+        /// Inverse of a homogeneous transform whose upper 3x3 columns are
+        /// orthogonal.
+        #[inline]
+        pub fn affine_inverse(self) -> Self {
+            let [x, y, z, w] = self.0;
+            let mut out = Mat4([
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0; 4],
+                [0.0; 4],
+                [0.0, 0.0, 0.0, 1.0],
+            ]);
+            for (i, col) in [x, y, z].iter().enumerate() {
+                // row `i` of the inverse is column `i` divided by its squared
+                // length
+                let col = Vec3([col[0], col[1], col[2]]);
+                let row = col * (1.0 / col.dot(col));
+                for (j, value) in row.0.iter().enumerate() {
+                    out.0[j][i] = *value;
+                }
+                out.0[3][i] = -row.dot(Vec3([w[0], w[1], w[2]]));
+            }
+            out
+        }
+
+        /// Transforms a point by a homogeneous transform without dividing by
+        /// w.
+        #[inline]
+        pub fn transform_point3(self, point: Vec3) -> Vec3 {
+            let [x, y, z] = point.0;
+            let [x, y, z, _] = (self * Vec4([x, y, z, 1.0])).0;
+            Vec3([x, y, z])
+        }
+
+        #[inline]
+        pub fn transform_vector3(self, vector: Vec3) -> Vec3 {
+            let [x, y, z] = vector.0;
+            let [x, y, z, _] = (self * Vec4([x, y, z, 0.0])).0;
+            Vec3([x, y, z])
+        }
+
+        /// Transforms a point and divides by w.
+        #[inline]
+        pub fn project_point3(self, point: Vec3) -> Vec3 {
+            let [x, y, z] = point.0;
+            let [x, y, z, w] = (self * Vec4([x, y, z, 1.0])).0;
+            Vec3([x / w, y / w, z / w])
+        }
+    }
+
+    impl Quat {
+        #[inline]
+        pub fn conjugate(self) -> Self {
+            let [x, y, z, w] = self.0;
+            Quat([-x, -y, -z, w])
+        }
+    }
+
+    impl Mul for Quat {
+        type Output = Self;
+        #[inline]
+        fn mul(self, rhs: Self) -> Self {
+            let [ax, ay, az, aw] = self.0;
+            let [bx, by, bz, bw] = rhs.0;
+            Quat([
+                aw * bx + ax * bw + ay * bz - az * by,
+                aw * by - ax * bz + ay * bw + az * bx,
+                aw * bz + ax * by - ay * bx + az * bw,
+                aw * bw - ax * bx - ay * by - az * bz,
+            ])
+        }
+    }
+
+    impl Mul<Vec3> for Quat {
+        type Output = Vec3;
+        #[inline]
+        fn mul(self, rhs: Vec3) -> Vec3 {
+            // v + w t + q x t where t = 2 q x v
+            let [x, y, z, w] = self.0;
+            let q = Vec3([x, y, z]);
+            let t = q.cross(rhs) * 2.0;
+            rhs + t * w + q.cross(t)
+        }
+    }
+
+    impl From<mint::Quaternion<f32>> for Quat {
+        fn from(q: mint::Quaternion<f32>) -> Self {
+            Quat([q.v.x, q.v.y, q.v.z, q.s])
+        }
+    }
+
+    impl From<Quat> for mint::Quaternion<f32> {
+        fn from(q: Quat) -> Self {
+            let [x, y, z, s] = q.0;
+            mint::Quaternion {
+                v: mint::Vector3 { x, y, z },
+                s,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "reference")]
+pub mod reference_support {
+    use super::mint_support::*;
+    use super::reference;
+    use super::BenchValue;
+
+    impl_bench_value!(reference::Mat2, random_mint_invertible_mat2);
+    impl_bench_value!(reference::Mat3, random_mint_homogeneous_mat3);
+    impl_bench_value!(reference::Mat4, random_mint_homogeneous_mat4);
+    impl_bench_value!(reference::Quat, random_mint_quat);
+    impl_bench_value!(reference::Vec2, random_mint_vec2);
+    impl_bench_value!(reference::Vec3, random_mint_vec3);
+    impl_bench_value!(reference::Vec4, random_mint_vec4);
+}
+
+/// Hand-written `std::arch` implementations of Mat4 mul, Mat4 inverse, Vec3
+/// normalize and Quat mul Vec3, as an upper bound for what the libraries could
+/// achieve on `x86_64`.
+///
+/// Everything uses SSE2, which every `x86_64` CPU supports. Mat4 mul also has
+/// an AVX version which is only benchmarked if AVX is detected at runtime.
+#[cfg(all(feature = "intrinsics", target_arch = "x86_64"))]
+pub mod intrinsics {
+    use std::arch::x86_64::*;
+    use std::ops::Mul;
+
+    /// Shuffles lanes `x` and `y` from `a` and lanes `z` and `w` from `b`.
+    macro_rules! shuffle {
+        ($a:expr, $b:expr, [$x:literal, $y:literal, $z:literal, $w:literal]) => {
+            _mm_shuffle_ps($a, $b, ($w << 6) | ($z << 4) | ($y << 2) | $x)
+        };
+    }
+
+    /// A 3D vector stored in the `x`, `y` and `z` lanes, `w` is always zero.
+    #[derive(Clone, Copy, Debug)]
+    pub struct Vec3(pub __m128);
+
+    /// A quaternion stored as `[x, y, z, w]`.
+    #[derive(Clone, Copy, Debug)]
+    pub struct Quat(pub __m128);
+
+    #[derive(Clone, Copy, Debug)]
+    pub struct Mat4(pub [__m128; 4]);
+
+    #[inline]
+    fn load(v: [f32; 4]) -> __m128 {
+        unsafe { _mm_loadu_ps(v.as_ptr()) }
+    }
+
+    #[inline]
+    fn store(v: __m128) -> [f32; 4] {
+        let mut out = [0.0; 4];
+        unsafe { _mm_storeu_ps(out.as_mut_ptr(), v) };
+        out
+    }
+
+    /// Returns the sum of all four lanes in every lane.
+    #[inline]
+    unsafe fn sum(v: __m128) -> __m128 {
+        let v = _mm_add_ps(v, shuffle!(v, v, [1, 0, 3, 2]));
+        _mm_add_ps(v, shuffle!(v, v, [2, 3, 0, 1]))
+    }
+
+    /// Cross product of the `x`, `y` and `z` lanes, the `w` lane of the result
+    /// is zero if `a` or `b` has a zero `w`.
+    #[inline]
+    unsafe fn cross(a: __m128, b: __m128) -> __m128 {
+        let c = _mm_sub_ps(
+            _mm_mul_ps(a, shuffle!(b, b, [1, 2, 0, 3])),
+            _mm_mul_ps(shuffle!(a, a, [1, 2, 0, 3]), b),
+        );
+        shuffle!(c, c, [1, 2, 0, 3])
+    }
+
+    /// 2x2 row-major `a * b`.
+    #[inline]
+    unsafe fn mat2_mul(a: __m128, b: __m128) -> __m128 {
+        _mm_add_ps(
+            _mm_mul_ps(a, shuffle!(b, b, [0, 3, 0, 3])),
+            _mm_mul_ps(shuffle!(a, a, [1, 0, 3, 2]), shuffle!(b, b, [2, 1, 2, 1])),
+        )
+    }
+
+    /// 2x2 row-major `adj(a) * b`.
+    #[inline]
+    unsafe fn mat2_adj_mul(a: __m128, b: __m128) -> __m128 {
+        _mm_sub_ps(
+            _mm_mul_ps(shuffle!(a, a, [3, 3, 0, 0]), b),
+            _mm_mul_ps(shuffle!(a, a, [1, 1, 2, 2]), shuffle!(b, b, [2, 3, 0, 1])),
+        )
+    }
+
+    /// 2x2 row-major `a * adj(b)`.
+    #[inline]
+    unsafe fn mat2_mul_adj(a: __m128, b: __m128) -> __m128 {
+        _mm_sub_ps(
+            _mm_mul_ps(a, shuffle!(b, b, [3, 0, 3, 0])),
+            _mm_mul_ps(shuffle!(a, a, [1, 0, 3, 2]), shuffle!(b, b, [2, 1, 2, 1])),
+        )
+    }
+
+    impl Vec3 {
+        #[inline]
+        pub fn normalize(self) -> Self {
+            unsafe {
+                let length = _mm_sqrt_ps(sum(_mm_mul_ps(self.0, self.0)));
+                Vec3(_mm_div_ps(self.0, length))
+            }
+        }
+    }
+
+    impl Mul<Vec3> for Quat {
+        type Output = Vec3;
+        #[inline]
+        fn mul(self, rhs: Vec3) -> Vec3 {
+            // v + w t + q x t where t = 2 q x v
+            unsafe {
+                let q = self.0;
+                let w = shuffle!(q, q, [3, 3, 3, 3]);
+                let t = cross(q, rhs.0);
+                let t = _mm_add_ps(t, t);
+                Vec3(_mm_add_ps(_mm_add_ps(rhs.0, _mm_mul_ps(w, t)), cross(q, t)))
+            }
+        }
+    }
+
+    impl Mat4 {
+        /// Returns `self * rhs` using AVX, computing two columns at once. The
+        /// result is identical to `self * rhs`.
+        ///
+        /// # Safety
+        ///
+        /// The CPU must support AVX.
+        #[target_feature(enable = "avx")]
+        pub unsafe fn mul_avx(self, rhs: Self) -> Self {
+            let [a0, a1, a2, a3] = self.0;
+            let a0 = _mm256_set_m128(a0, a0);
+            let a1 = _mm256_set_m128(a1, a1);
+            let a2 = _mm256_set_m128(a2, a2);
+            let a3 = _mm256_set_m128(a3, a3);
+            let [b0, b1, b2, b3] = rhs.0;
+            let mut out = [_mm_setzero_ps(); 4];
+            for (i, &(lo, hi)) in [(b0, b1), (b2, b3)].iter().enumerate() {
+                let b = _mm256_set_m128(hi, lo);
+                let c = _mm256_add_ps(
+                    _mm256_add_ps(
+                        _mm256_mul_ps(a0, _mm256_shuffle_ps(b, b, 0x00)),
+                        _mm256_mul_ps(a1, _mm256_shuffle_ps(b, b, 0x55)),
+                    ),
+                    _mm256_add_ps(
+                        _mm256_mul_ps(a2, _mm256_shuffle_ps(b, b, 0xaa)),
+                        _mm256_mul_ps(a3, _mm256_shuffle_ps(b, b, 0xff)),
+                    ),
+                );
+                out[i * 2] = _mm256_castps256_ps128(c);
+                out[i * 2 + 1] = _mm256_extractf128_ps(c, 1);
+            }
+            Mat4(out)
+        }
+
+        /// Inverts the matrix by splitting it into 2x2 blocks. Returns NaNs or
+        /// infinities if the matrix is not invertible.
+        #[inline]
+        pub fn inverse(self) -> Self {
+            // The columns are treated as the rows of the transpose, since the
+            // inverse of the transpose is the transpose of the inverse the
+            // rows of the result are the columns of the inverse.
+            let [r0, r1, r2, r3] = self.0;
+            unsafe {
+                let a = _mm_movelh_ps(r0, r1);
+                let b = _mm_movehl_ps(r1, r0);
+                let c = _mm_movelh_ps(r2, r3);
+                let d = _mm_movehl_ps(r3, r2);
+
+                // |A| |B| |C| |D|
+                let det_sub = _mm_sub_ps(
+                    _mm_mul_ps(
+                        shuffle!(r0, r2, [0, 2, 0, 2]),
+                        shuffle!(r1, r3, [1, 3, 1, 3]),
+                    ),
+                    _mm_mul_ps(
+                        shuffle!(r0, r2, [1, 3, 1, 3]),
+                        shuffle!(r1, r3, [0, 2, 0, 2]),
+                    ),
+                );
+                let det_a = shuffle!(det_sub, det_sub, [0, 0, 0, 0]);
+                let det_b = shuffle!(det_sub, det_sub, [1, 1, 1, 1]);
+                let det_c = shuffle!(det_sub, det_sub, [2, 2, 2, 2]);
+                let det_d = shuffle!(det_sub, det_sub, [3, 3, 3, 3]);
+
+                let d_c = mat2_adj_mul(d, c);
+                let a_b = mat2_adj_mul(a, b);
+                let x = _mm_sub_ps(_mm_mul_ps(det_d, a), mat2_mul(b, d_c));
+                let w = _mm_sub_ps(_mm_mul_ps(det_a, d), mat2_mul(c, a_b));
+                let y = _mm_sub_ps(_mm_mul_ps(det_b, c), mat2_mul_adj(d, a_b));
+                let z = _mm_sub_ps(_mm_mul_ps(det_c, b), mat2_mul_adj(a, d_c));
+
+                // |M| = |A| |D| + |B| |C| - tr(adj(A) B adj(D) C)
+                let tr = sum(_mm_mul_ps(a_b, shuffle!(d_c, d_c, [0, 2, 1, 3])));
+                let det = _mm_sub_ps(
+                    _mm_add_ps(_mm_mul_ps(det_a, det_d), _mm_mul_ps(det_b, det_c)),
+                    tr,
+                );
+                let rcp_det = _mm_div_ps(_mm_setr_ps(1.0, -1.0, -1.0, 1.0), det);
+
+                let x = _mm_mul_ps(x, rcp_det);
+                let y = _mm_mul_ps(y, rcp_det);
+                let z = _mm_mul_ps(z, rcp_det);
+                let w = _mm_mul_ps(w, rcp_det);
+                Mat4([
+                    shuffle!(x, y, [3, 1, 3, 1]),
+                    shuffle!(x, y, [2, 0, 2, 0]),
+                    shuffle!(z, w, [3, 1, 3, 1]),
+                    shuffle!(z, w, [2, 0, 2, 0]),
+                ])
+            }
+        }
+    }
+
+    impl Mul for Mat4 {
+        type Output = Self;
+        #[inline]
+        fn mul(self, rhs: Self) -> Self {
+            let [a0, a1, a2, a3] = self.0;
+            let mut out = rhs.0;
+            for col in &mut out {
+                let b = *col;
+                unsafe {
+                    *col = _mm_add_ps(
+                        _mm_add_ps(
+                            _mm_mul_ps(a0, shuffle!(b, b, [0, 0, 0, 0])),
+                            _mm_mul_ps(a1, shuffle!(b, b, [1, 1, 1, 1])),
+                        ),
+                        _mm_add_ps(
+                            _mm_mul_ps(a2, shuffle!(b, b, [2, 2, 2, 2])),
+                            _mm_mul_ps(a3, shuffle!(b, b, [3, 3, 3, 3])),
+                        ),
+                    );
+                }
+            }
+            Mat4(out)
+        }
+    }
+
+    impl From<mint::Vector3<f32>> for Vec3 {
+        fn from(v: mint::Vector3<f32>) -> Self {
+            Vec3(load([v.x, v.y, v.z, 0.0]))
+        }
+    }
+
+    impl From<Vec3> for mint::Vector3<f32> {
+        fn from(v: Vec3) -> Self {
+            let [x, y, z, _] = store(v.0);
+            mint::Vector3 { x, y, z }
+        }
+    }
+
+    impl From<mint::Quaternion<f32>> for Quat {
+        fn from(q: mint::Quaternion<f32>) -> Self {
+            Quat(load([q.v.x, q.v.y, q.v.z, q.s]))
+        }
+    }
+
+    impl From<Quat> for mint::Quaternion<f32> {
+        fn from(q: Quat) -> Self {
+            let [x, y, z, s] = store(q.0);
+            mint::Quaternion {
+                v: mint::Vector3 { x, y, z },
+                s,
+            }
+        }
+    }
+
+    impl From<mint::ColumnMatrix4<f32>> for Mat4 {
+        fn from(m: mint::ColumnMatrix4<f32>) -> Self {
+            Mat4([
+                load(m.x.into()),
+                load(m.y.into()),
+                load(m.z.into()),
+                load(m.w.into()),
+            ])
+        }
+    }
+
+    impl From<Mat4> for mint::ColumnMatrix4<f32> {
+        fn from(m: Mat4) -> Self {
+            let [x, y, z, w] = m.0;
+            mint::ColumnMatrix4 {
+                x: store(x).into(),
+                y: store(y).into(),
+                z: store(z).into(),
+                w: store(w).into(),
+            }
+        }
+    }
+}
+
+#[cfg(all(feature = "intrinsics", target_arch = "x86_64"))]
+pub mod intrinsics_support {
+    use super::intrinsics;
+    use super::mint_support::*;
+    use super::BenchValue;
+
+    impl_bench_value!(intrinsics::Mat4, random_mint_homogeneous_mat4);
+    impl_bench_value!(intrinsics::Quat, random_mint_quat);
+    impl_bench_value!(intrinsics::Vec3, random_mint_vec3);
+}
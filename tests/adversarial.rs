@@ -0,0 +1,363 @@
+//! Tests for the adversarial input distributions and a report of how each
+//! library's matrix inverse and vector normalize cope with them.
+//!
+//! The report is printed by `test_inverse_report` and `test_normalize_report`,
+//! run `cargo test --test adversarial -- --nocapture` to see it. Only the
+//! `Standard` distribution is required to give accurate results.
+mod support;
+
+use cgmath::{self, InnerSpace, SquareMatrix};
+use glam;
+use mathbench::mint_support::*;
+use mathbench::{input_distribution, with_input_distribution, InputDistribution};
+use nalgebra;
+use rand_pcg::Pcg64Mcg;
+use support::{catch_quietly, FromMint};
+
+const NUM_SAMPLES: usize = 1024;
+
+fn samples<T, F>(dist: InputDistribution, f: F) -> Vec<T>
+where
+    F: Fn(&mut Pcg64Mcg) -> T,
+{
+    with_input_distribution(dist, || {
+        let mut rng = Pcg64Mcg::new(rand::random());
+        (0..NUM_SAMPLES).map(|_| f(&mut rng)).collect()
+    })
+}
+
+fn mat4_to_array<T: Copy>(m: mint::ColumnMatrix4<T>) -> [T; 16] {
+    [
+        m.x.x, m.x.y, m.x.z, m.x.w, m.y.x, m.y.y, m.y.z, m.y.w, m.z.x, m.z.y, m.z.z, m.z.w, m.w.x,
+        m.w.y, m.w.z, m.w.w,
+    ]
+}
+
+fn is_subnormal_or_zero(x: f32) -> bool {
+    x.abs() < std::f32::MIN_POSITIVE
+}
+
+fn assert_affine(m: &mint::ColumnMatrix4<f32>) {
+    assert_eq!([m.x.w, m.y.w, m.z.w, m.w.w], [0.0, 0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn test_degenerate_inputs() {
+    let dist = InputDistribution::Degenerate;
+    for m in samples(dist, random_mint_invertible_mat2) {
+        let det = nalgebra::Matrix2::<f32>::from(m)
+            .map(f64::from)
+            .determinant();
+        assert!(det.abs() <= 32.0 * f64::from(std::f32::EPSILON), "{}", det);
+    }
+    for m in samples(dist, random_mint_homogeneous_mat4) {
+        assert_affine(&m);
+        let det = nalgebra::Matrix4::<f32>::from(m)
+            .map(f64::from)
+            .determinant();
+        assert!(det.abs() <= 1.0e-5, "{}", det);
+    }
+    for m in samples(dist, random_mint_homogeneous_dmat4) {
+        let det = nalgebra::Matrix4::<f64>::from(m).determinant();
+        assert!(det.abs() <= 1.0e-13, "{}", det);
+    }
+    for v in samples(dist, random_mint_vec3) {
+        let length = nalgebra::Vector3::<f32>::from(v).map(f64::from).norm();
+        assert!(length <= 2.0f64.powi(-50), "{}", length);
+    }
+    let quats = samples(dist, random_mint_quat);
+    for pair in quats.windows(2) {
+        let q1 = nalgebra::Quaternion::<f32>::from(pair[0]);
+        let q2 = nalgebra::Quaternion::<f32>::from(pair[1]);
+        assert!(q1.dot(&q2).abs() > 0.99);
+    }
+}
+
+#[test]
+fn test_extreme_inputs() {
+    let dist = InputDistribution::Extreme;
+    let components: Vec<f32> = samples(dist, random_mint_vec3)
+        .into_iter()
+        .flat_map(|v| vec![v.x, v.y, v.z])
+        .map(f32::abs)
+        .filter(|&x| x != 0.0)
+        .collect();
+    assert!(components.iter().all(|x| x.is_finite()));
+    assert!(components.iter().any(|&x| x * x == std::f32::INFINITY));
+    assert!(components.iter().any(|&x| is_subnormal_or_zero(x * x)));
+    for m in samples(dist, random_mint_homogeneous_mat4) {
+        assert_affine(&m);
+    }
+}
+
+#[test]
+fn test_subnormal_inputs() {
+    let dist = InputDistribution::Subnormal;
+    for v in samples(dist, random_mint_vec3) {
+        assert!([v.x, v.y, v.z].iter().all(|&x| is_subnormal_or_zero(x)));
+    }
+    for q in samples(dist, random_mint_quat) {
+        assert!([q.v.x, q.v.y, q.v.z, q.s]
+            .iter()
+            .all(|&x| is_subnormal_or_zero(x)));
+    }
+    for m in samples(dist, random_mint_homogeneous_mat4) {
+        assert_affine(&m);
+        let a = mat4_to_array(m);
+        assert!((0..16)
+            .filter(|i| i % 4 != 3)
+            .all(|i| is_subnormal_or_zero(a[i])));
+    }
+    for v in samples(dist, random_mint_dvec3) {
+        assert!([v.x, v.y, v.z]
+            .iter()
+            .all(|x| x.abs() < std::f64::MIN_POSITIVE));
+    }
+}
+
+#[test]
+fn test_non_finite_inputs() {
+    let dist = InputDistribution::NonFinite;
+    let components: Vec<f32> = samples(dist, random_mint_vec3)
+        .into_iter()
+        .flat_map(|v| vec![v.x, v.y, v.z])
+        .collect();
+    assert!(components.iter().any(|x| x.is_nan()));
+    assert!(components.iter().any(|x| x.is_infinite()));
+    for m in samples(dist, random_mint_homogeneous_mat4) {
+        assert_affine(&m);
+    }
+}
+
+#[test]
+fn test_distribution_restored_after_panic() {
+    with_input_distribution(InputDistribution::Degenerate, || {
+        let result = catch_quietly(|| {
+            with_input_distribution(InputDistribution::Extreme, || panic!("in `f`"))
+        });
+        assert_eq!(result, None::<()>);
+        assert_eq!(input_distribution(), InputDistribution::Degenerate);
+    });
+}
+
+// report ---------------------------------------------------------------------
+
+#[derive(Default)]
+struct Outcomes {
+    accurate: usize,
+    inaccurate: usize,
+    none: usize,
+    non_finite: usize,
+    panicked: usize,
+}
+
+impl Outcomes {
+    /// Records the result of one call compared to the `f64` reference result.
+    fn record<A: AsRef<[f32]>>(
+        &mut self,
+        result: Option<Option<A>>,
+        reference: Option<&[f64]>,
+        max_rel_error: f64,
+    ) {
+        let result = match result {
+            None => return self.panicked += 1,
+            Some(None) => return self.none += 1,
+            Some(Some(result)) => result,
+        };
+        let result = result.as_ref();
+        if !result.iter().all(|x| x.is_finite()) {
+            return self.non_finite += 1;
+        }
+        let accurate = reference.map_or(false, |reference| {
+            let scale = reference.iter().fold(1.0f64, |max, x| max.max(x.abs()));
+            result
+                .iter()
+                .zip(reference)
+                .all(|(&a, &b)| (f64::from(a) - b).abs() <= max_rel_error * scale)
+        });
+        if accurate {
+            self.accurate += 1;
+        } else {
+            self.inaccurate += 1;
+        }
+    }
+}
+
+fn format_report(title: &str, dist: InputDistribution, libs: &[(&str, Outcomes)]) -> String {
+    let mut report = format!(
+        "\n{} ({} inputs)\n{:<12} {:>9} {:>11} {:>6} {:>11} {:>7}\n",
+        title,
+        dist.name(),
+        "library",
+        "accurate",
+        "inaccurate",
+        "none",
+        "non-finite",
+        "panic"
+    );
+    for (lib, o) in libs {
+        report += &format!(
+            "{:<12} {:>9} {:>11} {:>6} {:>11} {:>7}\n",
+            lib, o.accurate, o.inaccurate, o.none, o.non_finite, o.panicked
+        );
+    }
+    report
+}
+
+fn mat4_inverse_report(dist: InputDistribution) -> Vec<(&'static str, Outcomes)> {
+    let mut glam_outcomes = Outcomes::default();
+    let mut cgmath_outcomes = Outcomes::default();
+    let mut nalgebra_outcomes = Outcomes::default();
+    let mut ultraviolet_outcomes = Outcomes::default();
+    let mut euclid_outcomes = Outcomes::default();
+    let mut vek_outcomes = Outcomes::default();
+    for mm in samples(dist, random_mint_homogeneous_mat4) {
+        let reference = nalgebra::Matrix4::<f32>::from(mm)
+            .map(f64::from)
+            .try_inverse()
+            .map(|m| m.as_slice().to_vec());
+        let reference = reference.as_deref();
+        let rel_error = 1.0e-3;
+
+        let result = catch_quietly(|| Some(glam::Mat4::from(mm).inverse().to_cols_array()));
+        glam_outcomes.record(result, reference, rel_error);
+
+        let result = catch_quietly(|| {
+            cgmath::Matrix4::<f32>::from(mm)
+                .invert()
+                .map(|m| mat4_to_array(m.into()))
+        });
+        cgmath_outcomes.record(result, reference, rel_error);
+
+        let result = catch_quietly(|| {
+            nalgebra::Matrix4::<f32>::from(mm)
+                .try_inverse()
+                .map(|m| mat4_to_array(m.into()))
+        });
+        nalgebra_outcomes.record(result, reference, rel_error);
+
+        let result = catch_quietly(|| {
+            let m = ultraviolet::Mat4::from_mint(mm).inversed();
+            Some(mat4_to_array(mint::ColumnMatrix4 {
+                x: [m.cols[0].x, m.cols[0].y, m.cols[0].z, m.cols[0].w].into(),
+                y: [m.cols[1].x, m.cols[1].y, m.cols[1].z, m.cols[1].w].into(),
+                z: [m.cols[2].x, m.cols[2].y, m.cols[2].z, m.cols[2].w].into(),
+                w: [m.cols[3].x, m.cols[3].y, m.cols[3].z, m.cols[3].w].into(),
+            }))
+        });
+        ultraviolet_outcomes.record(result, reference, rel_error);
+
+        // euclid uses row vectors so its row major array is our column major array
+        let result = catch_quietly(|| {
+            support::EuclidTransform3D::from_mint(mm)
+                .inverse()
+                .map(|m| m.to_row_major_array())
+        });
+        euclid_outcomes.record(result, reference, rel_error);
+
+        let result = catch_quietly(|| {
+            let m: mint::ColumnMatrix4<f32> = vek::Mat4::<f32>::from(mm).inverted().into();
+            Some(mat4_to_array(m))
+        });
+        vek_outcomes.record(result, reference, rel_error);
+    }
+    vec![
+        ("glam", glam_outcomes),
+        ("cgmath", cgmath_outcomes),
+        ("nalgebra", nalgebra_outcomes),
+        ("ultraviolet", ultraviolet_outcomes),
+        ("euclid", euclid_outcomes),
+        ("vek", vek_outcomes),
+    ]
+}
+
+fn vec3_normalize_report(dist: InputDistribution) -> Vec<(&'static str, Outcomes)> {
+    let mut glam_outcomes = Outcomes::default();
+    let mut cgmath_outcomes = Outcomes::default();
+    let mut nalgebra_outcomes = Outcomes::default();
+    let mut ultraviolet_outcomes = Outcomes::default();
+    let mut euclid_outcomes = Outcomes::default();
+    let mut vek_outcomes = Outcomes::default();
+    for mv in samples(dist, random_mint_vec3) {
+        let reference = nalgebra::Vector3::<f32>::from(mv)
+            .map(f64::from)
+            .try_normalize(0.0)
+            .map(|v| v.as_slice().to_vec());
+        let reference = reference.as_deref();
+        let rel_error = 1.0e-5;
+
+        let result = catch_quietly(|| {
+            let v = glam::Vec3::from(mv).normalize();
+            Some([v.x(), v.y(), v.z()])
+        });
+        glam_outcomes.record(result, reference, rel_error);
+
+        let result = catch_quietly(|| {
+            let v = cgmath::Vector3::<f32>::from(mv).normalize();
+            Some([v.x, v.y, v.z])
+        });
+        cgmath_outcomes.record(result, reference, rel_error);
+
+        let result = catch_quietly(|| {
+            let v = nalgebra::Vector3::<f32>::from(mv).normalize();
+            Some([v.x, v.y, v.z])
+        });
+        nalgebra_outcomes.record(result, reference, rel_error);
+
+        let result = catch_quietly(|| {
+            let v = ultraviolet::Vec3::from_mint(mv).normalized();
+            Some([v.x, v.y, v.z])
+        });
+        ultraviolet_outcomes.record(result, reference, rel_error);
+
+        let result = catch_quietly(|| {
+            let v = euclid::Vector3D::<f32, euclid::UnknownUnit>::from(mv).normalize();
+            Some([v.x, v.y, v.z])
+        });
+        euclid_outcomes.record(result, reference, rel_error);
+
+        let result = catch_quietly(|| {
+            let v = vek::Vec3::<f32>::from(mv).normalized();
+            Some([v.x, v.y, v.z])
+        });
+        vek_outcomes.record(result, reference, rel_error);
+    }
+    vec![
+        ("glam", glam_outcomes),
+        ("cgmath", cgmath_outcomes),
+        ("nalgebra", nalgebra_outcomes),
+        ("ultraviolet", ultraviolet_outcomes),
+        ("euclid", euclid_outcomes),
+        ("vek", vek_outcomes),
+    ]
+}
+
+#[test]
+fn test_inverse_report() {
+    let mut report = String::new();
+    for &dist in InputDistribution::ALL.iter() {
+        let libs = mat4_inverse_report(dist);
+        report += &format_report("matrix4 inverse", dist, &libs);
+        if dist == InputDistribution::Standard {
+            for (lib, outcomes) in &libs {
+                assert_eq!(outcomes.accurate, NUM_SAMPLES, "{}", lib);
+            }
+        }
+    }
+    println!("{}", report);
+}
+
+#[test]
+fn test_normalize_report() {
+    let mut report = String::new();
+    for &dist in InputDistribution::ALL.iter() {
+        let libs = vec3_normalize_report(dist);
+        report += &format_report("vector3 normalize", dist, &libs);
+        if dist == InputDistribution::Standard {
+            for (lib, outcomes) in &libs {
+                assert_eq!(outcomes.accurate, NUM_SAMPLES, "{}", lib);
+            }
+        }
+    }
+    println!("{}", report);
+}
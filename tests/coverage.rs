@@ -0,0 +1,1307 @@
+//! Audits that every benchmarked (library, operation) pair has a correctness
+//! check.
+//!
+//! Each benchmark group is declared once in `BENCH_OPS` along with the
+//! implementation used as the reference answer, the registered checks that
+//! compare the other libraries against it and any libraries that are known to
+//! produce incorrect results. `test_bench_coverage` scans the `benches`
+//! directory and fails if a benchmark is missing from the registry, if the
+//! registry has gone stale or if a benchmarked library isn't registered by any
+//! of its group's checks. The comparison tests assert that they check exactly
+//! the libraries their `registry::Check` lists, see `support::compare`.
+//!
+//! `test_layout_coverage` checks that the `layout` example reports every type
+//! that implements `BenchValue`.
+mod support;
+
+#[allow(dead_code)]
+#[path = "../examples/layout.rs"]
+mod layout;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+use support::registry::{self, Check};
+
+struct BenchOp {
+    /// the `benchmark_group!` name
+    group: &'static str,
+    /// the implementation used as the correct answer, the first path segment
+    /// is the library name
+    reference: &'static str,
+    /// the checks of the non-wide libraries
+    checks: &'static [&'static Check],
+    /// `<module>::<test fn>` in `wide.rs`, where the module is the wide library
+    /// being tested
+    wide: &'static [&'static str],
+    /// libraries that are benchmarked but listed under "Known incorrect
+    /// results" in the README
+    known_incorrect: &'static [&'static str],
+}
+
+static BENCH_OPS: &[BenchOp] = &[
+    BenchOp {
+        group: "euler 2d",
+        reference: "nalgebra::Vector2",
+        checks: &[&registry::EULER_2D],
+        wide: &[
+            "ultraviolet_f32x4::euler_2d",
+            "nalgebra_f32x4::euler_2d",
+            "nalgebra_f32x8::euler_2d",
+            "nalgebra_f32x16::euler_2d",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "euler 3d",
+        reference: "nalgebra::Vector3",
+        checks: &[&registry::EULER_3D],
+        wide: &[
+            "ultraviolet_f32x4::euler_3d",
+            "nalgebra_f32x4::euler_3d",
+            "nalgebra_f32x8::euler_3d",
+            "nalgebra_f32x16::euler_3d",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "isometry transform point2",
+        reference: "nalgebra::Isometry2::transform_point",
+        checks: &[&registry::ISOMETRY2_TRANSFORM_POINT2],
+        wide: &[
+            "ultraviolet_f32x4::isometry2_transform_point2",
+            "nalgebra_f32x4::isometry2_transform_point2",
+            "nalgebra_f32x8::isometry2_transform_point2",
+            "nalgebra_f32x16::isometry2_transform_point2",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "isometry transform point3",
+        reference: "nalgebra::Isometry3::transform_point",
+        checks: &[&registry::ISOMETRY3_TRANSFORM_POINT3],
+        wide: &[
+            "ultraviolet_f32x4::isometry3_transform_point3",
+            "nalgebra_f32x4::isometry3_transform_point3",
+            "nalgebra_f32x8::isometry3_transform_point3",
+            "nalgebra_f32x16::isometry3_transform_point3",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "isometry transform point3 slice",
+        reference: "nalgebra::Isometry3::transform_point",
+        checks: &[&registry::ISOMETRY3_TRANSFORM_POINT3],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "isometry2 inverse",
+        reference: "nalgebra::Isometry2::inverse",
+        checks: &[&registry::ISOMETRY2_INVERSE],
+        wide: &[
+            "ultraviolet_f32x4::isometry2_inverse",
+            "nalgebra_f32x4::isometry2_inverse",
+            "nalgebra_f32x8::isometry2_inverse",
+            "nalgebra_f32x16::isometry2_inverse",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "isometry2 mul isometry2",
+        reference: "nalgebra::Isometry2::mul",
+        checks: &[&registry::ISOMETRY2_MUL_ISOMETRY2],
+        wide: &[
+            "ultraviolet_f32x4::isometry2_mul_isometry2",
+            "nalgebra_f32x4::isometry2_mul_isometry2",
+            "nalgebra_f32x8::isometry2_mul_isometry2",
+            "nalgebra_f32x16::isometry2_mul_isometry2",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "isometry3 inverse",
+        reference: "nalgebra::Isometry3::inverse",
+        checks: &[&registry::ISOMETRY3_INVERSE],
+        wide: &[
+            "ultraviolet_f32x4::isometry3_inverse",
+            "nalgebra_f32x4::isometry3_inverse",
+            "nalgebra_f32x8::isometry3_inverse",
+            "nalgebra_f32x16::isometry3_inverse",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "isometry3 mul isometry3",
+        reference: "nalgebra::Isometry3::mul",
+        checks: &[&registry::ISOMETRY3_MUL_ISOMETRY3],
+        wide: &[
+            "ultraviolet_f32x4::isometry3_mul_isometry3",
+            "nalgebra_f32x4::isometry3_mul_isometry3",
+            "nalgebra_f32x8::isometry3_mul_isometry3",
+            "nalgebra_f32x16::isometry3_mul_isometry3",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix2 transpose",
+        reference: "nalgebra::Matrix2::transpose",
+        checks: &[&registry::MAT2_TRANSPOSE],
+        wide: &[
+            "nalgebra_f32x4::matrix2_transpose",
+            "nalgebra_f32x8::matrix2_transpose",
+            "nalgebra_f32x16::matrix2_transpose",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix2 determinant",
+        reference: "nalgebra::Matrix2::determinant",
+        checks: &[&registry::MAT2_DET],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix2 inverse",
+        reference: "nalgebra::Matrix2::try_inverse",
+        checks: &[&registry::MAT2_INVERSE],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix2 mul matrix2",
+        reference: "nalgebra::Matrix2::mul",
+        checks: &[&registry::MAT2_MUL_MAT2],
+        wide: &[
+            "nalgebra_f32x4::matrix2_mul_matrix2",
+            "nalgebra_f32x8::matrix2_mul_matrix2",
+            "nalgebra_f32x16::matrix2_mul_matrix2",
+        ],
+        known_incorrect: &["ultraviolet", "ultraviolet_f32x4"],
+    },
+    BenchOp {
+        group: "matrix2 mul vector2",
+        reference: "nalgebra::Matrix2::mul",
+        checks: &[&registry::MAT2_MUL_VEC2],
+        wide: &[
+            "ultraviolet_f32x4::matrix2_mul_vector2",
+            "nalgebra_f32x4::matrix2_mul_vector2",
+            "nalgebra_f32x8::matrix2_mul_vector2",
+            "nalgebra_f32x16::matrix2_mul_vector2",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix2 add matrix2",
+        reference: "nalgebra::Matrix2::add",
+        checks: &[&registry::MAT2_ADD_MAT2],
+        wide: &[
+            "ultraviolet_f32x4::matrix2_add_matrix2",
+            "nalgebra_f32x4::matrix2_add_matrix2",
+            "nalgebra_f32x8::matrix2_add_matrix2",
+            "nalgebra_f32x16::matrix2_add_matrix2",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix2 sub matrix2",
+        reference: "nalgebra::Matrix2::sub",
+        checks: &[&registry::MAT2_SUB_MAT2],
+        wide: &[
+            "ultraviolet_f32x4::matrix2_sub_matrix2",
+            "nalgebra_f32x4::matrix2_sub_matrix2",
+            "nalgebra_f32x8::matrix2_sub_matrix2",
+            "nalgebra_f32x16::matrix2_sub_matrix2",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix2 mul scalar",
+        reference: "nalgebra::Matrix2::mul",
+        checks: &[&registry::MAT2_MUL_SCALAR],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix2 transpose mul matrix2",
+        reference: "nalgebra::Matrix2::tr_mul",
+        checks: &[&registry::MAT2_TRANSPOSE_MUL_MAT2],
+        wide: &[
+            "ultraviolet_f32x4::matrix2_transpose_mul_matrix2",
+            "nalgebra_f32x4::matrix2_transpose_mul_matrix2",
+            "nalgebra_f32x8::matrix2_transpose_mul_matrix2",
+            "nalgebra_f32x16::matrix2_transpose_mul_matrix2",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix2 trace",
+        reference: "nalgebra::Matrix2::trace",
+        checks: &[&registry::MAT2_TRACE],
+        wide: &[
+            "ultraviolet_f32x4::matrix2_trace",
+            "nalgebra_f32x4::matrix2_trace",
+            "nalgebra_f32x8::matrix2_trace",
+            "nalgebra_f32x16::matrix2_trace",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix2 from cols",
+        reference: "nalgebra::Matrix2::from_columns",
+        checks: &[&registry::MAT2_FROM_COLS],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix3 transpose",
+        reference: "nalgebra::Matrix3::transpose",
+        checks: &[&registry::MAT3_TRANSPOSE],
+        wide: &[
+            "ultraviolet_f32x4::matrix3_transpose",
+            "nalgebra_f32x4::matrix3_transpose",
+            "nalgebra_f32x8::matrix3_transpose",
+            "nalgebra_f32x16::matrix3_transpose",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix3 determinant",
+        reference: "nalgebra::Matrix3::determinant",
+        checks: &[&registry::MAT3_DET],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix3 inverse",
+        reference: "nalgebra::Matrix3::try_inverse",
+        checks: &[&registry::MAT3_INVERSE],
+        wide: &[],
+        known_incorrect: &["ultraviolet", "ultraviolet_f32x4"],
+    },
+    BenchOp {
+        group: "matrix3 mul matrix3",
+        reference: "nalgebra::Matrix3::mul",
+        checks: &[&registry::MAT3_MUL_MAT3],
+        wide: &[
+            "ultraviolet_f32x4::matrix3_mul_matrix3",
+            "nalgebra_f32x4::matrix3_mul_matrix3",
+            "nalgebra_f32x8::matrix3_mul_matrix3",
+            "nalgebra_f32x16::matrix3_mul_matrix3",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix3 mul vector3",
+        reference: "nalgebra::Matrix3::mul",
+        checks: &[&registry::MAT3_MUL_VEC3],
+        wide: &[
+            "ultraviolet_f32x4::matrix3_mul_vector3",
+            "nalgebra_f32x4::matrix3_mul_vector3",
+            "nalgebra_f32x8::matrix3_mul_vector3",
+            "nalgebra_f32x16::matrix3_mul_vector3",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix3 add matrix3",
+        reference: "nalgebra::Matrix3::add",
+        checks: &[&registry::MAT3_ADD_MAT3],
+        wide: &[
+            "ultraviolet_f32x4::matrix3_add_matrix3",
+            "nalgebra_f32x4::matrix3_add_matrix3",
+            "nalgebra_f32x8::matrix3_add_matrix3",
+            "nalgebra_f32x16::matrix3_add_matrix3",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix3 sub matrix3",
+        reference: "nalgebra::Matrix3::sub",
+        checks: &[&registry::MAT3_SUB_MAT3],
+        wide: &[
+            "ultraviolet_f32x4::matrix3_sub_matrix3",
+            "nalgebra_f32x4::matrix3_sub_matrix3",
+            "nalgebra_f32x8::matrix3_sub_matrix3",
+            "nalgebra_f32x16::matrix3_sub_matrix3",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix3 mul scalar",
+        reference: "nalgebra::Matrix3::mul",
+        checks: &[&registry::MAT3_MUL_SCALAR],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix3 transpose mul matrix3",
+        reference: "nalgebra::Matrix3::tr_mul",
+        checks: &[&registry::MAT3_TRANSPOSE_MUL_MAT3],
+        wide: &[
+            "ultraviolet_f32x4::matrix3_transpose_mul_matrix3",
+            "nalgebra_f32x4::matrix3_transpose_mul_matrix3",
+            "nalgebra_f32x8::matrix3_transpose_mul_matrix3",
+            "nalgebra_f32x16::matrix3_transpose_mul_matrix3",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix3 trace",
+        reference: "nalgebra::Matrix3::trace",
+        checks: &[&registry::MAT3_TRACE],
+        wide: &[
+            "ultraviolet_f32x4::matrix3_trace",
+            "nalgebra_f32x4::matrix3_trace",
+            "nalgebra_f32x8::matrix3_trace",
+            "nalgebra_f32x16::matrix3_trace",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix3 from cols",
+        reference: "nalgebra::Matrix3::from_columns",
+        checks: &[&registry::MAT3_FROM_COLS],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix4 transpose",
+        reference: "nalgebra::Matrix4::transpose",
+        checks: &[&registry::MAT4_TRANSPOSE],
+        wide: &[
+            "ultraviolet_f32x4::matrix4_transpose",
+            "nalgebra_f32x4::matrix4_transpose",
+            "nalgebra_f32x8::matrix4_transpose",
+            "nalgebra_f32x16::matrix4_transpose",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix4 determinant",
+        reference: "nalgebra::Matrix4::determinant",
+        checks: &[&registry::MAT4_DET],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix4 inverse",
+        reference: "nalgebra::Matrix4::try_inverse",
+        checks: &[&registry::MAT4_INVERSE],
+        wide: &["ultraviolet_f32x4::matrix4_inverse"],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix4 mul matrix4",
+        reference: "nalgebra::Matrix4::mul",
+        checks: &[&registry::MAT4_MUL_MAT4],
+        wide: &[
+            "ultraviolet_f32x4::matrix4_mul_matrix4",
+            "nalgebra_f32x4::matrix4_mul_matrix4",
+            "nalgebra_f32x8::matrix4_mul_matrix4",
+            "nalgebra_f32x16::matrix4_mul_matrix4",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix4 mul vector4",
+        reference: "nalgebra::Matrix4::mul",
+        checks: &[&registry::MAT4_MUL_VEC4],
+        wide: &[
+            "ultraviolet_f32x4::matrix4_mul_vector4",
+            "nalgebra_f32x4::matrix4_mul_vector4",
+            "nalgebra_f32x8::matrix4_mul_vector4",
+            "nalgebra_f32x16::matrix4_mul_vector4",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix4 add matrix4",
+        reference: "nalgebra::Matrix4::add",
+        checks: &[&registry::MAT4_ADD_MAT4],
+        wide: &[
+            "ultraviolet_f32x4::matrix4_add_matrix4",
+            "nalgebra_f32x4::matrix4_add_matrix4",
+            "nalgebra_f32x8::matrix4_add_matrix4",
+            "nalgebra_f32x16::matrix4_add_matrix4",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix4 sub matrix4",
+        reference: "nalgebra::Matrix4::sub",
+        checks: &[&registry::MAT4_SUB_MAT4],
+        wide: &[
+            "ultraviolet_f32x4::matrix4_sub_matrix4",
+            "nalgebra_f32x4::matrix4_sub_matrix4",
+            "nalgebra_f32x8::matrix4_sub_matrix4",
+            "nalgebra_f32x16::matrix4_sub_matrix4",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix4 mul scalar",
+        reference: "nalgebra::Matrix4::mul",
+        checks: &[&registry::MAT4_MUL_SCALAR],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix4 transpose mul matrix4",
+        reference: "nalgebra::Matrix4::tr_mul",
+        checks: &[&registry::MAT4_TRANSPOSE_MUL_MAT4],
+        wide: &[
+            "ultraviolet_f32x4::matrix4_transpose_mul_matrix4",
+            "nalgebra_f32x4::matrix4_transpose_mul_matrix4",
+            "nalgebra_f32x8::matrix4_transpose_mul_matrix4",
+            "nalgebra_f32x16::matrix4_transpose_mul_matrix4",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix4 trace",
+        reference: "nalgebra::Matrix4::trace",
+        checks: &[&registry::MAT4_TRACE],
+        wide: &[
+            "ultraviolet_f32x4::matrix4_trace",
+            "nalgebra_f32x4::matrix4_trace",
+            "nalgebra_f32x8::matrix4_trace",
+            "nalgebra_f32x16::matrix4_trace",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix4 from cols",
+        reference: "nalgebra::Matrix4::from_columns",
+        checks: &[&registry::MAT4_FROM_COLS],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix4 affine inverse",
+        reference: "nalgebra::Matrix4::try_inverse",
+        checks: &[&registry::MAT4_AFFINE_INVERSE],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "matrix4 project point3",
+        reference: "nalgebra::Matrix4::transform_point",
+        checks: &[&registry::MAT4_PROJECT_POINT3],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 matrix2 transpose",
+        reference: "nalgebra::Matrix2::transpose",
+        checks: &[&registry::F64_MAT2_TRANSPOSE],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 matrix2 determinant",
+        reference: "nalgebra::Matrix2::determinant",
+        checks: &[&registry::F64_MAT2_DET],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 matrix2 inverse",
+        reference: "nalgebra::Matrix2::try_inverse",
+        checks: &[&registry::F64_MAT2_INVERSE],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 matrix2 mul matrix2",
+        reference: "nalgebra::Matrix2::mul",
+        checks: &[&registry::F64_MAT2_MUL_MAT2],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 matrix2 mul vector2",
+        reference: "nalgebra::Matrix2::mul",
+        checks: &[&registry::F64_MAT2_MUL_VEC2],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 matrix3 transpose",
+        reference: "nalgebra::Matrix3::transpose",
+        checks: &[&registry::F64_MAT3_TRANSPOSE],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 matrix3 determinant",
+        reference: "nalgebra::Matrix3::determinant",
+        checks: &[&registry::F64_MAT3_DET],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 matrix3 inverse",
+        reference: "nalgebra::Matrix3::try_inverse",
+        checks: &[&registry::F64_MAT3_INVERSE],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 matrix3 mul matrix3",
+        reference: "nalgebra::Matrix3::mul",
+        checks: &[&registry::F64_MAT3_MUL_MAT3],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 matrix3 mul vector3",
+        reference: "nalgebra::Matrix3::mul",
+        checks: &[&registry::F64_MAT3_MUL_VEC3],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 matrix4 transpose",
+        reference: "nalgebra::Matrix4::transpose",
+        checks: &[&registry::F64_MAT4_TRANSPOSE],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 matrix4 determinant",
+        reference: "nalgebra::Matrix4::determinant",
+        checks: &[&registry::F64_MAT4_DET],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 matrix4 inverse",
+        reference: "nalgebra::Matrix4::try_inverse",
+        checks: &[&registry::F64_MAT4_INVERSE],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 matrix4 mul matrix4",
+        reference: "nalgebra::Matrix4::mul",
+        checks: &[&registry::F64_MAT4_MUL_MAT4],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 matrix4 mul vector4",
+        reference: "nalgebra::Matrix4::mul",
+        checks: &[&registry::F64_MAT4_MUL_VEC4],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "quaternion conjugate",
+        reference: "nalgebra::UnitQuaternion::conjugate",
+        checks: &[&registry::QUAT_CONJUGATE],
+        wide: &[
+            "ultraviolet_f32x4::quaternion_conjugate",
+            "nalgebra_f32x4::quaternion_conjugate",
+            "nalgebra_f32x8::quaternion_conjugate",
+            "nalgebra_f32x16::quaternion_conjugate",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "quaternion mul quaternion",
+        reference: "nalgebra::UnitQuaternion::mul",
+        checks: &[&registry::QUAT_MUL_QUAT],
+        wide: &[
+            "ultraviolet_f32x4::quaternion_mul_quaternion",
+            "nalgebra_f32x4::quaternion_mul_quaternion",
+            "nalgebra_f32x8::quaternion_mul_quaternion",
+            "nalgebra_f32x16::quaternion_mul_quaternion",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "quaternion mul vector3",
+        reference: "nalgebra::UnitQuaternion::mul",
+        checks: &[&registry::QUAT_MUL_VEC3],
+        wide: &[
+            "ultraviolet_f32x4::quaternion_mul_vector3",
+            "nalgebra_f32x4::quaternion_mul_vector3",
+            "nalgebra_f32x8::quaternion_mul_vector3",
+            "nalgebra_f32x16::quaternion_mul_vector3",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 quaternion conjugate",
+        reference: "nalgebra::UnitQuaternion::conjugate",
+        checks: &[&registry::F64_QUAT_CONJUGATE],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 quaternion mul quaternion",
+        reference: "nalgebra::UnitQuaternion::mul",
+        checks: &[&registry::F64_QUAT_MUL_QUAT],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 quaternion mul vector3",
+        reference: "nalgebra::UnitQuaternion::mul",
+        checks: &[&registry::F64_QUAT_MUL_VEC3],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "rotation2 inverse",
+        reference: "nalgebra::UnitComplex::inverse",
+        checks: &[&registry::ROTATION2_INVERSE],
+        wide: &[
+            "ultraviolet_f32x4::rotation2_inverse",
+            "nalgebra_f32x4::rotation2_inverse",
+            "nalgebra_f32x8::rotation2_inverse",
+            "nalgebra_f32x16::rotation2_inverse",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "rotation2 mul rotation2",
+        reference: "nalgebra::UnitComplex::mul",
+        checks: &[&registry::ROTATION2_MUL_ROTATION2],
+        wide: &[
+            "ultraviolet_f32x4::rotation2_mul_rotation2",
+            "nalgebra_f32x4::rotation2_mul_rotation2",
+            "nalgebra_f32x8::rotation2_mul_rotation2",
+            "nalgebra_f32x16::rotation2_mul_rotation2",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "rotation2 rotate vector2",
+        reference: "nalgebra::UnitComplex::transform_vector",
+        checks: &[&registry::ROTATION2_ROTATE_VECTOR2],
+        wide: &[
+            "ultraviolet_f32x4::rotation2_rotate_vector2",
+            "nalgebra_f32x4::rotation2_rotate_vector2",
+            "nalgebra_f32x8::rotation2_rotate_vector2",
+            "nalgebra_f32x16::rotation2_rotate_vector2",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "rotation2 slerp",
+        reference: "nalgebra::UnitComplex::powf",
+        checks: &[&registry::ROTATION2_SLERP],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "rotation2 from angle",
+        reference: "nalgebra::UnitComplex::new",
+        checks: &[&registry::ROTATION2_FROM_ANGLE],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "similarity3 inverse",
+        reference: "nalgebra::Similarity3::inverse",
+        checks: &[&registry::SIMILARITY3_INVERSE],
+        wide: &[],
+        known_incorrect: &["ultraviolet"],
+    },
+    BenchOp {
+        group: "similarity3 mul similarity3",
+        reference: "nalgebra::Similarity3::mul",
+        checks: &[&registry::SIMILARITY3_MUL_SIMILARITY3],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "similarity3 transform point3",
+        reference: "nalgebra::Similarity3::transform_point",
+        checks: &[&registry::SIMILARITY3_TRANSFORM_POINT3],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "similarity3 transform vector3",
+        reference: "nalgebra::Similarity3::transform_vector",
+        checks: &[&registry::SIMILARITY3_TRANSFORM_VECTOR3],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "similarity3 interpolate",
+        reference: "nalgebra::UnitQuaternion::nlerp",
+        checks: &[&registry::SIMILARITY3_INTERPOLATE],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "similarity3 to matrix4",
+        reference: "nalgebra::Similarity3::to_homogeneous",
+        checks: &[&registry::SIMILARITY3_TO_MATRIX4],
+        wide: &[],
+        known_incorrect: &["ultraviolet"],
+    },
+    BenchOp {
+        group: "transform2 inverse",
+        reference: "nalgebra::Transform2::try_inverse",
+        checks: &[&registry::TRANSFORM2_INVERSE],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "transform2 mul transform2",
+        reference: "nalgebra::Transform2::mul",
+        checks: &[&registry::TRANSFORM2_MUL_TRANSFORM2],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "transform3 inverse",
+        reference: "nalgebra::Transform3::try_inverse",
+        checks: &[&registry::TRANSFORM3_INVERSE],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "transform3 mul transform3d",
+        reference: "nalgebra::Transform3::mul",
+        checks: &[&registry::TRANSFORM3_MUL_TRANSFORM3],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "transform vector3",
+        reference: "nalgebra::Transform3::transform_vector",
+        checks: &[&registry::TRANSFORM_VECTOR3],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "transform point3",
+        reference: "nalgebra::Transform3::transform_point",
+        checks: &[&registry::TRANSFORM_POINT3],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "transform point3 slice",
+        reference: "nalgebra::Matrix4::transform_point",
+        checks: &[&registry::TRANSFORM_POINT3, &registry::MAT4_PROJECT_POINT3],
+        wide: &[
+            "ultraviolet_f32x4::matrix4_transform_point3",
+            "nalgebra_f32x4::matrix4_transform_point3",
+            "nalgebra_f32x8::matrix4_transform_point3",
+            "nalgebra_f32x16::matrix4_transform_point3",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "vector3 aos to soa",
+        reference: "nalgebra_f32x4::Vector3::from",
+        checks: &[],
+        wide: &[
+            "ultraviolet_f32x4::vector3_aos_to_soa",
+            "nalgebra_f32x4::vector3_aos_to_soa",
+            "nalgebra_f32x8::vector3_aos_to_soa",
+            "nalgebra_f32x16::vector3_aos_to_soa",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "vector3 soa to aos",
+        reference: "nalgebra_f32x4::Vector3::extract",
+        checks: &[],
+        wide: &[
+            "ultraviolet_f32x4::vector3_soa_to_aos",
+            "nalgebra_f32x4::vector3_soa_to_aos",
+            "nalgebra_f32x8::vector3_soa_to_aos",
+            "nalgebra_f32x16::vector3_soa_to_aos",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "transform point3 aos via soa",
+        reference: "nalgebra_f32x4::SimdMatrix4Ext::transform_point3",
+        checks: &[&registry::TRANSFORM_POINT3],
+        wide: &[
+            "ultraviolet_f32x4::vector3_aos_to_soa",
+            "ultraviolet_f32x4::matrix4_transform_point3",
+            "ultraviolet_f32x4::vector3_soa_to_aos",
+            "nalgebra_f32x4::vector3_aos_to_soa",
+            "nalgebra_f32x4::matrix4_transform_point3",
+            "nalgebra_f32x4::vector3_soa_to_aos",
+            "nalgebra_f32x8::vector3_aos_to_soa",
+            "nalgebra_f32x8::matrix4_transform_point3",
+            "nalgebra_f32x8::vector3_soa_to_aos",
+            "nalgebra_f32x16::vector3_aos_to_soa",
+            "nalgebra_f32x16::matrix4_transform_point3",
+            "nalgebra_f32x16::vector3_soa_to_aos",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "transform point2",
+        reference: "nalgebra::Transform2::transform_point",
+        checks: &[&registry::TRANSFORM_POINT2],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "transform vector2",
+        reference: "nalgebra::Transform2::transform_vector",
+        checks: &[&registry::TRANSFORM_VECTOR2],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 transform vector3",
+        reference: "nalgebra::Transform3::transform_vector",
+        checks: &[&registry::F64_TRANSFORM_VECTOR3],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 transform point3",
+        reference: "nalgebra::Transform3::transform_point",
+        checks: &[&registry::F64_TRANSFORM_POINT3],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 transform point2",
+        reference: "nalgebra::Transform2::transform_point",
+        checks: &[&registry::F64_TRANSFORM_POINT2],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 transform vector2",
+        reference: "nalgebra::Transform2::transform_vector",
+        checks: &[&registry::F64_TRANSFORM_VECTOR2],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 transform2 inverse",
+        reference: "nalgebra::Transform2::try_inverse",
+        checks: &[&registry::F64_TRANSFORM2_INVERSE],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 transform2 mul transform2",
+        reference: "nalgebra::Transform2::mul",
+        checks: &[&registry::F64_TRANSFORM2_MUL_TRANSFORM2],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 transform3 inverse",
+        reference: "nalgebra::Transform3::try_inverse",
+        checks: &[&registry::F64_TRANSFORM3_INVERSE],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 transform3 mul transform3d",
+        reference: "nalgebra::Transform3::mul",
+        checks: &[&registry::F64_TRANSFORM3_MUL_TRANSFORM3],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "vector3 length",
+        reference: "nalgebra::Vector3::magnitude",
+        checks: &[&registry::VEC3_LENGTH],
+        wide: &[
+            "ultraviolet_f32x4::vector3_length",
+            "nalgebra_f32x4::vector3_length",
+            "nalgebra_f32x8::vector3_length",
+            "nalgebra_f32x16::vector3_length",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "vector3 normalize",
+        reference: "nalgebra::Vector3::normalize",
+        checks: &[&registry::VEC3_NORMALIZE],
+        wide: &[
+            "ultraviolet_f32x4::vector3_normalize",
+            "nalgebra_f32x4::vector3_normalize",
+            "nalgebra_f32x8::vector3_normalize",
+            "nalgebra_f32x16::vector3_normalize",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "vector3 dot",
+        reference: "nalgebra::Vector3::dot",
+        checks: &[&registry::VEC3_DOT],
+        wide: &[
+            "ultraviolet_f32x4::vector3_dot",
+            "nalgebra_f32x4::vector3_dot",
+            "nalgebra_f32x8::vector3_dot",
+            "nalgebra_f32x16::vector3_dot",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "vector3 cross",
+        reference: "nalgebra::Vector3::cross",
+        checks: &[&registry::VEC3_CROSS],
+        wide: &[
+            "ultraviolet_f32x4::vector3_cross",
+            "nalgebra_f32x4::vector3_cross",
+            "nalgebra_f32x8::vector3_cross",
+            "nalgebra_f32x16::vector3_cross",
+        ],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 vector3 length",
+        reference: "nalgebra::Vector3::magnitude",
+        checks: &[&registry::F64_VEC3_LENGTH],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 vector3 normalize",
+        reference: "nalgebra::Vector3::normalize",
+        checks: &[&registry::F64_VEC3_NORMALIZE],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 vector3 dot",
+        reference: "nalgebra::Vector3::dot",
+        checks: &[&registry::F64_VEC3_DOT],
+        wide: &[],
+        known_incorrect: &[],
+    },
+    BenchOp {
+        group: "f64 vector3 cross",
+        reference: "nalgebra::Vector3::cross",
+        checks: &[&registry::F64_VEC3_CROSS],
+        wide: &[],
+        known_incorrect: &[],
+    },
+];
+
+/// The benchmarked libraries, the wide types are only tested by the `wide.rs`
+/// module named after them.
+const LIBS: &[&str] = &[
+    "glam",
+    "cgmath",
+    "euclid",
+    "ultraviolet",
+    "ultraviolet_f32x4",
+    "nalgebra",
+    "nalgebra_f32x4",
+    "nalgebra_f32x8",
+    "nalgebra_f32x16",
+    "nalgebra_glm",
+    "vek",
+    "pathfinder",
+    "reference",
+    "intrinsics",
+    "intrinsics_avx",
+];
+
+const WIDE_LIBS: &[&str] = &[
+    "ultraviolet_f32x4",
+    "nalgebra_f32x4",
+    "nalgebra_f32x8",
+    "nalgebra_f32x16",
+];
+
+/// Returns the libraries benchmarked in each benchmark group.
+fn parse_benches(dir: &Path) -> BTreeMap<String, BTreeSet<String>> {
+    let mut groups = BTreeMap::new();
+    let mut paths: Vec<_> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().map_or(false, |ext| ext == "rs"))
+        .collect();
+    paths.sort();
+    for path in paths {
+        let source = fs::read_to_string(&path).unwrap();
+        let mut group: Option<String> = None;
+        for line in source.lines() {
+            let line = line.trim();
+            if line.starts_with("//") {
+                continue;
+            }
+            if let Some(start) = line.find("benchmark_group!(c, \"") {
+                let rest = &line[start + "benchmark_group!(c, \"".len()..];
+                let name = rest[..rest.find('"').unwrap()].to_string();
+                groups.entry(name.clone()).or_insert_with(BTreeSet::new);
+                group = Some(name);
+            } else if line.starts_with("bench_") {
+                if let Some(end) = line.find("!(") {
+                    let lib = &line["bench_".len()..end];
+                    if LIBS.contains(&lib) {
+                        let name = group.as_ref().unwrap_or_else(|| {
+                            panic!("{}: bench_{}! outside of a group", path.display(), lib)
+                        });
+                        groups.get_mut(name).unwrap().insert(lib.to_string());
+                    }
+                }
+            }
+        }
+    }
+    groups
+}
+
+/// Returns the signature and body of `fn name` in `source`.
+fn fn_source<'a>(source: &'a str, name: &str) -> Option<&'a str> {
+    let start = source.find(&format!("fn {}(", name))?;
+    let open = start + source[start..].find('{')?;
+    let body = bracketed(source, open);
+    Some(&source[start..open + body.len() + 2])
+}
+
+/// Returns the wide library tested by `test`, or `None` after recording an
+/// error if the test doesn't exist.
+fn check_wide(wide_source: &str, test: &str, errors: &mut Vec<String>) -> Option<&'static str> {
+    let (module, name) = match test.split("::").collect::<Vec<_>>().as_slice() {
+        [module, name] => (*module, *name),
+        _ => {
+            errors.push(format!("malformed wide test path `{}`", test));
+            return None;
+        }
+    };
+    let lib = match WIDE_LIBS.iter().find(|lib| **lib == module) {
+        Some(lib) => *lib,
+        None => {
+            errors.push(format!("`{}`: `{}` isn't a wide library", test, module));
+            return None;
+        }
+    };
+    if !(wide_source.contains(&format!("mod {} ", module))
+        || wide_source.contains(&format!("({},", module)))
+    {
+        errors.push(format!("`{}`: no such test module", test));
+        return None;
+    }
+    if fn_source(wide_source, name).is_none() {
+        errors.push(format!("`{}`: no such test function", test));
+        return None;
+    }
+    Some(lib)
+}
+
+/// Returns whether `source` names `registry::<name>`.
+fn consumes(source: &str, name: &str) -> bool {
+    let path = format!("registry::{}", name);
+    source.match_indices(&path).any(|(start, _)| {
+        !source[start + path.len()..].starts_with(|c: char| c.is_alphanumeric() || c == '_')
+    })
+}
+
+/// Returns the sources of the test files, other than this one.
+fn test_sources(dir: &Path) -> Vec<String> {
+    fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            path.extension().map_or(false, |ext| ext == "rs")
+                && path.file_name().map_or(false, |name| name != "coverage.rs")
+        })
+        .map(|path| fs::read_to_string(path).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_bench_coverage() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let benched = parse_benches(&root.join("benches"));
+    let wide_source = fs::read_to_string(root.join("tests/wide.rs")).unwrap();
+    let sources = test_sources(&root.join("tests"));
+    let mut errors = Vec::new();
+    let mut used = BTreeSet::new();
+
+    let mut registered = BTreeSet::new();
+    for op in BENCH_OPS {
+        if !registered.insert(op.group) {
+            errors.push(format!("\"{}\": registered more than once", op.group));
+        }
+        let libs = match benched.get(op.group) {
+            Some(libs) => libs,
+            None => {
+                errors.push(format!("\"{}\": registered but not benchmarked", op.group));
+                continue;
+            }
+        };
+        let mut listed = vec![op.reference.split("::").next().unwrap()];
+        listed.extend(op.known_incorrect.iter().copied());
+        for lib in &listed {
+            if !libs.contains(*lib) {
+                errors.push(format!(
+                    "\"{}\": `{}` is listed but not benchmarked",
+                    op.group, lib
+                ));
+            }
+        }
+        let mut covered: BTreeSet<&str> = listed.into_iter().collect();
+        for check in op.checks {
+            used.insert(check.name);
+            if !check.libs.iter().any(|lib| libs.contains(*lib)) {
+                errors.push(format!(
+                    "\"{}\": `{}` checks none of the benchmarked libraries",
+                    op.group, check.name
+                ));
+            }
+            covered.extend(check.libs.iter().copied());
+        }
+        for test in op.wide {
+            if let Some(lib) = check_wide(&wide_source, test, &mut errors) {
+                if !libs.contains(lib) {
+                    errors.push(format!(
+                        "\"{}\": `{}` checks none of the benchmarked libraries",
+                        op.group, test
+                    ));
+                }
+                covered.insert(lib);
+            }
+        }
+        for lib in libs {
+            if !covered.contains(lib.as_str()) {
+                errors.push(format!(
+                    "\"{}\": `{}` has no correctness check",
+                    op.group, lib
+                ));
+            }
+        }
+    }
+
+    for group in benched.keys() {
+        if !group.ends_with("return self") && !registered.contains(group.as_str()) {
+            errors.push(format!("\"{}\": benchmarked but not registered", group));
+        }
+    }
+
+    for check in registry::ALL {
+        for lib in check.libs {
+            if !LIBS.contains(lib) {
+                errors.push(format!("`{}`: `{}` isn't a library", check.name, lib));
+            }
+        }
+        if !used.contains(check.name) {
+            errors.push(format!("`{}`: registered but not benchmarked", check.name));
+        }
+        if !sources.iter().any(|source| consumes(source, check.name)) {
+            errors.push(format!("`{}`: registered but no test runs it", check.name));
+        }
+    }
+
+    assert!(
+        errors.is_empty(),
+        "benchmark coverage errors:\n  {}",
+        errors.join("\n  ")
+    );
+}
+
+/// Splits `s` at the commas that aren't inside brackets.
+fn split_args(s: &str) -> Vec<&str> {
+    let (mut depth, mut start, mut args) = (0, 0, Vec::new());
+    for (i, c) in s.char_indices() {
+        match c {
+            '<' | '(' | '[' | '{' => depth += 1,
+            '>' | ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    args.push(&s[start..]);
+    args
+}
+
+/// Returns the contents of the brackets that open at `start`.
+fn bracketed(s: &str, start: usize) -> &str {
+    let mut depth = 0;
+    for (i, c) in s[start..].char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return &s[start + 1..start + i];
+                }
+            }
+            _ => {}
+        }
+    }
+    panic!("unbalanced brackets");
+}
+
+fn strip_whitespace(s: &str) -> String {
+    s.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// Returns the types that implement `BenchValue` in `src/lib.rs`, other than
+/// `f32` and `f64`.
+fn parse_bench_values(source: &str) -> BTreeSet<String> {
+    let source = strip_whitespace(source);
+    let mut types = BTreeSet::new();
+    for (start, _) in source.match_indices("impl_bench_value!(") {
+        let args = bracketed(&source, start + "impl_bench_value!".len());
+        types.insert(split_args(args)[0].to_string());
+    }
+    // types in `<lib>_support` modules that implement the trait directly are
+    // imported from `<lib>`
+    for (start, _) in source.match_indices("implBenchValuefor") {
+        let rest = &source[start + "implBenchValuefor".len()..];
+        let name = &rest[..rest.find('{').unwrap()];
+        if let Some(module) = source[..start].rfind("pubmod") {
+            let module = &source[module + "pubmod".len()..];
+            let lib = &module[..module.find("_support").unwrap()];
+            types.insert(format!("{}::{}", lib, name));
+        }
+    }
+    types
+}
+
+/// Whether the features that `BenchValue` is implemented for `path` with are
+/// enabled. The other libraries are default features, which the tests need.
+fn bench_value_enabled(path: &str) -> bool {
+    let lib = path.trim_start_matches('(').split("::").next().unwrap();
+    match lib {
+        "pathfinder_geometry" => cfg!(feature = "pathfinder_geometry"),
+        "nalgebra_glm" => cfg!(feature = "nalgebra_glm"),
+        "intrinsics" => cfg!(all(feature = "intrinsics", target_arch = "x86_64")),
+        "nalgebra" if path.contains("f32x4") => cfg!(feature = "nalgebra_f32x4"),
+        "nalgebra" if path.contains("f32x8") => cfg!(feature = "nalgebra_f32x8"),
+        "nalgebra" if path.contains("f32x16") => cfg!(feature = "nalgebra_f32x16"),
+        _ => true,
+    }
+}
+
+#[test]
+fn test_layout_coverage() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let bench_values = parse_bench_values(&fs::read_to_string(root.join("src/lib.rs")).unwrap());
+    // `layout::measure` only accepts `BenchValue` types, so the compiler checks
+    // the other direction
+    let layouts: BTreeSet<String> = layout::layouts()
+        .into_iter()
+        .map(|layout| layout.path)
+        .collect();
+    let errors: Vec<String> = bench_values
+        .iter()
+        .filter(|name| bench_value_enabled(name) && !layouts.contains(*name))
+        .map(|name| format!("`{}`: implements `BenchValue` but has no layout", name))
+        .collect();
+
+    assert!(
+        errors.is_empty(),
+        "layout coverage errors:\n  {}",
+        errors.join("\n  ")
+    );
+}
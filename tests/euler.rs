@@ -0,0 +1,233 @@
+mod support;
+use cgmath;
+use glam;
+use mathbench::mint_support::{random_mint_vec2, random_mint_vec3};
+use mathbench::reference;
+use nalgebra;
+use rand_pcg::Pcg64Mcg;
+use support::{registry, FromMint};
+
+const NUM_ITERS: usize = 1024;
+
+const UPDATE_RATE: f32 = 1.0 / 60.0;
+
+// a single semi-implicit euler integration step as run by the euler benchmarks,
+// returns the updated position and velocity
+macro_rules! euler_step {
+    ($pos:expr, $vel:expr, $acc:expr, $dt:expr) => {{
+        let vel = $vel + $acc * $dt;
+        ($pos + vel * $dt, vel)
+    }};
+}
+
+fn euler_2d_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mp = random_mint_vec2(&mut rng);
+    let mv = random_mint_vec2(&mut rng);
+    let ma = random_mint_vec2(&mut rng);
+
+    let (gp, gv) = euler_step!(
+        glam::Vec2::from(mp),
+        glam::Vec2::from(mv),
+        glam::Vec2::from(ma),
+        glam::Vec2::splat(UPDATE_RATE)
+    );
+
+    let (np, nv) = euler_step!(
+        nalgebra::Vector2::<f32>::from(mp),
+        nalgebra::Vector2::<f32>::from(mv),
+        nalgebra::Vector2::<f32>::from(ma),
+        UPDATE_RATE
+    );
+
+    let (cp, cv) = euler_step!(
+        cgmath::Vector2::<f32>::from(mp),
+        cgmath::Vector2::<f32>::from(mv),
+        cgmath::Vector2::<f32>::from(ma),
+        UPDATE_RATE
+    );
+
+    let (up, uv) = euler_step!(
+        ultraviolet::Vec2::from_mint(mp),
+        ultraviolet::Vec2::from_mint(mv),
+        ultraviolet::Vec2::from_mint(ma),
+        UPDATE_RATE
+    );
+
+    let (ep, ev) = euler_step!(
+        euclid::Vector2D::<f32, euclid::UnknownUnit>::from(mp),
+        euclid::Vector2D::<f32, euclid::UnknownUnit>::from(mv),
+        euclid::Vector2D::<f32, euclid::UnknownUnit>::from(ma),
+        UPDATE_RATE
+    );
+
+    let (vp, vv) = euler_step!(
+        vek::Vec2::<f32>::from(mp),
+        vek::Vec2::<f32>::from(mv),
+        vek::Vec2::<f32>::from(ma),
+        vek::Vec2::broadcast(UPDATE_RATE)
+    );
+
+    let (rp, rv) = euler_step!(
+        reference::Vec2::from(mp),
+        reference::Vec2::from(mv),
+        reference::Vec2::from(ma),
+        UPDATE_RATE
+    );
+
+    // use nalgebra as assumed correct answer
+    let mp1: mint::Vector2<f32> = np.into();
+    let mv1: mint::Vector2<f32> = nv.into();
+
+    assert_ulps_eq!(cgmath => cp, mp1.into(), epsilon = 1e-6);
+    assert_ulps_eq!(cgmath => cv, mv1.into(), epsilon = 1e-6);
+    assert_ulps_eq!(glam => gp, mp1.into(), epsilon = 1e-6);
+    assert_ulps_eq!(glam => gv, mv1.into(), epsilon = 1e-6);
+    assert_ulps_eq!(ultraviolet => up, ultraviolet::Vec2::from_mint(mp1), epsilon = 1e-6);
+    assert_ulps_eq!(ultraviolet => uv, ultraviolet::Vec2::from_mint(mv1), epsilon = 1e-6);
+    assert_ulps_eq!(euclid => ep, mp1.into(), epsilon = 1e-6);
+    assert_ulps_eq!(euclid => ev, mv1.into(), epsilon = 1e-6);
+    assert_ulps_eq!(vek => vp, mp1.into(), epsilon = 1e-6);
+    assert_ulps_eq!(vek => vv, mv1.into(), epsilon = 1e-6);
+    assert_ulps_eq!(reference => rp, mp1.into(), epsilon = 1e-6);
+    assert_ulps_eq!(reference => rv, mv1.into(), epsilon = 1e-6);
+
+    #[cfg(feature = "pathfinder_geometry")]
+    {
+        use pathfinder_geometry::vector::Vector2F;
+        let (pp, pv) = euler_step!(
+            Vector2F::from_mint(mp),
+            Vector2F::from_mint(mv),
+            Vector2F::from_mint(ma),
+            Vector2F::splat(UPDATE_RATE)
+        );
+        assert_ulps_eq!(pathfinder => pp, Vector2F::from_mint(mp1), epsilon = 1e-6);
+        assert_ulps_eq!(pathfinder => pv, Vector2F::from_mint(mv1), epsilon = 1e-6);
+    }
+}
+
+fn euler_3d_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mp = random_mint_vec3(&mut rng);
+    let mv = random_mint_vec3(&mut rng);
+    let ma = random_mint_vec3(&mut rng);
+
+    let (gp, gv) = euler_step!(
+        glam::Vec3::from(mp),
+        glam::Vec3::from(mv),
+        glam::Vec3::from(ma),
+        glam::Vec3::splat(UPDATE_RATE)
+    );
+
+    let (np, nv) = euler_step!(
+        nalgebra::Vector3::<f32>::from(mp),
+        nalgebra::Vector3::<f32>::from(mv),
+        nalgebra::Vector3::<f32>::from(ma),
+        UPDATE_RATE
+    );
+
+    let (cp, cv) = euler_step!(
+        cgmath::Vector3::<f32>::from(mp),
+        cgmath::Vector3::<f32>::from(mv),
+        cgmath::Vector3::<f32>::from(ma),
+        UPDATE_RATE
+    );
+
+    let (up, uv) = euler_step!(
+        ultraviolet::Vec3::from_mint(mp),
+        ultraviolet::Vec3::from_mint(mv),
+        ultraviolet::Vec3::from_mint(ma),
+        UPDATE_RATE
+    );
+
+    let (ep, ev) = euler_step!(
+        euclid::Vector3D::<f32, euclid::UnknownUnit>::from(mp),
+        euclid::Vector3D::<f32, euclid::UnknownUnit>::from(mv),
+        euclid::Vector3D::<f32, euclid::UnknownUnit>::from(ma),
+        UPDATE_RATE
+    );
+
+    let (vp, vv) = euler_step!(
+        vek::Vec3::<f32>::from(mp),
+        vek::Vec3::<f32>::from(mv),
+        vek::Vec3::<f32>::from(ma),
+        vek::Vec3::broadcast(UPDATE_RATE)
+    );
+
+    let (rp, rv) = euler_step!(
+        reference::Vec3::from(mp),
+        reference::Vec3::from(mv),
+        reference::Vec3::from(ma),
+        UPDATE_RATE
+    );
+
+    // use nalgebra as assumed correct answer
+    let mp1: mint::Vector3<f32> = np.into();
+    let mv1: mint::Vector3<f32> = nv.into();
+
+    assert_ulps_eq!(cgmath => cp, mp1.into(), epsilon = 1e-6);
+    assert_ulps_eq!(cgmath => cv, mv1.into(), epsilon = 1e-6);
+    assert_ulps_eq!(glam => gp, mp1.into(), epsilon = 1e-6);
+    assert_ulps_eq!(glam => gv, mv1.into(), epsilon = 1e-6);
+    assert_ulps_eq!(ultraviolet => up, ultraviolet::Vec3::from_mint(mp1), epsilon = 1e-6);
+    assert_ulps_eq!(ultraviolet => uv, ultraviolet::Vec3::from_mint(mv1), epsilon = 1e-6);
+    assert_ulps_eq!(euclid => ep, mp1.into(), epsilon = 1e-6);
+    assert_ulps_eq!(euclid => ev, mv1.into(), epsilon = 1e-6);
+    assert_ulps_eq!(vek => vp, mp1.into(), epsilon = 1e-6);
+    assert_ulps_eq!(vek => vv, mv1.into(), epsilon = 1e-6);
+    assert_ulps_eq!(reference => rp, mp1.into(), epsilon = 1e-6);
+    assert_ulps_eq!(reference => rv, mv1.into(), epsilon = 1e-6);
+}
+
+// pathfinder doesn't have a 3D vector so the benchmark uses `Vector4F`
+#[cfg(feature = "pathfinder_geometry")]
+fn euler_3d_pathfinder_compare() {
+    use mathbench::mint_support::random_mint_vec4;
+    use pathfinder_geometry::vector::Vector4F;
+
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mp = random_mint_vec4(&mut rng);
+    let mv = random_mint_vec4(&mut rng);
+    let ma = random_mint_vec4(&mut rng);
+
+    let (np, nv) = euler_step!(
+        nalgebra::Vector4::<f32>::from(mp),
+        nalgebra::Vector4::<f32>::from(mv),
+        nalgebra::Vector4::<f32>::from(ma),
+        UPDATE_RATE
+    );
+
+    let (pp, pv) = euler_step!(
+        Vector4F::from_mint(mp),
+        Vector4F::from_mint(mv),
+        Vector4F::from_mint(ma),
+        Vector4F::splat(UPDATE_RATE)
+    );
+
+    // use nalgebra as assumed correct answer
+    let mp1: mint::Vector4<f32> = np.into();
+    let mv1: mint::Vector4<f32> = nv.into();
+
+    assert_ulps_eq!(pathfinder => pp, Vector4F::from_mint(mp1), epsilon = 1e-6);
+    assert_ulps_eq!(pathfinder => pv, Vector4F::from_mint(mv1), epsilon = 1e-6);
+}
+
+#[test]
+fn test_euler_2d() {
+    support::compare(&registry::EULER_2D, || {
+        for _ in 0..NUM_ITERS {
+            euler_2d_compare();
+        }
+    });
+}
+
+#[test]
+fn test_euler_3d() {
+    support::compare(&registry::EULER_3D, || {
+        for _ in 0..NUM_ITERS {
+            euler_3d_compare();
+            #[cfg(feature = "pathfinder_geometry")]
+            euler_3d_pathfinder_compare();
+        }
+    });
+}
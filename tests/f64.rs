@@ -0,0 +1,905 @@
+//! Compares the `f64` benchmarked operations against `nalgebra`.
+//!
+//! Results are converted to `mint` types for comparison as the `FloatCompare`
+//! implementations for library types are `f32` only.
+mod support;
+use cgmath::{self, InnerSpace, Matrix, SquareMatrix};
+use mathbench::mint_support::*;
+use nalgebra;
+use rand_pcg::Pcg64Mcg;
+use support::registry;
+
+const NUM_ITERS: usize = 1024;
+
+type EuclidDTransform2D = euclid::Transform2D<f64, euclid::UnknownUnit, euclid::UnknownUnit>;
+type EuclidDTransform3D = euclid::Transform3D<f64, euclid::UnknownUnit, euclid::UnknownUnit>;
+type EuclidDRotation3D = euclid::Rotation3D<f64, euclid::UnknownUnit, euclid::UnknownUnit>;
+type EuclidDVector2D = euclid::Vector2D<f64, euclid::UnknownUnit>;
+type EuclidDVector3D = euclid::Vector3D<f64, euclid::UnknownUnit>;
+
+// euclid transforms use row vectors so the column major mint matrix is
+// transposed
+fn euclid_dtransform2(m: mint::ColumnMatrix3<f64>) -> EuclidDTransform2D {
+    EuclidDTransform2D::row_major(m.x.x, m.x.y, m.y.x, m.y.y, m.z.x, m.z.y)
+}
+
+fn euclid_dtransform3(m: mint::ColumnMatrix4<f64>) -> EuclidDTransform3D {
+    EuclidDTransform3D::row_major(
+        m.x.x, m.x.y, m.x.z, m.x.w, m.y.x, m.y.y, m.y.z, m.y.w, m.z.x, m.z.y, m.z.z, m.z.w, m.w.x,
+        m.w.y, m.w.z, m.w.w,
+    )
+}
+
+fn euclid_drotation3(q: mint::Quaternion<f64>) -> EuclidDRotation3D {
+    EuclidDRotation3D::quaternion(q.v.x, q.v.y, q.v.z, q.s)
+}
+
+fn mint_from_euclid_dtransform2(e: EuclidDTransform2D) -> mint::ColumnMatrix3<f64> {
+    let a = e.to_row_major_array();
+    mint::ColumnMatrix3 {
+        x: [a[0], a[1], 0.0].into(),
+        y: [a[2], a[3], 0.0].into(),
+        z: [a[4], a[5], 1.0].into(),
+    }
+}
+
+fn mint_from_euclid_dtransform3(e: EuclidDTransform3D) -> mint::ColumnMatrix4<f64> {
+    nalgebra::Matrix4::from_column_slice(&e.to_row_major_array()).into()
+}
+
+fn mint_from_euclid_drotation3(e: EuclidDRotation3D) -> mint::Quaternion<f64> {
+    mint::Quaternion {
+        v: [e.i, e.j, e.k].into(),
+        s: e.r,
+    }
+}
+
+// the random quaternion is generated as f32 so it's normalized again in f64
+// precision, otherwise it won't match nalgebra which normalizes unit quaternions
+fn random_unit_dquat(rng: &mut Pcg64Mcg) -> mint::Quaternion<f64> {
+    nalgebra::UnitQuaternion::from_quaternion(random_mint_dquat(rng).into()).into()
+}
+
+fn mat2_transpose_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_invertible_dmat2(&mut rng);
+
+    let nmt = nalgebra::Matrix2::<f64>::from(mm).transpose();
+    let cmt: mint::ColumnMatrix2<f64> = cgmath::Matrix2::<f64>::from(mm).transpose().into();
+    let vmt: mint::ColumnMatrix2<f64> = vek::Mat2::<f64>::from(mm).transposed().into();
+
+    // use nalgebra as assumed correct answer
+    let mmt: mint::ColumnMatrix2<f64> = nmt.into();
+
+    assert_ulps_eq!(cgmath => cmt, mmt, epsilon = 0.0);
+    assert_ulps_eq!(vek => vmt, mmt, epsilon = 0.0);
+}
+
+fn mat3_transpose_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_homogeneous_dmat3(&mut rng);
+
+    let nmt = nalgebra::Matrix3::<f64>::from(mm).transpose();
+    let cmt: mint::ColumnMatrix3<f64> = cgmath::Matrix3::<f64>::from(mm).transpose().into();
+    let vmt: mint::ColumnMatrix3<f64> = vek::Mat3::<f64>::from(mm).transposed().into();
+
+    // use nalgebra as assumed correct answer
+    let mmt: mint::ColumnMatrix3<f64> = nmt.into();
+
+    assert_ulps_eq!(cgmath => cmt, mmt, epsilon = 0.0);
+    assert_ulps_eq!(vek => vmt, mmt, epsilon = 0.0);
+}
+
+fn mat4_transpose_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_homogeneous_dmat4(&mut rng);
+
+    let nmt = nalgebra::Matrix4::<f64>::from(mm).transpose();
+    let cmt: mint::ColumnMatrix4<f64> = cgmath::Matrix4::<f64>::from(mm).transpose().into();
+    let vmt: mint::ColumnMatrix4<f64> = vek::Mat4::<f64>::from(mm).transposed().into();
+
+    // use nalgebra as assumed correct answer
+    let mmt: mint::ColumnMatrix4<f64> = nmt.into();
+
+    assert_ulps_eq!(cgmath => cmt, mmt, epsilon = 0.0);
+    assert_ulps_eq!(vek => vmt, mmt, epsilon = 0.0);
+}
+
+fn mat2_det_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_invertible_dmat2(&mut rng);
+
+    let nd = nalgebra::Matrix2::<f64>::from(mm).determinant();
+    let cd = cgmath::Matrix2::<f64>::from(mm).determinant();
+    let vd = vek::Mat2::<f64>::from(mm).determinant();
+
+    // use nalgebra as assumed correct answer
+    assert_ulps_eq!(cgmath => cd, nd, epsilon = 1e-12);
+    assert_ulps_eq!(vek => vd, nd, epsilon = 1e-12);
+}
+
+fn mat3_det_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_homogeneous_dmat3(&mut rng);
+
+    let nd = nalgebra::Matrix3::<f64>::from(mm).determinant();
+    let cd = cgmath::Matrix3::<f64>::from(mm).determinant();
+    let vd = vek::Mat3::<f64>::from(mm).determinant();
+
+    // use nalgebra as assumed correct answer
+    assert_ulps_eq!(cgmath => cd, nd, epsilon = 1e-12);
+    assert_ulps_eq!(vek => vd, nd, epsilon = 1e-12);
+}
+
+fn mat4_det_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_homogeneous_dmat4(&mut rng);
+
+    let nd = nalgebra::Matrix4::<f64>::from(mm).determinant();
+    let cd = cgmath::Matrix4::<f64>::from(mm).determinant();
+    let ed = euclid_dtransform3(mm).determinant();
+    let vd = vek::Mat4::<f64>::from(mm).determinant();
+
+    // use nalgebra as assumed correct answer
+    assert_ulps_eq!(cgmath => cd, nd, epsilon = 1e-12);
+    assert_ulps_eq!(euclid => ed, nd, epsilon = 1e-12);
+    assert_ulps_eq!(vek => vd, nd, epsilon = 1e-12);
+}
+
+fn mat2_inverse_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_invertible_dmat2(&mut rng);
+
+    let nmi = nalgebra::Matrix2::<f64>::from(mm).try_inverse();
+    assert!(nmi.is_some());
+
+    let cmi = cgmath::Matrix2::<f64>::from(mm).invert();
+    assert!(cmi.is_some());
+    let cmi: mint::ColumnMatrix2<f64> = cmi.unwrap().into();
+
+    // use nalgebra as assumed correct answer
+    let mmi: mint::ColumnMatrix2<f64> = nmi.unwrap().into();
+
+    assert_ulps_eq!(cgmath => cmi, mmi, epsilon = 1e-9);
+}
+
+fn mat3_inverse_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_homogeneous_dmat3(&mut rng);
+
+    let nmi = nalgebra::Matrix3::<f64>::from(mm).try_inverse();
+    assert!(nmi.is_some());
+
+    let cmi = cgmath::Matrix3::<f64>::from(mm).invert();
+    assert!(cmi.is_some());
+    let cmi: mint::ColumnMatrix3<f64> = cmi.unwrap().into();
+
+    // use nalgebra as assumed correct answer
+    let mmi: mint::ColumnMatrix3<f64> = nmi.unwrap().into();
+
+    assert_ulps_eq!(cgmath => cmi, mmi, epsilon = 1e-9);
+}
+
+fn mat4_inverse_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_homogeneous_dmat4(&mut rng);
+
+    let nmi = nalgebra::Matrix4::<f64>::from(mm).try_inverse();
+    assert!(nmi.is_some());
+
+    let cmi = cgmath::Matrix4::<f64>::from(mm).invert();
+    assert!(cmi.is_some());
+    let cmi: mint::ColumnMatrix4<f64> = cmi.unwrap().into();
+
+    let emi = euclid_dtransform3(mm).inverse();
+    assert!(emi.is_some());
+
+    let vmi: mint::ColumnMatrix4<f64> = vek::Mat4::<f64>::from(mm).inverted().into();
+
+    // use nalgebra as assumed correct answer
+    let mmi: mint::ColumnMatrix4<f64> = nmi.unwrap().into();
+
+    assert_ulps_eq!(cgmath => cmi, mmi, epsilon = 1e-9);
+    assert_ulps_eq!(
+        euclid => mint_from_euclid_dtransform3(emi.unwrap()),
+        mmi,
+        epsilon = 1e-9
+    );
+    assert_ulps_eq!(vek => vmi, mmi, epsilon = 1e-9);
+}
+
+fn mat2_mul_mat2_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_invertible_dmat2(&mut rng);
+    let mm2 = random_mint_invertible_dmat2(&mut rng);
+
+    let nm3 = nalgebra::Matrix2::<f64>::from(mm1) * nalgebra::Matrix2::<f64>::from(mm2);
+    let cm3: mint::ColumnMatrix2<f64> =
+        (cgmath::Matrix2::<f64>::from(mm1) * cgmath::Matrix2::<f64>::from(mm2)).into();
+    let vm3: mint::ColumnMatrix2<f64> =
+        (vek::Mat2::<f64>::from(mm1) * vek::Mat2::<f64>::from(mm2)).into();
+
+    // use nalgebra as assumed correct answer
+    let mm3: mint::ColumnMatrix2<f64> = nm3.into();
+
+    assert_ulps_eq!(cgmath => cm3, mm3, epsilon = 1e-12);
+    assert_ulps_eq!(vek => vm3, mm3, epsilon = 1e-12);
+}
+
+fn mat3_mul_mat3_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_homogeneous_dmat3(&mut rng);
+    let mm2 = random_mint_homogeneous_dmat3(&mut rng);
+
+    let nm3 = nalgebra::Matrix3::<f64>::from(mm1) * nalgebra::Matrix3::<f64>::from(mm2);
+    let cm3: mint::ColumnMatrix3<f64> =
+        (cgmath::Matrix3::<f64>::from(mm1) * cgmath::Matrix3::<f64>::from(mm2)).into();
+    let vm3: mint::ColumnMatrix3<f64> =
+        (vek::Mat3::<f64>::from(mm1) * vek::Mat3::<f64>::from(mm2)).into();
+
+    // use nalgebra as assumed correct answer
+    let mm3: mint::ColumnMatrix3<f64> = nm3.into();
+
+    assert_ulps_eq!(cgmath => cm3, mm3, epsilon = 1e-12);
+    assert_ulps_eq!(vek => vm3, mm3, epsilon = 1e-12);
+}
+
+fn mat4_mul_mat4_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_homogeneous_dmat4(&mut rng);
+    let mm2 = random_mint_homogeneous_dmat4(&mut rng);
+
+    let nm3 = nalgebra::Matrix4::<f64>::from(mm1) * nalgebra::Matrix4::<f64>::from(mm2);
+    let cm3: mint::ColumnMatrix4<f64> =
+        (cgmath::Matrix4::<f64>::from(mm1) * cgmath::Matrix4::<f64>::from(mm2)).into();
+    // euclid uses row vectors so `m2.post_transform(m1)` is `m1 * m2`
+    let em3 = euclid_dtransform3(mm2).post_transform(&euclid_dtransform3(mm1));
+    let vm3: mint::ColumnMatrix4<f64> =
+        (vek::Mat4::<f64>::from(mm1) * vek::Mat4::<f64>::from(mm2)).into();
+
+    // use nalgebra as assumed correct answer
+    let mm3: mint::ColumnMatrix4<f64> = nm3.into();
+
+    assert_ulps_eq!(cgmath => cm3, mm3, epsilon = 1e-12);
+    assert_ulps_eq!(euclid => mint_from_euclid_dtransform3(em3), mm3, epsilon = 1e-12);
+    assert_ulps_eq!(vek => vm3, mm3, epsilon = 1e-12);
+}
+
+fn mat2_mul_vec2_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_invertible_dmat2(&mut rng);
+    let mv = random_mint_dvec2(&mut rng);
+
+    let nmv = nalgebra::Matrix2::<f64>::from(mm) * nalgebra::Vector2::<f64>::from(mv);
+    let cmv: mint::Vector2<f64> =
+        (cgmath::Matrix2::<f64>::from(mm) * cgmath::Vector2::<f64>::from(mv)).into();
+    let vmv: mint::Vector2<f64> = (vek::Mat2::<f64>::from(mm) * vek::Vec2::<f64>::from(mv)).into();
+
+    // use nalgebra as assumed correct answer
+    let mmv: mint::Vector2<f64> = nmv.into();
+
+    assert_ulps_eq!(cgmath => cmv, mmv, epsilon = 1e-12);
+    assert_ulps_eq!(vek => vmv, mmv, epsilon = 1e-12);
+}
+
+fn mat3_mul_vec3_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_homogeneous_dmat3(&mut rng);
+    let mv = random_mint_dvec3(&mut rng);
+
+    let nmv = nalgebra::Matrix3::<f64>::from(mm) * nalgebra::Vector3::<f64>::from(mv);
+    let cmv: mint::Vector3<f64> =
+        (cgmath::Matrix3::<f64>::from(mm) * cgmath::Vector3::<f64>::from(mv)).into();
+    let vmv: mint::Vector3<f64> = (vek::Mat3::<f64>::from(mm) * vek::Vec3::<f64>::from(mv)).into();
+
+    // use nalgebra as assumed correct answer
+    let mmv: mint::Vector3<f64> = nmv.into();
+
+    assert_ulps_eq!(cgmath => cmv, mmv, epsilon = 1e-12);
+    assert_ulps_eq!(vek => vmv, mmv, epsilon = 1e-12);
+}
+
+fn mat4_mul_vec4_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_homogeneous_dmat4(&mut rng);
+    let mv = random_mint_dvec4(&mut rng);
+
+    let nmv = nalgebra::Matrix4::<f64>::from(mm) * nalgebra::Vector4::<f64>::from(mv);
+    let cmv: mint::Vector4<f64> =
+        (cgmath::Matrix4::<f64>::from(mm) * cgmath::Vector4::<f64>::from(mv)).into();
+    let vmv: mint::Vector4<f64> = (vek::Mat4::<f64>::from(mm) * vek::Vec4::<f64>::from(mv)).into();
+
+    // use nalgebra as assumed correct answer
+    let mmv: mint::Vector4<f64> = nmv.into();
+
+    assert_ulps_eq!(cgmath => cmv, mmv, epsilon = 1e-12);
+    assert_ulps_eq!(vek => vmv, mmv, epsilon = 1e-12);
+}
+
+fn quat_conjugate_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mq = random_unit_dquat(&mut rng);
+
+    let nqc = nalgebra::UnitQuaternion::from_quaternion(mq.into()).conjugate();
+    let cqc: mint::Quaternion<f64> = cgmath::Quaternion::<f64>::from(mq).conjugate().into();
+    // euclid inverse assumes a normalized quaternion so it's just a conjugate
+    let eqc = euclid_drotation3(mq).inverse();
+    let vqc: mint::Quaternion<f64> = vek::Quaternion::<f64>::from(mq).conjugate().into();
+
+    // use nalgebra as assumed correct answer
+    let mqc: mint::Quaternion<f64> = nqc.into();
+
+    assert_ulps_eq!(cgmath => cqc, mqc, epsilon = 1e-12);
+    assert_ulps_eq!(euclid => mint_from_euclid_drotation3(eqc), mqc, epsilon = 1e-12);
+    assert_ulps_eq!(vek => vqc, mqc, epsilon = 1e-12);
+}
+
+fn quat_mul_quat_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mq1 = random_unit_dquat(&mut rng);
+    let mq2 = random_unit_dquat(&mut rng);
+
+    let nq3 = nalgebra::UnitQuaternion::from_quaternion(mq1.into())
+        * nalgebra::UnitQuaternion::from_quaternion(mq2.into());
+    let cq3: mint::Quaternion<f64> =
+        (cgmath::Quaternion::<f64>::from(mq1) * cgmath::Quaternion::<f64>::from(mq2)).into();
+    let eq3 = euclid_drotation3(mq1).pre_rotate(&euclid_drotation3(mq2));
+    let vq3: mint::Quaternion<f64> =
+        (vek::Quaternion::<f64>::from(mq1) * vek::Quaternion::<f64>::from(mq2)).into();
+
+    // use nalgebra as assumed correct answer
+    let mq3: mint::Quaternion<f64> = nq3.into();
+
+    assert_ulps_eq!(cgmath => cq3, mq3, epsilon = 1e-12);
+    assert_ulps_eq!(euclid => mint_from_euclid_drotation3(eq3), mq3, epsilon = 1e-12);
+    assert_ulps_eq!(vek => vq3, mq3, epsilon = 1e-12);
+}
+
+fn quat_mul_vec3_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mq = random_unit_dquat(&mut rng);
+    let mv = random_mint_dvec3(&mut rng);
+
+    let nqv =
+        nalgebra::UnitQuaternion::from_quaternion(mq.into()) * nalgebra::Vector3::<f64>::from(mv);
+    let cqv: mint::Vector3<f64> =
+        (cgmath::Quaternion::<f64>::from(mq) * cgmath::Vector3::<f64>::from(mv)).into();
+    let eqv = euclid_drotation3(mq).transform_point3d(euclid::point3(mv.x, mv.y, mv.z));
+    let vqv: mint::Vector3<f64> =
+        (vek::Quaternion::<f64>::from(mq) * vek::Vec3::<f64>::from(mv)).into();
+
+    // use nalgebra as assumed correct answer
+    let mqv: mint::Vector3<f64> = nqv.into();
+
+    assert_ulps_eq!(cgmath => cqv, mqv, epsilon = 1e-12);
+    assert_ulps_eq!(euclid => mint::Vector3::from(eqv.to_array()), mqv, epsilon = 1e-12);
+    assert_ulps_eq!(vek => vqv, mqv, epsilon = 1e-12);
+}
+
+fn transform_point2_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_homogeneous_dmat3(&mut rng);
+    let mv = random_mint_dvec2(&mut rng);
+
+    let nm = nalgebra::Transform2::from_matrix_unchecked(nalgebra::Matrix3::from(mm));
+    let nmv = nm.transform_point(&nalgebra::Point2::new(mv.x, mv.y));
+
+    let cm = cgmath::Matrix3::<f64>::from(mm);
+    let cmv = cgmath::Transform::transform_point(&cm, cgmath::Point2::new(mv.x, mv.y));
+
+    let emv = euclid_dtransform2(mm).transform_point(euclid::point2(mv.x, mv.y));
+
+    let vmv: mint::Vector2<f64> = vek::Mat3::<f64>::from(mm)
+        .mul_point_2d(vek::Vec2::from(mv))
+        .into();
+
+    // use nalgebra as assumed correct answer
+    let mmv: mint::Vector2<f64> = nmv.coords.into();
+
+    assert_ulps_eq!(cgmath => mint::Vector2::from([cmv.x, cmv.y]), mmv, epsilon = 1e-12);
+    assert_ulps_eq!(euclid => mint::Vector2::from(emv.to_array()), mmv, epsilon = 1e-12);
+    assert_ulps_eq!(vek => vmv, mmv, epsilon = 1e-12);
+}
+
+fn transform_vector2_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_homogeneous_dmat3(&mut rng);
+    let mv = random_mint_dvec2(&mut rng);
+
+    let nm = nalgebra::Transform2::from_matrix_unchecked(nalgebra::Matrix3::from(mm));
+    let nmv = nm.transform_vector(&nalgebra::Vector2::from(mv));
+
+    let emv: mint::Vector2<f64> = euclid_dtransform2(mm)
+        .transform_vector(EuclidDVector2D::from(mv))
+        .into();
+
+    let vmv: mint::Vector2<f64> = vek::Mat3::<f64>::from(mm)
+        .mul_direction_2d(vek::Vec2::from(mv))
+        .into();
+
+    // use nalgebra as assumed correct answer
+    let mmv: mint::Vector2<f64> = nmv.into();
+
+    assert_ulps_eq!(euclid => emv, mmv, epsilon = 1e-12);
+    assert_ulps_eq!(vek => vmv, mmv, epsilon = 1e-12);
+}
+
+fn transform_point3_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_homogeneous_dmat4(&mut rng);
+    let mv = random_mint_dvec3(&mut rng);
+
+    let nm = nalgebra::Transform3::from_matrix_unchecked(nalgebra::Matrix4::from(mm));
+    let nmv = nm.transform_point(&nalgebra::Point3::new(mv.x, mv.y, mv.z));
+
+    let cm = cgmath::Matrix4::<f64>::from(mm);
+    let cmv = cgmath::Transform::transform_point(&cm, cgmath::Point3::new(mv.x, mv.y, mv.z));
+
+    let emv = euclid_dtransform3(mm).transform_point3d(euclid::point3(mv.x, mv.y, mv.z));
+    assert!(emv.is_some());
+
+    let vmv: mint::Vector3<f64> = vek::Mat4::<f64>::from(mm)
+        .mul_point(vek::Vec3::from(mv))
+        .into();
+
+    // use nalgebra as assumed correct answer
+    let mmv: mint::Vector3<f64> = nmv.coords.into();
+
+    assert_ulps_eq!(
+        cgmath => mint::Vector3::from([cmv.x, cmv.y, cmv.z]),
+        mmv,
+        epsilon = 1e-12
+    );
+    assert_ulps_eq!(
+        euclid => mint::Vector3::from(emv.unwrap().to_array()),
+        mmv,
+        epsilon = 1e-12
+    );
+    assert_ulps_eq!(vek => vmv, mmv, epsilon = 1e-12);
+}
+
+fn transform_vector3_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_homogeneous_dmat4(&mut rng);
+    let mv = random_mint_dvec3(&mut rng);
+
+    let nm = nalgebra::Transform3::from_matrix_unchecked(nalgebra::Matrix4::from(mm));
+    let nmv = nm.transform_vector(&nalgebra::Vector3::from(mv));
+
+    let cm = cgmath::Matrix4::<f64>::from(mm);
+    let cmv: mint::Vector3<f64> =
+        cgmath::Transform::transform_vector(&cm, cgmath::Vector3::from(mv)).into();
+
+    let emv: mint::Vector3<f64> = euclid_dtransform3(mm)
+        .transform_vector3d(EuclidDVector3D::from(mv))
+        .into();
+
+    let vmv: mint::Vector3<f64> = vek::Mat4::<f64>::from(mm)
+        .mul_direction(vek::Vec3::from(mv))
+        .into();
+
+    // use nalgebra as assumed correct answer
+    let mmv: mint::Vector3<f64> = nmv.into();
+
+    assert_ulps_eq!(cgmath => cmv, mmv, epsilon = 1e-12);
+    assert_ulps_eq!(euclid => emv, mmv, epsilon = 1e-12);
+    assert_ulps_eq!(vek => vmv, mmv, epsilon = 1e-12);
+}
+
+fn transform2_inverse_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_homogeneous_dmat3(&mut rng);
+
+    let nm = nalgebra::Transform2::from_matrix_unchecked(nalgebra::Matrix3::from(mm));
+    let nmi = nm.try_inverse();
+    assert!(nmi.is_some());
+
+    let emi = euclid_dtransform2(mm).inverse();
+    assert!(emi.is_some());
+
+    // use nalgebra as assumed correct answer
+    let mmi: mint::ColumnMatrix3<f64> = nmi.unwrap().into_inner().into();
+
+    assert_ulps_eq!(
+        euclid => mint_from_euclid_dtransform2(emi.unwrap()),
+        mmi,
+        epsilon = 1e-9
+    );
+}
+
+fn transform2_mul_transform2_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_homogeneous_dmat3(&mut rng);
+    let mm2 = random_mint_homogeneous_dmat3(&mut rng);
+
+    let nm3 = nalgebra::Transform2::from_matrix_unchecked(nalgebra::Matrix3::from(mm1))
+        * nalgebra::Transform2::from_matrix_unchecked(nalgebra::Matrix3::from(mm2));
+
+    // euclid uses row vectors so `m2.post_transform(m1)` is `m1 * m2`
+    let em3 = euclid_dtransform2(mm2).post_transform(&euclid_dtransform2(mm1));
+
+    // use nalgebra as assumed correct answer
+    let mm3: mint::ColumnMatrix3<f64> = nm3.into_inner().into();
+
+    assert_ulps_eq!(euclid => mint_from_euclid_dtransform2(em3), mm3, epsilon = 1e-12);
+}
+
+fn transform3_inverse_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_homogeneous_dmat4(&mut rng);
+
+    let nm = nalgebra::Transform3::from_matrix_unchecked(nalgebra::Matrix4::from(mm));
+    let nmi = nm.try_inverse();
+    assert!(nmi.is_some());
+
+    let emi = euclid_dtransform3(mm).inverse();
+    assert!(emi.is_some());
+
+    // use nalgebra as assumed correct answer
+    let mmi: mint::ColumnMatrix4<f64> = nmi.unwrap().into_inner().into();
+
+    assert_ulps_eq!(
+        euclid => mint_from_euclid_dtransform3(emi.unwrap()),
+        mmi,
+        epsilon = 1e-9
+    );
+}
+
+fn transform3_mul_transform3_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_homogeneous_dmat4(&mut rng);
+    let mm2 = random_mint_homogeneous_dmat4(&mut rng);
+
+    let nm3 = nalgebra::Transform3::from_matrix_unchecked(nalgebra::Matrix4::from(mm1))
+        * nalgebra::Transform3::from_matrix_unchecked(nalgebra::Matrix4::from(mm2));
+
+    // euclid uses row vectors so `m2.post_transform(m1)` is `m1 * m2`
+    let em3 = euclid_dtransform3(mm2).post_transform(&euclid_dtransform3(mm1));
+
+    // use nalgebra as assumed correct answer
+    let mm3: mint::ColumnMatrix4<f64> = nm3.into_inner().into();
+
+    assert_ulps_eq!(euclid => mint_from_euclid_dtransform3(em3), mm3, epsilon = 1e-12);
+}
+
+fn vec3_length_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mv = random_mint_dvec3(&mut rng);
+
+    let nl = nalgebra::Vector3::<f64>::from(mv).magnitude();
+    let cl = cgmath::Vector3::<f64>::from(mv).magnitude();
+    let el = EuclidDVector3D::from(mv).length();
+    let vl = vek::Vec3::<f64>::from(mv).magnitude();
+
+    // use nalgebra as assumed correct answer
+    assert_ulps_eq!(cgmath => cl, nl, epsilon = 1e-12);
+    assert_ulps_eq!(euclid => el, nl, epsilon = 1e-12);
+    assert_ulps_eq!(vek => vl, nl, epsilon = 1e-12);
+}
+
+fn vec3_normalize_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mv = random_mint_dvec3(&mut rng);
+
+    let nvn = nalgebra::Vector3::<f64>::from(mv).normalize();
+    let cvn: mint::Vector3<f64> = cgmath::Vector3::<f64>::from(mv).normalize().into();
+    let evn: mint::Vector3<f64> = EuclidDVector3D::from(mv).normalize().into();
+    let vvn: mint::Vector3<f64> = vek::Vec3::<f64>::from(mv).normalized().into();
+
+    // use nalgebra as assumed correct answer
+    let mvn: mint::Vector3<f64> = nvn.into();
+
+    assert_ulps_eq!(cgmath => cvn, mvn, epsilon = 1e-12);
+    assert_ulps_eq!(euclid => evn, mvn, epsilon = 1e-12);
+    assert_ulps_eq!(vek => vvn, mvn, epsilon = 1e-12);
+}
+
+fn vec3_dot_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mv1 = random_mint_dvec3(&mut rng);
+    let mv2 = random_mint_dvec3(&mut rng);
+
+    let nd = nalgebra::Vector3::<f64>::from(mv1).dot(&nalgebra::Vector3::from(mv2));
+    let cd = cgmath::Vector3::<f64>::from(mv1).dot(cgmath::Vector3::from(mv2));
+    let ed = EuclidDVector3D::from(mv1).dot(EuclidDVector3D::from(mv2));
+    let vd = vek::Vec3::<f64>::from(mv1).dot(vek::Vec3::from(mv2));
+
+    // use nalgebra as assumed correct answer
+    assert_ulps_eq!(cgmath => cd, nd, epsilon = 1e-12);
+    assert_ulps_eq!(euclid => ed, nd, epsilon = 1e-12);
+    assert_ulps_eq!(vek => vd, nd, epsilon = 1e-12);
+}
+
+fn vec3_cross_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mv1 = random_mint_dvec3(&mut rng);
+    let mv2 = random_mint_dvec3(&mut rng);
+
+    let nc = nalgebra::Vector3::<f64>::from(mv1).cross(&nalgebra::Vector3::from(mv2));
+    let cc: mint::Vector3<f64> = cgmath::Vector3::<f64>::from(mv1)
+        .cross(cgmath::Vector3::from(mv2))
+        .into();
+    let ec: mint::Vector3<f64> = EuclidDVector3D::from(mv1)
+        .cross(EuclidDVector3D::from(mv2))
+        .into();
+    let vc: mint::Vector3<f64> = vek::Vec3::<f64>::from(mv1)
+        .cross(vek::Vec3::from(mv2))
+        .into();
+
+    // use nalgebra as assumed correct answer
+    let mc: mint::Vector3<f64> = nc.into();
+
+    assert_ulps_eq!(cgmath => cc, mc, epsilon = 1e-12);
+    assert_ulps_eq!(euclid => ec, mc, epsilon = 1e-12);
+    assert_ulps_eq!(vek => vc, mc, epsilon = 1e-12);
+}
+
+#[test]
+fn test_f64_mat2_transpose() {
+    support::compare(&registry::F64_MAT2_TRANSPOSE, || {
+        for _ in 0..NUM_ITERS {
+            mat2_transpose_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_mat3_transpose() {
+    support::compare(&registry::F64_MAT3_TRANSPOSE, || {
+        for _ in 0..NUM_ITERS {
+            mat3_transpose_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_mat4_transpose() {
+    support::compare(&registry::F64_MAT4_TRANSPOSE, || {
+        for _ in 0..NUM_ITERS {
+            mat4_transpose_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_mat2_det() {
+    support::compare(&registry::F64_MAT2_DET, || {
+        for _ in 0..NUM_ITERS {
+            mat2_det_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_mat3_det() {
+    support::compare(&registry::F64_MAT3_DET, || {
+        for _ in 0..NUM_ITERS {
+            mat3_det_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_mat4_det() {
+    support::compare(&registry::F64_MAT4_DET, || {
+        for _ in 0..NUM_ITERS {
+            mat4_det_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_mat2_inverse() {
+    support::compare(&registry::F64_MAT2_INVERSE, || {
+        for _ in 0..NUM_ITERS {
+            mat2_inverse_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_mat3_inverse() {
+    support::compare(&registry::F64_MAT3_INVERSE, || {
+        for _ in 0..NUM_ITERS {
+            mat3_inverse_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_mat4_inverse() {
+    support::compare(&registry::F64_MAT4_INVERSE, || {
+        for _ in 0..NUM_ITERS {
+            mat4_inverse_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_mat2_mul_mat2() {
+    support::compare(&registry::F64_MAT2_MUL_MAT2, || {
+        for _ in 0..NUM_ITERS {
+            mat2_mul_mat2_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_mat3_mul_mat3() {
+    support::compare(&registry::F64_MAT3_MUL_MAT3, || {
+        for _ in 0..NUM_ITERS {
+            mat3_mul_mat3_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_mat4_mul_mat4() {
+    support::compare(&registry::F64_MAT4_MUL_MAT4, || {
+        for _ in 0..NUM_ITERS {
+            mat4_mul_mat4_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_mat2_mul_vec2() {
+    support::compare(&registry::F64_MAT2_MUL_VEC2, || {
+        for _ in 0..NUM_ITERS {
+            mat2_mul_vec2_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_mat3_mul_vec3() {
+    support::compare(&registry::F64_MAT3_MUL_VEC3, || {
+        for _ in 0..NUM_ITERS {
+            mat3_mul_vec3_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_mat4_mul_vec4() {
+    support::compare(&registry::F64_MAT4_MUL_VEC4, || {
+        for _ in 0..NUM_ITERS {
+            mat4_mul_vec4_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_quat_conjugate() {
+    support::compare(&registry::F64_QUAT_CONJUGATE, || {
+        for _ in 0..NUM_ITERS {
+            quat_conjugate_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_quat_mul_quat() {
+    support::compare(&registry::F64_QUAT_MUL_QUAT, || {
+        for _ in 0..NUM_ITERS {
+            quat_mul_quat_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_quat_mul_vec3() {
+    support::compare(&registry::F64_QUAT_MUL_VEC3, || {
+        for _ in 0..NUM_ITERS {
+            quat_mul_vec3_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_transform_point2() {
+    support::compare(&registry::F64_TRANSFORM_POINT2, || {
+        for _ in 0..NUM_ITERS {
+            transform_point2_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_transform_vector2() {
+    support::compare(&registry::F64_TRANSFORM_VECTOR2, || {
+        for _ in 0..NUM_ITERS {
+            transform_vector2_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_transform_point3() {
+    support::compare(&registry::F64_TRANSFORM_POINT3, || {
+        for _ in 0..NUM_ITERS {
+            transform_point3_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_transform_vector3() {
+    support::compare(&registry::F64_TRANSFORM_VECTOR3, || {
+        for _ in 0..NUM_ITERS {
+            transform_vector3_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_transform2_inverse() {
+    support::compare(&registry::F64_TRANSFORM2_INVERSE, || {
+        for _ in 0..NUM_ITERS {
+            transform2_inverse_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_transform2_mul_transform2() {
+    support::compare(&registry::F64_TRANSFORM2_MUL_TRANSFORM2, || {
+        for _ in 0..NUM_ITERS {
+            transform2_mul_transform2_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_transform3_inverse() {
+    support::compare(&registry::F64_TRANSFORM3_INVERSE, || {
+        for _ in 0..NUM_ITERS {
+            transform3_inverse_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_transform3_mul_transform3() {
+    support::compare(&registry::F64_TRANSFORM3_MUL_TRANSFORM3, || {
+        for _ in 0..NUM_ITERS {
+            transform3_mul_transform3_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_vec3_length() {
+    support::compare(&registry::F64_VEC3_LENGTH, || {
+        for _ in 0..NUM_ITERS {
+            vec3_length_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_vec3_normalize() {
+    support::compare(&registry::F64_VEC3_NORMALIZE, || {
+        for _ in 0..NUM_ITERS {
+            vec3_normalize_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_vec3_dot() {
+    support::compare(&registry::F64_VEC3_DOT, || {
+        for _ in 0..NUM_ITERS {
+            vec3_dot_compare();
+        }
+    });
+}
+
+#[test]
+fn test_f64_vec3_cross() {
+    support::compare(&registry::F64_VEC3_CROSS, || {
+        for _ in 0..NUM_ITERS {
+            vec3_cross_compare();
+        }
+    });
+}
@@ -0,0 +1,198 @@
+//! Checks what each library's matrix inverse does with singular and
+//! non-finite matrices.
+//!
+//! The inputs and libraries are in `support::inverse`. The behaviour on exactly
+//! singular and non-finite inputs is asserted by `EXPECTED` so changes are
+//! noticed when a library is updated. Near singular inputs are only reported,
+//! run `cargo run --example inverse` for a markdown report of every outcome.
+mod support;
+
+use support::inverse::{
+    mat2_cases, mat2_libs, mat3_cases, mat3_libs, mat4_cases, mat4_libs, outcomes, Case, Kind,
+    Library, Outcome,
+};
+
+/// Outcomes for exactly singular and non-finite inputs as (matrix, input,
+/// library, outcome) in a debug build on x86_64. Near singular inputs are only
+/// reported since their results depend on rounding.
+const EXPECTED: &[(&str, &str, &str, Outcome)] = &[
+    ("Mat2", "zero", "glam", Outcome::NaN),
+    ("Mat2", "zero", "cgmath", Outcome::None),
+    ("Mat2", "zero", "nalgebra", Outcome::None),
+    ("Mat2", "zero", "pathfinder", Outcome::NaN),
+    ("Mat2", "zero", "reference", Outcome::NaN),
+    ("Mat2", "zero column", "glam", Outcome::NaN),
+    ("Mat2", "zero column", "cgmath", Outcome::None),
+    ("Mat2", "zero column", "nalgebra", Outcome::None),
+    ("Mat2", "zero column", "pathfinder", Outcome::NaN),
+    ("Mat2", "zero column", "reference", Outcome::NaN),
+    ("Mat2", "rank 1", "glam", Outcome::Inf),
+    ("Mat2", "rank 1", "cgmath", Outcome::None),
+    ("Mat2", "rank 1", "nalgebra", Outcome::None),
+    ("Mat2", "rank 1", "pathfinder", Outcome::Inf),
+    ("Mat2", "rank 1", "reference", Outcome::Inf),
+    ("Mat2", "NaN element", "glam", Outcome::NaN),
+    ("Mat2", "NaN element", "cgmath", Outcome::NaN),
+    ("Mat2", "NaN element", "nalgebra", Outcome::NaN),
+    ("Mat2", "NaN element", "pathfinder", Outcome::NaN),
+    ("Mat2", "NaN element", "reference", Outcome::NaN),
+    ("Mat2", "inf element", "glam", Outcome::NaN),
+    ("Mat2", "inf element", "cgmath", Outcome::NaN),
+    ("Mat2", "inf element", "nalgebra", Outcome::NaN),
+    ("Mat2", "inf element", "pathfinder", Outcome::NaN),
+    ("Mat2", "inf element", "reference", Outcome::NaN),
+    ("Mat3", "zero linear part", "glam", Outcome::Panic),
+    ("Mat3", "zero linear part", "cgmath", Outcome::None),
+    ("Mat3", "zero linear part", "nalgebra", Outcome::None),
+    ("Mat3", "zero linear part", "ultraviolet", Outcome::NaN),
+    ("Mat3", "zero linear part", "euclid", Outcome::None),
+    ("Mat3", "zero linear part", "pathfinder", Outcome::NaN),
+    ("Mat3", "zero linear part", "reference", Outcome::NaN),
+    ("Mat3", "zero scale y", "glam", Outcome::Panic),
+    ("Mat3", "zero scale y", "cgmath", Outcome::None),
+    ("Mat3", "zero scale y", "nalgebra", Outcome::None),
+    ("Mat3", "zero scale y", "ultraviolet", Outcome::NaN),
+    ("Mat3", "zero scale y", "euclid", Outcome::None),
+    ("Mat3", "zero scale y", "pathfinder", Outcome::NaN),
+    ("Mat3", "zero scale y", "reference", Outcome::NaN),
+    ("Mat3", "rank 1", "glam", Outcome::Panic),
+    ("Mat3", "rank 1", "cgmath", Outcome::None),
+    ("Mat3", "rank 1", "nalgebra", Outcome::None),
+    ("Mat3", "rank 1", "ultraviolet", Outcome::NaN),
+    ("Mat3", "rank 1", "euclid", Outcome::None),
+    ("Mat3", "rank 1", "pathfinder", Outcome::NaN),
+    ("Mat3", "rank 1", "reference", Outcome::NaN),
+    ("Mat3", "NaN element", "glam", Outcome::NaN),
+    ("Mat3", "NaN element", "cgmath", Outcome::NaN),
+    ("Mat3", "NaN element", "nalgebra", Outcome::NaN),
+    ("Mat3", "NaN element", "ultraviolet", Outcome::NaN),
+    ("Mat3", "NaN element", "euclid", Outcome::NaN),
+    ("Mat3", "NaN element", "pathfinder", Outcome::NaN),
+    ("Mat3", "NaN element", "reference", Outcome::NaN),
+    ("Mat3", "inf element", "glam", Outcome::NaN),
+    ("Mat3", "inf element", "cgmath", Outcome::NaN),
+    ("Mat3", "inf element", "nalgebra", Outcome::NaN),
+    ("Mat3", "inf element", "ultraviolet", Outcome::NaN),
+    ("Mat3", "inf element", "euclid", Outcome::NaN),
+    ("Mat3", "inf element", "pathfinder", Outcome::NaN),
+    ("Mat3", "inf element", "reference", Outcome::NaN),
+    ("Mat4", "zero linear part", "glam", Outcome::Panic),
+    ("Mat4", "zero linear part", "cgmath", Outcome::None),
+    ("Mat4", "zero linear part", "nalgebra", Outcome::None),
+    ("Mat4", "zero linear part", "ultraviolet", Outcome::NaN),
+    ("Mat4", "zero linear part", "euclid", Outcome::None),
+    ("Mat4", "zero linear part", "vek", Outcome::NaN),
+    ("Mat4", "zero linear part", "pathfinder", Outcome::NaN),
+    ("Mat4", "zero linear part", "reference", Outcome::NaN),
+    ("Mat4", "zero linear part", "intrinsics", Outcome::NaN),
+    ("Mat4", "zero scale z", "glam", Outcome::Panic),
+    ("Mat4", "zero scale z", "cgmath", Outcome::None),
+    ("Mat4", "zero scale z", "nalgebra", Outcome::None),
+    ("Mat4", "zero scale z", "ultraviolet", Outcome::NaN),
+    ("Mat4", "zero scale z", "euclid", Outcome::None),
+    ("Mat4", "zero scale z", "vek", Outcome::NaN),
+    ("Mat4", "zero scale z", "pathfinder", Outcome::NaN),
+    ("Mat4", "zero scale z", "reference", Outcome::NaN),
+    ("Mat4", "zero scale z", "intrinsics", Outcome::NaN),
+    ("Mat4", "duplicate columns", "glam", Outcome::Panic),
+    ("Mat4", "duplicate columns", "cgmath", Outcome::None),
+    ("Mat4", "duplicate columns", "nalgebra", Outcome::None),
+    ("Mat4", "duplicate columns", "ultraviolet", Outcome::NaN),
+    ("Mat4", "duplicate columns", "euclid", Outcome::None),
+    ("Mat4", "duplicate columns", "vek", Outcome::NaN),
+    ("Mat4", "duplicate columns", "pathfinder", Outcome::NaN),
+    ("Mat4", "duplicate columns", "reference", Outcome::NaN),
+    ("Mat4", "duplicate columns", "intrinsics", Outcome::NaN),
+    ("Mat4", "rank 1", "glam", Outcome::Panic),
+    ("Mat4", "rank 1", "cgmath", Outcome::None),
+    ("Mat4", "rank 1", "nalgebra", Outcome::None),
+    ("Mat4", "rank 1", "ultraviolet", Outcome::NaN),
+    ("Mat4", "rank 1", "euclid", Outcome::None),
+    ("Mat4", "rank 1", "vek", Outcome::NaN),
+    ("Mat4", "rank 1", "pathfinder", Outcome::NaN),
+    ("Mat4", "rank 1", "reference", Outcome::NaN),
+    ("Mat4", "rank 1", "intrinsics", Outcome::NaN),
+    ("Mat4", "NaN element", "glam", Outcome::NaN),
+    ("Mat4", "NaN element", "cgmath", Outcome::NaN),
+    ("Mat4", "NaN element", "nalgebra", Outcome::NaN),
+    ("Mat4", "NaN element", "ultraviolet", Outcome::NaN),
+    ("Mat4", "NaN element", "euclid", Outcome::NaN),
+    ("Mat4", "NaN element", "vek", Outcome::NaN),
+    ("Mat4", "NaN element", "pathfinder", Outcome::NaN),
+    ("Mat4", "NaN element", "reference", Outcome::NaN),
+    ("Mat4", "NaN element", "intrinsics", Outcome::NaN),
+    ("Mat4", "inf element", "glam", Outcome::NaN),
+    ("Mat4", "inf element", "cgmath", Outcome::NaN),
+    ("Mat4", "inf element", "nalgebra", Outcome::NaN),
+    ("Mat4", "inf element", "ultraviolet", Outcome::NaN),
+    ("Mat4", "inf element", "euclid", Outcome::NaN),
+    ("Mat4", "inf element", "vek", Outcome::NaN),
+    ("Mat4", "inf element", "pathfinder", Outcome::NaN),
+    ("Mat4", "inf element", "reference", Outcome::NaN),
+    ("Mat4", "inf element", "intrinsics", Outcome::NaN),
+];
+
+/// Returns true if `glam` asserts that the determinant of `matrix` is non-zero.
+/// The assertions are only enabled in debug builds with the `glam_assert`
+/// feature and the SSE2 `Mat2` inverse doesn't have one.
+fn glam_asserts(matrix: &str) -> bool {
+    cfg!(all(debug_assertions, feature = "glam_assert"))
+        && (matrix != "Mat2" || cfg!(any(not(target_feature = "sse2"), feature = "glam_scalar")))
+}
+
+fn check_expected(
+    matrix: &str,
+    cases: &[Case],
+    libs: &[Library],
+    outcomes: &[Vec<Outcome>],
+    errors: &mut Vec<String>,
+) {
+    for (case, row) in cases.iter().zip(outcomes) {
+        if case.kind == Kind::NearSingular {
+            continue;
+        }
+        for (lib, &outcome) in libs.iter().zip(row) {
+            let mut expected = EXPECTED
+                .iter()
+                .find(|e| e.0 == matrix && e.1 == case.name && e.2 == lib.name)
+                .map(|e| e.3);
+            if lib.name == "glam" && case.kind == Kind::Singular {
+                if glam_asserts(matrix) {
+                    expected = Some(Outcome::Panic);
+                } else if expected == Some(Outcome::Panic) {
+                    // the result without the assertion isn't recorded
+                    continue;
+                }
+            }
+            if expected != Some(outcome) {
+                errors.push(format!(
+                    "(\"{}\", \"{}\", \"{}\", Outcome::{:?}), expected {:?}",
+                    matrix, case.name, lib.name, outcome, expected
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_inverse_outcomes() {
+    let mut errors = Vec::new();
+
+    let (cases, libs) = (mat2_cases(), mat2_libs());
+    let mat2_outcomes = outcomes(2, &cases, &libs);
+    check_expected("Mat2", &cases, &libs, &mat2_outcomes, &mut errors);
+
+    let (cases, libs) = (mat3_cases(), mat3_libs());
+    let mat3_outcomes = outcomes(3, &cases, &libs);
+    check_expected("Mat3", &cases, &libs, &mat3_outcomes, &mut errors);
+
+    let (cases, libs) = (mat4_cases(), mat4_libs());
+    let mat4_outcomes = outcomes(4, &cases, &libs);
+    check_expected("Mat4", &cases, &libs, &mat4_outcomes, &mut errors);
+
+    assert!(
+        errors.is_empty(),
+        "unexpected inverse outcomes:\n  {}",
+        errors.join("\n  ")
+    );
+}
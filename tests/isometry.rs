@@ -0,0 +1,281 @@
+mod support;
+use mathbench::glam_support::Isometry3Ext as _;
+use mathbench::mint_support::*;
+use mathbench::vek_support::Isometry3Ext as _;
+use nalgebra;
+use rand_pcg::Pcg64Mcg;
+use support::{registry, FromMint};
+
+const NUM_ITERS: usize = 1024;
+
+// Rotors double cover rotations so the same isometry can have a rotor of either
+// sign, isometries are compared as homogeneous matrices to avoid this.
+
+type Isometries3 = (
+    nalgebra::Isometry3<f32>,
+    ultraviolet::Isometry3,
+    (glam::Quat, glam::Vec3),
+    cgmath::Decomposed<cgmath::Vector3<f32>, cgmath::Quaternion<f32>>,
+    vek::Transform<f32, f32, f32>,
+);
+
+fn random_isometry2<R>(rng: &mut R) -> (nalgebra::Isometry2<f32>, ultraviolet::Isometry2)
+where
+    R: rand::Rng,
+{
+    let angle = random_angle_radians(rng);
+    let mt = random_mint_vec2(rng);
+    let ni = nalgebra::Isometry2::new(mt.into(), angle);
+    let ui = ultraviolet::Isometry2::new(
+        ultraviolet::Vec2::from_mint(mt),
+        ultraviolet::Rotor2::from_angle(angle),
+    );
+    (ni, ui)
+}
+
+fn random_isometry3<R>(rng: &mut R) -> Isometries3
+where
+    R: rand::Rng,
+{
+    let mq = random_mint_quat(rng);
+    let mt = random_mint_vec3(rng);
+    let ni = nalgebra::Isometry3::from_parts(
+        nalgebra::Translation3::new(mt.x, mt.y, mt.z),
+        nalgebra::UnitQuaternion::from_quaternion(mq.into()),
+    );
+    let ui = ultraviolet::Isometry3::new(
+        ultraviolet::Vec3::from_mint(mt),
+        ultraviolet::Rotor3::from_mint(mq),
+    );
+    let gi = (mq.into(), mt.into());
+    let ci = cgmath::Decomposed {
+        scale: 1.0,
+        rot: mq.into(),
+        disp: mt.into(),
+    };
+    let vi = vek::Transform {
+        position: mt.into(),
+        orientation: mq.into(),
+        scale: vek::Vec3::one(),
+    };
+    (ni, ui, gi, ci, vi)
+}
+
+/// Returns the tolerance for comparing an isometry with the homogeneous matrix
+/// `expected`.
+///
+/// nalgebra normalizes the random quaternion while the other libraries use it
+/// as is, so their rotations differ by a few ulps. The translation of an
+/// inverse or product is the rotation applied to a translation, which scales
+/// that difference by the translation length. The largest difference seen over
+/// many runs is about 6 ulps of `1 + |translation|`.
+fn isometry3_epsilon(expected: &mint::ColumnMatrix4<f32>) -> f32 {
+    let t = expected.w;
+    let len = (t.x * t.x + t.y * t.y + t.z * t.z).sqrt();
+    8.0 * std::f32::EPSILON * (1.0 + len)
+}
+
+/// Converts the glam, cgmath and vek isometries to homogeneous matrices and
+/// compares them with `expected`.
+fn assert_isometry3_eq(
+    gi: (glam::Quat, glam::Vec3),
+    ci: cgmath::Decomposed<cgmath::Vector3<f32>, cgmath::Quaternion<f32>>,
+    vi: vek::Transform<f32, f32, f32>,
+    expected: mint::ColumnMatrix4<f32>,
+) {
+    let epsilon = isometry3_epsilon(&expected);
+    assert_ulps_eq!(
+        glam => glam::Mat4::from_rotation_translation(gi.0, gi.1),
+        glam::Mat4::from(expected),
+        epsilon = epsilon
+    );
+    assert_ulps_eq!(
+        cgmath => cgmath::Matrix4::from(ci),
+        cgmath::Matrix4::from(expected),
+        epsilon = epsilon
+    );
+    assert_ulps_eq!(
+        vek => vek::Mat4::from(vi),
+        vek::Mat4::from(expected),
+        epsilon = epsilon
+    );
+}
+
+fn isometry2_inverse_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let (ni, ui) = random_isometry2(&mut rng);
+
+    let nii = ni.inverse();
+    let uii = ui.inversed();
+
+    // use nalgebra as assumed correct answer
+    let mii: mint::ColumnMatrix3<f32> = nii.to_homogeneous().into();
+
+    assert_ulps_eq!(
+        ultraviolet => uii.into_homogeneous_matrix(),
+        ultraviolet::Mat3::from_mint(mii),
+        epsilon = 1e-6
+    );
+}
+
+fn isometry2_mul_isometry2_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let (ni1, ui1) = random_isometry2(&mut rng);
+    let (ni2, ui2) = random_isometry2(&mut rng);
+
+    let ni3 = ni1 * ni2;
+    let ui3 = ui1 * ui2;
+
+    // use nalgebra as assumed correct answer
+    let mi3: mint::ColumnMatrix3<f32> = ni3.to_homogeneous().into();
+
+    assert_ulps_eq!(
+        ultraviolet => ui3.into_homogeneous_matrix(),
+        ultraviolet::Mat3::from_mint(mi3),
+        epsilon = 1e-6
+    );
+}
+
+fn isometry2_transform_point2_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let (ni, ui) = random_isometry2(&mut rng);
+    let mv = random_mint_vec2(&mut rng);
+
+    let niv = ni.transform_point(&nalgebra::Point2::new(mv.x, mv.y));
+    let uiv = ui.transform_vec(ultraviolet::Vec2::from_mint(mv));
+
+    // use nalgebra as assumed correct answer
+    let miv: mint::Vector2<f32> = niv.coords.into();
+
+    assert_ulps_eq!(ultraviolet => uiv, ultraviolet::Vec2::from_mint(miv), epsilon = 1e-6);
+}
+
+fn isometry3_inverse_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let (ni, ui, gi, ci, vi) = random_isometry3(&mut rng);
+
+    let nii = ni.inverse();
+    let uii = ui.inversed();
+    let gii = gi.inverse();
+    let cii = {
+        use cgmath::Transform;
+        ci.inverse_transform().unwrap()
+    };
+    let vii = vi.inverted();
+
+    // use nalgebra as assumed correct answer
+    let mii: mint::ColumnMatrix4<f32> = nii.to_homogeneous().into();
+
+    assert_ulps_eq!(
+        ultraviolet => uii.into_homogeneous_matrix(),
+        ultraviolet::Mat4::from_mint(mii),
+        epsilon = isometry3_epsilon(&mii)
+    );
+    assert_isometry3_eq(gii, cii, vii, mii);
+}
+
+fn isometry3_mul_isometry3_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let (ni1, ui1, gi1, ci1, vi1) = random_isometry3(&mut rng);
+    let (ni2, ui2, gi2, ci2, vi2) = random_isometry3(&mut rng);
+
+    let ni3 = ni1 * ni2;
+    let ui3 = ui1 * ui2;
+    let gi3 = gi1.mul_isometry3(gi2);
+    let ci3 = {
+        use cgmath::Transform;
+        ci1.concat(&ci2)
+    };
+    let vi3 = vi1.mul_isometry(vi2);
+
+    // use nalgebra as assumed correct answer
+    let mi3: mint::ColumnMatrix4<f32> = ni3.to_homogeneous().into();
+
+    assert_ulps_eq!(
+        ultraviolet => ui3.into_homogeneous_matrix(),
+        ultraviolet::Mat4::from_mint(mi3),
+        epsilon = isometry3_epsilon(&mi3)
+    );
+    assert_isometry3_eq(gi3, ci3, vi3, mi3);
+}
+
+fn isometry3_transform_point3_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let (ni, ui, gi, ci, vi) = random_isometry3(&mut rng);
+    let mv = random_mint_vec3(&mut rng);
+
+    let niv = ni.transform_point(&nalgebra::Point3::new(mv.x, mv.y, mv.z));
+    let uiv = ui.transform_vec(ultraviolet::Vec3::from_mint(mv));
+    let giv = gi.transform_point3(mv.into());
+    let civ = {
+        use cgmath::Transform;
+        ci.transform_point(cgmath::Point3::new(mv.x, mv.y, mv.z))
+    };
+    let viv = vi.mul_point(mv.into());
+
+    // use nalgebra as assumed correct answer
+    let miv: mint::Vector3<f32> = niv.coords.into();
+
+    assert_ulps_eq!(ultraviolet => uiv, ultraviolet::Vec3::from_mint(miv), epsilon = 1e-6);
+    assert_ulps_eq!(glam => giv, glam::Vec3::from(miv), epsilon = 1e-6);
+    assert_ulps_eq!(
+        cgmath => civ,
+        cgmath::Point3::new(miv.x, miv.y, miv.z),
+        epsilon = 1e-6
+    );
+    assert_ulps_eq!(vek => viv, vek::Vec3::from(miv), epsilon = 1e-6);
+}
+
+#[test]
+fn test_isometry2_inverse() {
+    support::compare(&registry::ISOMETRY2_INVERSE, || {
+        for _ in 0..NUM_ITERS {
+            isometry2_inverse_compare();
+        }
+    });
+}
+
+#[test]
+fn test_isometry2_mul_isometry2() {
+    support::compare(&registry::ISOMETRY2_MUL_ISOMETRY2, || {
+        for _ in 0..NUM_ITERS {
+            isometry2_mul_isometry2_compare();
+        }
+    });
+}
+
+#[test]
+fn test_isometry2_transform_point2() {
+    support::compare(&registry::ISOMETRY2_TRANSFORM_POINT2, || {
+        for _ in 0..NUM_ITERS {
+            isometry2_transform_point2_compare();
+        }
+    });
+}
+
+#[test]
+fn test_isometry3_inverse() {
+    support::compare(&registry::ISOMETRY3_INVERSE, || {
+        for _ in 0..NUM_ITERS {
+            isometry3_inverse_compare();
+        }
+    });
+}
+
+#[test]
+fn test_isometry3_mul_isometry3() {
+    support::compare(&registry::ISOMETRY3_MUL_ISOMETRY3, || {
+        for _ in 0..NUM_ITERS {
+            isometry3_mul_isometry3_compare();
+        }
+    });
+}
+
+#[test]
+fn test_isometry3_transform_point3() {
+    support::compare(&registry::ISOMETRY3_TRANSFORM_POINT3, || {
+        for _ in 0..NUM_ITERS {
+            isometry3_transform_point3_compare();
+        }
+    });
+}
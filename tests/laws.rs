@@ -0,0 +1,594 @@
+//! Property based tests checking that each library obeys basic algebraic laws.
+//!
+//! Unlike the comparison tests these don't assume any library is correct, each
+//! library is checked against the law itself. Failing inputs are shrunk by
+//! `proptest` to a minimal counterexample which makes it easier to report
+//! problems upstream.
+mod support;
+use cgmath;
+use glam;
+use mathbench::mint_support::mint_quat_from_rotation_ypr;
+use mathbench::reference;
+use nalgebra;
+use proptest::prelude::*;
+use support::FromMint;
+
+const EPSILON: f64 = core::f32::EPSILON as f64;
+
+/// Matrices with a condition number above this are considered too close to
+/// singular to test in `f32`.
+const MAX_CONDITION: f64 = 1.0e4;
+
+/// Multiplier applied to the theoretical error bound to allow for the
+/// different orders of operations used by each library.
+const SLACK: f64 = 8.0;
+
+fn element() -> impl Strategy<Value = f32> {
+    -100.0f32..100.0
+}
+
+fn mat2() -> impl Strategy<Value = mint::ColumnMatrix2<f32>> {
+    prop::array::uniform4(element()).prop_map(mint::ColumnMatrix2::from)
+}
+
+fn mat3() -> impl Strategy<Value = mint::ColumnMatrix3<f32>> {
+    prop::array::uniform9(element()).prop_map(mint::ColumnMatrix3::from)
+}
+
+fn mat4() -> impl Strategy<Value = mint::ColumnMatrix4<f32>> {
+    prop::array::uniform16(element()).prop_map(mint::ColumnMatrix4::from)
+}
+
+fn vec3() -> impl Strategy<Value = mint::Vector3<f32>> {
+    prop::array::uniform3(element()).prop_map(mint::Vector3::from)
+}
+
+fn quat() -> impl Strategy<Value = mint::Quaternion<f32>> {
+    let angle = -core::f32::consts::PI..core::f32::consts::PI;
+    (angle.clone(), angle.clone(), angle)
+        .prop_map(|(yaw, pitch, roll)| mint_quat_from_rotation_ypr(yaw, pitch, roll))
+}
+
+/// Frobenius norm of a column major `n` by `n` matrix.
+fn norm(m: &[f64]) -> f64 {
+    m.iter().map(|x| x * x).sum::<f64>().sqrt()
+}
+
+fn widen(m: &[f32]) -> Vec<f64> {
+    m.iter().map(|&x| x as f64).collect()
+}
+
+/// Reference `f64` inverse of a column major `n` by `n` matrix using Gauss-Jordan
+/// elimination with partial pivoting.
+fn inverse_f64(m: &[f64], n: usize) -> Option<Vec<f64>> {
+    let mut a = m.to_vec();
+    let mut inv = vec![0.0; n * n];
+    for i in 0..n {
+        inv[i * n + i] = 1.0;
+    }
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&i, &j| {
+                a[col * n + i]
+                    .abs()
+                    .partial_cmp(&a[col * n + j].abs())
+                    .unwrap()
+            })
+            .unwrap();
+        if a[col * n + pivot] == 0.0 {
+            return None;
+        }
+        for k in 0..n {
+            a.swap(k * n + col, k * n + pivot);
+            inv.swap(k * n + col, k * n + pivot);
+        }
+        let p = a[col * n + col];
+        for k in 0..n {
+            a[k * n + col] /= p;
+            inv[k * n + col] /= p;
+        }
+        for row in 0..n {
+            if row != col {
+                let f = a[col * n + row];
+                for k in 0..n {
+                    a[k * n + row] -= f * a[k * n + col];
+                    inv[k * n + row] -= f * inv[k * n + col];
+                }
+            }
+        }
+    }
+    Some(inv)
+}
+
+/// Condition number of `m` in the Frobenius norm, or infinity if singular.
+fn condition(m: &[f32], n: usize) -> f64 {
+    let m = widen(m);
+    inverse_f64(&m, n).map_or(core::f64::INFINITY, |inv| norm(&m) * norm(&inv))
+}
+
+/// Asserts that `m` is the identity to within a tolerance scaled by the
+/// condition number `cond` of the matrix that was inverted.
+fn check_identity(m: &[f32], n: usize, cond: f64) -> Result<(), TestCaseError> {
+    let tol = SLACK * n as f64 * EPSILON * cond;
+    for col in 0..n {
+        for row in 0..n {
+            let expected = if row == col { 1.0 } else { 0.0 };
+            let diff = (m[col * n + row] as f64 - expected).abs();
+            prop_assert!(
+                diff <= tol,
+                "element ({}, {}) differs from identity by {} (tolerance {}, condition {})",
+                row,
+                col,
+                diff,
+                tol,
+                cond
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Asserts that `det(a * b) == det(a) * det(b)`. The rounding error of a
+/// determinant grows with the `n`th power of the matrix norm so the tolerance
+/// is scaled accordingly.
+fn check_det_mul(
+    a: &[f32],
+    b: &[f32],
+    n: usize,
+    det_ab: f32,
+    det_a: f32,
+    det_b: f32,
+) -> Result<(), TestCaseError> {
+    let scale = (norm(&widen(a)) * norm(&widen(b))).powi(n as i32);
+    let tol = SLACK * n as f64 * EPSILON * scale;
+    let diff = (det_ab as f64 - det_a as f64 * det_b as f64).abs();
+    prop_assert!(
+        diff <= tol,
+        "det(a * b) = {} but det(a) * det(b) = {} (diff {}, tolerance {})",
+        det_ab,
+        det_a as f64 * det_b as f64,
+        diff,
+        tol
+    );
+    Ok(())
+}
+
+/// Asserts that `actual` length matches `expected` to within a tolerance
+/// relative to `expected`.
+fn check_length(expected: f64, actual: f64) -> Result<(), TestCaseError> {
+    let tol = SLACK * EPSILON * expected;
+    let diff = (actual - expected).abs();
+    prop_assert!(
+        diff <= tol,
+        "length changed from {} to {} (tolerance {})",
+        expected,
+        actual,
+        tol
+    );
+    Ok(())
+}
+
+fn vec3_length(v: mint::Vector3<f32>) -> f64 {
+    norm(&widen(&[v.x, v.y, v.z]))
+}
+
+fn quat_length(q: mint::Quaternion<f32>) -> f64 {
+    norm(&widen(&[q.v.x, q.v.y, q.v.z, q.s]))
+}
+
+/// A library's matrix type with the conversions and product used by the laws.
+trait LawMatrix: Copy {
+    type Mint;
+    fn from_mint(m: Self::Mint) -> Self;
+    /// The column major elements of the matrix.
+    fn flat(self) -> Vec<f32>;
+    fn mul(self, rhs: Self) -> Self;
+}
+
+trait Transpose: LawMatrix {
+    fn transposed(self) -> Self;
+}
+
+trait Invert: LawMatrix {
+    fn inverted(self) -> Option<Self>;
+}
+
+trait Determinant: LawMatrix {
+    fn det(self) -> f32;
+}
+
+macro_rules! impl_mint_law_matrix {
+    ($mint:ident, $flat:ty, [$($m:ty),+]) => {
+        $(
+            impl LawMatrix for $m {
+                type Mint = mint::$mint<f32>;
+
+                fn from_mint(m: Self::Mint) -> Self {
+                    m.into()
+                }
+
+                fn flat(self) -> Vec<f32> {
+                    let m: mint::$mint<f32> = self.into();
+                    let flat: $flat = m.into();
+                    flat.to_vec()
+                }
+
+                fn mul(self, rhs: Self) -> Self {
+                    self * rhs
+                }
+            }
+        )+
+    };
+}
+
+impl_mint_law_matrix!(
+    ColumnMatrix2,
+    [f32; 4],
+    [
+        glam::Mat2,
+        cgmath::Matrix2<f32>,
+        nalgebra::Matrix2<f32>,
+        vek::Mat2<f32>,
+        reference::Mat2
+    ]
+);
+impl_mint_law_matrix!(
+    ColumnMatrix3,
+    [f32; 9],
+    [
+        glam::Mat3,
+        cgmath::Matrix3<f32>,
+        nalgebra::Matrix3<f32>,
+        vek::Mat3<f32>,
+        reference::Mat3
+    ]
+);
+impl_mint_law_matrix!(
+    ColumnMatrix4,
+    [f32; 16],
+    [
+        glam::Mat4,
+        cgmath::Matrix4<f32>,
+        nalgebra::Matrix4<f32>,
+        vek::Mat4<f32>,
+        reference::Mat4
+    ]
+);
+
+macro_rules! impl_ultraviolet_law_matrix {
+    ($mint:ident, [$($m:ty),+]) => {
+        $(
+            impl LawMatrix for $m {
+                type Mint = mint::$mint<f32>;
+
+                fn from_mint(m: Self::Mint) -> Self {
+                    FromMint::from_mint(m)
+                }
+
+                fn flat(self) -> Vec<f32> {
+                    self.as_array().to_vec()
+                }
+
+                fn mul(self, rhs: Self) -> Self {
+                    self * rhs
+                }
+            }
+        )+
+    };
+}
+
+impl_ultraviolet_law_matrix!(ColumnMatrix3, [ultraviolet::Mat3]);
+impl_ultraviolet_law_matrix!(ColumnMatrix4, [ultraviolet::Mat4]);
+
+impl LawMatrix for support::EuclidTransform3D {
+    type Mint = mint::ColumnMatrix4<f32>;
+
+    fn from_mint(m: Self::Mint) -> Self {
+        FromMint::from_mint(m)
+    }
+
+    // euclid uses row vectors so its row major elements are the column major
+    // elements of the mint matrix
+    fn flat(self) -> Vec<f32> {
+        self.to_row_major_array().to_vec()
+    }
+
+    fn mul(self, rhs: Self) -> Self {
+        rhs.post_transform(&self)
+    }
+}
+
+// Implements an operation for each matrix type, the expression is evaluated
+// with the matrix bound to the given name
+macro_rules! impl_law_op {
+    ($op:ident, $f:ident, $r:ty, [$($m:ty => |$x:ident| $e:expr),+ $(,)?]) => {
+        $(
+            impl $op for $m {
+                fn $f(self) -> $r {
+                    let $x = self;
+                    $e
+                }
+            }
+        )+
+    };
+}
+
+impl_law_op!(
+    Transpose,
+    transposed,
+    Self,
+    [
+        glam::Mat2 => |m| m.transpose(),
+        glam::Mat3 => |m| m.transpose(),
+        glam::Mat4 => |m| m.transpose(),
+        cgmath::Matrix2<f32> => |m| cgmath::Matrix::transpose(&m),
+        cgmath::Matrix3<f32> => |m| cgmath::Matrix::transpose(&m),
+        cgmath::Matrix4<f32> => |m| cgmath::Matrix::transpose(&m),
+        nalgebra::Matrix2<f32> => |m| m.transpose(),
+        nalgebra::Matrix3<f32> => |m| m.transpose(),
+        nalgebra::Matrix4<f32> => |m| m.transpose(),
+        ultraviolet::Mat3 => |m| ultraviolet::Mat3::transposed(&m),
+        ultraviolet::Mat4 => |m| ultraviolet::Mat4::transposed(&m),
+        vek::Mat2<f32> => |m| vek::Mat2::transposed(m),
+        vek::Mat3<f32> => |m| vek::Mat3::transposed(m),
+        vek::Mat4<f32> => |m| vek::Mat4::transposed(m),
+        reference::Mat2 => |m| m.transpose(),
+        reference::Mat3 => |m| m.transpose(),
+        reference::Mat4 => |m| m.transpose(),
+    ]
+);
+
+// ultraviolet 0.4.5 `Mat3::inversed` computes the determinant incorrectly and
+// vek only inverts 4x4 matrices.
+impl_law_op!(
+    Invert,
+    inverted,
+    Option<Self>,
+    [
+        glam::Mat2 => |m| Some(m.inverse()),
+        glam::Mat3 => |m| Some(m.inverse()),
+        glam::Mat4 => |m| Some(m.inverse()),
+        cgmath::Matrix2<f32> => |m| cgmath::SquareMatrix::invert(&m),
+        cgmath::Matrix3<f32> => |m| cgmath::SquareMatrix::invert(&m),
+        cgmath::Matrix4<f32> => |m| cgmath::SquareMatrix::invert(&m),
+        nalgebra::Matrix2<f32> => |m| m.try_inverse(),
+        nalgebra::Matrix3<f32> => |m| m.try_inverse(),
+        nalgebra::Matrix4<f32> => |m| m.try_inverse(),
+        ultraviolet::Mat4 => |m| Some(m.inversed()),
+        vek::Mat4<f32> => |m| Some(vek::Mat4::inverted(m)),
+        support::EuclidTransform3D => |m| m.inverse(),
+        reference::Mat2 => |m| Some(m.inverse()),
+        reference::Mat3 => |m| Some(m.inverse()),
+        reference::Mat4 => |m| Some(m.inverse()),
+    ]
+);
+
+// ultraviolet has no determinant.
+impl_law_op!(
+    Determinant,
+    det,
+    f32,
+    [
+        glam::Mat2 => |m| m.determinant(),
+        glam::Mat3 => |m| m.determinant(),
+        glam::Mat4 => |m| m.determinant(),
+        cgmath::Matrix2<f32> => |m| cgmath::SquareMatrix::determinant(&m),
+        cgmath::Matrix3<f32> => |m| cgmath::SquareMatrix::determinant(&m),
+        cgmath::Matrix4<f32> => |m| cgmath::SquareMatrix::determinant(&m),
+        nalgebra::Matrix2<f32> => |m| m.determinant(),
+        nalgebra::Matrix3<f32> => |m| m.determinant(),
+        nalgebra::Matrix4<f32> => |m| m.determinant(),
+        vek::Mat2<f32> => |m| m.determinant(),
+        vek::Mat3<f32> => |m| m.determinant(),
+        vek::Mat4<f32> => |m| m.determinant(),
+        support::EuclidTransform3D => |m| m.determinant(),
+        reference::Mat2 => |m| m.determinant(),
+        reference::Mat3 => |m| m.determinant(),
+        reference::Mat4 => |m| m.determinant(),
+    ]
+);
+
+fn check_transpose_involution<M: Transpose>(m: M::Mint) -> Result<(), TestCaseError> {
+    let m = M::from_mint(m);
+    prop_assert_eq!(m.transposed().transposed().flat(), m.flat());
+    Ok(())
+}
+
+fn check_inverse_mul<M: Invert>(m: M::Mint, n: usize, cond: f64) -> Result<(), TestCaseError> {
+    let m = M::from_mint(m);
+    let inv = m.inverted();
+    prop_assert!(inv.is_some());
+    check_identity(&inv.unwrap().mul(m).flat(), n, cond)
+}
+
+fn check_det_of_product<M: Determinant>(
+    a: M::Mint,
+    b: M::Mint,
+    n: usize,
+) -> Result<(), TestCaseError> {
+    let (a, b) = (M::from_mint(a), M::from_mint(b));
+    check_det_mul(&a.flat(), &b.flat(), n, a.mul(b).det(), a.det(), b.det())
+}
+
+macro_rules! matrix_laws {
+    (
+        $name:ident,
+        $n:expr,
+        $strategy:ident,
+        $mint:ident,
+        $flat:ty,
+        transpose => [$($t:ty),+],
+        invert => [$($i:ty),+],
+        determinant => [$($d:ty),+]
+    ) => {
+        mod $name {
+            use super::*;
+
+            fn flat(m: mint::$mint<f32>) -> $flat {
+                m.into()
+            }
+
+            proptest! {
+                #[test]
+                fn transpose_involution(m in $strategy()) {
+                    $(check_transpose_involution::<$t>(m)?;)+
+                }
+
+                #[test]
+                fn inverse_mul_is_identity(m in $strategy()) {
+                    let cond = condition(&flat(m), $n);
+                    prop_assume!(cond < MAX_CONDITION);
+                    $(check_inverse_mul::<$i>(m, $n, cond)?;)+
+                }
+
+                #[test]
+                fn determinant_of_product(a in $strategy(), b in $strategy()) {
+                    $(check_det_of_product::<$d>(a, b, $n)?;)+
+                }
+            }
+        }
+    };
+}
+
+matrix_laws!(
+    mat2,
+    2,
+    mat2,
+    ColumnMatrix2,
+    [f32; 4],
+    transpose => [
+        glam::Mat2,
+        cgmath::Matrix2<f32>,
+        nalgebra::Matrix2<f32>,
+        vek::Mat2<f32>,
+        reference::Mat2
+    ],
+    invert => [
+        glam::Mat2,
+        cgmath::Matrix2<f32>,
+        nalgebra::Matrix2<f32>,
+        reference::Mat2
+    ],
+    determinant => [
+        glam::Mat2,
+        cgmath::Matrix2<f32>,
+        nalgebra::Matrix2<f32>,
+        vek::Mat2<f32>,
+        reference::Mat2
+    ]
+);
+matrix_laws!(
+    mat3,
+    3,
+    mat3,
+    ColumnMatrix3,
+    [f32; 9],
+    transpose => [
+        glam::Mat3,
+        cgmath::Matrix3<f32>,
+        nalgebra::Matrix3<f32>,
+        ultraviolet::Mat3,
+        vek::Mat3<f32>,
+        reference::Mat3
+    ],
+    invert => [
+        glam::Mat3,
+        cgmath::Matrix3<f32>,
+        nalgebra::Matrix3<f32>,
+        reference::Mat3
+    ],
+    determinant => [
+        glam::Mat3,
+        cgmath::Matrix3<f32>,
+        nalgebra::Matrix3<f32>,
+        vek::Mat3<f32>,
+        reference::Mat3
+    ]
+);
+matrix_laws!(
+    mat4,
+    4,
+    mat4,
+    ColumnMatrix4,
+    [f32; 16],
+    transpose => [
+        glam::Mat4,
+        cgmath::Matrix4<f32>,
+        nalgebra::Matrix4<f32>,
+        ultraviolet::Mat4,
+        vek::Mat4<f32>,
+        reference::Mat4
+    ],
+    invert => [
+        glam::Mat4,
+        cgmath::Matrix4<f32>,
+        nalgebra::Matrix4<f32>,
+        ultraviolet::Mat4,
+        vek::Mat4<f32>,
+        support::EuclidTransform3D,
+        reference::Mat4
+    ],
+    determinant => [
+        glam::Mat4,
+        cgmath::Matrix4<f32>,
+        nalgebra::Matrix4<f32>,
+        vek::Mat4<f32>,
+        support::EuclidTransform3D,
+        reference::Mat4
+    ]
+);
+
+proptest! {
+    #[test]
+    fn quat_mul_vec3_preserves_norm(q in quat(), v in vec3()) {
+        let len = vec3_length(v);
+
+        let g: glam::Quat = q.into();
+        check_length(len, vec3_length((g * glam::Vec3::from(v)).into()))?;
+
+        let c: cgmath::Quaternion<f32> = q.into();
+        check_length(len, vec3_length((c * cgmath::Vector3::from(v)).into()))?;
+
+        let n = nalgebra::UnitQuaternion::from_quaternion(q.into());
+        check_length(len, vec3_length((n * nalgebra::Vector3::from(v)).into()))?;
+
+        let u = ultraviolet::Rotor3::from_mint(q) * ultraviolet::Vec3::from_mint(v);
+        check_length(len, vec3_length(mint::Vector3::from([u.x, u.y, u.z])))?;
+
+        let e = support::EuclidRotation3D::from_mint(q).transform_point3d(euclid::point3(v.x, v.y, v.z));
+        check_length(len, vec3_length(mint::Vector3::from([e.x, e.y, e.z])))?;
+
+        let vq: vek::Quaternion<f32> = q.into();
+        check_length(len, vec3_length((vq * vek::Vec3::from(v)).into()))?;
+
+        let r: reference::Quat = q.into();
+        check_length(len, vec3_length((r * reference::Vec3::from(v)).into()))?;
+    }
+
+    #[test]
+    fn quat_mul_quat_preserves_norm(q1 in quat(), q2 in quat()) {
+        let (g1, g2): (glam::Quat, glam::Quat) = (q1.into(), q2.into());
+        check_length(1.0, quat_length((g1 * g2).into()))?;
+
+        let (c1, c2): (cgmath::Quaternion<f32>, cgmath::Quaternion<f32>) = (q1.into(), q2.into());
+        check_length(1.0, quat_length((c1 * c2).into()))?;
+
+        let n1 = nalgebra::UnitQuaternion::from_quaternion(q1.into());
+        let n2 = nalgebra::UnitQuaternion::from_quaternion(q2.into());
+        check_length(1.0, quat_length((n1 * n2).into_inner().into()))?;
+
+        let u = ultraviolet::Rotor3::from_mint(q1) * ultraviolet::Rotor3::from_mint(q2);
+        check_length(1.0, quat_length(mint::Quaternion::from([u.bv.xy, u.bv.xz, u.bv.yz, u.s])))?;
+
+        let e1 = support::EuclidRotation3D::from_mint(q1);
+        let e2 = support::EuclidRotation3D::from_mint(q2);
+        let e = e1.pre_rotate(&e2);
+        check_length(1.0, quat_length(mint::Quaternion::from([e.i, e.j, e.k, e.r])))?;
+
+        let (v1, v2): (vek::Quaternion<f32>, vek::Quaternion<f32>) = (q1.into(), q2.into());
+        check_length(1.0, quat_length((v1 * v2).into()))?;
+
+        let (r1, r2): (reference::Quat, reference::Quat) = (q1.into(), q2.into());
+        check_length(1.0, quat_length((r1 * r2).into()))?;
+    }
+}
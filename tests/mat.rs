@@ -1,9 +1,18 @@
 mod support;
 use cgmath;
 use glam;
+use mathbench::cgmath_support::{Matrix4Ext as _, MatrixExt as _};
+use mathbench::glam_support::{Matrix4Ext as _, MatrixExt as _};
 use mathbench::mint_support::*;
+use mathbench::nalgebra_support::Matrix4Ext as _;
+#[cfg(feature = "pathfinder_geometry")]
+use mathbench::pathfinder_support::Matrix2Ext as _;
+use mathbench::reference;
+use mathbench::ultraviolet_support::{Matrix4Ext as _, MatrixExt as _};
+use mathbench::vek_support::{Matrix4Ext as _, MatrixExt as _};
 use nalgebra;
 use rand_pcg::Pcg64Mcg;
+use support::{registry, FromMint};
 
 const NUM_ITERS: usize = 1024;
 
@@ -36,11 +45,33 @@ fn mat2_mul_vec2_compare() {
     let cv: cgmath::Vector2<f32> = mv.into();
     let cmv = cm * cv;
 
+    let um = ultraviolet::Mat2::from_mint(mm);
+    let uv = ultraviolet::Vec2::from_mint(mv);
+    let umv = um * uv;
+
+    let vm: vek::Mat2<f32> = mm.into();
+    let vv: vek::Vec2<f32> = mv.into();
+    let vmv = vm * vv;
+
+    let rm: reference::Mat2 = mm.into();
+    let rv: reference::Vec2 = mv.into();
+    let rmv = rm * rv;
+
     // use nalgebra as assumed correct answer
     let mmv: mint::Vector2<f32> = nmv.into();
 
-    assert_ulps_eq!(cmv, mmv.into());
-    assert_ulps_eq!(gmv, mmv.into());
+    assert_ulps_eq!(cgmath => cmv, mmv.into());
+    assert_ulps_eq!(glam => gmv, mmv.into());
+    assert_ulps_eq!(ultraviolet => umv, ultraviolet::Vec2::from_mint(mmv));
+    assert_ulps_eq!(vek => vmv, mmv.into());
+    assert_ulps_eq!(reference => rmv, mmv.into());
+
+    #[cfg(feature = "pathfinder_geometry")]
+    {
+        use pathfinder_geometry::{transform2d::Matrix2x2F, vector::Vector2F};
+        let pmv = Matrix2x2F::from_mint(mm) * Vector2F::from_mint(mv);
+        assert_ulps_eq!(pathfinder => pmv, Vector2F::from_mint(mmv));
+    }
 }
 
 fn mat3_mul_vec3_compare() {
@@ -60,11 +91,26 @@ fn mat3_mul_vec3_compare() {
     let cv: cgmath::Vector3<f32> = mv.into();
     let cmv = cm * cv;
 
+    let um = ultraviolet::Mat3::from_mint(mm);
+    let uv = ultraviolet::Vec3::from_mint(mv);
+    let umv = um * uv;
+
+    let vm: vek::Mat3<f32> = mm.into();
+    let vv: vek::Vec3<f32> = mv.into();
+    let vmv = vm * vv;
+
+    let rm: reference::Mat3 = mm.into();
+    let rv: reference::Vec3 = mv.into();
+    let rmv = rm * rv;
+
     // use nalgebra as assumed correct answer
     let mmv: mint::Vector3<f32> = nmv.into();
 
-    assert_ulps_eq!(cmv, mmv.into());
-    assert_ulps_eq!(gmv, mmv.into());
+    assert_ulps_eq!(cgmath => cmv, mmv.into());
+    assert_ulps_eq!(glam => gmv, mmv.into());
+    assert_ulps_eq!(ultraviolet => umv, ultraviolet::Vec3::from_mint(mmv));
+    assert_ulps_eq!(vek => vmv, mmv.into());
+    assert_ulps_eq!(reference => rmv, mmv.into());
 }
 
 fn mat4_mul_vec4_compare() {
@@ -84,11 +130,26 @@ fn mat4_mul_vec4_compare() {
     let cv: cgmath::Vector4<f32> = mv.into();
     let cmv = cm * cv;
 
+    let um = ultraviolet::Mat4::from_mint(mm);
+    let uv = ultraviolet::Vec4::from_mint(mv);
+    let umv = um * uv;
+
+    let vm: vek::Mat4<f32> = mm.into();
+    let vv: vek::Vec4<f32> = mv.into();
+    let vmv = vm * vv;
+
+    let rm: reference::Mat4 = mm.into();
+    let rv: reference::Vec4 = mv.into();
+    let rmv = rm * rv;
+
     // use nalgebra as assumed correct answer
     let mmv: mint::Vector4<f32> = nmv.into();
 
-    assert_ulps_eq!(cmv, mmv.into());
-    assert_ulps_eq!(gmv, mmv.into());
+    assert_ulps_eq!(cgmath => cmv, mmv.into());
+    assert_ulps_eq!(glam => gmv, mmv.into());
+    assert_ulps_eq!(ultraviolet => umv, ultraviolet::Vec4::from_mint(mmv));
+    assert_ulps_eq!(vek => vmv, mmv.into());
+    assert_ulps_eq!(reference => rmv, mmv.into());
 }
 
 fn mat2_mul_mat2_compare() {
@@ -108,11 +169,31 @@ fn mat2_mul_mat2_compare() {
     let cm2: cgmath::Matrix2<f32> = mm2.into();
     let cm3 = cm1 * cm2;
 
+    // ultraviolet 0.4.5 `Mat2 * Mat2` returns the transpose of the product so
+    // it isn't compared here.
+
+    let vm1: vek::Mat2<f32> = mm1.into();
+    let vm2: vek::Mat2<f32> = mm2.into();
+    let vm3 = vm1 * vm2;
+
+    let rm1: reference::Mat2 = mm1.into();
+    let rm2: reference::Mat2 = mm2.into();
+    let rm3 = rm1 * rm2;
+
     // use nalgebra as assumed correct answer
     let mm3: mint::ColumnMatrix2<f32> = nm3.into();
 
-    assert_ulps_eq!(cm3, mm3.into());
-    assert_ulps_eq!(gm3, mm3.into());
+    assert_ulps_eq!(cgmath => cm3, mm3.into());
+    assert_ulps_eq!(glam => gm3, mm3.into());
+    assert_ulps_eq!(vek => vm3, mm3.into());
+    assert_ulps_eq!(reference => rm3, mm3.into());
+
+    #[cfg(feature = "pathfinder_geometry")]
+    {
+        use pathfinder_geometry::transform2d::Matrix2x2F;
+        let pm3 = Matrix2x2F::from_mint(mm1) * Matrix2x2F::from_mint(mm2);
+        assert_ulps_eq!(pathfinder => pm3, Matrix2x2F::from_mint(mm3));
+    }
 }
 
 fn mat3_mul_mat3_compare() {
@@ -132,11 +213,24 @@ fn mat3_mul_mat3_compare() {
     let cm2: cgmath::Matrix3<f32> = mm2.into();
     let cm3 = cm1 * cm2;
 
+    let um3 = ultraviolet::Mat3::from_mint(mm1) * ultraviolet::Mat3::from_mint(mm2);
+
+    let vm1: vek::Mat3<f32> = mm1.into();
+    let vm2: vek::Mat3<f32> = mm2.into();
+    let vm3 = vm1 * vm2;
+
+    let rm1: reference::Mat3 = mm1.into();
+    let rm2: reference::Mat3 = mm2.into();
+    let rm3 = rm1 * rm2;
+
     // use nalgebra as assumed correct answer
     let mm3: mint::ColumnMatrix3<f32> = nm3.into();
 
-    assert_ulps_eq!(cm3, mm3.into());
-    assert_ulps_eq!(gm3, mm3.into());
+    assert_ulps_eq!(cgmath => cm3, mm3.into());
+    assert_ulps_eq!(glam => gm3, mm3.into());
+    assert_ulps_eq!(ultraviolet => um3, ultraviolet::Mat3::from_mint(mm3));
+    assert_ulps_eq!(vek => vm3, mm3.into());
+    assert_ulps_eq!(reference => rm3, mm3.into());
 }
 
 fn mat4_mul_mat4_compare() {
@@ -156,11 +250,151 @@ fn mat4_mul_mat4_compare() {
     let cm2: cgmath::Matrix4<f32> = mm2.into();
     let cm3 = cm1 * cm2;
 
+    let um3 = ultraviolet::Mat4::from_mint(mm1) * ultraviolet::Mat4::from_mint(mm2);
+
+    let vm1: vek::Mat4<f32> = mm1.into();
+    let vm2: vek::Mat4<f32> = mm2.into();
+    let vm3 = vm1 * vm2;
+
+    // euclid uses row vectors so `m2.post_transform(m1)` is `m1 * m2`
+    let em1 = support::EuclidTransform3D::from_mint(mm1);
+    let em2 = support::EuclidTransform3D::from_mint(mm2);
+    let em3 = em2.post_transform(&em1);
+
+    let rm1: reference::Mat4 = mm1.into();
+    let rm2: reference::Mat4 = mm2.into();
+    let rm3 = rm1 * rm2;
+
     // use nalgebra as assumed correct answer
     let mm3: mint::ColumnMatrix4<f32> = nm3.into();
 
-    assert_ulps_eq!(cm3, mm3.into());
-    assert_ulps_eq!(gm3, mm3.into());
+    assert_ulps_eq!(cgmath => cm3, mm3.into());
+    assert_ulps_eq!(glam => gm3, mm3.into());
+    assert_ulps_eq!(ultraviolet => um3, ultraviolet::Mat4::from_mint(mm3));
+    assert_ulps_eq!(vek => vm3, mm3.into());
+    assert_ulps_eq!(euclid => em3, support::EuclidTransform3D::from_mint(mm3));
+    assert_ulps_eq!(reference => rm3, mm3.into());
+
+    #[cfg(all(feature = "intrinsics", target_arch = "x86_64"))]
+    {
+        use mathbench::intrinsics::Mat4;
+        let im1 = Mat4::from(mm1);
+        let im2 = Mat4::from(mm2);
+        // the products are summed pairwise rather than in order so the result
+        // can differ in the last couple of bits
+        assert_ulps_eq!(intrinsics => im1 * im2, mm3.into(), epsilon = 1e-6);
+        if is_x86_feature_detected!("avx") {
+            assert_ulps_eq!(intrinsics_avx => unsafe { im1.mul_avx(im2) }, mm3.into(), epsilon = 1e-6);
+        }
+    }
+}
+
+fn mat2_transpose_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat2(&mut rng);
+
+    let gm1: glam::Mat2 = mm1.into();
+    let gmt = gm1.transpose();
+
+    let nm1: nalgebra::Matrix2<f32> = mm1.into();
+    let nmt = nm1.transpose();
+
+    let cm1: cgmath::Matrix2<f32> = mm1.into();
+    let cmt = cgmath::Matrix::transpose(&cm1);
+
+    let vm1: vek::Mat2<f32> = mm1.into();
+    let vmt = vm1.transposed();
+
+    let rm1: reference::Mat2 = mm1.into();
+    let rmt = rm1.transpose();
+
+    // use nalgebra as assumed correct answer
+    let mmt: mint::ColumnMatrix2<f32> = nmt.into();
+
+    assert_ulps_eq!(cgmath => cmt, mmt.into());
+    assert_ulps_eq!(glam => gmt, mmt.into());
+    assert_ulps_eq!(vek => vmt, mmt.into());
+    assert_ulps_eq!(reference => rmt, mmt.into());
+
+    #[cfg(feature = "nalgebra_glm")]
+    {
+        let glmt = nalgebra_glm::transpose(&nm1);
+        assert_ulps_eq!(nalgebra_glm => glmt, mmt.into());
+    }
+}
+
+fn mat3_transpose_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat3(&mut rng);
+
+    let gm1: glam::Mat3 = mm1.into();
+    let gmt = gm1.transpose();
+
+    let nm1: nalgebra::Matrix3<f32> = mm1.into();
+    let nmt = nm1.transpose();
+
+    let cm1: cgmath::Matrix3<f32> = mm1.into();
+    let cmt = cgmath::Matrix::transpose(&cm1);
+
+    let umt = ultraviolet::Mat3::from_mint(mm1).transposed();
+
+    let vm1: vek::Mat3<f32> = mm1.into();
+    let vmt = vm1.transposed();
+
+    let rm1: reference::Mat3 = mm1.into();
+    let rmt = rm1.transpose();
+
+    // use nalgebra as assumed correct answer
+    let mmt: mint::ColumnMatrix3<f32> = nmt.into();
+
+    assert_ulps_eq!(cgmath => cmt, mmt.into());
+    assert_ulps_eq!(glam => gmt, mmt.into());
+    assert_ulps_eq!(ultraviolet => umt, ultraviolet::Mat3::from_mint(mmt));
+    assert_ulps_eq!(vek => vmt, mmt.into());
+    assert_ulps_eq!(reference => rmt, mmt.into());
+
+    #[cfg(feature = "nalgebra_glm")]
+    {
+        let glmt = nalgebra_glm::transpose(&nm1);
+        assert_ulps_eq!(nalgebra_glm => glmt, mmt.into());
+    }
+}
+
+fn mat4_transpose_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat4(&mut rng);
+
+    let gm1: glam::Mat4 = mm1.into();
+    let gmt = gm1.transpose();
+
+    let nm1: nalgebra::Matrix4<f32> = mm1.into();
+    let nmt = nm1.transpose();
+
+    let cm1: cgmath::Matrix4<f32> = mm1.into();
+    let cmt = cgmath::Matrix::transpose(&cm1);
+
+    let umt = ultraviolet::Mat4::from_mint(mm1).transposed();
+
+    let vm1: vek::Mat4<f32> = mm1.into();
+    let vmt = vm1.transposed();
+
+    let rm1: reference::Mat4 = mm1.into();
+    let rmt = rm1.transpose();
+
+    // use nalgebra as assumed correct answer
+    let mmt: mint::ColumnMatrix4<f32> = nmt.into();
+
+    assert_ulps_eq!(cgmath => cmt, mmt.into());
+    assert_ulps_eq!(glam => gmt, mmt.into());
+    assert_ulps_eq!(ultraviolet => umt, ultraviolet::Mat4::from_mint(mmt));
+    assert_ulps_eq!(vek => vmt, mmt.into());
+    assert_ulps_eq!(reference => rmt, mmt.into());
+
+    #[cfg(feature = "nalgebra_glm")]
+    {
+        let glmt = nalgebra_glm::transpose(&nm1);
+        assert_ulps_eq!(nalgebra_glm => glmt, mmt.into());
+    }
 }
 
 fn mat2_det_compare() {
@@ -178,9 +412,101 @@ fn mat2_det_compare() {
     let cm1: cgmath::Matrix2<f32> = mm1.into();
     let cmd = cm1.determinant();
 
+    let vm1: vek::Mat2<f32> = mm1.into();
+    let vmd = vm1.determinant();
+
+    let rm1: reference::Mat2 = mm1.into();
+    let rmd = rm1.determinant();
+
+    // use nalgebra as assumed correct answer
+    assert_ulps_eq!(cgmath => cmd, nmd);
+    assert_ulps_eq!(glam => gmd, nmd);
+    assert_ulps_eq!(vek => vmd, nmd);
+    assert_ulps_eq!(reference => rmd, nmd);
+
+    #[cfg(feature = "pathfinder_geometry")]
+    {
+        use pathfinder_geometry::transform2d::Matrix2x2F;
+        let pmd = Matrix2x2F::from_mint(mm1).det();
+        assert_ulps_eq!(pathfinder => pmd, nmd);
+    }
+
+    #[cfg(feature = "nalgebra_glm")]
+    {
+        let glmd = nalgebra_glm::determinant(&nm1);
+        assert_ulps_eq!(nalgebra_glm => glmd, nmd);
+    }
+}
+
+fn mat3_det_compare() {
+    use cgmath::prelude::*;
+
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat3(&mut rng);
+
+    let gm1: glam::Mat3 = mm1.into();
+    let gmd = gm1.determinant();
+
+    let nm1: nalgebra::Matrix3<f32> = mm1.into();
+    let nmd = nm1.determinant();
+
+    let cm1: cgmath::Matrix3<f32> = mm1.into();
+    let cmd = cm1.determinant();
+
+    let vm1: vek::Mat3<f32> = mm1.into();
+    let vmd = vm1.determinant();
+
+    let rm1: reference::Mat3 = mm1.into();
+    let rmd = rm1.determinant();
+
+    // use nalgebra as assumed correct answer
+    assert_ulps_eq!(cgmath => cmd, nmd, epsilon = 1e-6);
+    assert_ulps_eq!(glam => gmd, nmd, epsilon = 1e-6);
+    assert_ulps_eq!(vek => vmd, nmd, epsilon = 1e-6);
+    assert_ulps_eq!(reference => rmd, nmd, epsilon = 1e-6);
+
+    #[cfg(feature = "nalgebra_glm")]
+    {
+        let glmd = nalgebra_glm::determinant(&nm1);
+        assert_ulps_eq!(nalgebra_glm => glmd, nmd, epsilon = 1e-6);
+    }
+}
+
+fn mat4_det_compare() {
+    use cgmath::prelude::*;
+
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat4(&mut rng);
+
+    let gm1: glam::Mat4 = mm1.into();
+    let gmd = gm1.determinant();
+
+    let nm1: nalgebra::Matrix4<f32> = mm1.into();
+    let nmd = nm1.determinant();
+
+    let cm1: cgmath::Matrix4<f32> = mm1.into();
+    let cmd = cm1.determinant();
+
+    let vm1: vek::Mat4<f32> = mm1.into();
+    let vmd = vm1.determinant();
+
+    let emd = support::EuclidTransform3D::from_mint(mm1).determinant();
+
+    let rm1: reference::Mat4 = mm1.into();
+    let rmd = rm1.determinant();
+
     // use nalgebra as assumed correct answer
-    assert_ulps_eq!(cmd, nmd);
-    assert_ulps_eq!(gmd, nmd);
+    assert_ulps_eq!(cgmath => cmd, nmd, epsilon = 1e-6);
+    assert_ulps_eq!(glam => gmd, nmd, epsilon = 1e-6);
+    assert_ulps_eq!(vek => vmd, nmd, epsilon = 1e-6);
+    assert_ulps_eq!(euclid => emd, nmd, epsilon = 1e-6);
+    assert_ulps_eq!(reference => rmd, nmd, epsilon = 1e-6);
+
+    #[cfg(feature = "nalgebra_glm")]
+    {
+        let glmd = nalgebra_glm::determinant(&nm1);
+        assert_ulps_eq!(nalgebra_glm => glmd, nmd, epsilon = 1e-6);
+    }
 }
 
 fn mat2_inv_compare() {
@@ -200,12 +526,29 @@ fn mat2_inv_compare() {
     let cmi = cm1.invert();
     assert!(cmi.is_some());
 
+    let rm1: reference::Mat2 = mm1.into();
+    let rmi = rm1.inverse();
+
     // use nalgebra as assumed correct answer
     let mmi: mint::ColumnMatrix2<f32> = nmi.unwrap().into();
 
-    assert_ulps_eq!(cmi.unwrap(), mmi.into());
+    assert_ulps_eq!(cgmath => cmi.unwrap(), mmi.into());
     // TODO: actually make a ulps test
-    assert_ulps_eq!(gmi, mmi.into(), epsilon = 1e-2);
+    assert_ulps_eq!(glam => gmi, mmi.into(), epsilon = 1e-2);
+    assert_ulps_eq!(reference => rmi, mmi.into(), epsilon = 1e-2);
+
+    #[cfg(feature = "pathfinder_geometry")]
+    {
+        use pathfinder_geometry::transform2d::Matrix2x2F;
+        let pmi = Matrix2x2F::from_mint(mm1).inverse();
+        assert_ulps_eq!(pathfinder => pmi, Matrix2x2F::from_mint(mmi), epsilon = 1e-2);
+    }
+
+    #[cfg(feature = "nalgebra_glm")]
+    {
+        let glmi = nalgebra_glm::inverse(&nm1);
+        assert_ulps_eq!(nalgebra_glm => glmi, mmi.into(), epsilon = 1e-2);
+    }
 }
 
 fn mat3_inv_compare() {
@@ -225,11 +568,24 @@ fn mat3_inv_compare() {
     let cmi = cm1.invert();
     assert!(cmi.is_some());
 
+    // ultraviolet 0.4.5 `Mat3::inversed` computes the determinant incorrectly
+    // so it isn't compared here.
+
+    let rm1: reference::Mat3 = mm1.into();
+    let rmi = rm1.inverse();
+
     // use nalgebra as assumed correct answer
     let mmi: mint::ColumnMatrix3<f32> = nmi.unwrap().into();
 
-    assert_ulps_eq!(cmi.unwrap(), mmi.into());
-    assert_ulps_eq!(gmi, mmi.into(), epsilon = 0.0001);
+    assert_ulps_eq!(cgmath => cmi.unwrap(), mmi.into());
+    assert_ulps_eq!(glam => gmi, mmi.into(), epsilon = 0.0001);
+    assert_ulps_eq!(reference => rmi, mmi.into(), epsilon = 0.0001);
+
+    #[cfg(feature = "nalgebra_glm")]
+    {
+        let glmi = nalgebra_glm::inverse(&nm1);
+        assert_ulps_eq!(nalgebra_glm => glmi, mmi.into(), epsilon = 0.0001);
+    }
 }
 
 fn mat4_inv_compare() {
@@ -249,79 +605,1136 @@ fn mat4_inv_compare() {
     let cmi = cm1.invert();
     assert!(cmi.is_some());
 
+    let umi = ultraviolet::Mat4::from_mint(mm1).inversed();
+
+    let vm1: vek::Mat4<f32> = mm1.into();
+    let vmi = vm1.inverted();
+
+    let emi = support::EuclidTransform3D::from_mint(mm1).inverse();
+    assert!(emi.is_some());
+
+    let rm1: reference::Mat4 = mm1.into();
+    let rmi = rm1.inverse();
+
     // use nalgebra as assumed correct answer
     let mmi: mint::ColumnMatrix4<f32> = nmi.unwrap().into();
 
-    assert_ulps_eq!(cmi.unwrap(), mmi.into(), epsilon = 0.0001);
-    assert_ulps_eq!(gmi, mmi.into(), epsilon = 0.0001);
-}
-
-#[test]
-fn test_mat2_mul_vec2() {
-    for _ in 0..NUM_ITERS {
-        mat2_mul_vec2_compare();
+    assert_ulps_eq!(cgmath => cmi.unwrap(), mmi.into(), epsilon = 0.0001);
+    assert_ulps_eq!(glam => gmi, mmi.into(), epsilon = 0.0001);
+    assert_ulps_eq!(ultraviolet => umi, ultraviolet::Mat4::from_mint(mmi), epsilon = 0.0001);
+    assert_ulps_eq!(vek => vmi, mmi.into(), epsilon = 0.0001);
+    assert_ulps_eq!(
+        euclid => emi.unwrap(),
+        support::EuclidTransform3D::from_mint(mmi),
+        epsilon = 0.0001
+    );
+    assert_ulps_eq!(reference => rmi, mmi.into(), epsilon = 0.0001);
+
+    #[cfg(all(feature = "intrinsics", target_arch = "x86_64"))]
+    {
+        let imi = mathbench::intrinsics::Mat4::from(mm1).inverse();
+        assert_ulps_eq!(intrinsics => imi, mmi.into(), epsilon = 0.0001);
     }
-}
 
-#[test]
-fn test_mat3_mul_vec3() {
-    for _ in 0..NUM_ITERS {
-        mat3_mul_vec3_compare();
+    #[cfg(feature = "nalgebra_glm")]
+    {
+        let glmi = nalgebra_glm::inverse(&nm1);
+        assert_ulps_eq!(nalgebra_glm => glmi, mmi.into(), epsilon = 0.0001);
     }
 }
 
-#[test]
-fn test_mat4_mul_vec4() {
-    for _ in 0..NUM_ITERS {
-        mat4_mul_vec4_compare();
-    }
-}
+fn mat2_add_mat2_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat2(&mut rng);
+    let mm2 = random_mint_mat2(&mut rng);
 
-#[test]
-fn test_mat2_mul_mat2() {
-    for _ in 0..NUM_ITERS {
-        mat2_mul_mat2_compare();
-    }
-}
+    let gm1: glam::Mat2 = mm1.into();
+    let gm2: glam::Mat2 = mm2.into();
+    let gm3 = gm1 + gm2;
 
-#[test]
-fn test_mat3_mul_mat3() {
-    for _ in 0..NUM_ITERS {
-        mat3_mul_mat3_compare();
+    let nm1: nalgebra::Matrix2<f32> = mm1.into();
+    let nm2: nalgebra::Matrix2<f32> = mm2.into();
+    let nm3 = nm1 + nm2;
+
+    let cm1: cgmath::Matrix2<f32> = mm1.into();
+    let cm2: cgmath::Matrix2<f32> = mm2.into();
+    let cm3 = cm1 + cm2;
+
+    let um1 = ultraviolet::Mat2::from_mint(mm1);
+    let um2 = ultraviolet::Mat2::from_mint(mm2);
+    let um3 = um1.add_matrix(um2);
+
+    let vm1: vek::Mat2<f32> = mm1.into();
+    let vm2: vek::Mat2<f32> = mm2.into();
+    let vm3 = vm1 + vm2;
+
+    let rm1: reference::Mat2 = mm1.into();
+    let rm2: reference::Mat2 = mm2.into();
+    let rm3 = rm1 + rm2;
+
+    // use nalgebra as assumed correct answer
+    let mm3: mint::ColumnMatrix2<f32> = nm3.into();
+
+    assert_ulps_eq!(cgmath => cm3, mm3.into());
+    assert_ulps_eq!(glam => gm3, mm3.into());
+    assert_ulps_eq!(ultraviolet => um3, ultraviolet::Mat2::from_mint(mm3));
+    assert_ulps_eq!(vek => vm3, mm3.into());
+    assert_ulps_eq!(reference => rm3, mm3.into());
+
+    #[cfg(feature = "pathfinder_geometry")]
+    {
+        use pathfinder_geometry::transform2d::Matrix2x2F;
+        let pm3 = Matrix2x2F::from_mint(mm1).add_matrix(Matrix2x2F::from_mint(mm2));
+        assert_ulps_eq!(pathfinder => pm3, Matrix2x2F::from_mint(mm3));
     }
 }
 
-#[test]
-fn test_mat4_mul_mat4() {
-    for _ in 0..NUM_ITERS {
-        mat4_mul_mat4_compare();
-    }
+fn mat3_add_mat3_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat3(&mut rng);
+    let mm2 = random_mint_mat3(&mut rng);
+
+    let gm1: glam::Mat3 = mm1.into();
+    let gm2: glam::Mat3 = mm2.into();
+    let gm3 = gm1 + gm2;
+
+    let nm1: nalgebra::Matrix3<f32> = mm1.into();
+    let nm2: nalgebra::Matrix3<f32> = mm2.into();
+    let nm3 = nm1 + nm2;
+
+    let cm1: cgmath::Matrix3<f32> = mm1.into();
+    let cm2: cgmath::Matrix3<f32> = mm2.into();
+    let cm3 = cm1 + cm2;
+
+    let um1 = ultraviolet::Mat3::from_mint(mm1);
+    let um2 = ultraviolet::Mat3::from_mint(mm2);
+    let um3 = um1.add_matrix(um2);
+
+    let vm1: vek::Mat3<f32> = mm1.into();
+    let vm2: vek::Mat3<f32> = mm2.into();
+    let vm3 = vm1 + vm2;
+
+    let rm1: reference::Mat3 = mm1.into();
+    let rm2: reference::Mat3 = mm2.into();
+    let rm3 = rm1 + rm2;
+
+    // use nalgebra as assumed correct answer
+    let mm3: mint::ColumnMatrix3<f32> = nm3.into();
+
+    assert_ulps_eq!(cgmath => cm3, mm3.into());
+    assert_ulps_eq!(glam => gm3, mm3.into());
+    assert_ulps_eq!(ultraviolet => um3, ultraviolet::Mat3::from_mint(mm3));
+    assert_ulps_eq!(vek => vm3, mm3.into());
+    assert_ulps_eq!(reference => rm3, mm3.into());
 }
 
-#[test]
-fn test_mat2_det() {
-    for _ in 0..NUM_ITERS {
-        mat2_det_compare();
-    }
+fn mat4_add_mat4_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat4(&mut rng);
+    let mm2 = random_mint_mat4(&mut rng);
+
+    let gm1: glam::Mat4 = mm1.into();
+    let gm2: glam::Mat4 = mm2.into();
+    let gm3 = gm1 + gm2;
+
+    let nm1: nalgebra::Matrix4<f32> = mm1.into();
+    let nm2: nalgebra::Matrix4<f32> = mm2.into();
+    let nm3 = nm1 + nm2;
+
+    let cm1: cgmath::Matrix4<f32> = mm1.into();
+    let cm2: cgmath::Matrix4<f32> = mm2.into();
+    let cm3 = cm1 + cm2;
+
+    let um1 = ultraviolet::Mat4::from_mint(mm1);
+    let um2 = ultraviolet::Mat4::from_mint(mm2);
+    let um3 = um1.add_matrix(um2);
+
+    let vm1: vek::Mat4<f32> = mm1.into();
+    let vm2: vek::Mat4<f32> = mm2.into();
+    let vm3 = vm1 + vm2;
+
+    let rm1: reference::Mat4 = mm1.into();
+    let rm2: reference::Mat4 = mm2.into();
+    let rm3 = rm1 + rm2;
+
+    // use nalgebra as assumed correct answer
+    let mm3: mint::ColumnMatrix4<f32> = nm3.into();
+
+    assert_ulps_eq!(cgmath => cm3, mm3.into());
+    assert_ulps_eq!(glam => gm3, mm3.into());
+    assert_ulps_eq!(ultraviolet => um3, ultraviolet::Mat4::from_mint(mm3));
+    assert_ulps_eq!(vek => vm3, mm3.into());
+    assert_ulps_eq!(reference => rm3, mm3.into());
 }
 
-#[test]
-fn test_mat2_inverse() {
-    for _ in 0..NUM_ITERS {
-        mat2_inv_compare();
+fn mat2_sub_mat2_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat2(&mut rng);
+    let mm2 = random_mint_mat2(&mut rng);
+
+    let gm1: glam::Mat2 = mm1.into();
+    let gm2: glam::Mat2 = mm2.into();
+    let gm3 = gm1 - gm2;
+
+    let nm1: nalgebra::Matrix2<f32> = mm1.into();
+    let nm2: nalgebra::Matrix2<f32> = mm2.into();
+    let nm3 = nm1 - nm2;
+
+    let cm1: cgmath::Matrix2<f32> = mm1.into();
+    let cm2: cgmath::Matrix2<f32> = mm2.into();
+    let cm3 = cm1 - cm2;
+
+    let um1 = ultraviolet::Mat2::from_mint(mm1);
+    let um2 = ultraviolet::Mat2::from_mint(mm2);
+    let um3 = um1.sub_matrix(um2);
+
+    let vm1: vek::Mat2<f32> = mm1.into();
+    let vm2: vek::Mat2<f32> = mm2.into();
+    let vm3 = vm1 - vm2;
+
+    let rm1: reference::Mat2 = mm1.into();
+    let rm2: reference::Mat2 = mm2.into();
+    let rm3 = rm1 - rm2;
+
+    // use nalgebra as assumed correct answer
+    let mm3: mint::ColumnMatrix2<f32> = nm3.into();
+
+    assert_ulps_eq!(cgmath => cm3, mm3.into());
+    assert_ulps_eq!(glam => gm3, mm3.into());
+    assert_ulps_eq!(ultraviolet => um3, ultraviolet::Mat2::from_mint(mm3));
+    assert_ulps_eq!(vek => vm3, mm3.into());
+    assert_ulps_eq!(reference => rm3, mm3.into());
+
+    #[cfg(feature = "pathfinder_geometry")]
+    {
+        use pathfinder_geometry::transform2d::Matrix2x2F;
+        let pm3 = Matrix2x2F::from_mint(mm1) - Matrix2x2F::from_mint(mm2);
+        assert_ulps_eq!(pathfinder => pm3, Matrix2x2F::from_mint(mm3));
     }
 }
 
-#[test]
-fn test_mat3_inverse() {
-    for _ in 0..NUM_ITERS {
-        mat3_inv_compare();
-    }
+fn mat3_sub_mat3_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat3(&mut rng);
+    let mm2 = random_mint_mat3(&mut rng);
+
+    let gm1: glam::Mat3 = mm1.into();
+    let gm2: glam::Mat3 = mm2.into();
+    let gm3 = gm1 - gm2;
+
+    let nm1: nalgebra::Matrix3<f32> = mm1.into();
+    let nm2: nalgebra::Matrix3<f32> = mm2.into();
+    let nm3 = nm1 - nm2;
+
+    let cm1: cgmath::Matrix3<f32> = mm1.into();
+    let cm2: cgmath::Matrix3<f32> = mm2.into();
+    let cm3 = cm1 - cm2;
+
+    let um1 = ultraviolet::Mat3::from_mint(mm1);
+    let um2 = ultraviolet::Mat3::from_mint(mm2);
+    let um3 = um1.sub_matrix(um2);
+
+    let vm1: vek::Mat3<f32> = mm1.into();
+    let vm2: vek::Mat3<f32> = mm2.into();
+    let vm3 = vm1 - vm2;
+
+    let rm1: reference::Mat3 = mm1.into();
+    let rm2: reference::Mat3 = mm2.into();
+    let rm3 = rm1 - rm2;
+
+    // use nalgebra as assumed correct answer
+    let mm3: mint::ColumnMatrix3<f32> = nm3.into();
+
+    assert_ulps_eq!(cgmath => cm3, mm3.into());
+    assert_ulps_eq!(glam => gm3, mm3.into());
+    assert_ulps_eq!(ultraviolet => um3, ultraviolet::Mat3::from_mint(mm3));
+    assert_ulps_eq!(vek => vm3, mm3.into());
+    assert_ulps_eq!(reference => rm3, mm3.into());
 }
 
-#[test]
-fn test_mat4_inverse() {
-    for _ in 0..NUM_ITERS {
-        mat4_inv_compare();
-    }
+fn mat4_sub_mat4_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat4(&mut rng);
+    let mm2 = random_mint_mat4(&mut rng);
+
+    let gm1: glam::Mat4 = mm1.into();
+    let gm2: glam::Mat4 = mm2.into();
+    let gm3 = gm1 - gm2;
+
+    let nm1: nalgebra::Matrix4<f32> = mm1.into();
+    let nm2: nalgebra::Matrix4<f32> = mm2.into();
+    let nm3 = nm1 - nm2;
+
+    let cm1: cgmath::Matrix4<f32> = mm1.into();
+    let cm2: cgmath::Matrix4<f32> = mm2.into();
+    let cm3 = cm1 - cm2;
+
+    let um1 = ultraviolet::Mat4::from_mint(mm1);
+    let um2 = ultraviolet::Mat4::from_mint(mm2);
+    let um3 = um1.sub_matrix(um2);
+
+    let vm1: vek::Mat4<f32> = mm1.into();
+    let vm2: vek::Mat4<f32> = mm2.into();
+    let vm3 = vm1 - vm2;
+
+    let rm1: reference::Mat4 = mm1.into();
+    let rm2: reference::Mat4 = mm2.into();
+    let rm3 = rm1 - rm2;
+
+    // use nalgebra as assumed correct answer
+    let mm3: mint::ColumnMatrix4<f32> = nm3.into();
+
+    assert_ulps_eq!(cgmath => cm3, mm3.into());
+    assert_ulps_eq!(glam => gm3, mm3.into());
+    assert_ulps_eq!(ultraviolet => um3, ultraviolet::Mat4::from_mint(mm3));
+    assert_ulps_eq!(vek => vm3, mm3.into());
+    assert_ulps_eq!(reference => rm3, mm3.into());
+}
+
+fn mat2_transpose_mul_mat2_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat2(&mut rng);
+    let mm2 = random_mint_mat2(&mut rng);
+
+    let gm1: glam::Mat2 = mm1.into();
+    let gm2: glam::Mat2 = mm2.into();
+    let gm3 = gm1.transpose_mul(&gm2);
+
+    let nm1: nalgebra::Matrix2<f32> = mm1.into();
+    let nm2: nalgebra::Matrix2<f32> = mm2.into();
+    let nm3 = nm1.tr_mul(&nm2);
+
+    let cm1: cgmath::Matrix2<f32> = mm1.into();
+    let cm2: cgmath::Matrix2<f32> = mm2.into();
+    let cm3 = cm1.transpose_mul(&cm2);
+
+    let um1 = ultraviolet::Mat2::from_mint(mm1);
+    let um2 = ultraviolet::Mat2::from_mint(mm2);
+    let um3 = um1.transpose_mul(um2);
+
+    let vm1: vek::Mat2<f32> = mm1.into();
+    let vm2: vek::Mat2<f32> = mm2.into();
+    let vm3 = vm1.transposed_mul(vm2);
+
+    let rm1: reference::Mat2 = mm1.into();
+    let rm2: reference::Mat2 = mm2.into();
+    let rm3 = rm1.transpose_mul(rm2);
+
+    // use nalgebra as assumed correct answer
+    let mm3: mint::ColumnMatrix2<f32> = nm3.into();
+
+    assert_ulps_eq!(cgmath => cm3, mm3.into(), epsilon = 1e-6);
+    assert_ulps_eq!(glam => gm3, mm3.into(), epsilon = 1e-6);
+    assert_ulps_eq!(ultraviolet => um3, ultraviolet::Mat2::from_mint(mm3), epsilon = 1e-6);
+    assert_ulps_eq!(vek => vm3, mm3.into(), epsilon = 1e-6);
+    assert_ulps_eq!(reference => rm3, mm3.into(), epsilon = 1e-6);
+
+    #[cfg(feature = "pathfinder_geometry")]
+    {
+        use pathfinder_geometry::transform2d::Matrix2x2F;
+        let pm3 = Matrix2x2F::from_mint(mm1).transpose_mul(Matrix2x2F::from_mint(mm2));
+        assert_ulps_eq!(pathfinder => pm3, Matrix2x2F::from_mint(mm3), epsilon = 1e-6);
+    }
+}
+
+fn mat3_transpose_mul_mat3_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat3(&mut rng);
+    let mm2 = random_mint_mat3(&mut rng);
+
+    let gm1: glam::Mat3 = mm1.into();
+    let gm2: glam::Mat3 = mm2.into();
+    let gm3 = gm1.transpose_mul(&gm2);
+
+    let nm1: nalgebra::Matrix3<f32> = mm1.into();
+    let nm2: nalgebra::Matrix3<f32> = mm2.into();
+    let nm3 = nm1.tr_mul(&nm2);
+
+    let cm1: cgmath::Matrix3<f32> = mm1.into();
+    let cm2: cgmath::Matrix3<f32> = mm2.into();
+    let cm3 = cm1.transpose_mul(&cm2);
+
+    let um1 = ultraviolet::Mat3::from_mint(mm1);
+    let um2 = ultraviolet::Mat3::from_mint(mm2);
+    let um3 = um1.transpose_mul(um2);
+
+    let vm1: vek::Mat3<f32> = mm1.into();
+    let vm2: vek::Mat3<f32> = mm2.into();
+    let vm3 = vm1.transposed_mul(vm2);
+
+    let rm1: reference::Mat3 = mm1.into();
+    let rm2: reference::Mat3 = mm2.into();
+    let rm3 = rm1.transpose_mul(rm2);
+
+    // use nalgebra as assumed correct answer
+    let mm3: mint::ColumnMatrix3<f32> = nm3.into();
+
+    assert_ulps_eq!(cgmath => cm3, mm3.into(), epsilon = 1e-6);
+    assert_ulps_eq!(glam => gm3, mm3.into(), epsilon = 1e-6);
+    assert_ulps_eq!(ultraviolet => um3, ultraviolet::Mat3::from_mint(mm3), epsilon = 1e-6);
+    assert_ulps_eq!(vek => vm3, mm3.into(), epsilon = 1e-6);
+    assert_ulps_eq!(reference => rm3, mm3.into(), epsilon = 1e-6);
+}
+
+fn mat4_transpose_mul_mat4_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat4(&mut rng);
+    let mm2 = random_mint_mat4(&mut rng);
+
+    let gm1: glam::Mat4 = mm1.into();
+    let gm2: glam::Mat4 = mm2.into();
+    let gm3 = gm1.transpose_mul(&gm2);
+
+    let nm1: nalgebra::Matrix4<f32> = mm1.into();
+    let nm2: nalgebra::Matrix4<f32> = mm2.into();
+    let nm3 = nm1.tr_mul(&nm2);
+
+    let cm1: cgmath::Matrix4<f32> = mm1.into();
+    let cm2: cgmath::Matrix4<f32> = mm2.into();
+    let cm3 = cm1.transpose_mul(&cm2);
+
+    let um1 = ultraviolet::Mat4::from_mint(mm1);
+    let um2 = ultraviolet::Mat4::from_mint(mm2);
+    let um3 = um1.transpose_mul(um2);
+
+    let vm1: vek::Mat4<f32> = mm1.into();
+    let vm2: vek::Mat4<f32> = mm2.into();
+    let vm3 = vm1.transposed_mul(vm2);
+
+    let rm1: reference::Mat4 = mm1.into();
+    let rm2: reference::Mat4 = mm2.into();
+    let rm3 = rm1.transpose_mul(rm2);
+
+    // use nalgebra as assumed correct answer
+    let mm3: mint::ColumnMatrix4<f32> = nm3.into();
+
+    assert_ulps_eq!(cgmath => cm3, mm3.into(), epsilon = 1e-6);
+    assert_ulps_eq!(glam => gm3, mm3.into(), epsilon = 1e-6);
+    assert_ulps_eq!(ultraviolet => um3, ultraviolet::Mat4::from_mint(mm3), epsilon = 1e-6);
+    assert_ulps_eq!(vek => vm3, mm3.into(), epsilon = 1e-6);
+    assert_ulps_eq!(reference => rm3, mm3.into(), epsilon = 1e-6);
+}
+
+fn mat2_mul_scalar_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat2(&mut rng);
+    let s: f32 = rand::Rng::gen(&mut rng);
+
+    let gm1: glam::Mat2 = mm1.into();
+    let gm2 = gm1 * s;
+
+    let nm1: nalgebra::Matrix2<f32> = mm1.into();
+    let nm2 = nm1 * s;
+
+    let cm1: cgmath::Matrix2<f32> = mm1.into();
+    let cm2 = cm1 * s;
+
+    let um1 = ultraviolet::Mat2::from_mint(mm1);
+    let um2 = um1.mul_scalar(s);
+
+    let vm1: vek::Mat2<f32> = mm1.into();
+    let vm2 = vm1 * s;
+
+    let rm1: reference::Mat2 = mm1.into();
+    let rm2 = rm1 * s;
+
+    // use nalgebra as assumed correct answer
+    let mm2: mint::ColumnMatrix2<f32> = nm2.into();
+
+    assert_ulps_eq!(cgmath => cm2, mm2.into());
+    assert_ulps_eq!(glam => gm2, mm2.into());
+    assert_ulps_eq!(ultraviolet => um2, ultraviolet::Mat2::from_mint(mm2));
+    assert_ulps_eq!(vek => vm2, mm2.into());
+    assert_ulps_eq!(reference => rm2, mm2.into());
+
+    #[cfg(feature = "pathfinder_geometry")]
+    {
+        use pathfinder_geometry::transform2d::Matrix2x2F;
+        let pm2 = Matrix2x2F::from_mint(mm1).mul_scalar(s);
+        assert_ulps_eq!(pathfinder => pm2, Matrix2x2F::from_mint(mm2));
+    }
+}
+
+fn mat3_mul_scalar_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat3(&mut rng);
+    let s: f32 = rand::Rng::gen(&mut rng);
+
+    let gm1: glam::Mat3 = mm1.into();
+    let gm2 = gm1 * s;
+
+    let nm1: nalgebra::Matrix3<f32> = mm1.into();
+    let nm2 = nm1 * s;
+
+    let cm1: cgmath::Matrix3<f32> = mm1.into();
+    let cm2 = cm1 * s;
+
+    let um1 = ultraviolet::Mat3::from_mint(mm1);
+    let um2 = um1.mul_scalar(s);
+
+    let vm1: vek::Mat3<f32> = mm1.into();
+    let vm2 = vm1 * s;
+
+    let rm1: reference::Mat3 = mm1.into();
+    let rm2 = rm1 * s;
+
+    // use nalgebra as assumed correct answer
+    let mm2: mint::ColumnMatrix3<f32> = nm2.into();
+
+    assert_ulps_eq!(cgmath => cm2, mm2.into());
+    assert_ulps_eq!(glam => gm2, mm2.into());
+    assert_ulps_eq!(ultraviolet => um2, ultraviolet::Mat3::from_mint(mm2));
+    assert_ulps_eq!(vek => vm2, mm2.into());
+    assert_ulps_eq!(reference => rm2, mm2.into());
+}
+
+fn mat4_mul_scalar_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat4(&mut rng);
+    let s: f32 = rand::Rng::gen(&mut rng);
+
+    let gm1: glam::Mat4 = mm1.into();
+    let gm2 = gm1 * s;
+
+    let nm1: nalgebra::Matrix4<f32> = mm1.into();
+    let nm2 = nm1 * s;
+
+    let cm1: cgmath::Matrix4<f32> = mm1.into();
+    let cm2 = cm1 * s;
+
+    let um1 = ultraviolet::Mat4::from_mint(mm1);
+    let um2 = um1 * s;
+
+    let vm1: vek::Mat4<f32> = mm1.into();
+    let vm2 = vm1 * s;
+
+    let em2 = support::EuclidTransform3D::from_mint(mm1).mul_s(s);
+
+    let rm1: reference::Mat4 = mm1.into();
+    let rm2 = rm1 * s;
+
+    // use nalgebra as assumed correct answer
+    let mm2: mint::ColumnMatrix4<f32> = nm2.into();
+
+    assert_ulps_eq!(cgmath => cm2, mm2.into());
+    assert_ulps_eq!(glam => gm2, mm2.into());
+    assert_ulps_eq!(ultraviolet => um2, ultraviolet::Mat4::from_mint(mm2));
+    assert_ulps_eq!(vek => vm2, mm2.into());
+    assert_ulps_eq!(euclid => em2, support::EuclidTransform3D::from_mint(mm2));
+    assert_ulps_eq!(reference => rm2, mm2.into());
+}
+
+fn mat2_trace_compare() {
+    use cgmath::prelude::*;
+
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat2(&mut rng);
+
+    let gm1: glam::Mat2 = mm1.into();
+    let gmt = gm1.trace();
+
+    let nm1: nalgebra::Matrix2<f32> = mm1.into();
+    let nmt = nm1.trace();
+
+    let cm1: cgmath::Matrix2<f32> = mm1.into();
+    let cmt = cm1.trace();
+
+    let umt = ultraviolet::Mat2::from_mint(mm1).trace();
+
+    let vm1: vek::Mat2<f32> = mm1.into();
+    let vmt = vm1.trace();
+
+    let rm1: reference::Mat2 = mm1.into();
+    let rmt = rm1.trace();
+
+    // use nalgebra as assumed correct answer
+    assert_ulps_eq!(cgmath => cmt, nmt, epsilon = 1e-6);
+    assert_ulps_eq!(glam => gmt, nmt, epsilon = 1e-6);
+    assert_ulps_eq!(ultraviolet => umt, nmt, epsilon = 1e-6);
+    assert_ulps_eq!(vek => vmt, nmt, epsilon = 1e-6);
+    assert_ulps_eq!(reference => rmt, nmt, epsilon = 1e-6);
+
+    #[cfg(feature = "pathfinder_geometry")]
+    {
+        use pathfinder_geometry::transform2d::Matrix2x2F;
+        let pmt = Matrix2x2F::from_mint(mm1).trace();
+        assert_ulps_eq!(pathfinder => pmt, nmt, epsilon = 1e-6);
+    }
+}
+
+fn mat3_trace_compare() {
+    use cgmath::prelude::*;
+
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat3(&mut rng);
+
+    let gm1: glam::Mat3 = mm1.into();
+    let gmt = gm1.trace();
+
+    let nm1: nalgebra::Matrix3<f32> = mm1.into();
+    let nmt = nm1.trace();
+
+    let cm1: cgmath::Matrix3<f32> = mm1.into();
+    let cmt = cm1.trace();
+
+    let umt = ultraviolet::Mat3::from_mint(mm1).trace();
+
+    let vm1: vek::Mat3<f32> = mm1.into();
+    let vmt = vm1.trace();
+
+    let rm1: reference::Mat3 = mm1.into();
+    let rmt = rm1.trace();
+
+    // use nalgebra as assumed correct answer
+    assert_ulps_eq!(cgmath => cmt, nmt, epsilon = 1e-6);
+    assert_ulps_eq!(glam => gmt, nmt, epsilon = 1e-6);
+    assert_ulps_eq!(ultraviolet => umt, nmt, epsilon = 1e-6);
+    assert_ulps_eq!(vek => vmt, nmt, epsilon = 1e-6);
+    assert_ulps_eq!(reference => rmt, nmt, epsilon = 1e-6);
+}
+
+fn mat4_trace_compare() {
+    use cgmath::prelude::*;
+
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat4(&mut rng);
+
+    let gm1: glam::Mat4 = mm1.into();
+    let gmt = gm1.trace();
+
+    let nm1: nalgebra::Matrix4<f32> = mm1.into();
+    let nmt = nm1.trace();
+
+    let cm1: cgmath::Matrix4<f32> = mm1.into();
+    let cmt = cm1.trace();
+
+    let umt = ultraviolet::Mat4::from_mint(mm1).trace();
+
+    let vm1: vek::Mat4<f32> = mm1.into();
+    let vmt = vm1.trace();
+
+    let rm1: reference::Mat4 = mm1.into();
+    let rmt = rm1.trace();
+
+    // use nalgebra as assumed correct answer
+    assert_ulps_eq!(cgmath => cmt, nmt, epsilon = 1e-6);
+    assert_ulps_eq!(glam => gmt, nmt, epsilon = 1e-6);
+    assert_ulps_eq!(ultraviolet => umt, nmt, epsilon = 1e-6);
+    assert_ulps_eq!(vek => vmt, nmt, epsilon = 1e-6);
+    assert_ulps_eq!(reference => rmt, nmt, epsilon = 1e-6);
+}
+
+fn mat2_from_cols_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mx = random_mint_vec2(&mut rng);
+    let my = random_mint_vec2(&mut rng);
+
+    let gm = glam::Mat2::from_cols(mx.into(), my.into());
+
+    let nm = nalgebra::Matrix2::from_columns(&[mx.into(), my.into()]);
+
+    let cm = cgmath::Matrix2::from_cols(mx.into(), my.into());
+
+    let um = ultraviolet::Mat2::new(
+        ultraviolet::Vec2::from_mint(mx),
+        ultraviolet::Vec2::from_mint(my),
+    );
+
+    let vm = vek::Mat2 {
+        cols: vek::Vec2::new(mx.into(), my.into()),
+    };
+
+    let rm = reference::Mat2::from_cols(mx.into(), my.into());
+
+    // use nalgebra as assumed correct answer
+    let mm: mint::ColumnMatrix2<f32> = nm.into();
+
+    assert_ulps_eq!(cgmath => cm, mm.into());
+    assert_ulps_eq!(glam => gm, mm.into());
+    assert_ulps_eq!(ultraviolet => um, ultraviolet::Mat2::from_mint(mm));
+    assert_ulps_eq!(vek => vm, mm.into());
+    assert_ulps_eq!(reference => rm, mm.into());
+
+    #[cfg(feature = "pathfinder_geometry")]
+    {
+        use pathfinder_geometry::{transform2d::Matrix2x2F, vector::Vector2F};
+        let pm = Matrix2x2F(
+            Vector2F::from_mint(mx)
+                .0
+                .concat_xy_xy(Vector2F::from_mint(my).0),
+        );
+        assert_ulps_eq!(pathfinder => pm, Matrix2x2F::from_mint(mm));
+    }
+}
+
+fn mat3_from_cols_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mx = random_mint_vec3(&mut rng);
+    let my = random_mint_vec3(&mut rng);
+    let mz = random_mint_vec3(&mut rng);
+
+    let gm = glam::Mat3::from_cols(mx.into(), my.into(), mz.into());
+
+    let nm = nalgebra::Matrix3::from_columns(&[mx.into(), my.into(), mz.into()]);
+
+    let cm = cgmath::Matrix3::from_cols(mx.into(), my.into(), mz.into());
+
+    let um = ultraviolet::Mat3::new(
+        ultraviolet::Vec3::from_mint(mx),
+        ultraviolet::Vec3::from_mint(my),
+        ultraviolet::Vec3::from_mint(mz),
+    );
+
+    let vm = vek::Mat3 {
+        cols: vek::Vec3::new(mx.into(), my.into(), mz.into()),
+    };
+
+    let rm = reference::Mat3::from_cols(mx.into(), my.into(), mz.into());
+
+    // use nalgebra as assumed correct answer
+    let mm: mint::ColumnMatrix3<f32> = nm.into();
+
+    assert_ulps_eq!(cgmath => cm, mm.into());
+    assert_ulps_eq!(glam => gm, mm.into());
+    assert_ulps_eq!(ultraviolet => um, ultraviolet::Mat3::from_mint(mm));
+    assert_ulps_eq!(vek => vm, mm.into());
+    assert_ulps_eq!(reference => rm, mm.into());
+}
+
+fn mat4_from_cols_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mx = random_mint_vec4(&mut rng);
+    let my = random_mint_vec4(&mut rng);
+    let mz = random_mint_vec4(&mut rng);
+    let mw = random_mint_vec4(&mut rng);
+
+    let gm = glam::Mat4::from_cols(mx.into(), my.into(), mz.into(), mw.into());
+
+    let nm = nalgebra::Matrix4::from_columns(&[mx.into(), my.into(), mz.into(), mw.into()]);
+
+    let cm = cgmath::Matrix4::from_cols(mx.into(), my.into(), mz.into(), mw.into());
+
+    let um = ultraviolet::Mat4::new(
+        ultraviolet::Vec4::from_mint(mx),
+        ultraviolet::Vec4::from_mint(my),
+        ultraviolet::Vec4::from_mint(mz),
+        ultraviolet::Vec4::from_mint(mw),
+    );
+
+    let vm = vek::Mat4 {
+        cols: vek::Vec4::new(mx.into(), my.into(), mz.into(), mw.into()),
+    };
+
+    let rm = reference::Mat4::from_cols(mx.into(), my.into(), mz.into(), mw.into());
+
+    // use nalgebra as assumed correct answer
+    let mm: mint::ColumnMatrix4<f32> = nm.into();
+
+    assert_ulps_eq!(cgmath => cm, mm.into());
+    assert_ulps_eq!(glam => gm, mm.into());
+    assert_ulps_eq!(ultraviolet => um, ultraviolet::Mat4::from_mint(mm));
+    assert_ulps_eq!(vek => vm, mm.into());
+    assert_ulps_eq!(reference => rm, mm.into());
+}
+
+fn mat4_affine_inverse_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_homogeneous_mat4(&mut rng);
+
+    let gm1: glam::Mat4 = mm1.into();
+    let gmi = gm1.affine_inverse();
+
+    let nm1: nalgebra::Matrix4<f32> = mm1.into();
+    let nmi = nm1.affine_inverse();
+
+    let cm1: cgmath::Matrix4<f32> = mm1.into();
+    let cmi = cm1.affine_invert();
+
+    let umi = ultraviolet::Mat4::from_mint(mm1).affine_inversed();
+
+    let vm1: vek::Mat4<f32> = mm1.into();
+    let vmi = vm1.inverted_affine_transform();
+
+    let rm1: reference::Mat4 = mm1.into();
+    let rmi = rm1.affine_inverse();
+
+    // use the general nalgebra inverse as assumed correct answer
+    let mmi: mint::ColumnMatrix4<f32> = nm1.try_inverse().unwrap().into();
+
+    assert_ulps_eq!(cgmath => cmi, mmi.into(), epsilon = 0.0001);
+    assert_ulps_eq!(glam => gmi, mmi.into(), epsilon = 0.0001);
+    assert_ulps_eq!(nalgebra => nmi, mmi.into(), epsilon = 0.0001);
+    assert_ulps_eq!(ultraviolet => umi, ultraviolet::Mat4::from_mint(mmi), epsilon = 0.0001);
+    assert_ulps_eq!(vek => vmi, mmi.into(), epsilon = 0.0001);
+    assert_ulps_eq!(reference => rmi, mmi.into(), epsilon = 0.0001);
+
+    #[cfg(feature = "nalgebra_glm")]
+    {
+        let glmi = nalgebra_glm::affine_inverse(nm1);
+        assert_ulps_eq!(nalgebra_glm => glmi, mmi.into(), epsilon = 0.0001);
+    }
+}
+
+fn mat4_project_point3_compare() {
+    use cgmath::Transform;
+
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mut mm1 = random_mint_homogeneous_mat4(&mut rng);
+    // scale w so the perspective divide has an effect
+    mm1.w.w = 1.0 + random_f32(&mut rng);
+    let mp = random_mint_vec3(&mut rng);
+
+    let gm1: glam::Mat4 = mm1.into();
+    let gmp = gm1.project_point3(mp.into());
+
+    let nm1: nalgebra::Matrix4<f32> = mm1.into();
+    let nmp = nm1.transform_point(&nalgebra::Point3::new(mp.x, mp.y, mp.z));
+
+    let cm1: cgmath::Matrix4<f32> = mm1.into();
+    let cmp = cm1.transform_point(cgmath::Point3::new(mp.x, mp.y, mp.z));
+
+    let ump = ultraviolet::Mat4::from_mint(mm1).project_point3(ultraviolet::Vec3::from_mint(mp));
+
+    let vm1: vek::Mat4<f32> = mm1.into();
+    let vmp = vm1.project_point(mp.into());
+
+    let emp = support::EuclidTransform3D::from_mint(mm1)
+        .transform_point3d(euclid::point3(mp.x, mp.y, mp.z));
+    assert!(emp.is_some());
+
+    let rm1: reference::Mat4 = mm1.into();
+    let rmp = rm1.project_point3(mp.into());
+
+    // use nalgebra as assumed correct answer
+    let mmp: mint::Point3<f32> = nmp.into();
+
+    assert_ulps_eq!(cgmath => cmp, mmp.into(), epsilon = 1e-5);
+    assert_ulps_eq!(glam => gmp, glam::Vec3::new(mmp.x, mmp.y, mmp.z), epsilon = 1e-5);
+    assert_ulps_eq!(
+        ultraviolet => ump,
+        ultraviolet::Vec3::new(mmp.x, mmp.y, mmp.z),
+        epsilon = 1e-5
+    );
+    assert_ulps_eq!(vek => vmp, vek::Vec3::new(mmp.x, mmp.y, mmp.z), epsilon = 1e-5);
+    assert_ulps_eq!(
+        euclid => emp.unwrap(),
+        euclid::point3(mmp.x, mmp.y, mmp.z),
+        epsilon = 1e-5
+    );
+    assert_ulps_eq!(reference => rmp, reference::Vec3([mmp.x, mmp.y, mmp.z]), epsilon = 1e-5);
+}
+
+#[test]
+fn test_mat2_mul_vec2() {
+    support::compare(&registry::MAT2_MUL_VEC2, || {
+        for _ in 0..NUM_ITERS {
+            mat2_mul_vec2_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat3_mul_vec3() {
+    support::compare(&registry::MAT3_MUL_VEC3, || {
+        for _ in 0..NUM_ITERS {
+            mat3_mul_vec3_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat4_mul_vec4() {
+    support::compare(&registry::MAT4_MUL_VEC4, || {
+        for _ in 0..NUM_ITERS {
+            mat4_mul_vec4_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat2_mul_mat2() {
+    support::compare(&registry::MAT2_MUL_MAT2, || {
+        for _ in 0..NUM_ITERS {
+            mat2_mul_mat2_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat3_mul_mat3() {
+    support::compare(&registry::MAT3_MUL_MAT3, || {
+        for _ in 0..NUM_ITERS {
+            mat3_mul_mat3_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat4_mul_mat4() {
+    support::compare(&registry::MAT4_MUL_MAT4, || {
+        for _ in 0..NUM_ITERS {
+            mat4_mul_mat4_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat2_transpose() {
+    support::compare(&registry::MAT2_TRANSPOSE, || {
+        for _ in 0..NUM_ITERS {
+            mat2_transpose_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat3_transpose() {
+    support::compare(&registry::MAT3_TRANSPOSE, || {
+        for _ in 0..NUM_ITERS {
+            mat3_transpose_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat4_transpose() {
+    support::compare(&registry::MAT4_TRANSPOSE, || {
+        for _ in 0..NUM_ITERS {
+            mat4_transpose_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat2_det() {
+    support::compare(&registry::MAT2_DET, || {
+        for _ in 0..NUM_ITERS {
+            mat2_det_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat3_det() {
+    support::compare(&registry::MAT3_DET, || {
+        for _ in 0..NUM_ITERS {
+            mat3_det_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat4_det() {
+    support::compare(&registry::MAT4_DET, || {
+        for _ in 0..NUM_ITERS {
+            mat4_det_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat2_inverse() {
+    support::compare(&registry::MAT2_INVERSE, || {
+        for _ in 0..NUM_ITERS {
+            mat2_inv_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat3_inverse() {
+    support::compare(&registry::MAT3_INVERSE, || {
+        for _ in 0..NUM_ITERS {
+            mat3_inv_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat4_inverse() {
+    support::compare(&registry::MAT4_INVERSE, || {
+        for _ in 0..NUM_ITERS {
+            mat4_inv_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat2_add_mat2() {
+    support::compare(&registry::MAT2_ADD_MAT2, || {
+        for _ in 0..NUM_ITERS {
+            mat2_add_mat2_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat3_add_mat3() {
+    support::compare(&registry::MAT3_ADD_MAT3, || {
+        for _ in 0..NUM_ITERS {
+            mat3_add_mat3_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat4_add_mat4() {
+    support::compare(&registry::MAT4_ADD_MAT4, || {
+        for _ in 0..NUM_ITERS {
+            mat4_add_mat4_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat2_sub_mat2() {
+    support::compare(&registry::MAT2_SUB_MAT2, || {
+        for _ in 0..NUM_ITERS {
+            mat2_sub_mat2_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat3_sub_mat3() {
+    support::compare(&registry::MAT3_SUB_MAT3, || {
+        for _ in 0..NUM_ITERS {
+            mat3_sub_mat3_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat4_sub_mat4() {
+    support::compare(&registry::MAT4_SUB_MAT4, || {
+        for _ in 0..NUM_ITERS {
+            mat4_sub_mat4_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat2_transpose_mul_mat2() {
+    support::compare(&registry::MAT2_TRANSPOSE_MUL_MAT2, || {
+        for _ in 0..NUM_ITERS {
+            mat2_transpose_mul_mat2_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat3_transpose_mul_mat3() {
+    support::compare(&registry::MAT3_TRANSPOSE_MUL_MAT3, || {
+        for _ in 0..NUM_ITERS {
+            mat3_transpose_mul_mat3_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat4_transpose_mul_mat4() {
+    support::compare(&registry::MAT4_TRANSPOSE_MUL_MAT4, || {
+        for _ in 0..NUM_ITERS {
+            mat4_transpose_mul_mat4_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat2_mul_scalar() {
+    support::compare(&registry::MAT2_MUL_SCALAR, || {
+        for _ in 0..NUM_ITERS {
+            mat2_mul_scalar_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat3_mul_scalar() {
+    support::compare(&registry::MAT3_MUL_SCALAR, || {
+        for _ in 0..NUM_ITERS {
+            mat3_mul_scalar_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat4_mul_scalar() {
+    support::compare(&registry::MAT4_MUL_SCALAR, || {
+        for _ in 0..NUM_ITERS {
+            mat4_mul_scalar_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat2_trace() {
+    support::compare(&registry::MAT2_TRACE, || {
+        for _ in 0..NUM_ITERS {
+            mat2_trace_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat3_trace() {
+    support::compare(&registry::MAT3_TRACE, || {
+        for _ in 0..NUM_ITERS {
+            mat3_trace_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat4_trace() {
+    support::compare(&registry::MAT4_TRACE, || {
+        for _ in 0..NUM_ITERS {
+            mat4_trace_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat2_from_cols() {
+    support::compare(&registry::MAT2_FROM_COLS, || {
+        for _ in 0..NUM_ITERS {
+            mat2_from_cols_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat3_from_cols() {
+    support::compare(&registry::MAT3_FROM_COLS, || {
+        for _ in 0..NUM_ITERS {
+            mat3_from_cols_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat4_from_cols() {
+    support::compare(&registry::MAT4_FROM_COLS, || {
+        for _ in 0..NUM_ITERS {
+            mat4_from_cols_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat4_affine_inverse() {
+    support::compare(&registry::MAT4_AFFINE_INVERSE, || {
+        for _ in 0..NUM_ITERS {
+            mat4_affine_inverse_compare();
+        }
+    });
+}
+
+#[test]
+fn test_mat4_project_point3() {
+    support::compare(&registry::MAT4_PROJECT_POINT3, || {
+        for _ in 0..NUM_ITERS {
+            mat4_project_point3_compare();
+        }
+    });
 }
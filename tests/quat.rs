@@ -2,11 +2,59 @@ mod support;
 use cgmath;
 use glam;
 use mathbench::mint_support::{random_mint_quat, random_mint_vec3};
+use mathbench::reference;
 use nalgebra;
 use rand_pcg::Pcg64Mcg;
+use support::{registry, FromMint};
 
 const NUM_ITERS: usize = 1024;
 
+fn quat_conjugate_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mq = random_mint_quat(&mut rng);
+
+    let gq: glam::Quat = mq.into();
+    let gqc = gq.conjugate();
+
+    let nq = nalgebra::UnitQuaternion::from_quaternion(mq.into());
+    let nqc = nq.conjugate();
+
+    let cq: cgmath::Quaternion<f32> = mq.into();
+    let cqc = cq.conjugate();
+
+    let uqc = ultraviolet::Rotor3::from_mint(mq).reversed();
+
+    // euclid inverse assumes a normalized quaternion so it's just a conjugate
+    let eqc = support::EuclidRotation3D::from_mint(mq).inverse();
+
+    let vq: vek::Quaternion<f32> = mq.into();
+    let vqc = vq.conjugate();
+
+    let rq: reference::Quat = mq.into();
+    let rqc = rq.conjugate();
+
+    // use nalgebra as assumed correct answer
+    let mqc: mint::Quaternion<f32> = nqc.into();
+
+    assert_ulps_eq!(cgmath => cqc, mqc.into(), epsilon = 1e-6);
+    assert_ulps_eq!(glam => gqc, mqc.into(), epsilon = 1e-6);
+    assert_ulps_eq!(ultraviolet => uqc, ultraviolet::Rotor3::from_mint(mqc), epsilon = 1e-6);
+    assert_ulps_eq!(
+        euclid => eqc,
+        support::EuclidRotation3D::from_mint(mqc),
+        epsilon = 1e-6
+    );
+    assert_ulps_eq!(vek => vqc, mqc.into(), epsilon = 1e-6);
+    assert_ulps_eq!(reference => rqc, mqc.into(), epsilon = 1e-6);
+
+    #[cfg(feature = "nalgebra_glm")]
+    {
+        let glmq: nalgebra_glm::Qua<f32> = nq.into_inner();
+        let glmqc = nalgebra_glm::quat_conjugate(&glmq);
+        assert_ulps_eq!(nalgebra_glm => glmqc, mqc.into(), epsilon = 1e-6);
+    }
+}
+
 fn quat_mul_vec3_compare() {
     let mut rng = Pcg64Mcg::new(rand::random());
     let mq = random_mint_quat(&mut rng);
@@ -24,11 +72,43 @@ fn quat_mul_vec3_compare() {
     let cv: cgmath::Vector3<f32> = mv.into();
     let cqv = cq * cv;
 
+    let uqv = ultraviolet::Rotor3::from_mint(mq) * ultraviolet::Vec3::from_mint(mv);
+
+    let eq = support::EuclidRotation3D::from_mint(mq);
+    let ep = euclid::point3(mv.x, mv.y, mv.z);
+    let eqv = eq.transform_point3d(ep);
+
+    let vq: vek::Quaternion<f32> = mq.into();
+    let vv: vek::Vec3<f32> = mv.into();
+    let vqv = vq * vv;
+
+    let rq: reference::Quat = mq.into();
+    let rv: reference::Vec3 = mv.into();
+    let rqv = rq * rv;
+
     // use nalgebra as assumed correct answer
     let mqv: mint::Vector3<f32> = nqv.into();
 
-    assert_ulps_eq!(cqv, mqv.into(), epsilon = 1e-6);
-    assert_ulps_eq!(gqv, mqv.into(), epsilon = 1e-6);
+    assert_ulps_eq!(cgmath => cqv, mqv.into(), epsilon = 1e-6);
+    assert_ulps_eq!(glam => gqv, mqv.into(), epsilon = 1e-6);
+    assert_ulps_eq!(ultraviolet => uqv, ultraviolet::Vec3::from_mint(mqv), epsilon = 1e-6);
+    assert_ulps_eq!(euclid => eqv, euclid::point3(mqv.x, mqv.y, mqv.z), epsilon = 1e-6);
+    assert_ulps_eq!(vek => vqv, mqv.into(), epsilon = 1e-6);
+    assert_ulps_eq!(reference => rqv, mqv.into(), epsilon = 1e-6);
+
+    #[cfg(all(feature = "intrinsics", target_arch = "x86_64"))]
+    {
+        use mathbench::intrinsics::{Quat, Vec3};
+        let iqv = Quat::from(mq) * Vec3::from(mv);
+        assert_ulps_eq!(intrinsics => iqv, mqv.into(), epsilon = 1e-6);
+    }
+
+    #[cfg(feature = "nalgebra_glm")]
+    {
+        let glmq: nalgebra_glm::Qua<f32> = nq.into_inner();
+        let glmqv = nalgebra_glm::quat_rotate_vec3(&glmq, &nv);
+        assert_ulps_eq!(nalgebra_glm => glmqv, mqv.into(), epsilon = 1e-6);
+    }
 }
 
 fn quat_mul_quat_compare() {
@@ -48,11 +128,39 @@ fn quat_mul_quat_compare() {
     let cq2: cgmath::Quaternion<f32> = mq2.into();
     let cq3 = cq1 * cq2;
 
+    let uq3 = ultraviolet::Rotor3::from_mint(mq1) * ultraviolet::Rotor3::from_mint(mq2);
+
+    let eq1 = support::EuclidRotation3D::from_mint(mq1);
+    let eq2 = support::EuclidRotation3D::from_mint(mq2);
+    let eq3 = eq1.pre_rotate(&eq2);
+
+    let vq1: vek::Quaternion<f32> = mq1.into();
+    let vq2: vek::Quaternion<f32> = mq2.into();
+    let vq3 = vq1 * vq2;
+
+    let rq1: reference::Quat = mq1.into();
+    let rq2: reference::Quat = mq2.into();
+    let rq3 = rq1 * rq2;
+
     // use nalgebra as assumed correct answer
     let mq3: mint::Quaternion<f32> = nq3.into();
 
-    assert_ulps_eq!(cq3, mq3.into(), epsilon = 1e-6);
-    assert_ulps_eq!(gq3, mq3.into(), epsilon = 1e-6);
+    assert_ulps_eq!(cgmath => cq3, mq3.into(), epsilon = 1e-6);
+    assert_ulps_eq!(glam => gq3, mq3.into(), epsilon = 1e-6);
+    assert_ulps_eq!(ultraviolet => uq3, ultraviolet::Rotor3::from_mint(mq3), epsilon = 1e-6);
+    assert_ulps_eq!(
+        euclid => eq3,
+        support::EuclidRotation3D::from_mint(mq3),
+        epsilon = 1e-6
+    );
+    assert_ulps_eq!(vek => vq3, mq3.into(), epsilon = 1e-6);
+    assert_ulps_eq!(reference => rq3, mq3.into(), epsilon = 1e-6);
+
+    #[cfg(feature = "nalgebra_glm")]
+    {
+        let glmq3 = nalgebra_glm::quat_cross(&nq1.into_inner(), &nq2.into_inner());
+        assert_ulps_eq!(nalgebra_glm => glmq3, mq3.into(), epsilon = 1e-6);
+    }
 }
 
 #[test]
@@ -81,16 +189,29 @@ fn test_quat_from_axis_angle() {
     assert_ulps_eq!(gqv, mcqv.into(), epsilon = 1e-5);
 }
 
+#[test]
+fn test_quat_conjugate() {
+    support::compare(&registry::QUAT_CONJUGATE, || {
+        for _ in 0..NUM_ITERS {
+            quat_conjugate_compare();
+        }
+    });
+}
+
 #[test]
 fn test_quat_mul_vec3() {
-    for _ in 0..NUM_ITERS {
-        quat_mul_vec3_compare();
-    }
+    support::compare(&registry::QUAT_MUL_VEC3, || {
+        for _ in 0..NUM_ITERS {
+            quat_mul_vec3_compare();
+        }
+    });
 }
 
 #[test]
 fn test_quat_mul_quat() {
-    for _ in 0..NUM_ITERS {
-        quat_mul_quat_compare();
-    }
+    support::compare(&registry::QUAT_MUL_QUAT, || {
+        for _ in 0..NUM_ITERS {
+            quat_mul_quat_compare();
+        }
+    });
 }
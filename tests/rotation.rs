@@ -0,0 +1,184 @@
+mod support;
+use mathbench::euclid_support::Rotation2Ext as _;
+use mathbench::glam_support::Rotation2Ext as _;
+use mathbench::mint_support::{random_angle_radians, random_mint_vec2};
+use mathbench::nalgebra_support::Rotation2Ext as _;
+use mathbench::ultraviolet_support::Rotation2Ext as _;
+use rand_pcg::Pcg64Mcg;
+use support::{registry, FromMint};
+
+const NUM_ITERS: usize = 1024;
+
+// Rotations are compared as rotation matrices as rotors can have either sign.
+
+type EuclidRotation2D = euclid::Rotation2D<f32, euclid::UnknownUnit, euclid::UnknownUnit>;
+
+type Rotations2 = (
+    nalgebra::UnitComplex<f32>,
+    ultraviolet::Rotor2,
+    glam::Mat2,
+    EuclidRotation2D,
+);
+
+fn rotation2_from_angle(angle: f32) -> Rotations2 {
+    (
+        nalgebra::UnitComplex::new(angle),
+        ultraviolet::Rotor2::from_angle(angle),
+        glam::Mat2::from_angle(angle),
+        EuclidRotation2D::radians(angle),
+    )
+}
+
+fn random_rotation2<R>(rng: &mut R) -> Rotations2
+where
+    R: rand::Rng,
+{
+    rotation2_from_angle(random_angle_radians(rng))
+}
+
+fn euclid_rotation2_to_mint(er: EuclidRotation2D) -> mint::ColumnMatrix2<f32> {
+    let (sin, cos) = er.angle.sin_cos();
+    mint::ColumnMatrix2 {
+        x: [cos, sin].into(),
+        y: [-sin, cos].into(),
+    }
+}
+
+/// Converts the ultraviolet, glam and euclid rotations to rotation matrices
+/// and compares them with `expected`.
+fn assert_rotation2_eq(
+    ur: ultraviolet::Rotor2,
+    gm: glam::Mat2,
+    er: EuclidRotation2D,
+    expected: mint::ColumnMatrix2<f32>,
+    epsilon: f32,
+) {
+    assert_ulps_eq!(
+        ultraviolet => ur.into_matrix(),
+        ultraviolet::Mat2::from_mint(expected),
+        epsilon = epsilon
+    );
+    assert_ulps_eq!(glam => gm, glam::Mat2::from(expected), epsilon = epsilon);
+    assert_ulps_eq!(euclid => euclid_rotation2_to_mint(er), expected, epsilon = epsilon);
+}
+
+fn rotation2_inverse_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let (nr, ur, gm, er) = random_rotation2(&mut rng);
+
+    let nri = nr.inverse();
+    let uri = ur.reversed();
+    let gmi = gm.transpose();
+    let eri = er.inverse();
+
+    // use nalgebra as assumed correct answer
+    let mri: mint::ColumnMatrix2<f32> = nri.to_rotation_matrix().into_inner().into();
+
+    assert_rotation2_eq(uri, gmi, eri, mri, 1e-6);
+}
+
+fn rotation2_mul_rotation2_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let (nr1, ur1, gm1, er1) = random_rotation2(&mut rng);
+    let (nr2, ur2, gm2, er2) = random_rotation2(&mut rng);
+
+    let nr3 = nr1 * nr2;
+    let ur3 = ur1 * ur2;
+    let gm3 = gm1.mul_mat2(&gm2);
+    let er3 = er1.pre_rotate(&er2);
+
+    // use nalgebra as assumed correct answer
+    let mr3: mint::ColumnMatrix2<f32> = nr3.to_rotation_matrix().into_inner().into();
+
+    assert_rotation2_eq(ur3, gm3, er3, mr3, 1e-6);
+}
+
+fn rotation2_rotate_vector2_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let (nr, ur, gm, er) = random_rotation2(&mut rng);
+    let mv = random_mint_vec2(&mut rng);
+
+    let nrv = nr.transform_vector(&mv.into());
+    let urv = ur * ultraviolet::Vec2::from_mint(mv);
+    let gmv = gm.mul_vec2(mv.into());
+    let erv = er.transform_vector(euclid::vec2(mv.x, mv.y));
+
+    // use nalgebra as assumed correct answer
+    let mrv: mint::Vector2<f32> = nrv.into();
+
+    assert_ulps_eq!(ultraviolet => urv, ultraviolet::Vec2::from_mint(mrv), epsilon = 1e-6);
+    assert_ulps_eq!(glam => gmv, glam::Vec2::from(mrv), epsilon = 1e-6);
+    assert_ulps_eq!(euclid => erv, euclid::vec2(mrv.x, mrv.y), epsilon = 1e-6);
+}
+
+fn rotation2_slerp_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let (nr1, ur1, gm1, er1) = random_rotation2(&mut rng);
+    let (nr2, ur2, gm2, er2) = random_rotation2(&mut rng);
+    let t: f32 = rand::Rng::gen(&mut rng);
+
+    let nr3 = nr1.slerp(&nr2, t);
+    let ur3 = ur1.slerp(ur2, t);
+    let gm3 = gm1.slerp(gm2, t);
+    let er3 = er1.slerp(&er2, t);
+
+    // use nalgebra as assumed correct answer
+    let mr3: mint::ColumnMatrix2<f32> = nr3.to_rotation_matrix().into_inner().into();
+
+    assert_rotation2_eq(ur3, gm3, er3, mr3, 1e-5);
+}
+
+fn rotation2_from_angle_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let (nr, ur, gm, er) = random_rotation2(&mut rng);
+
+    // use nalgebra as assumed correct answer
+    let mr: mint::ColumnMatrix2<f32> = nr.to_rotation_matrix().into_inner().into();
+
+    assert_rotation2_eq(ur, gm, er, mr, 1e-6);
+}
+
+#[test]
+fn test_rotation2_inverse() {
+    support::compare(&registry::ROTATION2_INVERSE, || {
+        for _ in 0..NUM_ITERS {
+            rotation2_inverse_compare();
+        }
+    });
+}
+
+#[test]
+fn test_rotation2_mul_rotation2() {
+    support::compare(&registry::ROTATION2_MUL_ROTATION2, || {
+        for _ in 0..NUM_ITERS {
+            rotation2_mul_rotation2_compare();
+        }
+    });
+}
+
+#[test]
+fn test_rotation2_rotate_vector2() {
+    support::compare(&registry::ROTATION2_ROTATE_VECTOR2, || {
+        for _ in 0..NUM_ITERS {
+            rotation2_rotate_vector2_compare();
+        }
+    });
+}
+
+#[test]
+fn test_rotation2_slerp() {
+    support::compare(&registry::ROTATION2_SLERP, || {
+        for _ in 0..NUM_ITERS {
+            rotation2_slerp_compare();
+        }
+    });
+}
+
+#[test]
+fn test_rotation2_from_angle() {
+    support::compare(&registry::ROTATION2_FROM_ANGLE, || {
+        for _ in 0..NUM_ITERS {
+            rotation2_from_angle_compare();
+        }
+    });
+}
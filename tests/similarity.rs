@@ -0,0 +1,266 @@
+mod support;
+use mathbench::cgmath_support::Similarity3Ext as _;
+use mathbench::glam_support::Similarity3Ext as _;
+use mathbench::mint_support::*;
+use mathbench::nalgebra_support::Similarity3Ext as _;
+use mathbench::ultraviolet_support::Similarity3Ext as _;
+use rand_pcg::Pcg64Mcg;
+use support::{registry, FromMint};
+
+const NUM_ITERS: usize = 1024;
+
+// Similarities are compared as homogeneous matrices as the rotation can have
+// either sign.
+
+type Similarities3 = (
+    nalgebra::Similarity3<f32>,
+    ultraviolet::Similarity3,
+    (glam::Vec3, glam::Quat, glam::Vec3),
+    cgmath::Decomposed<cgmath::Vector3<f32>, cgmath::Quaternion<f32>>,
+);
+
+fn similarity3_from_mint(
+    ms: f32,
+    mq: mint::Quaternion<f32>,
+    mt: mint::Vector3<f32>,
+) -> Similarities3 {
+    let ns = nalgebra::Similarity3::from_parts(
+        nalgebra::Translation3::new(mt.x, mt.y, mt.z),
+        nalgebra::UnitQuaternion::from_quaternion(mq.into()),
+        ms,
+    );
+    let us = ultraviolet::Similarity3::new(
+        ultraviolet::Vec3::from_mint(mt),
+        ultraviolet::Rotor3::from_mint(mq),
+        ms,
+    );
+    let gs = (glam::Vec3::splat(ms), mq.into(), mt.into());
+    let cs = cgmath::Decomposed {
+        scale: ms,
+        rot: mq.into(),
+        disp: mt.into(),
+    };
+    (ns, us, gs, cs)
+}
+
+fn random_similarity3<R>(rng: &mut R) -> Similarities3
+where
+    R: rand::Rng,
+{
+    let ms = random_nonzero_f32(rng);
+    let mq = random_mint_quat(rng);
+    let mt = random_mint_vec3(rng);
+    similarity3_from_mint(ms, mq, mt)
+}
+
+/// ultraviolet 0.4.5 `Similarity3::into_homogeneous_matrix` ignores the scale.
+fn ultraviolet_similarity3_to_mat4(us: ultraviolet::Similarity3) -> ultraviolet::Mat4 {
+    ultraviolet::Mat4::from_translation(us.translation)
+        * us.rotation.into_matrix().into_homogeneous()
+        * ultraviolet::Mat4::from_scale(us.scale)
+}
+
+/// Converts the glam and cgmath similarities to homogeneous matrices and
+/// compares them with `expected`.
+fn assert_similarity3_eq(
+    gs: (glam::Vec3, glam::Quat, glam::Vec3),
+    cs: cgmath::Decomposed<cgmath::Vector3<f32>, cgmath::Quaternion<f32>>,
+    expected: mint::ColumnMatrix4<f32>,
+    epsilon: f32,
+) {
+    assert_ulps_eq!(glam => gs.to_mat4(), glam::Mat4::from(expected), epsilon = epsilon);
+    assert_ulps_eq!(
+        cgmath => cs.to_matrix4(),
+        cgmath::Matrix4::from(expected),
+        epsilon = epsilon
+    );
+}
+
+fn similarity3_inverse_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let (ns, _, gs, cs) = random_similarity3(&mut rng);
+
+    let nsi = ns.inverse();
+    let gsi = gs.inverse();
+    let csi = {
+        use cgmath::Transform;
+        cs.inverse_transform().unwrap()
+    };
+
+    // ultraviolet 0.4.5 `Similarity3::inversed` doesn't scale the translation
+    // so it isn't compared here.
+
+    // use nalgebra as assumed correct answer
+    let msi: mint::ColumnMatrix4<f32> = nsi.to_homogeneous().into();
+
+    // the inverse scale is up to 10 so compare with a larger epsilon
+    assert_similarity3_eq(gsi, csi, msi, 1e-4);
+}
+
+fn similarity3_mul_similarity3_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let (ns1, us1, gs1, cs1) = random_similarity3(&mut rng);
+    let (ns2, us2, gs2, cs2) = random_similarity3(&mut rng);
+
+    let ns3 = ns1 * ns2;
+    let us3 = us1 * us2;
+    let gs3 = gs1.mul_similarity3(gs2);
+    let cs3 = {
+        use cgmath::Transform;
+        cs1.concat(&cs2)
+    };
+
+    // use nalgebra as assumed correct answer
+    let ms3: mint::ColumnMatrix4<f32> = ns3.to_homogeneous().into();
+
+    assert_ulps_eq!(
+        ultraviolet => ultraviolet_similarity3_to_mat4(us3),
+        ultraviolet::Mat4::from_mint(ms3),
+        epsilon = 1e-6
+    );
+    assert_similarity3_eq(gs3, cs3, ms3, 1e-6);
+}
+
+fn similarity3_transform_point3_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let (ns, us, gs, cs) = random_similarity3(&mut rng);
+    let mv = random_mint_vec3(&mut rng);
+
+    let nsv = ns.transform_point(&nalgebra::Point3::new(mv.x, mv.y, mv.z));
+    let usv = us.transform_vec(ultraviolet::Vec3::from_mint(mv));
+    let gsv = gs.transform_point3(mv.into());
+    let csv = {
+        use cgmath::Transform;
+        cs.transform_point(cgmath::Point3::new(mv.x, mv.y, mv.z))
+    };
+
+    // use nalgebra as assumed correct answer
+    let msv: mint::Vector3<f32> = nsv.coords.into();
+
+    assert_ulps_eq!(ultraviolet => usv, ultraviolet::Vec3::from_mint(msv), epsilon = 1e-6);
+    assert_ulps_eq!(glam => gsv, glam::Vec3::from(msv), epsilon = 1e-6);
+    assert_ulps_eq!(
+        cgmath => csv,
+        cgmath::Point3::new(msv.x, msv.y, msv.z),
+        epsilon = 1e-6
+    );
+}
+
+fn similarity3_transform_vector3_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let (ns, us, gs, cs) = random_similarity3(&mut rng);
+    let mv = random_mint_vec3(&mut rng);
+
+    let nsv = ns.transform_vector(&mv.into());
+    let usv = us.transform_vec_no_translation(ultraviolet::Vec3::from_mint(mv));
+    let gsv = gs.transform_vector3(mv.into());
+    let csv = {
+        use cgmath::Transform;
+        cs.transform_vector(mv.into())
+    };
+
+    // use nalgebra as assumed correct answer
+    let msv: mint::Vector3<f32> = nsv.into();
+
+    assert_ulps_eq!(ultraviolet => usv, ultraviolet::Vec3::from_mint(msv), epsilon = 1e-6);
+    assert_ulps_eq!(glam => gsv, glam::Vec3::from(msv), epsilon = 1e-6);
+    assert_ulps_eq!(cgmath => csv, cgmath::Vector3::from(msv), epsilon = 1e-6);
+}
+
+fn similarity3_interpolate_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let (ns1, us1, gs1, cs1) = random_similarity3(&mut rng);
+    let ms = random_nonzero_f32(&mut rng);
+    let mut mq = random_mint_quat(&mut rng);
+    let mt = random_mint_vec3(&mut rng);
+    // glam interpolates along the shortest path and the other libraries don't,
+    // so only compare rotations in the same hemisphere
+    let q1 = gs1.1;
+    if q1.dot(mq.into()) < 0.0 {
+        mq = (-glam::Quat::from(mq)).into();
+    }
+    let (ns2, us2, gs2, cs2) = similarity3_from_mint(ms, mq, mt);
+    let t: f32 = rand::Rng::gen(&mut rng);
+
+    let ns3 = ns1.lerp(&ns2, t);
+    let us3 = us1.lerp(us2, t);
+    let gs3 = gs1.lerp(gs2, t);
+    let cs3 = cs1.lerp(cs2, t);
+
+    // use nalgebra as assumed correct answer
+    let ms3: mint::ColumnMatrix4<f32> = ns3.to_homogeneous().into();
+
+    assert_ulps_eq!(
+        ultraviolet => ultraviolet_similarity3_to_mat4(us3),
+        ultraviolet::Mat4::from_mint(ms3),
+        epsilon = 1e-5
+    );
+    assert_similarity3_eq(gs3, cs3, ms3, 1e-5);
+}
+
+fn similarity3_to_matrix4_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let (ns, _, gs, cs) = random_similarity3(&mut rng);
+
+    // ultraviolet 0.4.5 `Similarity3::into_homogeneous_matrix` ignores the
+    // scale so it isn't compared here.
+
+    // use nalgebra as assumed correct answer
+    let ms: mint::ColumnMatrix4<f32> = ns.to_homogeneous().into();
+
+    assert_similarity3_eq(gs, cs, ms, 1e-6);
+}
+
+#[test]
+fn test_similarity3_inverse() {
+    support::compare(&registry::SIMILARITY3_INVERSE, || {
+        for _ in 0..NUM_ITERS {
+            similarity3_inverse_compare();
+        }
+    });
+}
+
+#[test]
+fn test_similarity3_mul_similarity3() {
+    support::compare(&registry::SIMILARITY3_MUL_SIMILARITY3, || {
+        for _ in 0..NUM_ITERS {
+            similarity3_mul_similarity3_compare();
+        }
+    });
+}
+
+#[test]
+fn test_similarity3_transform_point3() {
+    support::compare(&registry::SIMILARITY3_TRANSFORM_POINT3, || {
+        for _ in 0..NUM_ITERS {
+            similarity3_transform_point3_compare();
+        }
+    });
+}
+
+#[test]
+fn test_similarity3_transform_vector3() {
+    support::compare(&registry::SIMILARITY3_TRANSFORM_VECTOR3, || {
+        for _ in 0..NUM_ITERS {
+            similarity3_transform_vector3_compare();
+        }
+    });
+}
+
+#[test]
+fn test_similarity3_interpolate() {
+    support::compare(&registry::SIMILARITY3_INTERPOLATE, || {
+        for _ in 0..NUM_ITERS {
+            similarity3_interpolate_compare();
+        }
+    });
+}
+
+#[test]
+fn test_similarity3_to_matrix4() {
+    support::compare(&registry::SIMILARITY3_TO_MATRIX4, || {
+        for _ in 0..NUM_ITERS {
+            similarity3_to_matrix4_compare();
+        }
+    });
+}
@@ -0,0 +1,438 @@
+//! Singular, near singular and non-finite matrices and how each library's
+//! matrix inverse handles them, shared by the `inverse` test and the `inverse`
+//! example which reports every outcome.
+//!
+//! Every library is given the same inputs and each result is classified as an
+//! accurate `inverse`, finite `garbage`, `NaN`, `inf`, `None` or a `panic`
+//! (`glam` asserts on a zero determinant in debug builds with the `glam_assert`
+//! feature). The 3x3 and 4x4 inputs are affine so they can also be used with the
+//! `euclid` and `pathfinder_geometry` transform types.
+#![allow(dead_code)]
+
+use super::{catch_quietly, FromMint};
+use cgmath::SquareMatrix;
+use mathbench::reference;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Outcome {
+    Inverse,
+    Garbage,
+    NaN,
+    Inf,
+    None,
+    Panic,
+}
+
+impl Outcome {
+    pub fn name(self) -> &'static str {
+        match self {
+            Outcome::Inverse => "inverse",
+            Outcome::Garbage => "garbage",
+            Outcome::NaN => "NaN",
+            Outcome::Inf => "inf",
+            Outcome::None => "None",
+            Outcome::Panic => "panic",
+        }
+    }
+}
+
+/// A column major `N` x `N` matrix.
+pub type Columns = Vec<f32>;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Kind {
+    /// the determinant is exactly zero
+    Singular,
+    /// invertible, but only just or the determinant underflows
+    NearSingular,
+    /// contains a NaN or infinite element
+    NonFinite,
+}
+
+pub struct Case {
+    pub name: &'static str,
+    pub kind: Kind,
+    pub input: Columns,
+}
+
+pub struct Library {
+    pub name: &'static str,
+    /// returns the column major inverse or `None` if the library reports failure
+    pub inverse: fn(&Columns) -> Option<Columns>,
+}
+
+fn classify(n: usize, input: &Columns, result: Option<Option<Columns>>) -> Outcome {
+    let result = match result {
+        None => return Outcome::Panic,
+        Some(None) => return Outcome::None,
+        Some(Some(result)) => result,
+    };
+    if result.iter().any(|x| x.is_nan()) {
+        return Outcome::NaN;
+    }
+    if result.iter().any(|x| x.is_infinite()) {
+        return Outcome::Inf;
+    }
+    let m = nalgebra::DMatrix::from_iterator(n, n, input.iter().map(|&x| f64::from(x)));
+    let r = nalgebra::DMatrix::from_iterator(n, n, result.iter().map(|&x| f64::from(x)));
+    let residual = (m * r - nalgebra::DMatrix::identity(n, n)).amax();
+    if residual <= 1.0e-3 {
+        Outcome::Inverse
+    } else {
+        Outcome::Garbage
+    }
+}
+
+pub fn outcomes(n: usize, cases: &[Case], libs: &[Library]) -> Vec<Vec<Outcome>> {
+    cases
+        .iter()
+        .map(|case| {
+            libs.iter()
+                .map(|lib| {
+                    let result = catch_quietly(|| (lib.inverse)(&case.input));
+                    classify(n, &case.input, result)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn format_table(
+    title: &str,
+    cases: &[Case],
+    libs: &[Library],
+    outcomes: &[Vec<Outcome>],
+) -> String {
+    let mut table = format!("\n### {}\n\n| input |", title);
+    for lib in libs {
+        write!(table, " {} |", lib.name).unwrap();
+    }
+    table += "\n|---|";
+    table += &"---|".repeat(libs.len());
+    for (case, row) in cases.iter().zip(outcomes) {
+        write!(table, "\n| {} |", case.name).unwrap();
+        for outcome in row {
+            write!(table, " {} |", outcome.name()).unwrap();
+        }
+    }
+    table + "\n"
+}
+
+// inputs ---------------------------------------------------------------------
+
+const NEAR_SINGULAR: f32 = 1.0 / (1 << 20) as f32;
+const TINY: f32 = 1.0e-20;
+
+pub fn mat2_cases() -> Vec<Case> {
+    let case = |name, kind, input: [f32; 4]| Case {
+        name,
+        kind,
+        input: input.to_vec(),
+    };
+    vec![
+        case("zero", Kind::Singular, [0.0, 0.0, 0.0, 0.0]),
+        case("zero column", Kind::Singular, [1.0, 2.0, 0.0, 0.0]),
+        case("rank 1", Kind::Singular, [1.0, 2.0, 2.0, 4.0]),
+        case(
+            "near singular",
+            Kind::NearSingular,
+            [1.0, 2.0, 1.0, 2.0 + NEAR_SINGULAR],
+        ),
+        case("tiny scale", Kind::NearSingular, [TINY, 0.0, 0.0, TINY]),
+        case(
+            "NaN element",
+            Kind::NonFinite,
+            [1.0, std::f32::NAN, 0.0, 1.0],
+        ),
+        case(
+            "inf element",
+            Kind::NonFinite,
+            [1.0, std::f32::INFINITY, 0.0, 1.0],
+        ),
+    ]
+}
+
+/// 3x3 affine cases, the upper left 2x2 is the linear part.
+pub fn mat3_cases() -> Vec<Case> {
+    let case = |name, kind, linear: [f32; 4]| Case {
+        name,
+        kind,
+        input: vec![
+            linear[0], linear[1], 0.0, linear[2], linear[3], 0.0, 1.0, 2.0, 1.0,
+        ],
+    };
+    vec![
+        case("zero linear part", Kind::Singular, [0.0, 0.0, 0.0, 0.0]),
+        case("zero scale y", Kind::Singular, [1.0, 0.0, 0.0, 0.0]),
+        case("rank 1", Kind::Singular, [1.0, 2.0, 2.0, 4.0]),
+        case(
+            "near singular",
+            Kind::NearSingular,
+            [1.0, 2.0, 1.0, 2.0 + NEAR_SINGULAR],
+        ),
+        case("tiny scale", Kind::NearSingular, [TINY, 0.0, 0.0, TINY]),
+        case(
+            "NaN element",
+            Kind::NonFinite,
+            [1.0, std::f32::NAN, 0.0, 1.0],
+        ),
+        case(
+            "inf element",
+            Kind::NonFinite,
+            [1.0, std::f32::INFINITY, 0.0, 1.0],
+        ),
+    ]
+}
+
+/// 4x4 affine cases, the upper left 3x3 is the linear part.
+pub fn mat4_cases() -> Vec<Case> {
+    let case = |name, kind, linear: [f32; 9]| Case {
+        name,
+        kind,
+        input: vec![
+            linear[0], linear[1], linear[2], 0.0, linear[3], linear[4], linear[5], 0.0, linear[6],
+            linear[7], linear[8], 0.0, 1.0, 2.0, 3.0, 1.0,
+        ],
+    };
+    vec![
+        case("zero linear part", Kind::Singular, [0.0; 9]),
+        case(
+            "zero scale z",
+            Kind::Singular,
+            [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0],
+        ),
+        case(
+            "duplicate columns",
+            Kind::Singular,
+            [1.0, 2.0, 3.0, 1.0, 2.0, 3.0, 0.0, 0.0, 1.0],
+        ),
+        case(
+            "rank 1",
+            Kind::Singular,
+            [1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 3.0, 6.0, 9.0],
+        ),
+        case(
+            "near singular",
+            Kind::NearSingular,
+            [1.0, 2.0, 3.0, 1.0, 2.0 + NEAR_SINGULAR, 3.0, 0.0, 0.0, 1.0],
+        ),
+        case(
+            "tiny scale",
+            Kind::NearSingular,
+            [TINY, 0.0, 0.0, 0.0, TINY, 0.0, 0.0, 0.0, TINY],
+        ),
+        case(
+            "NaN element",
+            Kind::NonFinite,
+            [1.0, std::f32::NAN, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+        ),
+        case(
+            "inf element",
+            Kind::NonFinite,
+            [1.0, std::f32::INFINITY, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+        ),
+    ]
+}
+
+fn mint2(c: &Columns) -> mint::ColumnMatrix2<f32> {
+    [c[0], c[1], c[2], c[3]].into()
+}
+
+fn mint3(c: &Columns) -> mint::ColumnMatrix3<f32> {
+    [c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7], c[8]].into()
+}
+
+fn mint4(c: &Columns) -> mint::ColumnMatrix4<f32> {
+    let mut a = [0.0; 16];
+    a.copy_from_slice(c);
+    a.into()
+}
+
+fn columns2(m: mint::ColumnMatrix2<f32>) -> Columns {
+    vec![m.x.x, m.x.y, m.y.x, m.y.y]
+}
+
+fn columns3(m: mint::ColumnMatrix3<f32>) -> Columns {
+    vec![
+        m.x.x, m.x.y, m.x.z, m.y.x, m.y.y, m.y.z, m.z.x, m.z.y, m.z.z,
+    ]
+}
+
+fn columns4(m: mint::ColumnMatrix4<f32>) -> Columns {
+    vec![
+        m.x.x, m.x.y, m.x.z, m.x.w, m.y.x, m.y.y, m.y.z, m.y.w, m.z.x, m.z.y, m.z.z, m.z.w, m.w.x,
+        m.w.y, m.w.z, m.w.w,
+    ]
+}
+
+// libraries ------------------------------------------------------------------
+
+pub fn mat2_libs() -> Vec<Library> {
+    let mut libs = vec![
+        Library {
+            name: "glam",
+            inverse: |c| Some(columns2(glam::Mat2::from(mint2(c)).inverse().into())),
+        },
+        Library {
+            name: "cgmath",
+            inverse: |c| {
+                cgmath::Matrix2::<f32>::from(mint2(c))
+                    .invert()
+                    .map(|m| columns2(m.into()))
+            },
+        },
+        Library {
+            name: "nalgebra",
+            inverse: |c| {
+                nalgebra::Matrix2::<f32>::from(mint2(c))
+                    .try_inverse()
+                    .map(|m| m.as_slice().to_vec())
+            },
+        },
+        // ultraviolet 0.4.5 doesn't have a `Mat2` inverse
+    ];
+    #[cfg(feature = "pathfinder_geometry")]
+    libs.push(Library {
+        name: "pathfinder",
+        inverse: |c| {
+            use pathfinder_geometry::transform2d::Matrix2x2F;
+            let m = Matrix2x2F::from_mint(mint2(c)).inverse();
+            // stored in column major order
+            Some((0..4).map(|i| m.0[i]).collect())
+        },
+    });
+    libs.push(Library {
+        name: "reference",
+        inverse: |c| Some(columns2(reference::Mat2::from(mint2(c)).inverse().into())),
+    });
+    libs
+}
+
+pub fn mat3_libs() -> Vec<Library> {
+    let mut libs = vec![
+        Library {
+            name: "glam",
+            inverse: |c| Some(columns3(glam::Mat3::from(mint3(c)).inverse().into())),
+        },
+        Library {
+            name: "cgmath",
+            inverse: |c| {
+                cgmath::Matrix3::<f32>::from(mint3(c))
+                    .invert()
+                    .map(|m| columns3(m.into()))
+            },
+        },
+        Library {
+            name: "nalgebra",
+            inverse: |c| {
+                nalgebra::Matrix3::<f32>::from(mint3(c))
+                    .try_inverse()
+                    .map(|m| m.as_slice().to_vec())
+            },
+        },
+        Library {
+            name: "ultraviolet",
+            inverse: |c| {
+                let m = ultraviolet::Mat3::from_mint(mint3(c)).inversed();
+                Some(m.cols.iter().flat_map(|v| vec![v.x, v.y, v.z]).collect())
+            },
+        },
+        Library {
+            name: "euclid",
+            inverse: |c| {
+                // euclid uses row vectors so the rows are our columns
+                super::EuclidTransform2D::from_mint(mint3(c))
+                    .inverse()
+                    .map(|m| vec![m.m11, m.m12, 0.0, m.m21, m.m22, 0.0, m.m31, m.m32, 1.0])
+            },
+        },
+    ];
+    #[cfg(feature = "pathfinder_geometry")]
+    libs.push(Library {
+        name: "pathfinder",
+        inverse: |c| {
+            use pathfinder_geometry::transform2d::Transform2F;
+            let m = Transform2F::from_mint(mint3(c)).inverse();
+            let (l, t) = (m.matrix.0, m.vector);
+            Some(vec![l[0], l[1], 0.0, l[2], l[3], 0.0, t.x(), t.y(), 1.0])
+        },
+    });
+    libs.push(Library {
+        name: "reference",
+        inverse: |c| Some(columns3(reference::Mat3::from(mint3(c)).inverse().into())),
+    });
+    libs
+}
+
+pub fn mat4_libs() -> Vec<Library> {
+    let mut libs = vec![
+        Library {
+            name: "glam",
+            inverse: |c| Some(columns4(glam::Mat4::from(mint4(c)).inverse().into())),
+        },
+        Library {
+            name: "cgmath",
+            inverse: |c| {
+                cgmath::Matrix4::<f32>::from(mint4(c))
+                    .invert()
+                    .map(|m| columns4(m.into()))
+            },
+        },
+        Library {
+            name: "nalgebra",
+            inverse: |c| {
+                nalgebra::Matrix4::<f32>::from(mint4(c))
+                    .try_inverse()
+                    .map(|m| m.as_slice().to_vec())
+            },
+        },
+        Library {
+            name: "ultraviolet",
+            inverse: |c| {
+                let m = ultraviolet::Mat4::from_mint(mint4(c)).inversed();
+                Some(
+                    m.cols
+                        .iter()
+                        .flat_map(|v| vec![v.x, v.y, v.z, v.w])
+                        .collect(),
+                )
+            },
+        },
+        Library {
+            name: "euclid",
+            inverse: |c| {
+                // euclid uses row vectors so its row major array is column major
+                super::EuclidTransform3D::from_mint(mint4(c))
+                    .inverse()
+                    .map(|m| m.to_row_major_array().to_vec())
+            },
+        },
+        Library {
+            name: "vek",
+            inverse: |c| Some(columns4(vek::Mat4::<f32>::from(mint4(c)).inverted().into())),
+        },
+    ];
+    #[cfg(feature = "pathfinder_geometry")]
+    libs.push(Library {
+        name: "pathfinder",
+        inverse: |c| {
+            use pathfinder_geometry::transform3d::Transform4F;
+            let m = Transform4F::from_mint(mint4(c)).inverse().to_columns();
+            Some((0..16).map(|i| m[i / 4][i % 4]).collect())
+        },
+    });
+    libs.push(Library {
+        name: "reference",
+        inverse: |c| Some(columns4(reference::Mat4::from(mint4(c)).inverse().into())),
+    });
+    #[cfg(all(feature = "intrinsics", target_arch = "x86_64"))]
+    libs.push(Library {
+        name: "intrinsics",
+        inverse: |c| {
+            use mathbench::intrinsics::Mat4;
+            Some(columns4(Mat4::from(mint4(c)).inverse().into()))
+        },
+    });
+    libs
+}
@@ -1,17 +1,24 @@
+/// Compares two values to within `epsilon`, which defaults to `f32::EPSILON`.
+/// With a leading `lib =>` the comparison is recorded as a check of `lib` for
+/// `support::compare`.
 #[macro_export]
 macro_rules! assert_ulps_eq {
+    ($lib:ident => $a:expr, $b:expr $(, epsilon = $eps:expr)?) => {{
+        support::checked(stringify!($lib));
+        assert_ulps_eq!($a, $b $(, epsilon = $eps)?)
+    }};
     ($a:expr, $b:expr) => {{
         use support::FloatCompare;
         let eps = core::f32::EPSILON;
         let (a, b) = (&$a, &$b);
         assert!(
-            a.approx_eq(b, eps),
+            FloatCompare::approx_eq(a, b, eps),
             "assertion failed: `(left !== right)` \
              (left: `{:?}`, right: `{:?}`, expect diff: `{:?}`, real diff: `{:?}`)",
             *a,
             *b,
             eps,
-            a.abs_diff(b)
+            FloatCompare::abs_diff(a, b)
         );
     }};
     ($a:expr, $b:expr, epsilon = $eps:expr) => {{
@@ -19,13 +26,13 @@ macro_rules! assert_ulps_eq {
         let (a, b) = (&$a, &$b);
         let eps = $eps;
         assert!(
-            a.approx_eq(b, $eps),
+            FloatCompare::approx_eq(a, b, $eps),
             "assertion failed: `(left !== right)` \
              (left: `{:?}`, right: `{:?}`, expect diff: `{:?}`, real diff: `{:?}`)",
             *a,
             *b,
             eps,
-            a.abs_diff(b)
+            FloatCompare::abs_diff(a, b)
         );
     }};
 }
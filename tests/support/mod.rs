@@ -1,10 +1,14 @@
 #[macro_use]
 mod macros;
+pub mod inverse;
+pub mod registry;
 
 use glam::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4};
+use mathbench::reference;
 use nalgebra;
 
 /// Trait used by the `assert_approx_eq` macro for floating point comparisons.
+#[allow(dead_code)]
 pub trait FloatCompare<Rhs: ?Sized = Self> {
     /// Return true if the absolute difference between `self` and `other` is
     /// less then or equal to `max_abs_diff`.
@@ -14,6 +18,87 @@ pub trait FloatCompare<Rhs: ?Sized = Self> {
     fn abs_diff(&self, other: &Rhs) -> Rhs;
 }
 
+/// Trait for converting `mint` types into library types which either don't
+/// support `mint` or whose `mint` conversion doesn't use column vectors.
+#[allow(dead_code)]
+pub trait FromMint<T> {
+    fn from_mint(m: T) -> Self;
+}
+
+thread_local! {
+    static QUIET_PANICS: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Calls `f`, returning `None` if it panics without printing the panic message.
+#[allow(dead_code)]
+pub fn catch_quietly<T, F>(f: F) -> Option<T>
+where
+    F: FnOnce() -> T + std::panic::UnwindSafe,
+{
+    static INSTALL_HOOK: std::sync::Once = std::sync::Once::new();
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if !QUIET_PANICS.with(std::cell::Cell::get) {
+                default_hook(info);
+            }
+        }));
+    });
+    QUIET_PANICS.with(|quiet| quiet.set(true));
+    let result = std::panic::catch_unwind(f);
+    QUIET_PANICS.with(|quiet| quiet.set(false));
+    result.ok()
+}
+
+thread_local! {
+    static CHECKED: std::cell::RefCell<std::collections::BTreeSet<&'static str>> =
+        const { std::cell::RefCell::new(std::collections::BTreeSet::new()) };
+}
+
+/// Records that the running comparison checked `lib`, see `compare`.
+#[allow(dead_code)]
+pub fn checked(lib: &'static str) {
+    CHECKED.with(|checked| checked.borrow_mut().insert(lib));
+}
+
+/// Whether the comparison tests can check `lib` with the enabled features and
+/// the current CPU.
+#[allow(dead_code)]
+pub fn lib_enabled(lib: &str) -> bool {
+    match lib {
+        "pathfinder" => cfg!(feature = "pathfinder_geometry"),
+        "nalgebra_glm" => cfg!(feature = "nalgebra_glm"),
+        "intrinsics" => cfg!(all(feature = "intrinsics", target_arch = "x86_64")),
+        "intrinsics_avx" => {
+            #[cfg(all(feature = "intrinsics", target_arch = "x86_64"))]
+            return is_x86_feature_detected!("avx");
+            #[cfg(not(all(feature = "intrinsics", target_arch = "x86_64")))]
+            return false;
+        }
+        _ => true,
+    }
+}
+
+/// Runs the comparison `f` and asserts that it checked exactly the enabled
+/// libraries that `check` registers.
+#[allow(dead_code)]
+pub fn compare(check: &registry::Check, f: impl FnOnce()) {
+    CHECKED.with(|checked| checked.borrow_mut().clear());
+    f();
+    let checked = CHECKED.with(|checked| std::mem::take(&mut *checked.borrow_mut()));
+    let registered: std::collections::BTreeSet<_> = check
+        .libs
+        .iter()
+        .copied()
+        .filter(|lib| lib_enabled(lib))
+        .collect();
+    assert_eq!(
+        checked, registered,
+        "`{}` checked different libraries than it registers",
+        check.name
+    );
+}
+
 impl FloatCompare for f32 {
     #[inline]
     fn approx_eq(&self, other: &f32, max_abs_diff: f32) -> bool {
@@ -139,6 +224,132 @@ impl FloatCompare for mint::ColumnMatrix4<f32> {
     }
 }
 
+// mint f64 -------------------------------------------------------------------
+impl FloatCompare for f64 {
+    #[inline]
+    fn approx_eq(&self, other: &f64, max_abs_diff: f32) -> bool {
+        self.abs_diff(other) <= max_abs_diff.into()
+    }
+    #[inline]
+    fn abs_diff(&self, other: &f64) -> f64 {
+        (self - other).abs()
+    }
+}
+
+impl FloatCompare for mint::Vector2<f64> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.x.approx_eq(&other.x, max_abs_diff) && self.y.approx_eq(&other.y, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self {
+            x: self.x.abs_diff(&other.x),
+            y: self.y.abs_diff(&other.y),
+        }
+    }
+}
+
+impl FloatCompare for mint::Vector3<f64> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.x.approx_eq(&other.x, max_abs_diff)
+            && self.y.approx_eq(&other.y, max_abs_diff)
+            && self.z.approx_eq(&other.z, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self {
+            x: self.x.abs_diff(&other.x),
+            y: self.y.abs_diff(&other.y),
+            z: self.z.abs_diff(&other.z),
+        }
+    }
+}
+
+impl FloatCompare for mint::Vector4<f64> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.x.approx_eq(&other.x, max_abs_diff)
+            && self.y.approx_eq(&other.y, max_abs_diff)
+            && self.z.approx_eq(&other.z, max_abs_diff)
+            && self.w.approx_eq(&other.w, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self {
+            x: self.x.abs_diff(&other.x),
+            y: self.y.abs_diff(&other.y),
+            z: self.z.abs_diff(&other.z),
+            w: self.w.abs_diff(&other.w),
+        }
+    }
+}
+
+impl FloatCompare for mint::Quaternion<f64> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.v.approx_eq(&other.v, max_abs_diff) && self.s.approx_eq(&other.s, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self {
+            v: self.v.abs_diff(&other.v),
+            s: self.s.abs_diff(&other.s),
+        }
+    }
+}
+
+impl FloatCompare for mint::ColumnMatrix2<f64> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.x.approx_eq(&other.x, max_abs_diff) && self.y.approx_eq(&other.y, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self {
+            x: self.x.abs_diff(&other.x),
+            y: self.y.abs_diff(&other.y),
+        }
+    }
+}
+
+impl FloatCompare for mint::ColumnMatrix3<f64> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.x.approx_eq(&other.x, max_abs_diff)
+            && self.y.approx_eq(&other.y, max_abs_diff)
+            && self.z.approx_eq(&other.z, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self {
+            x: self.x.abs_diff(&other.x),
+            y: self.y.abs_diff(&other.y),
+            z: self.z.abs_diff(&other.z),
+        }
+    }
+}
+
+impl FloatCompare for mint::ColumnMatrix4<f64> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.x.approx_eq(&other.x, max_abs_diff)
+            && self.y.approx_eq(&other.y, max_abs_diff)
+            && self.z.approx_eq(&other.z, max_abs_diff)
+            && self.w.approx_eq(&other.w, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self {
+            x: self.x.abs_diff(&other.x),
+            y: self.y.abs_diff(&other.y),
+            z: self.z.abs_diff(&other.z),
+            w: self.w.abs_diff(&other.w),
+        }
+    }
+}
+
 // glam -----------------------------------------------------------------------
 impl FloatCompare for Mat2 {
     #[inline]
@@ -452,3 +663,729 @@ impl FloatCompare for nalgebra::Matrix4<f32> {
         (self - other).abs()
     }
 }
+
+impl FloatCompare for nalgebra::Point2<f32> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.coords.approx_eq(&other.coords, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::from(self.coords.abs_diff(&other.coords))
+    }
+}
+
+impl FloatCompare for nalgebra::Point3<f32> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.coords.approx_eq(&other.coords, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::from(self.coords.abs_diff(&other.coords))
+    }
+}
+
+impl FloatCompare for nalgebra::UnitQuaternion<f32> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.as_ref().approx_eq(other.as_ref(), max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::new_unchecked(self.as_ref().abs_diff(other.as_ref()))
+    }
+}
+
+impl FloatCompare for nalgebra::UnitComplex<f32> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.re.approx_eq(&other.re, max_abs_diff) && self.im.approx_eq(&other.im, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::new_unchecked(nalgebra::Complex::new(
+            self.re.abs_diff(&other.re),
+            self.im.abs_diff(&other.im),
+        ))
+    }
+}
+
+impl FloatCompare for nalgebra::Isometry2<f32> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.translation
+            .vector
+            .approx_eq(&other.translation.vector, max_abs_diff)
+            && self.rotation.approx_eq(&other.rotation, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::from_parts(
+            self.translation
+                .vector
+                .abs_diff(&other.translation.vector)
+                .into(),
+            self.rotation.abs_diff(&other.rotation),
+        )
+    }
+}
+
+impl FloatCompare for nalgebra::Isometry3<f32> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.translation
+            .vector
+            .approx_eq(&other.translation.vector, max_abs_diff)
+            && self.rotation.approx_eq(&other.rotation, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::from_parts(
+            self.translation
+                .vector
+                .abs_diff(&other.translation.vector)
+                .into(),
+            self.rotation.abs_diff(&other.rotation),
+        )
+    }
+}
+
+// cgmath points --------------------------------------------------------------
+impl FloatCompare for cgmath::Point2<f32> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.x.approx_eq(&other.x, max_abs_diff) && self.y.approx_eq(&other.y, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::new(self.x.abs_diff(&other.x), self.y.abs_diff(&other.y))
+    }
+}
+
+impl FloatCompare for cgmath::Point3<f32> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.x.approx_eq(&other.x, max_abs_diff)
+            && self.y.approx_eq(&other.y, max_abs_diff)
+            && self.z.approx_eq(&other.z, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::new(
+            self.x.abs_diff(&other.x),
+            self.y.abs_diff(&other.y),
+            self.z.abs_diff(&other.z),
+        )
+    }
+}
+
+// euclid ---------------------------------------------------------------------
+pub type EuclidTransform2D = euclid::Transform2D<f32, euclid::UnknownUnit, euclid::UnknownUnit>;
+pub type EuclidTransform3D = euclid::Transform3D<f32, euclid::UnknownUnit, euclid::UnknownUnit>;
+pub type EuclidRotation3D = euclid::Rotation3D<f32, euclid::UnknownUnit, euclid::UnknownUnit>;
+
+// euclid transforms use row vectors so the column major mint matrix is
+// transposed
+impl FromMint<mint::ColumnMatrix3<f32>> for EuclidTransform2D {
+    fn from_mint(m: mint::ColumnMatrix3<f32>) -> Self {
+        Self::row_major(m.x.x, m.x.y, m.y.x, m.y.y, m.z.x, m.z.y)
+    }
+}
+
+impl FromMint<mint::ColumnMatrix4<f32>> for EuclidTransform3D {
+    fn from_mint(m: mint::ColumnMatrix4<f32>) -> Self {
+        Self::row_major(
+            m.x.x, m.x.y, m.x.z, m.x.w, m.y.x, m.y.y, m.y.z, m.y.w, m.z.x, m.z.y, m.z.z, m.z.w,
+            m.w.x, m.w.y, m.w.z, m.w.w,
+        )
+    }
+}
+
+impl FromMint<mint::Quaternion<f32>> for EuclidRotation3D {
+    fn from_mint(q: mint::Quaternion<f32>) -> Self {
+        Self::quaternion(q.v.x, q.v.y, q.v.z, q.s)
+    }
+}
+
+impl FloatCompare for euclid::Vector2D<f32, euclid::UnknownUnit> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.x.approx_eq(&other.x, max_abs_diff) && self.y.approx_eq(&other.y, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        euclid::vec2(self.x.abs_diff(&other.x), self.y.abs_diff(&other.y))
+    }
+}
+
+impl FloatCompare for euclid::Vector3D<f32, euclid::UnknownUnit> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.x.approx_eq(&other.x, max_abs_diff)
+            && self.y.approx_eq(&other.y, max_abs_diff)
+            && self.z.approx_eq(&other.z, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        euclid::vec3(
+            self.x.abs_diff(&other.x),
+            self.y.abs_diff(&other.y),
+            self.z.abs_diff(&other.z),
+        )
+    }
+}
+
+impl FloatCompare for euclid::Point2D<f32, euclid::UnknownUnit> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.to_vector().approx_eq(&other.to_vector(), max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        self.to_vector().abs_diff(&other.to_vector()).to_point()
+    }
+}
+
+impl FloatCompare for euclid::Point3D<f32, euclid::UnknownUnit> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.to_vector().approx_eq(&other.to_vector(), max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        self.to_vector().abs_diff(&other.to_vector()).to_point()
+    }
+}
+
+impl FloatCompare for EuclidRotation3D {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.i.approx_eq(&other.i, max_abs_diff)
+            && self.j.approx_eq(&other.j, max_abs_diff)
+            && self.k.approx_eq(&other.k, max_abs_diff)
+            && self.r.approx_eq(&other.r, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::quaternion(
+            self.i.abs_diff(&other.i),
+            self.j.abs_diff(&other.j),
+            self.k.abs_diff(&other.k),
+            self.r.abs_diff(&other.r),
+        )
+    }
+}
+
+impl FloatCompare for EuclidTransform2D {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        let a = self.to_row_major_array();
+        let b = other.to_row_major_array();
+        a.iter()
+            .zip(b.iter())
+            .all(|(e1, e2)| e1.approx_eq(e2, max_abs_diff))
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        let a = self.to_row_major_array();
+        let b = other.to_row_major_array();
+        Self::row_major(
+            a[0].abs_diff(&b[0]),
+            a[1].abs_diff(&b[1]),
+            a[2].abs_diff(&b[2]),
+            a[3].abs_diff(&b[3]),
+            a[4].abs_diff(&b[4]),
+            a[5].abs_diff(&b[5]),
+        )
+    }
+}
+
+impl FloatCompare for EuclidTransform3D {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        let a = self.to_row_major_array();
+        let b = other.to_row_major_array();
+        a.iter()
+            .zip(b.iter())
+            .all(|(e1, e2)| e1.approx_eq(e2, max_abs_diff))
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        let a = self.to_row_major_array();
+        let b = other.to_row_major_array();
+        let mut d = [0.0; 16];
+        for (d, (e1, e2)) in d.iter_mut().zip(a.iter().zip(b.iter())) {
+            *d = e1.abs_diff(e2);
+        }
+        Self::row_major(
+            d[0], d[1], d[2], d[3], d[4], d[5], d[6], d[7], d[8], d[9], d[10], d[11], d[12], d[13],
+            d[14], d[15],
+        )
+    }
+}
+
+// vek ------------------------------------------------------------------------
+impl FloatCompare for vek::Vec2<f32> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.x.approx_eq(&other.x, max_abs_diff) && self.y.approx_eq(&other.y, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        (*self - *other).map(f32::abs)
+    }
+}
+
+impl FloatCompare for vek::Vec3<f32> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.x.approx_eq(&other.x, max_abs_diff)
+            && self.y.approx_eq(&other.y, max_abs_diff)
+            && self.z.approx_eq(&other.z, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        (*self - *other).map(f32::abs)
+    }
+}
+
+impl FloatCompare for vek::Vec4<f32> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.x.approx_eq(&other.x, max_abs_diff)
+            && self.y.approx_eq(&other.y, max_abs_diff)
+            && self.z.approx_eq(&other.z, max_abs_diff)
+            && self.w.approx_eq(&other.w, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        (*self - *other).map(f32::abs)
+    }
+}
+
+impl FloatCompare for vek::Quaternion<f32> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.into_vec4().approx_eq(&other.into_vec4(), max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::from_vec4(self.into_vec4().abs_diff(&other.into_vec4()))
+    }
+}
+
+impl FloatCompare for vek::Mat2<f32> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        let a: mint::ColumnMatrix2<f32> = (*self).into();
+        a.approx_eq(&(*other).into(), max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        let a: mint::ColumnMatrix2<f32> = (*self).into();
+        a.abs_diff(&(*other).into()).into()
+    }
+}
+
+impl FloatCompare for vek::Mat3<f32> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        let a: mint::ColumnMatrix3<f32> = (*self).into();
+        a.approx_eq(&(*other).into(), max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        let a: mint::ColumnMatrix3<f32> = (*self).into();
+        a.abs_diff(&(*other).into()).into()
+    }
+}
+
+impl FloatCompare for vek::Mat4<f32> {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        let a: mint::ColumnMatrix4<f32> = (*self).into();
+        a.approx_eq(&(*other).into(), max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        let a: mint::ColumnMatrix4<f32> = (*self).into();
+        a.abs_diff(&(*other).into()).into()
+    }
+}
+
+// ultraviolet ----------------------------------------------------------------
+impl FromMint<mint::Vector2<f32>> for ultraviolet::Vec2 {
+    fn from_mint(v: mint::Vector2<f32>) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
+impl FromMint<mint::Vector3<f32>> for ultraviolet::Vec3 {
+    fn from_mint(v: mint::Vector3<f32>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl FromMint<mint::Vector4<f32>> for ultraviolet::Vec4 {
+    fn from_mint(v: mint::Vector4<f32>) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl FromMint<mint::ColumnMatrix2<f32>> for ultraviolet::Mat2 {
+    fn from_mint(m: mint::ColumnMatrix2<f32>) -> Self {
+        Self::new(FromMint::from_mint(m.x), FromMint::from_mint(m.y))
+    }
+}
+
+impl FromMint<mint::ColumnMatrix3<f32>> for ultraviolet::Mat3 {
+    fn from_mint(m: mint::ColumnMatrix3<f32>) -> Self {
+        Self::new(
+            FromMint::from_mint(m.x),
+            FromMint::from_mint(m.y),
+            FromMint::from_mint(m.z),
+        )
+    }
+}
+
+impl FromMint<mint::ColumnMatrix4<f32>> for ultraviolet::Mat4 {
+    fn from_mint(m: mint::ColumnMatrix4<f32>) -> Self {
+        Self::new(
+            FromMint::from_mint(m.x),
+            FromMint::from_mint(m.y),
+            FromMint::from_mint(m.z),
+            FromMint::from_mint(m.w),
+        )
+    }
+}
+
+// A rotor's bivector plane is the dual of the quaternion's rotation axis with
+// the opposite orientation for the `xy` and `yz` planes.
+impl FromMint<mint::Quaternion<f32>> for ultraviolet::Rotor3 {
+    fn from_mint(q: mint::Quaternion<f32>) -> Self {
+        Self::new(q.s, ultraviolet::Bivec3::new(-q.v.z, q.v.y, -q.v.x))
+    }
+}
+
+impl FloatCompare for ultraviolet::Vec2 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.x.approx_eq(&other.x, max_abs_diff) && self.y.approx_eq(&other.y, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        (*self - *other).abs()
+    }
+}
+
+impl FloatCompare for ultraviolet::Vec3 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.x.approx_eq(&other.x, max_abs_diff)
+            && self.y.approx_eq(&other.y, max_abs_diff)
+            && self.z.approx_eq(&other.z, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        (*self - *other).abs()
+    }
+}
+
+impl FloatCompare for ultraviolet::Vec4 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.x.approx_eq(&other.x, max_abs_diff)
+            && self.y.approx_eq(&other.y, max_abs_diff)
+            && self.z.approx_eq(&other.z, max_abs_diff)
+            && self.w.approx_eq(&other.w, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        (*self - *other).abs()
+    }
+}
+
+impl FloatCompare for ultraviolet::Mat2 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.cols[0].approx_eq(&other.cols[0], max_abs_diff)
+            && self.cols[1].approx_eq(&other.cols[1], max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::new(
+            self.cols[0].abs_diff(&other.cols[0]),
+            self.cols[1].abs_diff(&other.cols[1]),
+        )
+    }
+}
+
+impl FloatCompare for ultraviolet::Mat3 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.cols[0].approx_eq(&other.cols[0], max_abs_diff)
+            && self.cols[1].approx_eq(&other.cols[1], max_abs_diff)
+            && self.cols[2].approx_eq(&other.cols[2], max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::new(
+            self.cols[0].abs_diff(&other.cols[0]),
+            self.cols[1].abs_diff(&other.cols[1]),
+            self.cols[2].abs_diff(&other.cols[2]),
+        )
+    }
+}
+
+impl FloatCompare for ultraviolet::Mat4 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.cols[0].approx_eq(&other.cols[0], max_abs_diff)
+            && self.cols[1].approx_eq(&other.cols[1], max_abs_diff)
+            && self.cols[2].approx_eq(&other.cols[2], max_abs_diff)
+            && self.cols[3].approx_eq(&other.cols[3], max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::new(
+            self.cols[0].abs_diff(&other.cols[0]),
+            self.cols[1].abs_diff(&other.cols[1]),
+            self.cols[2].abs_diff(&other.cols[2]),
+            self.cols[3].abs_diff(&other.cols[3]),
+        )
+    }
+}
+
+impl FloatCompare for ultraviolet::Rotor2 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.s.approx_eq(&other.s, max_abs_diff) && self.bv.xy.approx_eq(&other.bv.xy, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::new(
+            self.s.abs_diff(&other.s),
+            ultraviolet::Bivec2::new(self.bv.xy.abs_diff(&other.bv.xy)),
+        )
+    }
+}
+
+impl FloatCompare for ultraviolet::Rotor3 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.s.approx_eq(&other.s, max_abs_diff)
+            && self.bv.xy.approx_eq(&other.bv.xy, max_abs_diff)
+            && self.bv.xz.approx_eq(&other.bv.xz, max_abs_diff)
+            && self.bv.yz.approx_eq(&other.bv.yz, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::new(
+            self.s.abs_diff(&other.s),
+            ultraviolet::Bivec3::new(
+                self.bv.xy.abs_diff(&other.bv.xy),
+                self.bv.xz.abs_diff(&other.bv.xz),
+                self.bv.yz.abs_diff(&other.bv.yz),
+            ),
+        )
+    }
+}
+
+impl FloatCompare for ultraviolet::Isometry2 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.translation.approx_eq(&other.translation, max_abs_diff)
+            && self.rotation.approx_eq(&other.rotation, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::new(
+            self.translation.abs_diff(&other.translation),
+            self.rotation.abs_diff(&other.rotation),
+        )
+    }
+}
+
+impl FloatCompare for ultraviolet::Isometry3 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.translation.approx_eq(&other.translation, max_abs_diff)
+            && self.rotation.approx_eq(&other.rotation, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::new(
+            self.translation.abs_diff(&other.translation),
+            self.rotation.abs_diff(&other.rotation),
+        )
+    }
+}
+
+// pathfinder -----------------------------------------------------------------
+#[cfg(feature = "pathfinder_geometry")]
+mod pathfinder {
+    use super::{FloatCompare, FromMint};
+    use pathfinder_geometry::transform2d::{Matrix2x2F, Transform2F};
+    use pathfinder_geometry::transform3d::Transform4F;
+    use pathfinder_geometry::vector::{Vector2F, Vector4F};
+
+    impl FromMint<mint::Vector2<f32>> for Vector2F {
+        fn from_mint(v: mint::Vector2<f32>) -> Self {
+            Self::new(v.x, v.y)
+        }
+    }
+
+    impl FromMint<mint::Vector4<f32>> for Vector4F {
+        fn from_mint(v: mint::Vector4<f32>) -> Self {
+            Self::new(v.x, v.y, v.z, v.w)
+        }
+    }
+
+    impl FromMint<mint::ColumnMatrix2<f32>> for Matrix2x2F {
+        fn from_mint(m: mint::ColumnMatrix2<f32>) -> Self {
+            Self::row_major(m.x.x, m.y.x, m.x.y, m.y.y)
+        }
+    }
+
+    impl FromMint<mint::ColumnMatrix3<f32>> for Transform2F {
+        fn from_mint(m: mint::ColumnMatrix3<f32>) -> Self {
+            Self::row_major(m.x.x, m.y.x, m.x.y, m.y.y, m.z.x, m.z.y)
+        }
+    }
+
+    impl FromMint<mint::ColumnMatrix4<f32>> for Transform4F {
+        fn from_mint(m: mint::ColumnMatrix4<f32>) -> Self {
+            Self::row_major(
+                m.x.x, m.y.x, m.z.x, m.w.x, m.x.y, m.y.y, m.z.y, m.w.y, m.x.z, m.y.z, m.z.z, m.w.z,
+                m.x.w, m.y.w, m.z.w, m.w.w,
+            )
+        }
+    }
+
+    impl FloatCompare for Vector2F {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            self.x().approx_eq(&other.x(), max_abs_diff)
+                && self.y().approx_eq(&other.y(), max_abs_diff)
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            Self::new(self.x().abs_diff(&other.x()), self.y().abs_diff(&other.y()))
+        }
+    }
+
+    impl FloatCompare for Vector4F {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            self.x().approx_eq(&other.x(), max_abs_diff)
+                && self.y().approx_eq(&other.y(), max_abs_diff)
+                && self.z().approx_eq(&other.z(), max_abs_diff)
+                && self.w().approx_eq(&other.w(), max_abs_diff)
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            Self::new(
+                self.x().abs_diff(&other.x()),
+                self.y().abs_diff(&other.y()),
+                self.z().abs_diff(&other.z()),
+                self.w().abs_diff(&other.w()),
+            )
+        }
+    }
+
+    impl FloatCompare for Matrix2x2F {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            (0..4).all(|i| self.0[i].approx_eq(&other.0[i], max_abs_diff))
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            let d = |i: usize| self.0[i].abs_diff(&other.0[i]);
+            // stored in column major order
+            Self::row_major(d(0), d(2), d(1), d(3))
+        }
+    }
+
+    impl FloatCompare for Transform2F {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            self.matrix.approx_eq(&other.matrix, max_abs_diff)
+                && self.vector.approx_eq(&other.vector, max_abs_diff)
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            Self {
+                matrix: self.matrix.abs_diff(&other.matrix),
+                vector: self.vector.abs_diff(&other.vector),
+            }
+        }
+    }
+
+    impl FloatCompare for Transform4F {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            let (a, b) = (self.to_columns(), other.to_columns());
+            (0..4).all(|c| (0..4).all(|r| a[c][r].approx_eq(&b[c][r], max_abs_diff)))
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            let (a, b) = (self.to_columns(), other.to_columns());
+            let d = |c: usize, r: usize| a[c][r].abs_diff(&b[c][r]);
+            Self::row_major(
+                d(0, 0),
+                d(1, 0),
+                d(2, 0),
+                d(3, 0),
+                d(0, 1),
+                d(1, 1),
+                d(2, 1),
+                d(3, 1),
+                d(0, 2),
+                d(1, 2),
+                d(2, 2),
+                d(3, 2),
+                d(0, 3),
+                d(1, 3),
+                d(2, 3),
+                d(3, 3),
+            )
+        }
+    }
+}
+
+// reference ------------------------------------------------------------------
+// for types which convert to and from mint without loss
+macro_rules! impl_mint_float_compare {
+    ($t:ty, $mint:ty) => {
+        impl FloatCompare for $t {
+            #[inline]
+            fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+                <$mint>::from(*self).approx_eq(&<$mint>::from(*other), max_abs_diff)
+            }
+            #[inline]
+            fn abs_diff(&self, other: &Self) -> Self {
+                <$mint>::from(*self).abs_diff(&<$mint>::from(*other)).into()
+            }
+        }
+    };
+}
+
+impl_mint_float_compare!(reference::Vec2, mint::Vector2<f32>);
+impl_mint_float_compare!(reference::Vec3, mint::Vector3<f32>);
+impl_mint_float_compare!(reference::Vec4, mint::Vector4<f32>);
+impl_mint_float_compare!(reference::Quat, mint::Quaternion<f32>);
+impl_mint_float_compare!(reference::Mat2, mint::ColumnMatrix2<f32>);
+impl_mint_float_compare!(reference::Mat3, mint::ColumnMatrix3<f32>);
+impl_mint_float_compare!(reference::Mat4, mint::ColumnMatrix4<f32>);
+
+// intrinsics -----------------------------------------------------------------
+#[cfg(all(feature = "intrinsics", target_arch = "x86_64"))]
+mod intrinsics_compare {
+    use super::FloatCompare;
+    use mathbench::intrinsics;
+
+    impl_mint_float_compare!(intrinsics::Vec3, mint::Vector3<f32>);
+    impl_mint_float_compare!(intrinsics::Quat, mint::Quaternion<f32>);
+    impl_mint_float_compare!(intrinsics::Mat4, mint::ColumnMatrix4<f32>);
+}
@@ -0,0 +1,128 @@
+//! The libraries each comparison test checks against the reference answer.
+//!
+//! Each test runs its comparison through `support::compare` with its `Check`,
+//! which fails unless the comparison checks exactly the registered libraries.
+//! The `coverage` test fails unless every benchmarked library is covered by
+//! the `Check`s registered for its benchmark group.
+#![allow(dead_code)]
+
+pub struct Check {
+    pub name: &'static str,
+    pub libs: &'static [&'static str],
+}
+
+macro_rules! checks {
+    ($($name:ident: [$($lib:ident),* $(,)?],)+) => {
+        $(
+            pub static $name: Check = Check {
+                name: stringify!($name),
+                libs: &[$(stringify!($lib)),*],
+            };
+        )+
+
+        /// Every registered check.
+        pub static ALL: &[&Check] = &[$(&$name),+];
+    };
+}
+
+checks! {
+    EULER_2D: [cgmath, euclid, glam, pathfinder, reference, ultraviolet, vek],
+    EULER_3D: [cgmath, euclid, glam, pathfinder, reference, ultraviolet, vek],
+    F64_MAT2_DET: [cgmath, vek],
+    F64_MAT2_INVERSE: [cgmath],
+    F64_MAT2_MUL_MAT2: [cgmath, vek],
+    F64_MAT2_MUL_VEC2: [cgmath, vek],
+    F64_MAT2_TRANSPOSE: [cgmath, vek],
+    F64_MAT3_DET: [cgmath, vek],
+    F64_MAT3_INVERSE: [cgmath],
+    F64_MAT3_MUL_MAT3: [cgmath, vek],
+    F64_MAT3_MUL_VEC3: [cgmath, vek],
+    F64_MAT3_TRANSPOSE: [cgmath, vek],
+    F64_MAT4_DET: [cgmath, euclid, vek],
+    F64_MAT4_INVERSE: [cgmath, euclid, vek],
+    F64_MAT4_MUL_MAT4: [cgmath, euclid, vek],
+    F64_MAT4_MUL_VEC4: [cgmath, vek],
+    F64_MAT4_TRANSPOSE: [cgmath, vek],
+    F64_QUAT_CONJUGATE: [cgmath, euclid, vek],
+    F64_QUAT_MUL_QUAT: [cgmath, euclid, vek],
+    F64_QUAT_MUL_VEC3: [cgmath, euclid, vek],
+    F64_TRANSFORM2_INVERSE: [euclid],
+    F64_TRANSFORM2_MUL_TRANSFORM2: [euclid],
+    F64_TRANSFORM3_INVERSE: [euclid],
+    F64_TRANSFORM3_MUL_TRANSFORM3: [euclid],
+    F64_TRANSFORM_POINT2: [cgmath, euclid, vek],
+    F64_TRANSFORM_POINT3: [cgmath, euclid, vek],
+    F64_TRANSFORM_VECTOR2: [euclid, vek],
+    F64_TRANSFORM_VECTOR3: [cgmath, euclid, vek],
+    F64_VEC3_CROSS: [cgmath, euclid, vek],
+    F64_VEC3_DOT: [cgmath, euclid, vek],
+    F64_VEC3_LENGTH: [cgmath, euclid, vek],
+    F64_VEC3_NORMALIZE: [cgmath, euclid, vek],
+    ISOMETRY2_INVERSE: [ultraviolet],
+    ISOMETRY2_MUL_ISOMETRY2: [ultraviolet],
+    ISOMETRY2_TRANSFORM_POINT2: [ultraviolet],
+    ISOMETRY3_INVERSE: [cgmath, glam, ultraviolet, vek],
+    ISOMETRY3_MUL_ISOMETRY3: [cgmath, glam, ultraviolet, vek],
+    ISOMETRY3_TRANSFORM_POINT3: [cgmath, glam, ultraviolet, vek],
+    MAT2_ADD_MAT2: [cgmath, glam, pathfinder, reference, ultraviolet, vek],
+    MAT2_DET: [cgmath, glam, nalgebra_glm, pathfinder, reference, vek],
+    MAT2_FROM_COLS: [cgmath, glam, pathfinder, reference, ultraviolet, vek],
+    MAT2_INVERSE: [cgmath, glam, nalgebra_glm, pathfinder, reference],
+    MAT2_MUL_MAT2: [cgmath, glam, pathfinder, reference, vek],
+    MAT2_MUL_SCALAR: [cgmath, glam, pathfinder, reference, ultraviolet, vek],
+    MAT2_MUL_VEC2: [cgmath, glam, pathfinder, reference, ultraviolet, vek],
+    MAT2_SUB_MAT2: [cgmath, glam, pathfinder, reference, ultraviolet, vek],
+    MAT2_TRACE: [cgmath, glam, pathfinder, reference, ultraviolet, vek],
+    MAT2_TRANSPOSE: [cgmath, glam, nalgebra_glm, reference, vek],
+    MAT2_TRANSPOSE_MUL_MAT2: [cgmath, glam, pathfinder, reference, ultraviolet, vek],
+    MAT3_ADD_MAT3: [cgmath, glam, reference, ultraviolet, vek],
+    MAT3_DET: [cgmath, glam, nalgebra_glm, reference, vek],
+    MAT3_FROM_COLS: [cgmath, glam, reference, ultraviolet, vek],
+    MAT3_INVERSE: [cgmath, glam, nalgebra_glm, reference],
+    MAT3_MUL_MAT3: [cgmath, glam, reference, ultraviolet, vek],
+    MAT3_MUL_SCALAR: [cgmath, glam, reference, ultraviolet, vek],
+    MAT3_MUL_VEC3: [cgmath, glam, reference, ultraviolet, vek],
+    MAT3_SUB_MAT3: [cgmath, glam, reference, ultraviolet, vek],
+    MAT3_TRACE: [cgmath, glam, reference, ultraviolet, vek],
+    MAT3_TRANSPOSE: [cgmath, glam, nalgebra_glm, reference, ultraviolet, vek],
+    MAT3_TRANSPOSE_MUL_MAT3: [cgmath, glam, reference, ultraviolet, vek],
+    MAT4_ADD_MAT4: [cgmath, glam, reference, ultraviolet, vek],
+    MAT4_AFFINE_INVERSE: [cgmath, glam, nalgebra, nalgebra_glm, reference, ultraviolet, vek],
+    MAT4_DET: [cgmath, euclid, glam, nalgebra_glm, reference, vek],
+    MAT4_FROM_COLS: [cgmath, glam, reference, ultraviolet, vek],
+    MAT4_INVERSE: [cgmath, euclid, glam, intrinsics, nalgebra_glm, reference, ultraviolet, vek],
+    MAT4_MUL_MAT4: [cgmath, euclid, glam, intrinsics, intrinsics_avx, reference, ultraviolet, vek],
+    MAT4_MUL_SCALAR: [cgmath, euclid, glam, reference, ultraviolet, vek],
+    MAT4_MUL_VEC4: [cgmath, glam, reference, ultraviolet, vek],
+    MAT4_PROJECT_POINT3: [cgmath, euclid, glam, reference, ultraviolet, vek],
+    MAT4_SUB_MAT4: [cgmath, glam, reference, ultraviolet, vek],
+    MAT4_TRACE: [cgmath, glam, reference, ultraviolet, vek],
+    MAT4_TRANSPOSE: [cgmath, glam, nalgebra_glm, reference, ultraviolet, vek],
+    MAT4_TRANSPOSE_MUL_MAT4: [cgmath, glam, reference, ultraviolet, vek],
+    QUAT_CONJUGATE: [cgmath, euclid, glam, nalgebra_glm, reference, ultraviolet, vek],
+    QUAT_MUL_QUAT: [cgmath, euclid, glam, nalgebra_glm, reference, ultraviolet, vek],
+    QUAT_MUL_VEC3: [cgmath, euclid, glam, intrinsics, nalgebra_glm, reference, ultraviolet, vek],
+    ROTATION2_FROM_ANGLE: [euclid, glam, ultraviolet],
+    ROTATION2_INVERSE: [euclid, glam, ultraviolet],
+    ROTATION2_MUL_ROTATION2: [euclid, glam, ultraviolet],
+    ROTATION2_ROTATE_VECTOR2: [euclid, glam, ultraviolet],
+    ROTATION2_SLERP: [euclid, glam, ultraviolet],
+    SIMILARITY3_INTERPOLATE: [cgmath, glam, ultraviolet],
+    SIMILARITY3_INVERSE: [cgmath, glam],
+    SIMILARITY3_MUL_SIMILARITY3: [cgmath, glam, ultraviolet],
+    SIMILARITY3_TO_MATRIX4: [cgmath, glam],
+    SIMILARITY3_TRANSFORM_POINT3: [cgmath, glam, ultraviolet],
+    SIMILARITY3_TRANSFORM_VECTOR3: [cgmath, glam, ultraviolet],
+    TRANSFORM2_INVERSE: [euclid, pathfinder],
+    TRANSFORM2_MUL_TRANSFORM2: [euclid, pathfinder],
+    TRANSFORM3_INVERSE: [cgmath, euclid, glam, pathfinder, vek],
+    TRANSFORM3_MUL_TRANSFORM3: [cgmath, euclid, glam, pathfinder, vek],
+    TRANSFORM_POINT2: [cgmath, euclid, glam, pathfinder, reference, vek],
+    TRANSFORM_POINT3: [cgmath, euclid, glam, pathfinder, reference, vek],
+    TRANSFORM_VECTOR2: [euclid, glam, reference, vek],
+    TRANSFORM_VECTOR3: [cgmath, euclid, glam, reference, vek],
+    VEC3_CROSS: [cgmath, euclid, glam, nalgebra_glm, reference, ultraviolet, vek],
+    VEC3_DOT: [cgmath, euclid, glam, nalgebra_glm, reference, ultraviolet, vek],
+    VEC3_LENGTH: [cgmath, euclid, glam, nalgebra_glm, reference, ultraviolet, vek],
+    VEC3_NORMALIZE: [cgmath, euclid, glam, intrinsics, nalgebra_glm, reference, ultraviolet, vek],
+}
@@ -0,0 +1,401 @@
+mod support;
+use cgmath;
+use glam;
+use mathbench::mint_support::*;
+use mathbench::reference;
+use nalgebra;
+use rand_pcg::Pcg64Mcg;
+use support::{registry, FromMint};
+
+const NUM_ITERS: usize = 1024;
+
+fn transform_point2_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_homogeneous_mat3(&mut rng);
+    let mv = random_mint_vec2(&mut rng);
+
+    let gm: glam::Mat3 = mm.into();
+    let gv: glam::Vec2 = mv.into();
+    let gmv = gm.transform_point2(gv);
+
+    let nm = nalgebra::Transform2::from_matrix_unchecked(nalgebra::Matrix3::from(mm));
+    let nv = nalgebra::Point2::new(mv.x, mv.y);
+    let nmv = nm.transform_point(&nv);
+
+    let cm: cgmath::Matrix3<f32> = mm.into();
+    let cv = cgmath::Point2::new(mv.x, mv.y);
+    let cmv = cgmath::Transform::transform_point(&cm, cv);
+
+    let em = support::EuclidTransform2D::from_mint(mm);
+    let ev = euclid::point2(mv.x, mv.y);
+    let emv = em.transform_point(ev);
+
+    let vm: vek::Mat3<f32> = mm.into();
+    let vv: vek::Vec2<f32> = mv.into();
+    let vmv = vm.mul_point_2d(vv);
+
+    let rm: reference::Mat3 = mm.into();
+    let rv: reference::Vec2 = mv.into();
+    let rmv = rm.transform_point2(rv);
+
+    // use nalgebra as assumed correct answer
+    let mmv: mint::Vector2<f32> = nmv.coords.into();
+
+    assert_ulps_eq!(cgmath => cmv, cgmath::Point2::new(mmv.x, mmv.y), epsilon = 1e-6);
+    assert_ulps_eq!(glam => gmv, mmv.into(), epsilon = 1e-6);
+    assert_ulps_eq!(euclid => emv, euclid::point2(mmv.x, mmv.y), epsilon = 1e-6);
+    assert_ulps_eq!(vek => vmv, mmv.into(), epsilon = 1e-6);
+    assert_ulps_eq!(reference => rmv, mmv.into(), epsilon = 1e-6);
+
+    #[cfg(feature = "pathfinder_geometry")]
+    {
+        use pathfinder_geometry::{transform2d::Transform2F, vector::Vector2F};
+        let pmv = Transform2F::from_mint(mm) * Vector2F::from_mint(mv);
+        assert_ulps_eq!(pathfinder => pmv, Vector2F::from_mint(mmv), epsilon = 1e-6);
+    }
+}
+
+fn transform_vector2_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_homogeneous_mat3(&mut rng);
+    let mv = random_mint_vec2(&mut rng);
+
+    let gm: glam::Mat3 = mm.into();
+    let gv: glam::Vec2 = mv.into();
+    let gmv = gm.transform_vector2(gv);
+
+    let nm = nalgebra::Transform2::from_matrix_unchecked(nalgebra::Matrix3::from(mm));
+    let nv: nalgebra::Vector2<f32> = mv.into();
+    let nmv = nm.transform_vector(&nv);
+
+    let em = support::EuclidTransform2D::from_mint(mm);
+    let ev: euclid::Vector2D<f32, euclid::UnknownUnit> = mv.into();
+    let emv = em.transform_vector(ev);
+
+    let vm: vek::Mat3<f32> = mm.into();
+    let vv: vek::Vec2<f32> = mv.into();
+    let vmv = vm.mul_direction_2d(vv);
+
+    let rm: reference::Mat3 = mm.into();
+    let rv: reference::Vec2 = mv.into();
+    let rmv = rm.transform_vector2(rv);
+
+    // use nalgebra as assumed correct answer
+    let mmv: mint::Vector2<f32> = nmv.into();
+
+    assert_ulps_eq!(glam => gmv, mmv.into(), epsilon = 1e-6);
+    assert_ulps_eq!(euclid => emv, mmv.into(), epsilon = 1e-6);
+    assert_ulps_eq!(vek => vmv, mmv.into(), epsilon = 1e-6);
+    assert_ulps_eq!(reference => rmv, mmv.into(), epsilon = 1e-6);
+}
+
+fn transform_point3_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_homogeneous_mat4(&mut rng);
+    let mv = random_mint_vec3(&mut rng);
+
+    let gm: glam::Mat4 = mm.into();
+    let gv: glam::Vec3 = mv.into();
+    let gmv = gm.transform_point3(gv);
+
+    let nm = nalgebra::Transform3::from_matrix_unchecked(nalgebra::Matrix4::from(mm));
+    let nv = nalgebra::Point3::new(mv.x, mv.y, mv.z);
+    let nmv = nm.transform_point(&nv);
+
+    let cm: cgmath::Matrix4<f32> = mm.into();
+    let cv = cgmath::Point3::new(mv.x, mv.y, mv.z);
+    let cmv = cgmath::Transform::transform_point(&cm, cv);
+
+    let em = support::EuclidTransform3D::from_mint(mm);
+    let ev = euclid::point3(mv.x, mv.y, mv.z);
+    let emv = em.transform_point3d(ev);
+
+    let vm: vek::Mat4<f32> = mm.into();
+    let vv: vek::Vec3<f32> = mv.into();
+    let vmv = vm.mul_point(vv);
+
+    let rm: reference::Mat4 = mm.into();
+    let rv: reference::Vec3 = mv.into();
+    let rmv = rm.transform_point3(rv);
+
+    // use nalgebra as assumed correct answer
+    let mmv: mint::Vector3<f32> = nmv.coords.into();
+
+    assert_ulps_eq!(
+        cgmath => cmv,
+        cgmath::Point3::new(mmv.x, mmv.y, mmv.z),
+        epsilon = 1e-6
+    );
+    assert_ulps_eq!(glam => gmv, mmv.into(), epsilon = 1e-6);
+    assert!(emv.is_some());
+    assert_ulps_eq!(
+        euclid => emv.unwrap(),
+        euclid::point3(mmv.x, mmv.y, mmv.z),
+        epsilon = 1e-6
+    );
+    assert_ulps_eq!(vek => vmv, mmv.into(), epsilon = 1e-6);
+    assert_ulps_eq!(reference => rmv, mmv.into(), epsilon = 1e-6);
+
+    #[cfg(feature = "pathfinder_geometry")]
+    {
+        use pathfinder_geometry::{transform3d::Transform4F, vector::Vector4F};
+        let pmv = Transform4F::from_mint(mm) * Vector4F::new(mv.x, mv.y, mv.z, 1.0);
+        assert_ulps_eq!(pathfinder => pmv, Vector4F::new(mmv.x, mmv.y, mmv.z, 1.0), epsilon = 1e-6);
+    }
+}
+
+fn transform_vector3_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_homogeneous_mat4(&mut rng);
+    let mv = random_mint_vec3(&mut rng);
+
+    let gm: glam::Mat4 = mm.into();
+    let gv: glam::Vec3 = mv.into();
+    let gmv = gm.transform_vector3(gv);
+
+    let nm = nalgebra::Transform3::from_matrix_unchecked(nalgebra::Matrix4::from(mm));
+    let nv: nalgebra::Vector3<f32> = mv.into();
+    let nmv = nm.transform_vector(&nv);
+
+    let cm: cgmath::Matrix4<f32> = mm.into();
+    let cv: cgmath::Vector3<f32> = mv.into();
+    let cmv = cgmath::Transform::transform_vector(&cm, cv);
+
+    let em = support::EuclidTransform3D::from_mint(mm);
+    let ev: euclid::Vector3D<f32, euclid::UnknownUnit> = mv.into();
+    let emv = em.transform_vector3d(ev);
+
+    let vm: vek::Mat4<f32> = mm.into();
+    let vv: vek::Vec3<f32> = mv.into();
+    let vmv = vm.mul_direction(vv);
+
+    let rm: reference::Mat4 = mm.into();
+    let rv: reference::Vec3 = mv.into();
+    let rmv = rm.transform_vector3(rv);
+
+    // use nalgebra as assumed correct answer
+    let mmv: mint::Vector3<f32> = nmv.into();
+
+    assert_ulps_eq!(cgmath => cmv, mmv.into(), epsilon = 1e-6);
+    assert_ulps_eq!(glam => gmv, mmv.into(), epsilon = 1e-6);
+    assert_ulps_eq!(euclid => emv, mmv.into(), epsilon = 1e-6);
+    assert_ulps_eq!(vek => vmv, mmv.into(), epsilon = 1e-6);
+    assert_ulps_eq!(reference => rmv, mmv.into(), epsilon = 1e-6);
+}
+
+fn transform2_inverse_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_homogeneous_mat3(&mut rng);
+
+    let nm = nalgebra::Transform2::from_matrix_unchecked(nalgebra::Matrix3::from(mm));
+    let nmi = nm.try_inverse();
+    assert!(nmi.is_some());
+
+    let emi = support::EuclidTransform2D::from_mint(mm).inverse();
+    assert!(emi.is_some());
+
+    // use nalgebra as assumed correct answer
+    let mmi: mint::ColumnMatrix3<f32> = nmi.unwrap().into_inner().into();
+
+    assert_ulps_eq!(
+        euclid => emi.unwrap(),
+        support::EuclidTransform2D::from_mint(mmi),
+        epsilon = 1e-4
+    );
+
+    #[cfg(feature = "pathfinder_geometry")]
+    {
+        use pathfinder_geometry::transform2d::Transform2F;
+        let pmi = Transform2F::from_mint(mm).inverse();
+        assert_ulps_eq!(pathfinder => pmi, Transform2F::from_mint(mmi), epsilon = 1e-4);
+    }
+}
+
+fn transform2_mul_transform2_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_homogeneous_mat3(&mut rng);
+    let mm2 = random_mint_homogeneous_mat3(&mut rng);
+
+    let nm1 = nalgebra::Transform2::from_matrix_unchecked(nalgebra::Matrix3::from(mm1));
+    let nm2 = nalgebra::Transform2::from_matrix_unchecked(nalgebra::Matrix3::from(mm2));
+    let nm3 = nm1 * nm2;
+
+    // euclid uses row vectors so `m2.post_transform(m1)` is `m1 * m2`
+    let em1 = support::EuclidTransform2D::from_mint(mm1);
+    let em2 = support::EuclidTransform2D::from_mint(mm2);
+    let em3 = em2.post_transform(&em1);
+
+    // use nalgebra as assumed correct answer
+    let mm3: mint::ColumnMatrix3<f32> = nm3.into_inner().into();
+
+    assert_ulps_eq!(
+        euclid => em3,
+        support::EuclidTransform2D::from_mint(mm3),
+        epsilon = 1e-6
+    );
+
+    #[cfg(feature = "pathfinder_geometry")]
+    {
+        use pathfinder_geometry::transform2d::Transform2F;
+        let pm3 = Transform2F::from_mint(mm1) * Transform2F::from_mint(mm2);
+        assert_ulps_eq!(pathfinder => pm3, Transform2F::from_mint(mm3), epsilon = 1e-6);
+    }
+}
+
+fn transform3_inverse_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm = random_mint_homogeneous_mat4(&mut rng);
+
+    let nm = nalgebra::Transform3::from_matrix_unchecked(nalgebra::Matrix4::from(mm));
+    let nmi = nm.try_inverse();
+    assert!(nmi.is_some());
+
+    let emi = support::EuclidTransform3D::from_mint(mm).inverse();
+    assert!(emi.is_some());
+
+    let gmi = glam::Mat4::from(mm).inverse();
+
+    let cmi = {
+        use cgmath::SquareMatrix;
+        cgmath::Matrix4::from(mm).invert()
+    };
+    assert!(cmi.is_some());
+
+    let vmi = vek::Mat4::from(mm).inverted();
+
+    // use nalgebra as assumed correct answer
+    let mmi: mint::ColumnMatrix4<f32> = nmi.unwrap().into_inner().into();
+
+    assert_ulps_eq!(glam => gmi, glam::Mat4::from(mmi), epsilon = 1e-4);
+    assert_ulps_eq!(cgmath => cmi.unwrap(), cgmath::Matrix4::from(mmi), epsilon = 1e-4);
+    assert_ulps_eq!(vek => vmi, vek::Mat4::from(mmi), epsilon = 1e-4);
+
+    assert_ulps_eq!(
+        euclid => emi.unwrap(),
+        support::EuclidTransform3D::from_mint(mmi),
+        epsilon = 1e-4
+    );
+
+    #[cfg(feature = "pathfinder_geometry")]
+    {
+        use pathfinder_geometry::transform3d::Transform4F;
+        // pathfinder uses blockwise inversion which assumes the upper left 2x2
+        // submatrix is invertible, precision is lost as it approaches singular
+        let upper_left_det = mm.x.x * mm.y.y - mm.y.x * mm.x.y;
+        if upper_left_det.abs() > 0.1 {
+            let pmi = Transform4F::from_mint(mm).inverse();
+            assert_ulps_eq!(pathfinder => pmi, Transform4F::from_mint(mmi), epsilon = 1e-3);
+        }
+    }
+}
+
+fn transform3_mul_transform3_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_homogeneous_mat4(&mut rng);
+    let mm2 = random_mint_homogeneous_mat4(&mut rng);
+
+    let nm1 = nalgebra::Transform3::from_matrix_unchecked(nalgebra::Matrix4::from(mm1));
+    let nm2 = nalgebra::Transform3::from_matrix_unchecked(nalgebra::Matrix4::from(mm2));
+    let nm3 = nm1 * nm2;
+
+    // euclid uses row vectors so `m2.post_transform(m1)` is `m1 * m2`
+    let em1 = support::EuclidTransform3D::from_mint(mm1);
+    let em2 = support::EuclidTransform3D::from_mint(mm2);
+    let em3 = em2.post_transform(&em1);
+
+    let gm3 = glam::Mat4::from(mm1) * glam::Mat4::from(mm2);
+    let cm3 = cgmath::Matrix4::from(mm1) * cgmath::Matrix4::from(mm2);
+    let vm3 = vek::Mat4::from(mm1) * vek::Mat4::from(mm2);
+
+    // use nalgebra as assumed correct answer
+    let mm3: mint::ColumnMatrix4<f32> = nm3.into_inner().into();
+
+    assert_ulps_eq!(glam => gm3, glam::Mat4::from(mm3), epsilon = 1e-6);
+    assert_ulps_eq!(cgmath => cm3, cgmath::Matrix4::from(mm3), epsilon = 1e-6);
+    assert_ulps_eq!(vek => vm3, vek::Mat4::from(mm3), epsilon = 1e-6);
+
+    assert_ulps_eq!(
+        euclid => em3,
+        support::EuclidTransform3D::from_mint(mm3),
+        epsilon = 1e-6
+    );
+
+    #[cfg(feature = "pathfinder_geometry")]
+    {
+        use pathfinder_geometry::transform3d::Transform4F;
+        let pm3 = Transform4F::from_mint(mm1) * Transform4F::from_mint(mm2);
+        assert_ulps_eq!(pathfinder => pm3, Transform4F::from_mint(mm3), epsilon = 1e-6);
+    }
+}
+
+#[test]
+fn test_transform_point2() {
+    support::compare(&registry::TRANSFORM_POINT2, || {
+        for _ in 0..NUM_ITERS {
+            transform_point2_compare();
+        }
+    });
+}
+
+#[test]
+fn test_transform_vector2() {
+    support::compare(&registry::TRANSFORM_VECTOR2, || {
+        for _ in 0..NUM_ITERS {
+            transform_vector2_compare();
+        }
+    });
+}
+
+#[test]
+fn test_transform_point3() {
+    support::compare(&registry::TRANSFORM_POINT3, || {
+        for _ in 0..NUM_ITERS {
+            transform_point3_compare();
+        }
+    });
+}
+
+#[test]
+fn test_transform_vector3() {
+    support::compare(&registry::TRANSFORM_VECTOR3, || {
+        for _ in 0..NUM_ITERS {
+            transform_vector3_compare();
+        }
+    });
+}
+
+#[test]
+fn test_transform2_inverse() {
+    support::compare(&registry::TRANSFORM2_INVERSE, || {
+        for _ in 0..NUM_ITERS {
+            transform2_inverse_compare();
+        }
+    });
+}
+
+#[test]
+fn test_transform2_mul_transform2() {
+    support::compare(&registry::TRANSFORM2_MUL_TRANSFORM2, || {
+        for _ in 0..NUM_ITERS {
+            transform2_mul_transform2_compare();
+        }
+    });
+}
+
+#[test]
+fn test_transform3_inverse() {
+    support::compare(&registry::TRANSFORM3_INVERSE, || {
+        for _ in 0..NUM_ITERS {
+            transform3_inverse_compare();
+        }
+    });
+}
+
+#[test]
+fn test_transform3_mul_transform3() {
+    support::compare(&registry::TRANSFORM3_MUL_TRANSFORM3, || {
+        for _ in 0..NUM_ITERS {
+            transform3_mul_transform3_compare();
+        }
+    });
+}
@@ -3,8 +3,10 @@ mod support;
 use cgmath::{self, InnerSpace};
 use glam;
 use mathbench::mint_support::{random_mint_vec2, random_mint_vec3, random_mint_vec4};
+use mathbench::reference;
 use nalgebra;
 use rand_pcg::Pcg64Mcg;
+use support::{registry, FromMint};
 
 const NUM_ITERS: usize = 1024;
 
@@ -21,11 +23,26 @@ fn vec2_normalize_compare() {
     let cv: cgmath::Vector2<f32> = mv.into();
     let cvn = cv.normalize();
 
+    let uvn = ultraviolet::Vec2::from_mint(mv).normalized();
+
+    let ev: euclid::Vector2D<f32, euclid::UnknownUnit> = mv.into();
+    let evn = ev.normalize();
+
+    let vv: vek::Vec2<f32> = mv.into();
+    let vvn = vv.normalized();
+
+    let rv: reference::Vec2 = mv.into();
+    let rvn = rv.normalize();
+
     // use nalgebra as assumed correct answer
     let mvn: mint::Vector2<f32> = nvn.into();
 
     assert_ulps_eq!(cvn, mvn.into(), epsilon = 1e-6);
     assert_ulps_eq!(gvn, mvn.into(), epsilon = 1e-6);
+    assert_ulps_eq!(uvn, ultraviolet::Vec2::from_mint(mvn), epsilon = 1e-6);
+    assert_ulps_eq!(evn, mvn.into(), epsilon = 1e-6);
+    assert_ulps_eq!(vvn, mvn.into(), epsilon = 1e-6);
+    assert_ulps_eq!(rvn, mvn.into(), epsilon = 1e-6);
 }
 
 fn vec3_dot_compare() {
@@ -45,9 +62,33 @@ fn vec3_dot_compare() {
     let cv2: cgmath::Vector3<f32> = mv2.into();
     let cd = cv1.dot(cv2);
 
+    let ud = ultraviolet::Vec3::from_mint(mv1).dot(ultraviolet::Vec3::from_mint(mv2));
+
+    let ev1: euclid::Vector3D<f32, euclid::UnknownUnit> = mv1.into();
+    let ev2: euclid::Vector3D<f32, euclid::UnknownUnit> = mv2.into();
+    let ed = ev1.dot(ev2);
+
+    let vv1: vek::Vec3<f32> = mv1.into();
+    let vv2: vek::Vec3<f32> = mv2.into();
+    let vd = vv1.dot(vv2);
+
+    let rv1: reference::Vec3 = mv1.into();
+    let rv2: reference::Vec3 = mv2.into();
+    let rd = rv1.dot(rv2);
+
     // use nalgebra as assumed correct answer
-    assert_ulps_eq!(cd, nd, epsilon = 1e-6);
-    assert_ulps_eq!(gd, nd, epsilon = 1e-6);
+    assert_ulps_eq!(cgmath => cd, nd, epsilon = 1e-6);
+    assert_ulps_eq!(glam => gd, nd, epsilon = 1e-6);
+    assert_ulps_eq!(ultraviolet => ud, nd, epsilon = 1e-6);
+    assert_ulps_eq!(euclid => ed, nd, epsilon = 1e-6);
+    assert_ulps_eq!(vek => vd, nd, epsilon = 1e-6);
+    assert_ulps_eq!(reference => rd, nd, epsilon = 1e-6);
+
+    #[cfg(feature = "nalgebra_glm")]
+    {
+        let glmd = nalgebra_glm::dot(&nv1, &nv2);
+        assert_ulps_eq!(nalgebra_glm => glmd, nd, epsilon = 1e-6);
+    }
 }
 
 fn vec3_cross_compare() {
@@ -67,11 +108,63 @@ fn vec3_cross_compare() {
     let cv2: cgmath::Vector3<f32> = mv2.into();
     let cc = cv1.cross(cv2);
 
+    let uc = ultraviolet::Vec3::from_mint(mv1).cross(ultraviolet::Vec3::from_mint(mv2));
+
+    let ev1: euclid::Vector3D<f32, euclid::UnknownUnit> = mv1.into();
+    let ev2: euclid::Vector3D<f32, euclid::UnknownUnit> = mv2.into();
+    let ec = ev1.cross(ev2);
+
+    let vv1: vek::Vec3<f32> = mv1.into();
+    let vv2: vek::Vec3<f32> = mv2.into();
+    let vc = vv1.cross(vv2);
+
+    let rv1: reference::Vec3 = mv1.into();
+    let rv2: reference::Vec3 = mv2.into();
+    let rc = rv1.cross(rv2);
+
     // use nalgebra as assumed correct answer
     let mc: mint::Vector3<f32> = nc.into();
 
-    assert_ulps_eq!(cc, mc.into(), epsilon = 1e-6);
-    assert_ulps_eq!(gc, mc.into(), epsilon = 1e-6);
+    assert_ulps_eq!(cgmath => cc, mc.into(), epsilon = 1e-6);
+    assert_ulps_eq!(glam => gc, mc.into(), epsilon = 1e-6);
+    assert_ulps_eq!(ultraviolet => uc, ultraviolet::Vec3::from_mint(mc), epsilon = 1e-6);
+    assert_ulps_eq!(euclid => ec, mc.into(), epsilon = 1e-6);
+    assert_ulps_eq!(vek => vc, mc.into(), epsilon = 1e-6);
+    assert_ulps_eq!(reference => rc, mc.into(), epsilon = 1e-6);
+
+    #[cfg(feature = "nalgebra_glm")]
+    {
+        let glmc = nalgebra_glm::cross(&nv1, &nv2);
+        assert_ulps_eq!(nalgebra_glm => glmc, mc.into(), epsilon = 1e-6);
+    }
+}
+
+fn vec3_length_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mv = random_mint_vec3(&mut rng);
+
+    let gl = glam::Vec3::from(mv).length();
+    let nl = nalgebra::Vector3::<f32>::from(mv).magnitude();
+    let cl = cgmath::Vector3::<f32>::from(mv).magnitude();
+    let ul = ultraviolet::Vec3::from_mint(mv).mag();
+    let el = euclid::Vector3D::<f32, euclid::UnknownUnit>::from(mv).length();
+    let vl = vek::Vec3::<f32>::from(mv).magnitude();
+
+    let rl = reference::Vec3::from(mv).length();
+
+    // use nalgebra as assumed correct answer
+    assert_ulps_eq!(cgmath => cl, nl, epsilon = 1e-6);
+    assert_ulps_eq!(glam => gl, nl, epsilon = 1e-6);
+    assert_ulps_eq!(ultraviolet => ul, nl, epsilon = 1e-6);
+    assert_ulps_eq!(euclid => el, nl, epsilon = 1e-6);
+    assert_ulps_eq!(vek => vl, nl, epsilon = 1e-6);
+    assert_ulps_eq!(reference => rl, nl, epsilon = 1e-6);
+
+    #[cfg(feature = "nalgebra_glm")]
+    {
+        let glml = nalgebra_glm::length(&nalgebra_glm::Vec3::from(mv));
+        assert_ulps_eq!(nalgebra_glm => glml, nl, epsilon = 1e-6);
+    }
 }
 
 fn vec3_normalize_compare() {
@@ -87,11 +180,38 @@ fn vec3_normalize_compare() {
     let cv: cgmath::Vector3<f32> = mv.into();
     let cvn = cv.normalize();
 
+    let uvn = ultraviolet::Vec3::from_mint(mv).normalized();
+
+    let ev: euclid::Vector3D<f32, euclid::UnknownUnit> = mv.into();
+    let evn = ev.normalize();
+
+    let vv: vek::Vec3<f32> = mv.into();
+    let vvn = vv.normalized();
+
+    let rv: reference::Vec3 = mv.into();
+    let rvn = rv.normalize();
+
     // use nalgebra as assumed correct answer
     let mvn: mint::Vector3<f32> = nvn.into();
 
-    assert_ulps_eq!(cvn, mvn.into(), epsilon = 1e-6);
-    assert_ulps_eq!(gvn, mvn.into(), epsilon = 1e-6);
+    assert_ulps_eq!(cgmath => cvn, mvn.into(), epsilon = 1e-6);
+    assert_ulps_eq!(glam => gvn, mvn.into(), epsilon = 1e-6);
+    assert_ulps_eq!(ultraviolet => uvn, ultraviolet::Vec3::from_mint(mvn), epsilon = 1e-6);
+    assert_ulps_eq!(euclid => evn, mvn.into(), epsilon = 1e-6);
+    assert_ulps_eq!(vek => vvn, mvn.into(), epsilon = 1e-6);
+    assert_ulps_eq!(reference => rvn, mvn.into(), epsilon = 1e-6);
+
+    #[cfg(all(feature = "intrinsics", target_arch = "x86_64"))]
+    {
+        let ivn = mathbench::intrinsics::Vec3::from(mv).normalize();
+        assert_ulps_eq!(intrinsics => ivn, mvn.into(), epsilon = 1e-6);
+    }
+
+    #[cfg(feature = "nalgebra_glm")]
+    {
+        let glmvn = nalgebra_glm::normalize(&nv);
+        assert_ulps_eq!(nalgebra_glm => glmvn, mvn.into(), epsilon = 1e-6);
+    }
 }
 
 fn vec4_dot_compare() {
@@ -111,9 +231,22 @@ fn vec4_dot_compare() {
     let cv2: cgmath::Vector4<f32> = mv2.into();
     let cd = cv1.dot(cv2);
 
+    let ud = ultraviolet::Vec4::from_mint(mv1).dot(ultraviolet::Vec4::from_mint(mv2));
+
+    let vv1: vek::Vec4<f32> = mv1.into();
+    let vv2: vek::Vec4<f32> = mv2.into();
+    let vd = vv1.dot(vv2);
+
+    let rv1: reference::Vec4 = mv1.into();
+    let rv2: reference::Vec4 = mv2.into();
+    let rd = rv1.dot(rv2);
+
     // use nalgebra as assumed correct answer
     assert_ulps_eq!(cd, nd, epsilon = 1e-6);
     assert_ulps_eq!(gd, nd, epsilon = 1e-6);
+    assert_ulps_eq!(ud, nd, epsilon = 1e-6);
+    assert_ulps_eq!(vd, nd, epsilon = 1e-6);
+    assert_ulps_eq!(rd, nd, epsilon = 1e-6);
 }
 
 fn vec4_normalize_compare() {
@@ -129,11 +262,22 @@ fn vec4_normalize_compare() {
     let cv: cgmath::Vector4<f32> = mv.into();
     let cvn = cv.normalize();
 
+    let uvn = ultraviolet::Vec4::from_mint(mv).normalized();
+
+    let vv: vek::Vec4<f32> = mv.into();
+    let vvn = vv.normalized();
+
+    let rv: reference::Vec4 = mv.into();
+    let rvn = rv.normalize();
+
     // use nalgebra as assumed correct answer
     let mvn: mint::Vector4<f32> = nvn.into();
 
     assert_ulps_eq!(cvn, mvn.into(), epsilon = 1e-6);
     assert_ulps_eq!(gvn, mvn.into(), epsilon = 1e-6);
+    assert_ulps_eq!(uvn, ultraviolet::Vec4::from_mint(mvn), epsilon = 1e-6);
+    assert_ulps_eq!(vvn, mvn.into(), epsilon = 1e-6);
+    assert_ulps_eq!(rvn, mvn.into(), epsilon = 1e-6);
 }
 
 #[test]
@@ -145,23 +289,38 @@ fn test_vec2_normalize() {
 
 #[test]
 fn test_vec3_dot() {
-    for _ in 0..NUM_ITERS {
-        vec3_dot_compare();
-    }
+    support::compare(&registry::VEC3_DOT, || {
+        for _ in 0..NUM_ITERS {
+            vec3_dot_compare();
+        }
+    });
 }
 
 #[test]
 fn test_vec3_cross() {
-    for _ in 0..NUM_ITERS {
-        vec3_cross_compare();
-    }
+    support::compare(&registry::VEC3_CROSS, || {
+        for _ in 0..NUM_ITERS {
+            vec3_cross_compare();
+        }
+    });
+}
+
+#[test]
+fn test_vec3_length() {
+    support::compare(&registry::VEC3_LENGTH, || {
+        for _ in 0..NUM_ITERS {
+            vec3_length_compare();
+        }
+    });
 }
 
 #[test]
 fn test_vec3_normalize() {
-    for _ in 0..NUM_ITERS {
-        vec3_normalize_compare();
-    }
+    support::compare(&registry::VEC3_NORMALIZE, || {
+        for _ in 0..NUM_ITERS {
+            vec3_normalize_compare();
+        }
+    });
 }
 
 #[test]
@@ -0,0 +1,1163 @@
+//! Lane-wise tests of the wide SIMD types used in the benchmarks.
+//!
+//! Each wide value is built from independent random scalar lanes and every lane
+//! of the result of a wide operation is checked against the same operation on
+//! the library's scalar type with that lane's data. The scalar types are checked
+//! against other libraries by the comparison tests.
+mod support;
+use mathbench::{with_input_distribution, BenchValue, InputDistribution};
+use rand_pcg::Pcg64Mcg;
+use std::fmt::Debug;
+
+const NUM_ITERS: usize = 256;
+
+/// Wide SIMD types which can be built from and split into their scalar lanes.
+trait Lanes: Sized {
+    type Element;
+    fn lanes() -> usize;
+    fn extract(&self, i: usize) -> Self::Element;
+    fn from_lanes(lanes: &[Self::Element]) -> Self;
+}
+
+fn random_lanes<W>(rng: &mut Pcg64Mcg) -> Vec<W::Element>
+where
+    W: Lanes,
+    W::Element: BenchValue,
+{
+    (0..W::lanes())
+        .map(|_| W::Element::random_value(rng))
+        .collect()
+}
+
+/// Builds a wide value from one field of each scalar lane.
+#[allow(dead_code)]
+fn gather<T, W, F>(lanes: &[T], f: F) -> W
+where
+    W: Lanes,
+    F: Fn(&T) -> W::Element,
+{
+    W::from_lanes(&lanes.iter().map(f).collect::<Vec<_>>())
+}
+
+fn check_unop<A, R>(
+    wide_op: impl Fn(A) -> R,
+    scalar_op: impl Fn(A::Element) -> R::Element,
+    eps: f32,
+) where
+    A: Lanes,
+    A::Element: BenchValue + Copy,
+    R: Lanes,
+    R::Element: support::FloatCompare + Debug,
+{
+    let mut rng = Pcg64Mcg::new(rand::random());
+    for _ in 0..NUM_ITERS {
+        let a = random_lanes::<A>(&mut rng);
+        let r = wide_op(A::from_lanes(&a));
+        for (i, a) in a.into_iter().enumerate() {
+            assert_ulps_eq!(r.extract(i), scalar_op(a), epsilon = eps);
+        }
+    }
+}
+
+fn check_binop<A, B, R>(
+    wide_op: impl Fn(A, B) -> R,
+    scalar_op: impl Fn(A::Element, B::Element) -> R::Element,
+    eps: f32,
+) where
+    A: Lanes,
+    A::Element: BenchValue + Copy,
+    B: Lanes,
+    B::Element: BenchValue + Copy,
+    R: Lanes,
+    R::Element: support::FloatCompare + Debug,
+{
+    let mut rng = Pcg64Mcg::new(rand::random());
+    for _ in 0..NUM_ITERS {
+        let a = random_lanes::<A>(&mut rng);
+        let b = random_lanes::<B>(&mut rng);
+        let r = wide_op(A::from_lanes(&a), B::from_lanes(&b));
+        for (i, (a, b)) in a.into_iter().zip(b.into_iter()).enumerate() {
+            assert_ulps_eq!(r.extract(i), scalar_op(a, b), epsilon = eps);
+        }
+    }
+}
+
+fn check_ternop<A, B, C, R>(
+    wide_op: impl Fn(A, B, C) -> R,
+    scalar_op: impl Fn(A::Element, B::Element, C::Element) -> R::Element,
+    eps: f32,
+) where
+    A: Lanes,
+    A::Element: BenchValue + Copy,
+    B: Lanes,
+    B::Element: BenchValue + Copy,
+    C: Lanes,
+    C::Element: BenchValue + Copy,
+    R: Lanes,
+    R::Element: support::FloatCompare + Debug,
+{
+    let mut rng = Pcg64Mcg::new(rand::random());
+    for _ in 0..NUM_ITERS {
+        let a = random_lanes::<A>(&mut rng);
+        let b = random_lanes::<B>(&mut rng);
+        let c = random_lanes::<C>(&mut rng);
+        let r = wide_op(A::from_lanes(&a), B::from_lanes(&b), C::from_lanes(&c));
+        for (i, ((a, b), c)) in a.into_iter().zip(b).zip(c).enumerate() {
+            assert_ulps_eq!(r.extract(i), scalar_op(a, b, c), epsilon = eps);
+        }
+    }
+}
+
+/// Checks that `to_soa` packs consecutive `glam::Vec3` values into the lanes of
+/// each wide value. `lane` converts a `glam::Vec3` to the scalar lane type.
+#[allow(dead_code)]
+fn check_aos_to_soa<W>(
+    to_soa: impl Fn(&[glam::Vec3], &mut [W]),
+    lane: impl Fn(glam::Vec3) -> W::Element,
+) where
+    W: Lanes + Copy,
+    W::Element: BenchValue + Copy + support::FloatCompare + Debug,
+{
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let aos: Vec<glam::Vec3> = (0..NUM_ITERS * W::lanes())
+        .map(|_| glam::Vec3::random_value(&mut rng))
+        .collect();
+    let mut soa = vec![W::from_lanes(&random_lanes::<W>(&mut rng)); NUM_ITERS];
+    to_soa(&aos, &mut soa);
+    for (wide, lanes) in soa.iter().zip(aos.chunks(W::lanes())) {
+        for (i, v) in lanes.iter().enumerate() {
+            assert_ulps_eq!(wide.extract(i), lane(*v), epsilon = 0.0);
+        }
+    }
+}
+
+/// Checks that `to_aos` unpacks the lanes of each wide value into consecutive
+/// `glam::Vec3` values. `lane` converts a `glam::Vec3` to the scalar lane type.
+#[allow(dead_code)]
+fn check_soa_to_aos<W>(
+    to_aos: impl Fn(&[W], &mut [glam::Vec3]),
+    lane: impl Fn(glam::Vec3) -> W::Element,
+) where
+    W: Lanes,
+    W::Element: BenchValue + Copy + support::FloatCompare + Debug,
+{
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let soa: Vec<W> = (0..NUM_ITERS)
+        .map(|_| W::from_lanes(&random_lanes::<W>(&mut rng)))
+        .collect();
+    let mut aos: Vec<glam::Vec3> = (0..NUM_ITERS * W::lanes())
+        .map(|_| glam::Vec3::random_value(&mut rng))
+        .collect();
+    to_aos(&soa, &mut aos);
+    for (wide, lanes) in soa.iter().zip(aos.chunks(W::lanes())) {
+        for (i, v) in lanes.iter().enumerate() {
+            assert_ulps_eq!(wide.extract(i), lane(*v), epsilon = 0.0);
+        }
+    }
+}
+
+/// Checks that every lane of a wide matrix drawn from the `Degenerate`
+/// distribution is near singular like the scalar matrices, rather than near
+/// zero. `elements` returns the column major elements of a lane.
+#[allow(dead_code)]
+fn check_degenerate_lanes<W>(n: usize, elements: impl Fn(&W::Element) -> Vec<f32>)
+where
+    W: Lanes + BenchValue,
+{
+    let mut rng = Pcg64Mcg::new(rand::random());
+    with_input_distribution(InputDistribution::Degenerate, || {
+        for _ in 0..NUM_ITERS {
+            let wide = W::random_value(&mut rng);
+            for i in 0..W::lanes() {
+                let m = nalgebra::DMatrix::from_iterator(
+                    n,
+                    n,
+                    elements(&wide.extract(i)).into_iter().map(f64::from),
+                );
+                let det = m.determinant();
+                assert!(det.abs() <= 1.0e-5, "lane {} determinant {}", i, det);
+                assert!(m.norm() >= 1.0e-6, "lane {} norm {}", i, m.norm());
+            }
+        }
+    });
+}
+
+const UPDATE_RATE: f32 = 1.0 / 60.0;
+
+// the position after a single semi-implicit euler integration step as run by
+// the euler benchmarks
+#[allow(unused_macros)]
+macro_rules! euler_position {
+    ($pos:expr, $vel:expr, $acc:expr, $dt:expr) => {{
+        let vel = $vel + $acc * $dt;
+        $pos + vel * $dt
+    }};
+}
+
+#[cfg(feature = "ultraviolet_f32x4")]
+mod ultraviolet_f32x4 {
+    use super::*;
+    use mathbench::ultraviolet_support::{Matrix4Ext, MatrixExt, Vector3SoaExt};
+    use ultraviolet::{
+        f32x4, Bivec2, Bivec3, Isometry2, Isometry3, Mat2, Mat3, Mat4, Rotor2, Rotor3, Vec2, Vec3,
+        Vec4, WBivec2, WBivec3, WIsometry2, WIsometry3, WRotor2, WRotor3, Wat2, Wat3, Wat4, Wec2,
+        Wec3, Wec4,
+    };
+
+    impl Lanes for f32x4 {
+        type Element = f32;
+        fn lanes() -> usize {
+            4
+        }
+        fn extract(&self, i: usize) -> f32 {
+            self[i]
+        }
+        fn from_lanes(lanes: &[f32]) -> Self {
+            f32x4::new(lanes[0], lanes[1], lanes[2], lanes[3])
+        }
+    }
+
+    impl Lanes for Wec2 {
+        type Element = Vec2;
+        fn lanes() -> usize {
+            4
+        }
+        fn extract(&self, i: usize) -> Vec2 {
+            Vec2::new(self.x[i], self.y[i])
+        }
+        fn from_lanes(lanes: &[Vec2]) -> Self {
+            Wec2::new(
+                gather::<_, f32x4, _>(lanes, |v| v.x),
+                gather::<_, f32x4, _>(lanes, |v| v.y),
+            )
+        }
+    }
+
+    impl Lanes for Wec3 {
+        type Element = Vec3;
+        fn lanes() -> usize {
+            4
+        }
+        fn extract(&self, i: usize) -> Vec3 {
+            Vec3::new(self.x[i], self.y[i], self.z[i])
+        }
+        fn from_lanes(lanes: &[Vec3]) -> Self {
+            Wec3::new(
+                gather::<_, f32x4, _>(lanes, |v| v.x),
+                gather::<_, f32x4, _>(lanes, |v| v.y),
+                gather::<_, f32x4, _>(lanes, |v| v.z),
+            )
+        }
+    }
+
+    impl Lanes for Wec4 {
+        type Element = Vec4;
+        fn lanes() -> usize {
+            4
+        }
+        fn extract(&self, i: usize) -> Vec4 {
+            Vec4::new(self.x[i], self.y[i], self.z[i], self.w[i])
+        }
+        fn from_lanes(lanes: &[Vec4]) -> Self {
+            Wec4::new(
+                gather::<_, f32x4, _>(lanes, |v| v.x),
+                gather::<_, f32x4, _>(lanes, |v| v.y),
+                gather::<_, f32x4, _>(lanes, |v| v.z),
+                gather::<_, f32x4, _>(lanes, |v| v.w),
+            )
+        }
+    }
+
+    impl Lanes for Wat2 {
+        type Element = Mat2;
+        fn lanes() -> usize {
+            4
+        }
+        fn extract(&self, i: usize) -> Mat2 {
+            Mat2::new(self.cols[0].extract(i), self.cols[1].extract(i))
+        }
+        fn from_lanes(lanes: &[Mat2]) -> Self {
+            Wat2::new(gather(lanes, |m| m.cols[0]), gather(lanes, |m| m.cols[1]))
+        }
+    }
+
+    impl Lanes for Wat3 {
+        type Element = Mat3;
+        fn lanes() -> usize {
+            4
+        }
+        fn extract(&self, i: usize) -> Mat3 {
+            Mat3::new(
+                self.cols[0].extract(i),
+                self.cols[1].extract(i),
+                self.cols[2].extract(i),
+            )
+        }
+        fn from_lanes(lanes: &[Mat3]) -> Self {
+            Wat3::new(
+                gather(lanes, |m| m.cols[0]),
+                gather(lanes, |m| m.cols[1]),
+                gather(lanes, |m| m.cols[2]),
+            )
+        }
+    }
+
+    impl Lanes for Wat4 {
+        type Element = Mat4;
+        fn lanes() -> usize {
+            4
+        }
+        fn extract(&self, i: usize) -> Mat4 {
+            Mat4::new(
+                self.cols[0].extract(i),
+                self.cols[1].extract(i),
+                self.cols[2].extract(i),
+                self.cols[3].extract(i),
+            )
+        }
+        fn from_lanes(lanes: &[Mat4]) -> Self {
+            Wat4::new(
+                gather(lanes, |m| m.cols[0]),
+                gather(lanes, |m| m.cols[1]),
+                gather(lanes, |m| m.cols[2]),
+                gather(lanes, |m| m.cols[3]),
+            )
+        }
+    }
+
+    impl Lanes for WRotor2 {
+        type Element = Rotor2;
+        fn lanes() -> usize {
+            4
+        }
+        fn extract(&self, i: usize) -> Rotor2 {
+            Rotor2::new(self.s[i], Bivec2::new(self.bv.xy[i]))
+        }
+        fn from_lanes(lanes: &[Rotor2]) -> Self {
+            WRotor2::new(
+                gather::<_, f32x4, _>(lanes, |r| r.s),
+                WBivec2::new(gather::<_, f32x4, _>(lanes, |r| r.bv.xy)),
+            )
+        }
+    }
+
+    impl Lanes for WRotor3 {
+        type Element = Rotor3;
+        fn lanes() -> usize {
+            4
+        }
+        fn extract(&self, i: usize) -> Rotor3 {
+            Rotor3::new(
+                self.s[i],
+                Bivec3::new(self.bv.xy[i], self.bv.xz[i], self.bv.yz[i]),
+            )
+        }
+        fn from_lanes(lanes: &[Rotor3]) -> Self {
+            WRotor3::new(
+                gather::<_, f32x4, _>(lanes, |r| r.s),
+                WBivec3::new(
+                    gather::<_, f32x4, _>(lanes, |r| r.bv.xy),
+                    gather::<_, f32x4, _>(lanes, |r| r.bv.xz),
+                    gather::<_, f32x4, _>(lanes, |r| r.bv.yz),
+                ),
+            )
+        }
+    }
+
+    impl Lanes for WIsometry2 {
+        type Element = Isometry2;
+        fn lanes() -> usize {
+            4
+        }
+        fn extract(&self, i: usize) -> Isometry2 {
+            Isometry2::new(self.translation.extract(i), self.rotation.extract(i))
+        }
+        fn from_lanes(lanes: &[Isometry2]) -> Self {
+            WIsometry2::new(
+                gather(lanes, |x| x.translation),
+                gather(lanes, |x| x.rotation),
+            )
+        }
+    }
+
+    impl Lanes for WIsometry3 {
+        type Element = Isometry3;
+        fn lanes() -> usize {
+            4
+        }
+        fn extract(&self, i: usize) -> Isometry3 {
+            Isometry3::new(self.translation.extract(i), self.rotation.extract(i))
+        }
+        fn from_lanes(lanes: &[Isometry3]) -> Self {
+            WIsometry3::new(
+                gather(lanes, |x| x.translation),
+                gather(lanes, |x| x.rotation),
+            )
+        }
+    }
+
+    #[test]
+    fn euler_2d() {
+        check_ternop(
+            |p: Wec2, v: Wec2, a: Wec2| euler_position!(p, v, a, f32x4::from(UPDATE_RATE)),
+            |p: Vec2, v: Vec2, a: Vec2| euler_position!(p, v, a, UPDATE_RATE),
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn euler_3d() {
+        check_ternop(
+            |p: Wec3, v: Wec3, a: Wec3| euler_position!(p, v, a, f32x4::from(UPDATE_RATE)),
+            |p: Vec3, v: Vec3, a: Vec3| euler_position!(p, v, a, UPDATE_RATE),
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn vector3_length() {
+        check_unop(|a: Wec3| a.mag(), |a: Vec3| a.mag(), 1e-6);
+    }
+
+    #[test]
+    fn vector3_normalize() {
+        check_unop(|a: Wec3| a.normalized(), |a: Vec3| a.normalized(), 1e-6);
+    }
+
+    #[test]
+    fn vector3_dot() {
+        check_binop(
+            |a: Wec3, b: Wec3| a.dot(b),
+            |a: Vec3, b: Vec3| a.dot(b),
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn vector3_cross() {
+        check_binop(
+            |a: Wec3, b: Wec3| a.cross(b),
+            |a: Vec3, b: Vec3| a.cross(b),
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn matrix2_mul_matrix2() {
+        check_binop(|a: Wat2, b: Wat2| a * b, |a: Mat2, b: Mat2| a * b, 1e-6);
+    }
+
+    #[test]
+    fn matrix2_mul_vector2() {
+        check_binop(|a: Wat2, b: Wec2| a * b, |a: Mat2, b: Vec2| a * b, 1e-6);
+    }
+
+    #[test]
+    fn matrix3_transpose() {
+        check_unop(|a: Wat3| a.transposed(), |a: Mat3| a.transposed(), 0.0);
+    }
+
+    // `Mat3::inversed` is known to be incorrect in ultraviolet 0.4.5 and gives
+    // huge results which can't be meaningfully compared.
+
+    #[test]
+    fn matrix3_mul_matrix3() {
+        check_binop(|a: Wat3, b: Wat3| a * b, |a: Mat3, b: Mat3| a * b, 1e-6);
+    }
+
+    #[test]
+    fn matrix3_mul_vector3() {
+        check_binop(|a: Wat3, b: Wec3| a * b, |a: Mat3, b: Vec3| a * b, 1e-6);
+    }
+
+    #[test]
+    fn matrix4_transpose() {
+        check_unop(|a: Wat4| a.transposed(), |a: Mat4| a.transposed(), 0.0);
+    }
+
+    #[test]
+    fn matrix4_inverse() {
+        check_unop(|a: Wat4| a.inversed(), |a: Mat4| a.inversed(), 1e-4);
+    }
+
+    #[test]
+    fn degenerate_matrix_lanes() {
+        check_degenerate_lanes::<Wat2>(2, |m| m.as_slice().to_vec());
+        check_degenerate_lanes::<Wat3>(3, |m| m.as_slice().to_vec());
+        check_degenerate_lanes::<Wat4>(4, |m| m.as_slice().to_vec());
+    }
+
+    #[test]
+    fn matrix4_mul_matrix4() {
+        check_binop(|a: Wat4, b: Wat4| a * b, |a: Mat4, b: Mat4| a * b, 1e-6);
+    }
+
+    #[test]
+    fn matrix4_mul_vector4() {
+        check_binop(|a: Wat4, b: Wec4| a * b, |a: Mat4, b: Vec4| a * b, 1e-6);
+    }
+
+    #[test]
+    fn matrix2_add_matrix2() {
+        check_binop(
+            |a: Wat2, b: Wat2| a.add_matrix(b),
+            |a: Mat2, b: Mat2| a.add_matrix(b),
+            0.0,
+        );
+    }
+
+    #[test]
+    fn matrix2_sub_matrix2() {
+        check_binop(
+            |a: Wat2, b: Wat2| a.sub_matrix(b),
+            |a: Mat2, b: Mat2| a.sub_matrix(b),
+            0.0,
+        );
+    }
+
+    #[test]
+    fn matrix2_transpose_mul_matrix2() {
+        check_binop(
+            |a: Wat2, b: Wat2| a.transpose_mul(b),
+            |a: Mat2, b: Mat2| a.transpose_mul(b),
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn matrix2_trace() {
+        check_unop(|a: Wat2| a.trace(), |a: Mat2| a.trace(), 1e-6);
+    }
+
+    #[test]
+    fn matrix3_add_matrix3() {
+        check_binop(
+            |a: Wat3, b: Wat3| a.add_matrix(b),
+            |a: Mat3, b: Mat3| a.add_matrix(b),
+            0.0,
+        );
+    }
+
+    #[test]
+    fn matrix3_sub_matrix3() {
+        check_binop(
+            |a: Wat3, b: Wat3| a.sub_matrix(b),
+            |a: Mat3, b: Mat3| a.sub_matrix(b),
+            0.0,
+        );
+    }
+
+    #[test]
+    fn matrix3_transpose_mul_matrix3() {
+        check_binop(
+            |a: Wat3, b: Wat3| a.transpose_mul(b),
+            |a: Mat3, b: Mat3| a.transpose_mul(b),
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn matrix3_trace() {
+        check_unop(|a: Wat3| a.trace(), |a: Mat3| a.trace(), 1e-6);
+    }
+
+    #[test]
+    fn matrix4_add_matrix4() {
+        check_binop(
+            |a: Wat4, b: Wat4| a.add_matrix(b),
+            |a: Mat4, b: Mat4| a.add_matrix(b),
+            0.0,
+        );
+    }
+
+    #[test]
+    fn matrix4_sub_matrix4() {
+        check_binop(
+            |a: Wat4, b: Wat4| a.sub_matrix(b),
+            |a: Mat4, b: Mat4| a.sub_matrix(b),
+            0.0,
+        );
+    }
+
+    #[test]
+    fn matrix4_transpose_mul_matrix4() {
+        check_binop(
+            |a: Wat4, b: Wat4| a.transpose_mul(b),
+            |a: Mat4, b: Mat4| a.transpose_mul(b),
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn matrix4_trace() {
+        check_unop(|a: Wat4| a.trace(), |a: Mat4| a.trace(), 1e-6);
+    }
+
+    #[test]
+    fn matrix4_transform_point3() {
+        check_binop(
+            |a: Wat4, b: Wec3| a.project_point3(b),
+            |a: Mat4, b: Vec3| a.project_point3(b),
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn vector3_aos_to_soa() {
+        check_aos_to_soa(Wec3::from_aos_slice, |v| Vec3::new(v.x(), v.y(), v.z()));
+    }
+
+    #[test]
+    fn vector3_soa_to_aos() {
+        check_soa_to_aos(Wec3::to_aos_slice, |v| Vec3::new(v.x(), v.y(), v.z()));
+    }
+
+    #[test]
+    fn quaternion_conjugate() {
+        check_unop(|a: WRotor3| a.reversed(), |a: Rotor3| a.reversed(), 0.0);
+    }
+
+    #[test]
+    fn quaternion_mul_quaternion() {
+        check_binop(
+            |a: WRotor3, b: WRotor3| a * b,
+            |a: Rotor3, b: Rotor3| a * b,
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn quaternion_mul_vector3() {
+        check_binop(
+            |a: WRotor3, b: Wec3| a * b,
+            |a: Rotor3, b: Vec3| a * b,
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn rotation2_inverse() {
+        check_unop(|a: WRotor2| a.reversed(), |a: Rotor2| a.reversed(), 0.0);
+    }
+
+    #[test]
+    fn rotation2_mul_rotation2() {
+        check_binop(
+            |a: WRotor2, b: WRotor2| a * b,
+            |a: Rotor2, b: Rotor2| a * b,
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn rotation2_rotate_vector2() {
+        check_binop(
+            |a: WRotor2, b: Wec2| a * b,
+            |a: Rotor2, b: Vec2| a * b,
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn isometry2_inverse() {
+        check_unop(
+            |a: WIsometry2| a.inversed(),
+            |a: Isometry2| a.inversed(),
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn isometry2_mul_isometry2() {
+        check_binop(
+            |a: WIsometry2, b: WIsometry2| a * b,
+            |a: Isometry2, b: Isometry2| a * b,
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn isometry2_transform_point2() {
+        check_binop(
+            |a: WIsometry2, b: Wec2| a.transform_vec(b),
+            |a: Isometry2, b: Vec2| a.transform_vec(b),
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn isometry3_inverse() {
+        check_unop(
+            |a: WIsometry3| a.inversed(),
+            |a: Isometry3| a.inversed(),
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn isometry3_mul_isometry3() {
+        check_binop(
+            |a: WIsometry3, b: WIsometry3| a * b,
+            |a: Isometry3, b: Isometry3| a * b,
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn isometry3_transform_point3() {
+        check_binop(
+            |a: WIsometry3, b: Wec3| a.transform_vec(b),
+            |a: Isometry3, b: Vec3| a.transform_vec(b),
+            1e-6,
+        );
+    }
+}
+
+/// Implements `Lanes` for `nalgebra` types using `simba`'s `SimdValue` trait.
+#[allow(unused_macros)]
+macro_rules! impl_simd_value_lanes {
+    ($($t:ty),+) => {
+        $(
+            impl Lanes for $t {
+                type Element = <$t as SimdValue>::Element;
+                fn lanes() -> usize {
+                    <$t as SimdValue>::lanes()
+                }
+                fn extract(&self, i: usize) -> Self::Element {
+                    SimdValue::extract(self, i)
+                }
+                fn from_lanes(lanes: &[Self::Element]) -> Self {
+                    let mut wide = <$t as SimdValue>::splat(lanes[0]);
+                    for (i, lane) in lanes.iter().enumerate().skip(1) {
+                        SimdValue::replace(&mut wide, i, *lane);
+                    }
+                    wide
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! nalgebra_wide_tests {
+    ($name:ident, $feature:literal, $t:ident) => {
+        #[cfg(feature = $feature)]
+        mod $name {
+            use super::*;
+            use mathbench::nalgebra_support::{SimdMatrix4Ext, Vector3SoaExt};
+            use nalgebra::{
+                Isometry2, Isometry3, Matrix2, Matrix3, Matrix4, Point2, Point3, UnitComplex,
+                UnitQuaternion, Vector2, Vector3, Vector4,
+            };
+            use simba::simd::{$t, SimdValue};
+
+            impl_simd_value_lanes!(
+                $t,
+                Vector2<$t>,
+                Vector3<$t>,
+                Vector4<$t>,
+                Point2<$t>,
+                Point3<$t>,
+                Matrix2<$t>,
+                Matrix3<$t>,
+                Matrix4<$t>,
+                UnitComplex<$t>,
+                UnitQuaternion<$t>,
+                Isometry2<$t>,
+                Isometry3<$t>
+            );
+
+            #[test]
+            fn euler_2d() {
+                check_ternop(
+                    |p: Vector2<$t>, v: Vector2<$t>, a: Vector2<$t>| {
+                        euler_position!(p, v, a, $t::splat(UPDATE_RATE))
+                    },
+                    |p: Vector2<f32>, v: Vector2<f32>, a: Vector2<f32>| {
+                        euler_position!(p, v, a, UPDATE_RATE)
+                    },
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn euler_3d() {
+                check_ternop(
+                    |p: Vector3<$t>, v: Vector3<$t>, a: Vector3<$t>| {
+                        euler_position!(p, v, a, $t::splat(UPDATE_RATE))
+                    },
+                    |p: Vector3<f32>, v: Vector3<f32>, a: Vector3<f32>| {
+                        euler_position!(p, v, a, UPDATE_RATE)
+                    },
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn vector3_length() {
+                check_unop(
+                    |a: Vector3<$t>| a.magnitude(),
+                    |a: Vector3<f32>| a.magnitude(),
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn vector3_normalize() {
+                check_unop(
+                    |a: Vector3<$t>| a.normalize(),
+                    |a: Vector3<f32>| a.normalize(),
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn vector3_dot() {
+                check_binop(
+                    |a: Vector3<$t>, b: Vector3<$t>| a.dot(&b),
+                    |a: Vector3<f32>, b: Vector3<f32>| a.dot(&b),
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn vector3_cross() {
+                check_binop(
+                    |a: Vector3<$t>, b: Vector3<$t>| a.cross(&b),
+                    |a: Vector3<f32>, b: Vector3<f32>| a.cross(&b),
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn matrix2_transpose() {
+                check_unop(
+                    |a: Matrix2<$t>| a.transpose(),
+                    |a: Matrix2<f32>| a.transpose(),
+                    0.0,
+                );
+            }
+
+            #[test]
+            fn matrix2_mul_matrix2() {
+                check_binop(
+                    |a: Matrix2<$t>, b: Matrix2<$t>| a * b,
+                    |a: Matrix2<f32>, b: Matrix2<f32>| a * b,
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn matrix2_mul_vector2() {
+                check_binop(
+                    |a: Matrix2<$t>, b: Vector2<$t>| a * b,
+                    |a: Matrix2<f32>, b: Vector2<f32>| a * b,
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn matrix3_transpose() {
+                check_unop(
+                    |a: Matrix3<$t>| a.transpose(),
+                    |a: Matrix3<f32>| a.transpose(),
+                    0.0,
+                );
+            }
+
+            #[test]
+            fn matrix3_mul_matrix3() {
+                check_binop(
+                    |a: Matrix3<$t>, b: Matrix3<$t>| a * b,
+                    |a: Matrix3<f32>, b: Matrix3<f32>| a * b,
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn matrix3_mul_vector3() {
+                check_binop(
+                    |a: Matrix3<$t>, b: Vector3<$t>| a * b,
+                    |a: Matrix3<f32>, b: Vector3<f32>| a * b,
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn matrix4_transpose() {
+                check_unop(
+                    |a: Matrix4<$t>| a.transpose(),
+                    |a: Matrix4<f32>| a.transpose(),
+                    0.0,
+                );
+            }
+
+            #[test]
+            fn matrix4_mul_matrix4() {
+                check_binop(
+                    |a: Matrix4<$t>, b: Matrix4<$t>| a * b,
+                    |a: Matrix4<f32>, b: Matrix4<f32>| a * b,
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn matrix4_mul_vector4() {
+                check_binop(
+                    |a: Matrix4<$t>, b: Vector4<$t>| a * b,
+                    |a: Matrix4<f32>, b: Vector4<f32>| a * b,
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn degenerate_matrix_lanes() {
+                check_degenerate_lanes::<Matrix2<$t>>(2, |m| m.as_slice().to_vec());
+                check_degenerate_lanes::<Matrix3<$t>>(3, |m| m.as_slice().to_vec());
+                check_degenerate_lanes::<Matrix4<$t>>(4, |m| m.as_slice().to_vec());
+            }
+
+            #[test]
+            fn matrix2_add_matrix2() {
+                check_binop(
+                    |a: Matrix2<$t>, b: Matrix2<$t>| a + b,
+                    |a: Matrix2<f32>, b: Matrix2<f32>| a + b,
+                    0.0,
+                );
+            }
+
+            #[test]
+            fn matrix2_sub_matrix2() {
+                check_binop(
+                    |a: Matrix2<$t>, b: Matrix2<$t>| a - b,
+                    |a: Matrix2<f32>, b: Matrix2<f32>| a - b,
+                    0.0,
+                );
+            }
+
+            #[test]
+            fn matrix2_transpose_mul_matrix2() {
+                check_binop(
+                    |a: Matrix2<$t>, b: Matrix2<$t>| a.tr_mul(&b),
+                    |a: Matrix2<f32>, b: Matrix2<f32>| a.tr_mul(&b),
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn matrix2_trace() {
+                check_unop(
+                    |a: Matrix2<$t>| a.trace(),
+                    |a: Matrix2<f32>| a.trace(),
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn matrix3_add_matrix3() {
+                check_binop(
+                    |a: Matrix3<$t>, b: Matrix3<$t>| a + b,
+                    |a: Matrix3<f32>, b: Matrix3<f32>| a + b,
+                    0.0,
+                );
+            }
+
+            #[test]
+            fn matrix3_sub_matrix3() {
+                check_binop(
+                    |a: Matrix3<$t>, b: Matrix3<$t>| a - b,
+                    |a: Matrix3<f32>, b: Matrix3<f32>| a - b,
+                    0.0,
+                );
+            }
+
+            #[test]
+            fn matrix3_transpose_mul_matrix3() {
+                check_binop(
+                    |a: Matrix3<$t>, b: Matrix3<$t>| a.tr_mul(&b),
+                    |a: Matrix3<f32>, b: Matrix3<f32>| a.tr_mul(&b),
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn matrix3_trace() {
+                check_unop(
+                    |a: Matrix3<$t>| a.trace(),
+                    |a: Matrix3<f32>| a.trace(),
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn matrix4_add_matrix4() {
+                check_binop(
+                    |a: Matrix4<$t>, b: Matrix4<$t>| a + b,
+                    |a: Matrix4<f32>, b: Matrix4<f32>| a + b,
+                    0.0,
+                );
+            }
+
+            #[test]
+            fn matrix4_sub_matrix4() {
+                check_binop(
+                    |a: Matrix4<$t>, b: Matrix4<$t>| a - b,
+                    |a: Matrix4<f32>, b: Matrix4<f32>| a - b,
+                    0.0,
+                );
+            }
+
+            #[test]
+            fn matrix4_transpose_mul_matrix4() {
+                check_binop(
+                    |a: Matrix4<$t>, b: Matrix4<$t>| a.tr_mul(&b),
+                    |a: Matrix4<f32>, b: Matrix4<f32>| a.tr_mul(&b),
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn matrix4_trace() {
+                check_unop(
+                    |a: Matrix4<$t>| a.trace(),
+                    |a: Matrix4<f32>| a.trace(),
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn matrix4_transform_point3() {
+                check_binop(
+                    |a: Matrix4<$t>, b: Point3<$t>| a.transform_point3(&b),
+                    |a: Matrix4<f32>, b: Point3<f32>| a.transform_point(&b),
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn vector3_aos_to_soa() {
+                check_aos_to_soa(Vector3::<$t>::from_aos_slice, |v| {
+                    Vector3::new(v.x(), v.y(), v.z())
+                });
+            }
+
+            #[test]
+            fn vector3_soa_to_aos() {
+                check_soa_to_aos(Vector3::<$t>::to_aos_slice, |v| {
+                    Vector3::new(v.x(), v.y(), v.z())
+                });
+            }
+
+            #[test]
+            fn quaternion_conjugate() {
+                check_unop(
+                    |a: UnitQuaternion<$t>| a.conjugate(),
+                    |a: UnitQuaternion<f32>| a.conjugate(),
+                    0.0,
+                );
+            }
+
+            #[test]
+            fn quaternion_mul_quaternion() {
+                check_binop(
+                    |a: UnitQuaternion<$t>, b: UnitQuaternion<$t>| a * b,
+                    |a: UnitQuaternion<f32>, b: UnitQuaternion<f32>| a * b,
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn quaternion_mul_vector3() {
+                check_binop(
+                    |a: UnitQuaternion<$t>, b: Vector3<$t>| a * b,
+                    |a: UnitQuaternion<f32>, b: Vector3<f32>| a * b,
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn rotation2_inverse() {
+                check_unop(
+                    |a: UnitComplex<$t>| a.inverse(),
+                    |a: UnitComplex<f32>| a.inverse(),
+                    0.0,
+                );
+            }
+
+            #[test]
+            fn rotation2_mul_rotation2() {
+                check_binop(
+                    |a: UnitComplex<$t>, b: UnitComplex<$t>| a * b,
+                    |a: UnitComplex<f32>, b: UnitComplex<f32>| a * b,
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn rotation2_rotate_vector2() {
+                check_binop(
+                    |a: UnitComplex<$t>, b: Vector2<$t>| a.transform_vector(&b),
+                    |a: UnitComplex<f32>, b: Vector2<f32>| a.transform_vector(&b),
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn isometry2_inverse() {
+                check_unop(
+                    |a: Isometry2<$t>| a.inverse(),
+                    |a: Isometry2<f32>| a.inverse(),
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn isometry2_mul_isometry2() {
+                check_binop(
+                    |a: Isometry2<$t>, b: Isometry2<$t>| a * b,
+                    |a: Isometry2<f32>, b: Isometry2<f32>| a * b,
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn isometry2_transform_point2() {
+                check_binop(
+                    |a: Isometry2<$t>, b: Point2<$t>| a.transform_point(&b),
+                    |a: Isometry2<f32>, b: Point2<f32>| a.transform_point(&b),
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn isometry3_inverse() {
+                check_unop(
+                    |a: Isometry3<$t>| a.inverse(),
+                    |a: Isometry3<f32>| a.inverse(),
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn isometry3_mul_isometry3() {
+                check_binop(
+                    |a: Isometry3<$t>, b: Isometry3<$t>| a * b,
+                    |a: Isometry3<f32>, b: Isometry3<f32>| a * b,
+                    1e-6,
+                );
+            }
+
+            #[test]
+            fn isometry3_transform_point3() {
+                check_binop(
+                    |a: Isometry3<$t>, b: Point3<$t>| a.transform_point(&b),
+                    |a: Isometry3<f32>, b: Point3<f32>| a.transform_point(&b),
+                    1e-6,
+                );
+            }
+        }
+    };
+}
+
+nalgebra_wide_tests!(nalgebra_f32x4, "nalgebra_f32x4", f32x4);
+nalgebra_wide_tests!(nalgebra_f32x8, "nalgebra_f32x8", f32x8);
+nalgebra_wide_tests!(nalgebra_f32x16, "nalgebra_f32x16", f32x16);
@@ -0,0 +1,130 @@
+//! A fixed workload used by `scripts/build_cost.py` to compare the build time
+//! and binary size of each library.
+//!
+//! It runs the `eulerbench` update on the types of every enabled library, so
+//! it is built with a single library feature enabled, for example:
+//!
+//! ```sh
+//! cargo build --manifest-path workload/Cargo.toml --features cgmath
+//! ```
+//!
+//! With no features enabled it only runs the update on `f32`, which is used as
+//! the baseline.
+// not every library implements `AddAssign`
+#![allow(clippy::assign_op_pattern)]
+use mathbench::BenchValue;
+
+const SIZE: usize = 1000;
+const STEPS: usize = 100;
+const UPDATE_RATE: f32 = 1.0 / 60.0;
+
+macro_rules! euler {
+    ($name:expr, $size:expr, ty => $t:ty, dt => $dt:expr) => {{
+        let mut rng = rand_pcg::Pcg64Mcg::new(0x2545_f491_4f6c_dd1d);
+        let acc: Vec<$t> = (0..$size)
+            .map(|_| <$t as BenchValue>::random_value(&mut rng))
+            .collect();
+        let mut vel: Vec<$t> = (0..$size)
+            .map(|_| <$t as BenchValue>::random_value(&mut rng))
+            .collect();
+        let mut pos: Vec<$t> = (0..$size)
+            .map(|_| <$t as BenchValue>::random_value(&mut rng))
+            .collect();
+
+        let dt = $dt;
+        for _ in 0..STEPS {
+            for ((position, acceleration), velocity) in pos.iter_mut().zip(&acc).zip(&mut vel) {
+                *velocity = *velocity + *acceleration * dt;
+                *position = *position + *velocity * dt;
+            }
+        }
+        println!("{}: {:?}", $name, pos[$size - 1]);
+    }};
+}
+
+fn main() {
+    euler!("f32 euler 1d", SIZE, ty => f32, dt => UPDATE_RATE);
+    #[cfg(feature = "glam")]
+    {
+        use glam::{Vec2, Vec3};
+        euler!("glam euler 2d", SIZE, ty => Vec2, dt => Vec2::splat(UPDATE_RATE));
+        euler!("glam euler 3d", SIZE, ty => Vec3, dt => Vec3::splat(UPDATE_RATE));
+    }
+    #[cfg(feature = "cgmath")]
+    {
+        use cgmath::{Vector2, Vector3};
+        euler!("cgmath euler 2d", SIZE, ty => Vector2<f32>, dt => UPDATE_RATE);
+        euler!("cgmath euler 3d", SIZE, ty => Vector3<f32>, dt => UPDATE_RATE);
+    }
+    #[cfg(feature = "ultraviolet")]
+    {
+        use ultraviolet::{Vec2, Vec3};
+        euler!("ultraviolet euler 2d", SIZE, ty => Vec2, dt => UPDATE_RATE);
+        euler!("ultraviolet euler 3d", SIZE, ty => Vec3, dt => UPDATE_RATE);
+    }
+    #[cfg(feature = "ultraviolet_f32x4")]
+    {
+        use ultraviolet::{f32x4, Wec2, Wec3};
+        euler!("ultraviolet_f32x4 euler 2d", SIZE / 4, ty => Wec2, dt => f32x4::from(UPDATE_RATE));
+        euler!("ultraviolet_f32x4 euler 3d", SIZE / 4, ty => Wec3, dt => f32x4::from(UPDATE_RATE));
+    }
+    #[cfg(feature = "nalgebra")]
+    {
+        use nalgebra::{Vector2, Vector3};
+        euler!("nalgebra euler 2d", SIZE, ty => Vector2<f32>, dt => UPDATE_RATE);
+        euler!("nalgebra euler 3d", SIZE, ty => Vector3<f32>, dt => UPDATE_RATE);
+    }
+    #[cfg(feature = "nalgebra_f32x4")]
+    {
+        use nalgebra::{Vector2, Vector3};
+        use simba::simd::{f32x4, SimdValue};
+        euler!("nalgebra_f32x4 euler 2d", SIZE / 4, ty => Vector2<f32x4>, dt => f32x4::splat(UPDATE_RATE));
+        euler!("nalgebra_f32x4 euler 3d", SIZE / 4, ty => Vector3<f32x4>, dt => f32x4::splat(UPDATE_RATE));
+    }
+    #[cfg(feature = "nalgebra_f32x8")]
+    {
+        use nalgebra::{Vector2, Vector3};
+        use simba::simd::{f32x8, SimdValue};
+        euler!("nalgebra_f32x8 euler 2d", SIZE / 8, ty => Vector2<f32x8>, dt => f32x8::splat(UPDATE_RATE));
+        euler!("nalgebra_f32x8 euler 3d", SIZE / 8, ty => Vector3<f32x8>, dt => f32x8::splat(UPDATE_RATE));
+    }
+    #[cfg(feature = "nalgebra_f32x16")]
+    {
+        use nalgebra::{Vector2, Vector3};
+        use simba::simd::{f32x16, SimdValue};
+        euler!("nalgebra_f32x16 euler 2d", SIZE / 16, ty => Vector2<f32x16>, dt => f32x16::splat(UPDATE_RATE));
+        euler!("nalgebra_f32x16 euler 3d", SIZE / 16, ty => Vector3<f32x16>, dt => f32x16::splat(UPDATE_RATE));
+    }
+    #[cfg(feature = "nalgebra_glm")]
+    {
+        // the glm vectors are `nalgebra` vectors, so this measures building
+        // `nalgebra-glm` on top of `nalgebra`
+        use nalgebra_glm::{Vec2, Vec3};
+        euler!("nalgebra_glm euler 2d", SIZE, ty => Vec2, dt => UPDATE_RATE);
+        euler!("nalgebra_glm euler 3d", SIZE, ty => Vec3, dt => UPDATE_RATE);
+    }
+    #[cfg(feature = "euclid")]
+    {
+        use euclid::{UnknownUnit, Vector2D, Vector3D};
+        euler!("euclid euler 2d", SIZE, ty => Vector2D<f32, UnknownUnit>, dt => UPDATE_RATE);
+        euler!("euclid euler 3d", SIZE, ty => Vector3D<f32, UnknownUnit>, dt => UPDATE_RATE);
+    }
+    #[cfg(feature = "vek")]
+    {
+        use vek::{Vec2, Vec3};
+        euler!("vek euler 2d", SIZE, ty => Vec2<f32>, dt => Vec2::broadcast(UPDATE_RATE));
+        euler!("vek euler 3d", SIZE, ty => Vec3<f32>, dt => Vec3::broadcast(UPDATE_RATE));
+    }
+    #[cfg(feature = "pathfinder_geometry")]
+    {
+        use pathfinder_geometry::vector::{Vector2F, Vector4F};
+        euler!("pathfinder euler 2d", SIZE, ty => Vector2F, dt => Vector2F::splat(UPDATE_RATE));
+        euler!("pathfinder euler 3d", SIZE, ty => Vector4F, dt => Vector4F::splat(UPDATE_RATE));
+    }
+    #[cfg(feature = "reference")]
+    {
+        use mathbench::reference::{Vec2, Vec3};
+        euler!("reference euler 2d", SIZE, ty => Vec2, dt => UPDATE_RATE);
+        euler!("reference euler 3d", SIZE, ty => Vec3, dt => UPDATE_RATE);
+    }
+}